@@ -11,7 +11,9 @@ use futures_util::AsyncReadExt;
 use std::{cell::RefCell, rc::Rc, time::Instant};
 use tokio::runtime::Runtime;
 
+mod report;
 mod shell;
+use report::ImportReport;
 use shell::{Autocomplete, Command, Shell};
 
 type Promise<T> = capnp::capability::Promise<T, capnp::Error>;
@@ -51,7 +53,10 @@ fn endpoint() -> String {
     }
 }
 pub struct Context {
+    hub: asset_hub::Client,
     snapshot: Rc<RefCell<Snapshot>>,
+    /// Set by `--json`, commands that support it print machine-readable output.
+    json_output: bool,
 }
 
 pub fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -62,6 +67,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
 
 async fn async_main() -> Result<(), Box<dyn std::error::Error>> {
     use std::net::ToSocketAddrs;
+    let json_output = std::env::args().skip(1).any(|arg| arg == "--json");
     let addr = "127.0.0.1:9999".to_socket_addrs()?.next().unwrap();
     let stream = tokio::net::TcpStream::connect(&addr).await?;
     stream.set_nodelay(true).unwrap();
@@ -92,7 +98,11 @@ async fn async_main() -> Result<(), Box<dyn std::error::Error>> {
     request.get().set_listener(listener);
 
     request.send().promise.await?;
-    let ctx = Context { snapshot };
+    let ctx = Context {
+        hub,
+        snapshot,
+        json_output,
+    };
 
     let mut shell = Shell::new(ctx);
 
@@ -101,6 +111,8 @@ async fn async_main() -> Result<(), Box<dyn std::error::Error>> {
     shell.register_command("build", CmdBuild);
     shell.register_command("path_for_asset", CmdPathForAsset);
     shell.register_command("assets_for_path", CmdAssetsForPath);
+    shell.register_command("reimport", CmdReimport);
+    shell.register_command("rebuild_all", CmdRebuildAll);
 
     shell.run_repl().await
 }
@@ -361,6 +373,87 @@ impl Command<Context> for CmdAssetsForPath {
     }
 }
 
+struct CmdReimport;
+#[async_trait(?Send)]
+impl Command<Context> for CmdReimport {
+    fn desc(&self) -> &str {
+        "<path> - Run the importer for a source file again"
+    }
+
+    fn nargs(&self) -> usize {
+        1
+    }
+
+    async fn run(&self, ctx: &Context, args: Vec<&str>) -> DynResult {
+        let mut request = ctx.snapshot.borrow().force_reimport_request();
+        request.get().set_path(args[0].as_bytes());
+        let start = Instant::now();
+        let result = request.send().promise.await;
+        let total_time = Instant::now().duration_since(start);
+        if !ctx.json_output {
+            result?;
+            println!("reimported {} in {}\r", args[0], total_time.as_secs_f32());
+            return Ok(());
+        }
+        let report = match result {
+            Ok(_) => {
+                let assets = assets_for_path(ctx, args[0]).await?;
+                ImportReport::succeeded(args[0], assets, total_time)
+            }
+            Err(e) => ImportReport::failed(args[0], e.to_string(), total_time),
+        };
+        println!("{}\r", report.to_json());
+        Ok(())
+    }
+
+    async fn autocomplete(
+        &self,
+        ctx: &Context,
+        args: Vec<&str>,
+        whitespaces_last: usize,
+    ) -> DynResult<Autocomplete> {
+        if args.len() > 1 {
+            return Ok(Autocomplete::empty());
+        }
+        autocomplete_asset_paths(ctx, args.last().copied(), whitespaces_last).await
+    }
+}
+
+struct CmdRebuildAll;
+#[async_trait(?Send)]
+impl Command<Context> for CmdRebuildAll {
+    fn desc(&self) -> &str {
+        "- Run the importers for all source files again to regenerate every artifact"
+    }
+
+    async fn run(&self, ctx: &Context, _args: Vec<&str>) -> DynResult {
+        let request = ctx.snapshot.borrow().rebuild_all_artifacts_request();
+        let start = Instant::now();
+        let response = request.send().promise.await?;
+        let source_files = response.get()?.get_source_files();
+        let total_time = Instant::now().duration_since(start);
+        println!("reimported {} source files in {}\r", source_files, total_time.as_secs_f32());
+        Ok(())
+    }
+}
+
+/// Looks up the assets of the source file at `path` in a new snapshot, since the current one
+/// may be from before the file was last imported.
+async fn assets_for_path(ctx: &Context, path: &str) -> DynResult<Vec<uuid::Uuid>> {
+    let request = ctx.hub.get_snapshot_request();
+    let snapshot = request.send().promise.await?.get()?.get_snapshot()?;
+    let mut request = snapshot.get_assets_for_paths_request();
+    request.get().init_paths(1).set(0, path.as_bytes());
+    let response = request.send().promise.await?;
+    let mut assets = Vec::new();
+    for path_assets in response.get()?.get_assets()? {
+        for asset in path_assets.get_assets()? {
+            assets.push(uuid::Uuid::from_slice(asset.get_id()?)?);
+        }
+    }
+    Ok(assets)
+}
+
 async fn autocomplete_asset_paths(
     ctx: &Context,
     starting_str: Option<&str>,
@@ -0,0 +1,71 @@
+use serde_derive::Serialize;
+use std::time::Duration;
+
+/// Outcome of reimporting one source file, printed as a single line of JSON when the CLI is
+/// started with `--json`. Build scripts parse this, so fields are only ever added, not renamed.
+#[derive(Debug, Serialize)]
+pub struct ImportReport {
+    pub path: String,
+    /// UUIDs of the assets the source file produced, empty when the import failed.
+    pub assets: Vec<String>,
+    pub success: bool,
+    pub error: Option<String>,
+    pub duration_secs: f64,
+}
+
+impl ImportReport {
+    pub fn succeeded(path: &str, assets: Vec<uuid::Uuid>, duration: Duration) -> Self {
+        Self {
+            path: path.to_owned(),
+            assets: assets.iter().map(|id| id.to_string()).collect(),
+            success: true,
+            error: None,
+            duration_secs: duration.as_secs_f64(),
+        }
+    }
+
+    pub fn failed(path: &str, error: String, duration: Duration) -> Self {
+        Self {
+            path: path.to_owned(),
+            assets: Vec::new(),
+            success: false,
+            error: Some(error),
+            duration_secs: duration.as_secs_f64(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("import report is always serializable")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn import_report_json_fields() {
+        let id = uuid::Uuid::parse_str("6c5ae1ad-ae30-471b-985b-7d017265f19f").unwrap();
+        let report =
+            ImportReport::succeeded("textures/grass.png", vec![id], Duration::from_millis(250));
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!("textures/grass.png", json["path"]);
+        assert_eq!(
+            serde_json::json!(["6c5ae1ad-ae30-471b-985b-7d017265f19f"]),
+            json["assets"]
+        );
+        assert_eq!(true, json["success"]);
+        assert!(json["error"].is_null());
+        assert_eq!(0.25, json["duration_secs"]);
+
+        let report = ImportReport::failed(
+            "textures/broken.png",
+            "invalid PNG signature".to_owned(),
+            Duration::from_millis(10),
+        );
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(false, json["success"]);
+        assert_eq!("invalid PNG signature", json["error"]);
+        assert_eq!(serde_json::json!([]), json["assets"]);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{AssetRef, AssetUuid};
 use futures_core::future::BoxFuture;
+use std::{any::Any, sync::Arc};
 
 pub trait ImporterContextHandle: Send + Sync {
     fn scope<'a>(&'a self, fut: BoxFuture<'a, ()>) -> BoxFuture<'a, ()>;
@@ -13,4 +14,9 @@ pub trait ImporterContextHandle: Send + Sync {
 
 pub trait ImporterContext: 'static + Send + Sync {
     fn handle(&self) -> Box<dyn ImporterContextHandle>;
+
+    /// Returns a service that importers can get by its type from their `ImportOp`.
+    fn service(&self) -> Option<Arc<dyn Any + Send + Sync>> {
+        None
+    }
 }
@@ -176,6 +176,34 @@ impl AssetRef {
     pub fn is_uuid(&self) -> bool {
         matches!(self, AssetRef::Uuid(_))
     }
+
+    pub fn as_uuid(&self) -> Option<&AssetUuid> {
+        match self {
+            AssetRef::Uuid(uuid) => Some(uuid),
+            AssetRef::Path(_) => None,
+        }
+    }
+
+    pub fn as_path(&self) -> Option<&std::path::Path> {
+        match self {
+            AssetRef::Uuid(_) => None,
+            AssetRef::Path(path) => Some(path),
+        }
+    }
+
+    /// Returns the UUID of the referenced asset, using `resolver` to look up path references.
+    pub fn resolve(&self, resolver: &dyn AssetRefResolver) -> Option<AssetUuid> {
+        match self {
+            AssetRef::Uuid(uuid) => Some(*uuid),
+            AssetRef::Path(path) => resolver.resolve_path(path),
+        }
+    }
+}
+
+/// Looks up the asset referenced by an [`AssetRef::Path`].
+pub trait AssetRefResolver {
+    /// Returns the UUID of the asset at `path`, if there is one.
+    fn resolve_path(&self, path: &std::path::Path) -> Option<AssetUuid>;
 }
 
 #[derive(Debug, Hash, PartialEq, Copy, Clone)]
@@ -191,6 +219,22 @@ impl Default for CompressionType {
     }
 }
 
+/// Format used to serialize an artifact's data. Loaders use it to pick the matching deserializer.
+#[derive(Debug, Hash, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SerializationFormat {
+    /// Compact, but requires the asset type to be unchanged between serializing and loading
+    Bincode,
+    /// Self-describing, tolerates added fields with `#[serde(default)]`
+    Json,
+}
+
+impl Default for SerializationFormat {
+    fn default() -> Self {
+        Self::Bincode
+    }
+}
+
 /// Serializable metadata for an asset.
 /// Stored in .meta files and metadata DB.
 #[derive(Debug, Clone, Hash, Default)]
@@ -204,6 +248,9 @@ pub struct AssetMetadata {
     pub build_pipeline: Option<AssetUuid>,
     /// The latest artifact produced when importing this asset
     pub artifact: Option<ArtifactMetadata>,
+    /// Unique name that loaders can use to refer to the asset instead of its UUID or path
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub label: Option<String>,
 }
 
 /// 64-bit hash of the inputs that would produce a given asset artifact
@@ -232,6 +279,51 @@ pub struct ArtifactMetadata {
     pub uncompressed_size: Option<u64>,
     /// The UUID of the artifact's Rust type
     pub type_id: AssetTypeId,
+    /// Hash of the artifact's serialized data. Unlike `id`, this does not depend on the asset's
+    /// identity or dependencies, so artifacts with identical data share a `content_hash`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub content_hash: u64,
+    /// Layout version of the serialized data, as reported by the importer in
+    /// `ImportedAsset::data_version`. Lets loaders migrate artifacts written by an older version.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub data_version: u32,
+    /// Format of the serialized data
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub serialization_format: SerializationFormat,
+    /// Full hash that identifies this artifact when it was produced by an `ArtifactHasher`
+    /// wider than 64 bits. The low 64 bits are equal to `id`.
+    /// Not stored in .meta files since it is recalculated on import.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub wide_id: Option<u128>,
+}
+
+impl ArtifactMetadata {
+    /// Recalculates `id` and `wide_id` from the import hash, the asset's UUID and its resolved
+    /// dependencies. Unresolved path dependencies are not part of the hash.
+    pub fn update_id(&mut self, hasher: &dyn utils::ArtifactHasher, import_hash: u64) {
+        let deps: Vec<AssetUuid> = self
+            .load_deps
+            .iter()
+            .chain(self.build_deps.iter())
+            .filter_map(|dep| dep.as_uuid().copied())
+            .collect();
+        let hash = hasher.hash_artifact(&self.asset_id, import_hash, &deps);
+        self.id = ArtifactId(hash as u64);
+        self.wide_id = if hasher.width() > 64 {
+            Some(hash)
+        } else {
+            None
+        };
+    }
+
+    /// Returns the little endian bytes of `wide_id` if set, otherwise those of `id`. These are
+    /// stored as the artifact's hash and identify it in the artifact cache.
+    pub fn hash_bytes(&self) -> Vec<u8> {
+        match self.wide_id {
+            Some(wide_id) => wide_id.to_le_bytes().to_vec(),
+            None => self.id.0.to_le_bytes().to_vec(),
+        }
+    }
 }
 
 /// Provides a unique 16-byte ID for a value's type.
@@ -2,8 +2,9 @@ use crate::{AssetTypeId, AssetUuid};
 use std::{
     ffi::OsStr,
     hash::{Hash, Hasher},
-    path::PathBuf,
+    path::{Component, Path, PathBuf},
 };
+use twox_hash::XxHash64;
 
 pub fn make_array<A, T>(slice: &[T]) -> A
 where
@@ -39,20 +40,135 @@ pub fn to_meta_path(p: &PathBuf) -> PathBuf {
     ))
 }
 
+/// Normalizes `path` lexically, without touching the filesystem. `.` components and redundant
+/// separators are dropped, and `..` removes the component before it where there is one.
+pub fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    // normal components at the end of `normalized`, which a `..` can remove
+    let mut depth = 0;
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if depth > 0 => {
+                normalized.pop();
+                depth -= 1;
+            }
+            // there is nothing above the root
+            Component::ParentDir if normalized.has_root() => {}
+            Component::Normal(_) => {
+                normalized.push(component);
+                depth += 1;
+            }
+            _ => normalized.push(component),
+        }
+    }
+    if normalized.as_os_str().is_empty() {
+        normalized.push(".");
+    }
+    normalized
+}
+
+/// Returns a fast, non-cryptographic hash of `data` that is the same across runs and platforms,
+/// so it can be persisted. Source files and serialized assets are hashed with it.
+pub fn content_hash(data: &[u8]) -> u64 {
+    let mut hasher = ContentHasher::new();
+    hasher.update(data);
+    hasher.finish()
+}
+
+/// Computes a [`content_hash`] of data that is read in chunks, without holding all of it in
+/// memory. The hash only depends on the bytes, not on how they are split into chunks.
+#[derive(Default)]
+pub struct ContentHasher(XxHash64);
+
+impl ContentHasher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the next chunk of data to the hash.
+    pub fn update(&mut self, data: &[u8]) {
+        self.0.write(data);
+    }
+
+    /// Returns the hash of the data added so far.
+    pub fn finish(&self) -> u64 {
+        self.0.finish()
+    }
+}
+
 pub fn calc_import_artifact_hash<T, V>(id: &AssetUuid, import_hash: u64, dep_list: T) -> u64
 where
     V: std::borrow::Borrow<AssetUuid>,
     T: IntoIterator<Item = V>,
 {
     let mut hasher = ::std::collections::hash_map::DefaultHasher::new();
-    import_hash.hash(&mut hasher);
-    (*id).hash(&mut hasher);
+    hash_import_artifact_inputs(&mut hasher, id, import_hash, dep_list);
+    hasher.finish()
+}
+
+fn hash_import_artifact_inputs<H, T, V>(
+    hasher: &mut H,
+    id: &AssetUuid,
+    import_hash: u64,
+    dep_list: T,
+) where
+    H: Hasher,
+    V: std::borrow::Borrow<AssetUuid>,
+    T: IntoIterator<Item = V>,
+{
+    import_hash.hash(hasher);
+    (*id).hash(hasher);
     use std::iter::FromIterator;
     let mut deps = Vec::from_iter(dep_list.into_iter());
     deps.sort_by_key(|dep| *dep.borrow());
     deps.dedup_by_key(|dep| *dep.borrow());
     for dep in &deps {
-        dep.borrow().hash(&mut hasher);
+        dep.borrow().hash(hasher);
+    }
+}
+
+/// Calculates the hash that identifies an import artifact.
+///
+/// Implementations must be deterministic across runs, since artifact hashes are persisted.
+pub trait ArtifactHasher: Send + Sync {
+    /// Returns the hash of the artifact inputs. Only the low `width()` bits may be set.
+    fn hash_artifact(&self, id: &AssetUuid, import_hash: u64, deps: &[AssetUuid]) -> u128;
+
+    /// Number of bits in the hashes returned by `hash_artifact`, either 64 or 128.
+    fn width(&self) -> u32;
+}
+
+/// 64-bit `ArtifactHasher`, equivalent to `calc_import_artifact_hash`.
+#[derive(Default)]
+pub struct DefaultArtifactHasher;
+
+impl ArtifactHasher for DefaultArtifactHasher {
+    fn hash_artifact(&self, id: &AssetUuid, import_hash: u64, deps: &[AssetUuid]) -> u128 {
+        calc_import_artifact_hash(id, import_hash, deps) as u128
+    }
+
+    fn width(&self) -> u32 {
+        64
+    }
+}
+
+/// 128-bit `ArtifactHasher` for large caches where 64-bit artifact hashes may collide.
+///
+/// Hashes the same inputs as `DefaultArtifactHasher` with 128-bit XXH3. The `ArtifactId` is the
+/// low 64 bits of the hash, while the artifact cache is keyed on all 128 bits.
+#[derive(Default)]
+pub struct WideArtifactHasher;
+
+impl ArtifactHasher for WideArtifactHasher {
+    fn hash_artifact(&self, id: &AssetUuid, import_hash: u64, deps: &[AssetUuid]) -> u128 {
+        use twox_hash::xxh3::HasherExt;
+        let mut hasher = twox_hash::xxh3::Hash128::default();
+        hash_import_artifact_inputs(&mut hasher, id, import_hash, deps);
+        hasher.finish_ext()
+    }
+
+    fn width(&self) -> u32 {
+        128
     }
-    hasher.finish()
 }
@@ -0,0 +1,51 @@
+extern crate atelier_core;
+
+use atelier_core::{
+    utils::{DefaultArtifactHasher, WideArtifactHasher},
+    ArtifactMetadata, AssetRef, AssetUuid,
+};
+
+fn artifact() -> ArtifactMetadata {
+    ArtifactMetadata {
+        asset_id: AssetUuid([1; 16]),
+        load_deps: vec![AssetRef::Uuid(AssetUuid([2; 16]))],
+        build_deps: vec![AssetRef::Uuid(AssetUuid([3; 16]))],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn default_hasher_has_no_wide_id() {
+    let mut metadata = artifact();
+    metadata.update_id(&DefaultArtifactHasher, 42);
+
+    assert_eq!(
+        metadata.id.0,
+        atelier_core::utils::calc_import_artifact_hash(
+            &metadata.asset_id,
+            42,
+            &[AssetUuid([2; 16]), AssetUuid([3; 16])]
+        )
+    );
+    assert!(metadata.wide_id.is_none());
+}
+
+#[test]
+fn wide_hasher_sets_128_bit_id() {
+    let mut narrow = artifact();
+    narrow.update_id(&DefaultArtifactHasher, 42);
+    let mut wide = artifact();
+    wide.update_id(&WideArtifactHasher, 42);
+
+    let wide_id = wide.wide_id.expect("expected 128-bit hash");
+    assert_eq!(wide_id as u64, wide.id.0);
+    // the id is taken from the wide hash rather than the 64-bit one
+    assert_ne!(wide.id.0, narrow.id.0);
+    assert_eq!(wide.hash_bytes(), wide_id.to_le_bytes().to_vec());
+    assert_eq!(narrow.hash_bytes(), narrow.id.0.to_le_bytes().to_vec());
+    assert_ne!(wide_id >> 64, 0);
+
+    let mut other = artifact();
+    other.update_id(&WideArtifactHasher, 43);
+    assert_ne!(other.wide_id, wide.wide_id);
+}
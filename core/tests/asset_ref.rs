@@ -0,0 +1,51 @@
+extern crate atelier_core;
+
+use atelier_core::{AssetRef, AssetRefResolver, AssetUuid};
+use std::path::{Path, PathBuf};
+
+struct SingleAsset;
+impl AssetRefResolver for SingleAsset {
+    fn resolve_path(&self, path: &Path) -> Option<AssetUuid> {
+        if path == Path::new("asset.txt") {
+            Some(AssetUuid([1; 16]))
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn uuid_ref_accessors() {
+    let asset_ref = AssetRef::Uuid(AssetUuid([2; 16]));
+
+    assert_eq!(Some(&AssetUuid([2; 16])), asset_ref.as_uuid());
+    assert_eq!(None, asset_ref.as_path());
+    assert!(asset_ref.is_uuid());
+    assert!(!asset_ref.is_path());
+}
+
+#[test]
+fn path_ref_accessors() {
+    let asset_ref = AssetRef::Path(PathBuf::from("asset.txt"));
+
+    assert_eq!(None, asset_ref.as_uuid());
+    assert_eq!(Some(Path::new("asset.txt")), asset_ref.as_path());
+    assert!(asset_ref.is_path());
+    assert!(!asset_ref.is_uuid());
+}
+
+#[test]
+fn resolve_uses_resolver_for_paths_only() {
+    assert_eq!(
+        Some(AssetUuid([2; 16])),
+        AssetRef::Uuid(AssetUuid([2; 16])).resolve(&SingleAsset)
+    );
+    assert_eq!(
+        Some(AssetUuid([1; 16])),
+        AssetRef::Path(PathBuf::from("asset.txt")).resolve(&SingleAsset)
+    );
+    assert_eq!(
+        None,
+        AssetRef::Path(PathBuf::from("missing.txt")).resolve(&SingleAsset)
+    );
+}
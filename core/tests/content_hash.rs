@@ -0,0 +1,23 @@
+extern crate atelier_core;
+
+use atelier_core::utils::{content_hash, ContentHasher};
+
+#[test]
+fn content_hash_is_stable() {
+    // content hashes are persisted, so they must not change between runs or releases
+    assert_eq!(0xef46_db37_51d8_e999, content_hash(b""));
+    assert_eq!(0x44bc_2cf5_ad77_0999, content_hash(b"abc"));
+}
+
+#[test]
+fn streamed_content_hash_ignores_chunking() {
+    let data = (0..1024).map(|i| i as u8).collect::<Vec<_>>();
+    for chunk_size in &[1, 7, 32, 100, 1024] {
+        let mut hasher = ContentHasher::new();
+        for chunk in data.chunks(*chunk_size) {
+            hasher.update(chunk);
+        }
+        assert_eq!(0x6f39_14f1_8fe4_df57, hasher.finish());
+    }
+    assert_eq!(0x6f39_14f1_8fe4_df57, content_hash(&data));
+}
@@ -0,0 +1,32 @@
+extern crate atelier_core;
+
+use atelier_core::utils::normalize_path;
+use std::path::{Path, PathBuf};
+
+fn normalize(path: &str) -> PathBuf {
+    normalize_path(Path::new(path))
+}
+
+#[test]
+fn parent_dir_removes_previous_component() {
+    assert_eq!(PathBuf::from("bar.png"), normalize("./foo/../bar.png"));
+    assert_eq!(
+        PathBuf::from("/assets/bar.png"),
+        normalize("/assets/foo/../bar.png")
+    );
+    assert_eq!(PathBuf::from("../bar.png"), normalize("foo/../../bar.png"));
+    assert_eq!(PathBuf::from("/bar.png"), normalize("/../bar.png"));
+}
+
+#[test]
+fn current_dir_is_dropped() {
+    assert_eq!(PathBuf::from("foo/bar.png"), normalize("./foo/./bar.png"));
+    assert_eq!(PathBuf::from("."), normalize("./"));
+    assert_eq!(PathBuf::from("."), normalize("foo/.."));
+}
+
+#[test]
+fn redundant_separators_are_dropped() {
+    assert_eq!(PathBuf::from("foo/bar.png"), normalize("foo//bar.png"));
+    assert_eq!(PathBuf::from("/foo/bar"), normalize("/foo///bar/"));
+}
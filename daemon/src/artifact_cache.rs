@@ -1,68 +1,285 @@
 use crate::capnp_db::{DBTransaction, Environment, MessageReader, RoTransaction, RwTransaction};
 use crate::error::Result;
+use atelier_core::utils;
 use atelier_importer::SerializedAsset;
 use atelier_schema::{build_artifact_metadata, data::artifact};
-use std::sync::Arc;
+use lmdb::Cursor;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 pub struct ArtifactCache {
     db: Arc<Environment>,
     tables: ArtifactCacheTables,
+    /// Total size of the cached artifacts in bytes, above which `evict` removes artifacts
+    max_size: Option<u64>,
+    /// Access times of the artifacts read since the last `evict`, which stores them
+    pending_accesses: Mutex<HashMap<Vec<u8>, u64>>,
+    /// The latest access time handed out, which keeps access times increasing
+    last_access: AtomicU64,
 }
 
 struct ArtifactCacheTables {
-    /// Maps a hash to the serialized artifact data
-    /// u64 -> Artifact
-    hash_to_artifact: lmdb::Database,
+    /// Maps a cache key to the serialized artifact data
+    /// CacheKey -> Artifact
+    key_to_artifact: lmdb::Database,
+    /// Maps a cache key to the time the artifact was last inserted or read, in nanoseconds since
+    /// the Unix epoch
+    /// CacheKey -> u64
+    key_to_access: lmdb::Database,
+    /// Holds the total size of the artifacts in `key_to_artifact` in bytes, if it is known
+    /// "total_size" -> u64
+    info: lmdb::Database,
+}
+
+const TOTAL_SIZE_KEY: &[u8] = b"total_size";
+
+/// Returns the key of an artifact in the cache: the artifact's full hash, 8 or 16 bytes, followed
+/// by the version of the importer that produced it, so artifacts of an older importer are not
+/// served after an upgrade.
+fn cache_key(hash: &[u8], importer_version: u32) -> Vec<u8> {
+    let mut key = Vec::with_capacity(hash.len() + 4);
+    key.extend_from_slice(hash);
+    key.extend_from_slice(&importer_version.to_le_bytes());
+    key
 }
 
 impl ArtifactCache {
     pub fn new(db: &Arc<Environment>) -> Result<ArtifactCache> {
+        Self::new_with_max_size(db, None)
+    }
+
+    /// Creates a cache whose least recently accessed artifacts are removed by `evict` once the
+    /// artifacts take up more than `max_size` bytes.
+    pub fn new_with_max_size(
+        db: &Arc<Environment>,
+        max_size: Option<u64>,
+    ) -> Result<ArtifactCache> {
+        // keys are no longer integers since they hold 128-bit hashes, so the integer keyed
+        // tables of earlier versions are replaced by differently named ones
+        for old_table in &[
+            "ArtifactCache::hash_to_artifact",
+            "ArtifactCache::hash_to_access",
+        ] {
+            if db.drop_db(old_table)? {
+                log::info!(
+                    "dropped artifact cache table {} of an earlier version",
+                    old_table
+                );
+            }
+        }
         Ok(ArtifactCache {
             db: db.clone(),
             tables: ArtifactCacheTables {
-                hash_to_artifact: db.create_db(
-                    Some("ArtifactCache::hash_to_artifact"),
-                    lmdb::DatabaseFlags::INTEGER_KEY,
+                key_to_artifact: db.create_db(
+                    Some("ArtifactCache::key_to_artifact"),
+                    lmdb::DatabaseFlags::default(),
+                )?,
+                key_to_access: db.create_db(
+                    Some("ArtifactCache::key_to_access"),
+                    lmdb::DatabaseFlags::default(),
                 )?,
+                info: db.create_db(Some("ArtifactCache::info"), lmdb::DatabaseFlags::default())?,
             },
+            max_size,
+            pending_accesses: Mutex::new(HashMap::new()),
+            last_access: AtomicU64::new(0),
         })
     }
 
     // TODO: invalidate cache
     #[allow(dead_code)]
-    pub async fn delete(&self, hash: u64) -> Result<bool> {
-        let mut txn = self.db.rw_txn().await?;
+    pub async fn delete(&self, hash: &[u8], importer_version: u32) -> Result<bool> {
+        let key = cache_key(hash, importer_version);
+        self.db
+            .write(|txn| {
+                let size = self.artifact_size(txn, &key)?;
+                txn.delete(self.tables.key_to_access, &key)?;
+                let deleted = txn.delete(self.tables.key_to_artifact, &key)?;
+                self.update_total_size(txn, 0, size)?;
+                Ok(deleted)
+            })
+            .await
+    }
+
+    /// Returns the size of the stored artifact with the cache key `key`, or 0 if there is none.
+    fn artifact_size(&self, txn: &RwTransaction<'_>, key: &[u8]) -> Result<u64> {
+        Ok(txn
+            .get_as_bytes(self.tables.key_to_artifact, &key)?
+            .map_or(0, |artifact| artifact.len() as u64))
+    }
+
+    fn total_size(&self, txn: &RwTransaction<'_>) -> Result<Option<u64>> {
         Ok(txn
-            .delete(self.tables.hash_to_artifact, &hash.to_le_bytes())
-            .expect("db: Failed to delete entry from hash_to_artifact table"))
+            .get_as_bytes(self.tables.info, &TOTAL_SIZE_KEY)?
+            .map(|size| u64::from_le_bytes(utils::make_array(size))))
+    }
+
+    /// Adds `added` bytes to the stored total size and removes `removed` bytes from it. Nothing
+    /// is stored while the total size is unknown, since `evict` computes it then.
+    fn update_total_size(
+        &self,
+        txn: &mut RwTransaction<'_>,
+        added: u64,
+        removed: u64,
+    ) -> Result<()> {
+        if let Some(total_size) = self.total_size(txn)? {
+            let total_size = (total_size + added).saturating_sub(removed);
+            txn.put_bytes(self.tables.info, &TOTAL_SIZE_KEY, &total_size.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    fn access_time(&self) -> u64 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_nanos() as u64)
+            .unwrap_or(0);
+        let mut last = self.last_access.load(Ordering::Relaxed);
+        loop {
+            let time = now.max(last + 1);
+            match self.last_access.compare_exchange_weak(
+                last,
+                time,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return time,
+                Err(actual) => last = actual,
+            }
+        }
+    }
+
+    /// Stores the access times of the artifacts read since the last call, then removes the least
+    /// recently inserted or read artifacts until the cache fits in its maximum size. Returns the
+    /// number of removed artifacts.
+    pub fn evict(&self, txn: &mut RwTransaction<'_>) -> Result<usize> {
+        let max_size = match self.max_size {
+            Some(max_size) => max_size,
+            None => return Ok(0),
+        };
+        let accesses: Vec<(Vec<u8>, u64)> = self.pending_accesses.lock().unwrap().drain().collect();
+        for (key, access) in accesses {
+            // the artifact may have been removed since it was read
+            if txn
+                .get_as_bytes(self.tables.key_to_artifact, &key)?
+                .is_some()
+            {
+                txn.put_bytes(self.tables.key_to_access, &key, &access.to_le_bytes())?;
+            }
+        }
+
+        // the artifacts are only scanned when the cache is over its maximum size, or once to
+        // compute the total size of caches written before it was stored
+        let mut total_size = match self.total_size(txn)? {
+            Some(total_size) => total_size,
+            None => {
+                let mut total_size = 0;
+                for entry in txn
+                    .open_ro_cursor(self.tables.key_to_artifact)?
+                    .iter_start()
+                {
+                    let (_, value) = entry?;
+                    total_size += value.len() as u64;
+                }
+                txn.put_bytes(self.tables.info, &TOTAL_SIZE_KEY, &total_size.to_le_bytes())?;
+                total_size
+            }
+        };
+        if total_size <= max_size {
+            return Ok(0);
+        }
+
+        let mut artifacts = Vec::new();
+        for entry in txn
+            .open_ro_cursor(self.tables.key_to_artifact)?
+            .iter_start()
+        {
+            let (key, value) = entry?;
+            artifacts.push((key.to_vec(), value.len() as u64));
+        }
+        let mut artifacts = artifacts
+            .into_iter()
+            .map(|(key, size)| {
+                // artifacts cached before there was a maximum size have no access time
+                let access = txn
+                    .get_as_bytes(self.tables.key_to_access, &key)?
+                    .map_or(0, |access| u64::from_le_bytes(utils::make_array(access)));
+                Ok((access, key, size))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        artifacts.sort();
+
+        let mut evicted = 0;
+        for (_, key, size) in artifacts {
+            if total_size <= max_size {
+                break;
+            }
+            txn.delete(self.tables.key_to_artifact, &key)?;
+            txn.delete(self.tables.key_to_access, &key)?;
+            total_size -= size;
+            evicted += 1;
+        }
+        txn.put_bytes(self.tables.info, &TOTAL_SIZE_KEY, &total_size.to_le_bytes())?;
+        log::debug!("evicted {} artifacts from the artifact cache", evicted);
+        Ok(evicted)
     }
 
     pub fn insert<T: AsRef<[u8]>>(
         &self,
         txn: &mut RwTransaction<'_>,
         artifact: &SerializedAsset<T>,
-    ) {
+        importer_version: u32,
+    ) -> Result<()> {
+        let key = cache_key(&artifact.metadata.hash_bytes(), importer_version);
+        let replaced_size = self.artifact_size(txn, &key)?;
         txn.put(
-            self.tables.hash_to_artifact,
-            &artifact.metadata.id.0.to_le_bytes(),
+            self.tables.key_to_artifact,
+            &key,
             &build_artifact_message(artifact),
-        )
-        .expect("lmdb: failed to put path ref");
+        )?;
+        let size = self.artifact_size(txn, &key)?;
+        self.update_total_size(txn, size, replaced_size)?;
+        if self.max_size.is_some() {
+            txn.put_bytes(
+                self.tables.key_to_access,
+                &key,
+                &self.access_time().to_le_bytes(),
+            )?;
+        }
+        Ok(())
     }
     pub async fn ro_txn(&self) -> Result<RoTransaction<'_>> {
         self.db.ro_txn().await
     }
-    pub async fn rw_txn(&self) -> Result<RwTransaction<'_>> {
-        self.db.rw_txn().await
+    /// Runs `f` in a write transaction of the cache, see `Environment::write`.
+    pub async fn write<F, R>(&self, f: F) -> Result<R>
+    where
+        F: FnMut(&mut RwTransaction<'_>) -> Result<R>,
+    {
+        self.db.write(f).await
     }
 
     pub async fn get<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
         &self,
         txn: &'a V,
-        hash: u64,
+        hash: &[u8],
+        importer_version: u32,
     ) -> Option<MessageReader<'a, artifact::Owned>> {
-        txn.get::<artifact::Owned, _>(self.tables.hash_to_artifact, &hash.to_le_bytes())
-            .expect("db: Failed to get entry from hash_to_artifact table")
+        let key = cache_key(hash, importer_version);
+        let artifact = txn
+            .get::<artifact::Owned, _>(self.tables.key_to_artifact, &key)
+            .expect("db: Failed to get entry from key_to_artifact table");
+        if artifact.is_some() && self.max_size.is_some() {
+            let access = self.access_time();
+            self.pending_accesses.lock().unwrap().insert(key, access);
+        }
+        artifact
     }
 
     // pub fn get_or_insert_with<'a, T: AsRef<[u8]>>(
@@ -94,3 +311,185 @@ fn build_artifact_message<T: AsRef<[u8]>>(
     }
     value_builder
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use atelier_core::{ArtifactId, ArtifactMetadata};
+
+    fn artifact(hash: u64) -> SerializedAsset<Vec<u8>> {
+        SerializedAsset {
+            metadata: ArtifactMetadata {
+                id: ArtifactId(hash),
+                ..Default::default()
+            },
+            data: vec![0; 1000],
+        }
+    }
+
+    fn hash(hash: u64) -> [u8; 8] {
+        hash.to_le_bytes()
+    }
+
+    #[test]
+    fn least_recently_accessed_artifacts_are_evicted() {
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 22).unwrap());
+        // room for two artifacts with their metadata, but not for three
+        let cache = ArtifactCache::new_with_max_size(&db, Some(3000)).unwrap();
+
+        runtime.block_on(async {
+            for hash in 1..=2 {
+                let evicted = cache
+                    .write(|txn| {
+                        cache.insert(txn, &artifact(hash), 1)?;
+                        cache.evict(txn)
+                    })
+                    .await
+                    .unwrap();
+                assert_eq!(0, evicted);
+            }
+            // reading the first artifact leaves the second one least recently accessed
+            {
+                let txn = cache.ro_txn().await.unwrap();
+                assert!(cache.get(&txn, &hash(1), 1).await.is_some());
+            }
+
+            let evicted = cache
+                .write(|txn| {
+                    cache.insert(txn, &artifact(3), 1)?;
+                    cache.evict(txn)
+                })
+                .await
+                .unwrap();
+            assert_eq!(1, evicted);
+
+            let txn = cache.ro_txn().await.unwrap();
+            assert!(cache.get(&txn, &hash(1), 1).await.is_some());
+            assert!(cache.get(&txn, &hash(2), 1).await.is_none());
+            assert!(cache.get(&txn, &hash(3), 1).await.is_some());
+        });
+    }
+
+    #[test]
+    fn total_size_is_kept_up_to_date() {
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 22).unwrap());
+        let cache = ArtifactCache::new_with_max_size(&db, Some(3000)).unwrap();
+        let scanned_size = |txn: &RwTransaction<'_>| {
+            txn.open_ro_cursor(cache.tables.key_to_artifact)
+                .unwrap()
+                .iter_start()
+                .map(|entry| entry.unwrap().1.len() as u64)
+                .sum::<u64>()
+        };
+
+        runtime.block_on(async {
+            cache
+                .write(|txn| {
+                    cache.insert(txn, &artifact(1), 1)?;
+                    // the total size is computed once by the first eviction
+                    assert_eq!(None, cache.total_size(txn)?);
+                    cache.evict(txn)?;
+                    assert_eq!(Some(scanned_size(txn)), cache.total_size(txn)?);
+                    Ok(())
+                })
+                .await
+                .unwrap();
+            for hash in 1..=3 {
+                cache
+                    .write(|txn| {
+                        cache.insert(txn, &artifact(hash), 1)?;
+                        assert_eq!(Some(scanned_size(txn)), cache.total_size(txn)?);
+                        cache.evict(txn)?;
+                        assert_eq!(Some(scanned_size(txn)), cache.total_size(txn)?);
+                        Ok(())
+                    })
+                    .await
+                    .unwrap();
+            }
+            cache.delete(&hash(3), 1).await.unwrap();
+            let total_size = cache
+                .write(|txn| Ok((scanned_size(txn), cache.total_size(txn)?)))
+                .await
+                .unwrap();
+            assert_eq!(Some(total_size.0), total_size.1);
+        });
+    }
+
+    #[test]
+    fn artifacts_of_another_importer_version_are_not_returned() {
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 22).unwrap());
+        let cache = ArtifactCache::new(&db).unwrap();
+
+        runtime.block_on(async {
+            cache
+                .write(|txn| cache.insert(txn, &artifact(1), 1))
+                .await
+                .unwrap();
+            {
+                let txn = cache.ro_txn().await.unwrap();
+                assert!(cache.get(&txn, &hash(1), 1).await.is_some());
+                assert!(cache.get(&txn, &hash(1), 2).await.is_none());
+            }
+
+            cache
+                .write(|txn| cache.insert(txn, &artifact(1), 2))
+                .await
+                .unwrap();
+            let txn = cache.ro_txn().await.unwrap();
+            assert!(cache.get(&txn, &hash(1), 2).await.is_some());
+        });
+    }
+
+    #[test]
+    fn wide_artifacts_are_keyed_on_their_full_hash() {
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 22).unwrap());
+        let cache = ArtifactCache::new(&db).unwrap();
+        // both artifacts have the same 64-bit id, but differ in the high bits of their hash
+        let first = 1u128 << 64 | 1;
+        let second = 2u128 << 64 | 1;
+
+        runtime.block_on(async {
+            let mut wide = artifact(1);
+            wide.metadata.wide_id = Some(first);
+            cache
+                .write(|txn| cache.insert(txn, &wide, 1))
+                .await
+                .unwrap();
+
+            let txn = cache.ro_txn().await.unwrap();
+            assert!(cache.get(&txn, &first.to_le_bytes(), 1).await.is_some());
+            assert!(cache.get(&txn, &second.to_le_bytes(), 1).await.is_none());
+            assert!(cache.get(&txn, &hash(1), 1).await.is_none());
+        });
+    }
+
+    #[test]
+    fn tables_of_earlier_versions_are_dropped() {
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 22).unwrap());
+        let old_table = db
+            .create_db(
+                Some("ArtifactCache::hash_to_artifact"),
+                lmdb::DatabaseFlags::INTEGER_KEY,
+            )
+            .unwrap();
+        runtime.block_on(async {
+            db.write(|txn| txn.put_bytes(old_table, &1u64.to_le_bytes(), &[0u8; 1000]))
+                .await
+                .unwrap();
+        });
+
+        let _cache = ArtifactCache::new(&db).unwrap();
+        assert!(!db.drop_db("ArtifactCache::hash_to_artifact").unwrap());
+        assert!(!db.drop_db("ArtifactCache::hash_to_access").unwrap());
+    }
+}
@@ -1,7 +1,6 @@
 use crate::capnp_db::{CapnpCursor, DBTransaction, Environment, MessageReader, RwTransaction};
 use crate::error::{Error, Result};
-use async_channel::Sender;
-use atelier_core::{utils, AssetRef, AssetUuid};
+use atelier_core::{utils, ArtifactMetadata, AssetRef, AssetUuid};
 use atelier_importer::AssetMetadata;
 use atelier_schema::{
     build_asset_metadata_message,
@@ -9,11 +8,13 @@ use atelier_schema::{
         self, asset_change_log_entry,
         asset_metadata::{self, latest_artifact},
     },
-    parse_db_asset_ref,
+    parse_db_asset_ref, parse_db_metadata,
 };
+use event_listener::Event;
 use std::{
-    collections::{HashMap, HashSet, VecDeque},
+    collections::{hash_map::DefaultHasher, HashMap, HashSet, VecDeque},
     hash::{Hash, Hasher},
+    path::{Path, PathBuf},
     sync::{
         atomic::{AtomicU64, Ordering},
         Arc, Mutex,
@@ -26,7 +27,9 @@ pub struct AssetHub {
     // db: Arc<Environment>,
     tables: AssetHubTables,
     id_gen: AtomicU64,
-    listeners: Mutex<HashMap<ListenerID, Sender<AssetBatchEvent>>>,
+    listeners: Mutex<HashMap<ListenerID, Arc<ListenerQueue>>>,
+    /// Maps a source file path to the error from its most recent failed import
+    import_errors: Mutex<HashMap<PathBuf, String>>,
 }
 
 struct AssetContentUpdateEvent {
@@ -40,21 +43,199 @@ enum ChangeEvent {
     Remove(AssetUuid),
 }
 
-#[derive(Debug)]
+/// How an asset was affected by a batch of updates.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AssetChange {
+    /// The asset is new, imported from the source file at the path
+    Added(AssetUuid, PathBuf),
+    /// The asset or one of its build dependencies changed
+    Changed(AssetUuid),
+    Removed(AssetUuid),
+}
+
+impl AssetChange {
+    pub fn id(&self) -> &AssetUuid {
+        match self {
+            AssetChange::Added(id, _) | AssetChange::Changed(id) | AssetChange::Removed(id) => id,
+        }
+    }
+}
+
+/// Hashes of the parts of an asset's metadata. Clients keep the version of the metadata they
+/// last received to ask for the parts that changed since.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AssetMetadataVersion {
+    pub search_tags: u64,
+    pub build_pipeline: u64,
+    pub artifact: u64,
+    pub label: u64,
+}
+
+impl AssetMetadataVersion {
+    pub fn of(metadata: &AssetMetadata) -> Self {
+        fn hash_of<T: Hash>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+        Self {
+            search_tags: hash_of(&metadata.search_tags),
+            build_pipeline: hash_of(&metadata.build_pipeline),
+            artifact: hash_of(&metadata.artifact),
+            label: hash_of(&metadata.label),
+        }
+    }
+}
+
+/// The parts of an asset's metadata that differ from a version known to a client. Parts that
+/// did not change are `None`.
+#[derive(Debug, Clone)]
+pub struct AssetMetadataDiff {
+    pub id: AssetUuid,
+    /// Version of the current metadata
+    pub version: AssetMetadataVersion,
+    pub search_tags: Option<Vec<(String, Option<String>)>>,
+    pub build_pipeline: Option<Option<AssetUuid>>,
+    pub artifact: Option<Option<ArtifactMetadata>>,
+    pub label: Option<Option<String>>,
+}
+
+#[derive(Debug, Clone)]
+pub enum AssetMetadataChange {
+    Changed(AssetMetadataDiff),
+    Removed(AssetUuid),
+}
+
+#[derive(Debug, Clone)]
 pub enum AssetBatchEvent {
     Commit,
+    /// Importing the source file at the path failed with the message
+    ImportError(PathBuf, String),
+    /// The assets that were affected by the committed batch
+    AssetChanges(Vec<AssetChange>),
+}
+
+/// Events that a listener has not taken yet
+#[derive(Default)]
+struct PendingEvents {
+    commit: bool,
+    /// The most recent import error of each source file
+    import_errors: HashMap<PathBuf, String>,
+    /// The most recent change of each asset
+    asset_changes: Vec<AssetChange>,
+    closed: bool,
+}
+
+impl PendingEvents {
+    fn push(&mut self, event: AssetBatchEvent) {
+        match event {
+            AssetBatchEvent::Commit => self.commit = true,
+            AssetBatchEvent::ImportError(path, message) => {
+                self.import_errors.insert(path, message);
+            }
+            AssetBatchEvent::AssetChanges(changes) => {
+                for change in changes {
+                    let id = *change.id();
+                    match self.asset_changes.iter_mut().find(|c| *c.id() == id) {
+                        // the listener has not seen the asset yet, so it is still added
+                        Some(AssetChange::Added(..)) if change == AssetChange::Changed(id) => {}
+                        Some(pending) => *pending = change,
+                        None => self.asset_changes.push(change),
+                    }
+                }
+            }
+        }
+    }
+
+    fn take(&mut self) -> Vec<AssetBatchEvent> {
+        let mut events = Vec::new();
+        if std::mem::replace(&mut self.commit, false) {
+            events.push(AssetBatchEvent::Commit);
+        }
+        if !self.asset_changes.is_empty() {
+            events.push(AssetBatchEvent::AssetChanges(std::mem::take(
+                &mut self.asset_changes,
+            )));
+        }
+        events.extend(
+            self.import_errors
+                .drain()
+                .map(|(path, message)| AssetBatchEvent::ImportError(path, message)),
+        );
+        events
+    }
+}
+
+/// The events of a listener registered with [`AssetHub::register_listener`].
+///
+/// Events that arrive before the listener takes them are merged: commits into one, import errors
+/// into the most recent error of each source file and asset changes into the most recent change
+/// of each asset. A slow listener therefore never misses an event or holds more than one pending
+/// event per asset and source file, and it is never dropped for falling behind.
+pub struct ListenerQueue {
+    pending: Mutex<PendingEvents>,
+    ready: Event,
+}
+
+impl ListenerQueue {
+    fn push(&self, event: AssetBatchEvent) {
+        self.pending.lock().unwrap().push(event);
+        self.ready.notify(1);
+    }
+
+    fn close(&self) {
+        self.pending.lock().unwrap().closed = true;
+        self.ready.notify(1);
+    }
+
+    /// Returns the pending events without waiting, like [`recv`].
+    ///
+    /// [`recv`]: ListenerQueue::recv
+    #[cfg(test)]
+    pub fn try_recv(&self) -> Vec<AssetBatchEvent> {
+        self.pending.lock().unwrap().take()
+    }
+
+    /// Waits until there are pending events and returns them in the order commit, asset changes,
+    /// import errors, or returns `None` once the listener was dropped.
+    pub async fn recv(&self) -> Option<Vec<AssetBatchEvent>> {
+        loop {
+            let ready = self.ready.listen();
+            {
+                let mut pending = self.pending.lock().unwrap();
+                if pending.closed {
+                    return None;
+                }
+                let events = pending.take();
+                if !events.is_empty() {
+                    return Some(events);
+                }
+            }
+            ready.await;
+        }
+    }
 }
 
 pub struct ChangeBatch {
     content_changes: Vec<AssetUuid>,
+    /// Assets that had no metadata before the batch
+    added: HashSet<AssetUuid>,
+    asset_paths: HashMap<AssetUuid, PathBuf>,
 }
 
 impl ChangeBatch {
     pub fn new() -> ChangeBatch {
         ChangeBatch {
             content_changes: Vec::new(),
+            added: HashSet::new(),
+            asset_paths: HashMap::new(),
         }
     }
+
+    /// Records the source file of an updated asset, which is reported when the asset is added.
+    pub fn set_asset_path(&mut self, id: AssetUuid, path: PathBuf) {
+        self.asset_paths.insert(id, path);
+    }
 }
 
 struct AssetHubTables {
@@ -67,6 +248,12 @@ struct AssetHubTables {
     /// Maps a SequenceNum to a AssetChangeLogEntry
     /// SequenceNum -> AssetChangeLogEntry
     asset_changes: lmdb::Database,
+    /// Maps a search tag key to the assets that have a search tag with that key
+    /// Key -> [AssetUuid]
+    search_tags: lmdb::Database,
+    /// Maps an asset label to the asset that has it
+    /// Label -> AssetUuid
+    labels: lmdb::Database,
 }
 
 fn add_asset_changelog_entry(
@@ -112,6 +299,25 @@ fn add_asset_changelog_entry(
     Ok(())
 }
 
+fn search_tag_keys(
+    metadata: &MessageReader<'_, asset_metadata::Owned>,
+) -> Result<HashSet<Vec<u8>>> {
+    let mut keys = HashSet::new();
+    for tag in metadata.get()?.get_search_tags()? {
+        keys.insert(tag.get_key()?.to_vec());
+    }
+    Ok(keys)
+}
+
+fn asset_label(metadata: &MessageReader<'_, asset_metadata::Owned>) -> Result<Option<Vec<u8>>> {
+    let metadata = metadata.get()?;
+    if metadata.has_label() && !metadata.get_label()?.is_empty() {
+        Ok(Some(metadata.get_label()?.to_vec()))
+    } else {
+        Ok(None)
+    }
+}
+
 impl AssetHub {
     pub fn new(db: Arc<Environment>) -> Result<AssetHub> {
         Ok(AssetHub {
@@ -122,9 +328,12 @@ impl AssetHub {
                     .create_db(Some("build_dep_reverse"), lmdb::DatabaseFlags::default())?,
                 asset_changes: db
                     .create_db(Some("asset_changes"), lmdb::DatabaseFlags::INTEGER_KEY)?,
+                search_tags: db.create_db(Some("search_tags"), lmdb::DatabaseFlags::default())?,
+                labels: db.create_db(Some("labels"), lmdb::DatabaseFlags::default())?,
             },
             id_gen: AtomicU64::new(1),
             listeners: Mutex::new(HashMap::new()),
+            import_errors: Mutex::new(HashMap::new()),
         })
     }
 
@@ -136,6 +345,42 @@ impl AssetHub {
         Ok(cursor)
     }
 
+    /// Returns an iterator over all assets in the hub and their metadata.
+    /// Entries are read lazily from the cursor and are valid for the lifetime of `txn`.
+    pub fn iter_metadata<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
+        &self,
+        txn: &'a V,
+    ) -> impl Iterator<Item = (AssetUuid, MessageReader<'a, asset_metadata::Owned>)> {
+        self.iter_metadata_after(txn, None)
+    }
+
+    /// Like [`iter_metadata`](AssetHub::iter_metadata), but starts after the asset `after`, so
+    /// that the assets can be read in pages. Assets are ordered by their `AssetUuid`.
+    pub fn iter_metadata_after<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
+        &self,
+        txn: &'a V,
+        after: Option<&AssetUuid>,
+    ) -> impl Iterator<Item = (AssetUuid, MessageReader<'a, asset_metadata::Owned>)> {
+        let cursor = txn
+            .open_ro_cursor(self.tables.asset_metadata)
+            .expect("db: Failed to open ro cursor for asset_metadata table");
+        let iter = match after {
+            Some(after) => cursor.capnp_iter_from(after),
+            None => cursor.capnp_iter_start(),
+        };
+        let after = after.copied();
+        iter.filter_map(move |(key, value)| {
+            let id = utils::uuid_from_slice(key)?;
+            if Some(id) == after {
+                return None;
+            }
+            let value = value
+                .map_err(|err| log::error!("failed to read asset metadata {}: {}", id, err))
+                .ok()?;
+            Some((id, value.into_typed::<asset_metadata::Owned>()))
+        })
+    }
+
     pub fn get_metadata<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
         &self,
         txn: &'a V,
@@ -153,6 +398,378 @@ impl AssetHub {
         Ok(txn.get::<data::asset_uuid_list::Owned, _>(self.tables.build_dep_reverse, &id)?)
     }
 
+    /// Returns the UUID load dependencies of the asset's latest artifact.
+    fn get_load_deps<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
+        &self,
+        txn: &'a V,
+        id: &AssetUuid,
+    ) -> Result<Vec<AssetUuid>> {
+        let mut deps = Vec::new();
+        if let Some(metadata) = self.get_metadata(txn, id) {
+            if let latest_artifact::Artifact(Ok(artifact)) =
+                metadata.get()?.get_latest_artifact().which()?
+            {
+                for dep in artifact.get_load_deps()? {
+                    if let Some(uuid) = parse_db_asset_ref(&dep).as_uuid() {
+                        deps.push(*uuid);
+                    }
+                }
+            }
+        }
+        Ok(deps)
+    }
+
+    /// Returns the UUID build dependencies of the asset's latest artifact.
+    fn get_build_deps<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
+        &self,
+        txn: &'a V,
+        id: &AssetUuid,
+    ) -> Result<Vec<AssetUuid>> {
+        let mut deps = Vec::new();
+        if let Some(metadata) = self.get_metadata(txn, id) {
+            if let latest_artifact::Artifact(Ok(artifact)) =
+                metadata.get()?.get_latest_artifact().which()?
+            {
+                for dep in artifact.get_build_deps()? {
+                    if let Some(uuid) = parse_db_asset_ref(&dep).as_uuid() {
+                        deps.push(*uuid);
+                    }
+                }
+            }
+        }
+        Ok(deps)
+    }
+
+    /// Returns the metadata of the assets and of all assets they depend on to build, for tools
+    /// that show the build graph. The assets come first in the order of `ids`, followed by their
+    /// build dependencies in breadth-first order. Each asset is returned once, assets without
+    /// metadata are skipped.
+    pub fn get_metadata_with_build_deps<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
+        &self,
+        txn: &'a V,
+        ids: &[AssetUuid],
+    ) -> Result<Vec<(AssetUuid, MessageReader<'a, asset_metadata::Owned>)>> {
+        let mut visited = HashSet::new();
+        let mut metadatas = Vec::new();
+        for id in ids {
+            if visited.insert(*id) {
+                if let Some(metadata) = self.get_metadata(txn, id) {
+                    metadatas.push((*id, metadata));
+                }
+            }
+        }
+        let mut next = 0;
+        while next < metadatas.len() {
+            let id = metadatas[next].0;
+            for dep in self.get_build_deps(txn, &id)? {
+                if visited.insert(dep) {
+                    if let Some(metadata) = self.get_metadata(txn, &dep) {
+                        metadatas.push((dep, metadata));
+                    }
+                }
+            }
+            next += 1;
+        }
+        Ok(metadatas)
+    }
+
+    /// Returns the metadata of the assets and of their direct load dependencies. The order only
+    /// depends on the inputs: the assets in the order of `ids`, followed by the dependencies in the
+    /// order they are listed. Each asset is returned once, assets without metadata are skipped.
+    pub fn get_metadata_with_load_deps<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
+        &self,
+        txn: &'a V,
+        ids: &[AssetUuid],
+    ) -> Result<Vec<(AssetUuid, MessageReader<'a, asset_metadata::Owned>)>> {
+        let mut visited = HashSet::new();
+        let mut metadatas = Vec::new();
+        for id in ids {
+            if visited.insert(*id) {
+                if let Some(metadata) = self.get_metadata(txn, id) {
+                    metadatas.push((*id, metadata));
+                }
+            }
+        }
+        let requested = metadatas.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+        for id in requested {
+            for dep in self.get_load_deps(txn, &id)? {
+                if visited.insert(dep) {
+                    if let Some(metadata) = self.get_metadata(txn, &dep) {
+                        metadatas.push((dep, metadata));
+                    }
+                }
+            }
+        }
+        Ok(metadatas)
+    }
+
+    /// Returns the cycles of load dependencies that the assets `ids` are part of or depend on.
+    /// Each asset is visited once, so a cycle that is reachable from several of the assets is
+    /// returned once. Each cycle starts with its smallest `AssetUuid`, and each asset has a load
+    /// dependency on the next one, the last one on the first.
+    pub fn find_load_dep_cycles<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
+        &self,
+        txn: &'a V,
+        ids: &[AssetUuid],
+    ) -> Result<Vec<Vec<AssetUuid>>> {
+        let mut cycles = Vec::new();
+        let mut finished = HashSet::new();
+        let mut on_path = HashSet::new();
+        for id in ids {
+            if finished.contains(id) || !on_path.insert(*id) {
+                continue;
+            }
+            // depth-first search, each entry is an asset on the current path and its unvisited
+            // deps
+            let mut path = vec![(*id, self.get_load_deps(txn, id)?)];
+            while let Some((_, deps)) = path.last_mut() {
+                match deps.pop() {
+                    Some(dep) if on_path.contains(&dep) => {
+                        let start = path.iter().position(|(id, _)| *id == dep).unwrap();
+                        let mut cycle = path[start..].iter().map(|(id, _)| *id).collect::<Vec<_>>();
+                        let first = (0..cycle.len()).min_by_key(|idx| cycle[*idx]).unwrap();
+                        cycle.rotate_left(first);
+                        cycles.push(cycle);
+                    }
+                    Some(dep) => {
+                        if !finished.contains(&dep) {
+                            on_path.insert(dep);
+                            let deps = self.get_load_deps(txn, &dep)?;
+                            path.push((dep, deps));
+                        }
+                    }
+                    None => {
+                        let (id, _) = path.pop().unwrap();
+                        on_path.remove(&id);
+                        finished.insert(id);
+                    }
+                }
+            }
+        }
+        Ok(cycles)
+    }
+
+    /// Warns about cycles of load dependencies and missing dependencies of the changed assets.
+    fn check_dependencies(
+        &self,
+        txn: &RwTransaction<'_>,
+        content_changes: &[AssetUuid],
+    ) -> Result<()> {
+        for cycle in self.find_load_dep_cycles(txn, content_changes)? {
+            let path = cycle
+                .iter()
+                .chain(cycle.first())
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>();
+            log::warn!("cyclic load dependencies: {}", path.join(" -> "));
+        }
+        let mut checked = HashSet::new();
+        for id in content_changes {
+            if checked.insert(*id) {
+                for missing in self.find_missing_deps(txn, id)? {
+                    log::warn!("asset {} references missing dependency {}", id, missing);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the load and build dependencies of the asset that no asset has metadata for, in
+    /// the order they are listed, load dependencies first. Loading or building the asset fails
+    /// on them.
+    pub fn find_missing_deps<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
+        &self,
+        txn: &'a V,
+        id: &AssetUuid,
+    ) -> Result<Vec<AssetUuid>> {
+        let mut deps = self.get_load_deps(txn, id)?;
+        deps.extend(self.get_build_deps(txn, id)?);
+        let mut seen = HashSet::new();
+        deps.retain(|dep| seen.insert(*dep) && self.get_metadata(txn, dep).is_none());
+        Ok(deps)
+    }
+
+    /// Returns the assets that have a search tag with `key`, and if `value` is set, with that
+    /// value. Assets are returned in the order their tag was first indexed.
+    pub fn search_assets<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
+        &self,
+        txn: &'a V,
+        key: &str,
+        value: Option<&str>,
+    ) -> Result<Vec<AssetUuid>> {
+        let mut assets = self.get_search_tag_assets(txn, key.as_bytes())?;
+        if let Some(value) = value {
+            let mut matching = Vec::new();
+            for id in assets {
+                if let Some(metadata) = self.get_metadata(txn, &id) {
+                    for tag in metadata.get()?.get_search_tags()? {
+                        if tag.get_key()? == key.as_bytes()
+                            && tag.has_value()
+                            && tag.get_value()? == value.as_bytes()
+                        {
+                            matching.push(id);
+                            break;
+                        }
+                    }
+                }
+            }
+            assets = matching;
+        }
+        Ok(assets)
+    }
+
+    fn get_search_tag_assets<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
+        &self,
+        txn: &'a V,
+        key: &[u8],
+    ) -> Result<Vec<AssetUuid>> {
+        let mut assets = Vec::new();
+        if key.is_empty() {
+            // lmdb does not allow empty keys, so tags with an empty key are not indexed
+            return Ok(assets);
+        }
+        if let Some(list) =
+            txn.get::<data::asset_uuid_list::Owned, _>(self.tables.search_tags, &key)?
+        {
+            for uuid in list.get()?.get_list()? {
+                assets.push(utils::uuid_from_slice(uuid.get_id()?).ok_or(Error::UuidLength)?);
+            }
+        }
+        Ok(assets)
+    }
+
+    /// Returns the asset that has `label`, if any.
+    pub fn get_asset_for_label<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
+        &self,
+        txn: &'a V,
+        label: &str,
+    ) -> Result<Option<AssetUuid>> {
+        if label.is_empty() {
+            return Ok(None);
+        }
+        match txn.get_as_bytes(self.tables.labels, &label.as_bytes())? {
+            Some(id) => Ok(Some(utils::uuid_from_slice(id).ok_or(Error::UuidLength)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns how the metadata of assets differs from the versions a client last received, so
+    /// editors can apply small metadata changes without fetching the whole metadata again.
+    /// Assets whose metadata is unchanged are left out, and assets without a known version are
+    /// returned in full.
+    pub fn get_metadata_changes<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
+        &self,
+        txn: &'a V,
+        known_versions: &[(AssetUuid, Option<AssetMetadataVersion>)],
+    ) -> Result<Vec<AssetMetadataChange>> {
+        let mut changes = Vec::new();
+        for (id, known) in known_versions {
+            let metadata = match self.get_metadata(txn, id) {
+                Some(metadata) => parse_db_metadata(&metadata.get()?),
+                None => {
+                    if known.is_some() {
+                        changes.push(AssetMetadataChange::Removed(*id));
+                    }
+                    continue;
+                }
+            };
+            let version = AssetMetadataVersion::of(&metadata);
+            if *known == Some(version) {
+                continue;
+            }
+            let changed = |part: fn(&AssetMetadataVersion) -> u64| {
+                known.as_ref().map(part) != Some(part(&version))
+            };
+            changes.push(AssetMetadataChange::Changed(AssetMetadataDiff {
+                id: *id,
+                version,
+                search_tags: Some(metadata.search_tags).filter(|_| changed(|v| v.search_tags)),
+                build_pipeline: Some(metadata.build_pipeline)
+                    .filter(|_| changed(|v| v.build_pipeline)),
+                artifact: Some(metadata.artifact).filter(|_| changed(|v| v.artifact)),
+                label: Some(metadata.label).filter(|_| changed(|v| v.label)),
+            }));
+        }
+        Ok(changes)
+    }
+
+    /// Takes the current label away from `id` unless it is `label`, so that another asset that
+    /// changes together with it can take the label over before `id` itself is updated.
+    pub(crate) fn release_label(
+        &self,
+        txn: &mut RwTransaction<'_>,
+        id: &AssetUuid,
+        label: Option<&str>,
+    ) -> Result<()> {
+        let existing_label = match self.get_metadata(txn, id) {
+            Some(metadata) => asset_label(&metadata)?,
+            None => None,
+        };
+        let label = label
+            .filter(|label| !label.is_empty())
+            .map(|label| label.as_bytes().to_vec());
+        if existing_label != label {
+            self.update_label_index(txn, id, None, existing_label.as_ref())?;
+        }
+        Ok(())
+    }
+
+    /// Moves `id` from the `removed` label to the `added` one, which must not belong to another
+    /// asset.
+    fn update_label_index(
+        &self,
+        txn: &mut RwTransaction<'_>,
+        id: &AssetUuid,
+        added: Option<&Vec<u8>>,
+        removed: Option<&Vec<u8>>,
+    ) -> Result<()> {
+        if added == removed {
+            return Ok(());
+        }
+        if let Some(label) = removed {
+            let owner = txn
+                .get_as_bytes(self.tables.labels, label)?
+                .and_then(utils::uuid_from_slice);
+            if owner.as_ref() == Some(id) {
+                txn.delete(self.tables.labels, label)?;
+            }
+        }
+        if let Some(label) = added {
+            txn.put_bytes(self.tables.labels, label, &id.0)?;
+        }
+        Ok(())
+    }
+
+    /// Adds `id` to the index entries of the `added` tag keys and removes it from the `removed`
+    /// ones.
+    fn update_search_tag_index(
+        &self,
+        txn: &mut RwTransaction<'_>,
+        id: &AssetUuid,
+        added: &[&Vec<u8>],
+        removed: &[&Vec<u8>],
+    ) -> Result<()> {
+        for key in added.iter().chain(removed).filter(|key| !key.is_empty()) {
+            let mut assets = self.get_search_tag_assets(txn, key)?;
+            assets.retain(|asset| asset != id);
+            if added.contains(key) {
+                assets.push(*id);
+            }
+            if assets.is_empty() {
+                txn.delete(self.tables.search_tags, key)?;
+            } else {
+                let mut value_builder = capnp::message::Builder::new_default();
+                let value = value_builder.init_root::<data::asset_uuid_list::Builder<'_>>();
+                let mut list = value.init_list(assets.len() as u32);
+                for (idx, uuid) in assets.iter().enumerate() {
+                    list.reborrow().get(idx as u32).set_id(&uuid.0);
+                }
+                txn.put(self.tables.search_tags, key, &value_builder)?;
+            }
+        }
+        Ok(())
+    }
+
     fn put_build_deps_reverse(
         &self,
         txn: &mut RwTransaction<'_>,
@@ -169,6 +786,8 @@ impl AssetHub {
         Ok(())
     }
 
+    /// Writes the metadata of an asset and updates the indexes. Returns [`Error::LabelInUse`]
+    /// without writing anything if another asset has the label of `metadata`.
     pub fn update_asset(
         &self,
         txn: &mut RwTransaction<'_>,
@@ -176,8 +795,35 @@ impl AssetHub {
         source: data::AssetSource,
         change_batch: &mut ChangeBatch,
     ) -> Result<()> {
+        if let Some(label) = &metadata.label {
+            let owner = self.get_asset_for_label(txn, label)?;
+            if let Some(owner) = owner.filter(|owner| *owner != metadata.id) {
+                return Err(Error::LabelInUse(label.clone(), owner));
+            }
+        }
         let existing_metadata: Option<MessageReader<'_, asset_metadata::Owned>> =
             txn.get(self.tables.asset_metadata, &metadata.id)?;
+        if existing_metadata.is_none() {
+            change_batch.added.insert(metadata.id);
+        }
+        let existing_tags = match &existing_metadata {
+            Some(existing_metadata) => search_tag_keys(existing_metadata)?,
+            None => HashSet::new(),
+        };
+        let existing_label = match &existing_metadata {
+            Some(existing_metadata) => asset_label(existing_metadata)?,
+            None => None,
+        };
+        let new_label = metadata
+            .label
+            .as_ref()
+            .filter(|label| !label.is_empty())
+            .map(|label| label.as_bytes().to_vec());
+        let new_tags: HashSet<Vec<u8>> = metadata
+            .search_tags
+            .iter()
+            .map(|(key, _)| key.as_bytes().to_vec())
+            .collect();
         let new_metadata = build_asset_metadata_message::<&[u8; 8]>(&metadata, source);
         let mut deps_to_delete = Vec::new();
         let mut deps_to_add = Vec::new();
@@ -188,8 +834,10 @@ impl AssetHub {
                 let latest_artifact = existing_metadata.get_latest_artifact();
                 let mut existing_deps = HashSet::new();
                 if let latest_artifact::Artifact(Ok(artifact)) = latest_artifact.which()? {
-                    artifact_changed =
-                        artifact_metadata.id.0.to_le_bytes() != artifact.get_hash()?;
+                    artifact_changed = match artifact_metadata.wide_id {
+                        Some(wide_id) => wide_id.to_le_bytes() != artifact.get_hash()?,
+                        None => artifact_metadata.id.0.to_le_bytes() != artifact.get_hash()?,
+                    };
                     for dep in artifact.get_build_deps()? {
                         let dep = *parse_db_asset_ref(&dep).expect_uuid();
                         existing_deps.insert(dep);
@@ -237,6 +885,18 @@ impl AssetHub {
             }
         }
         txn.put(self.tables.asset_metadata, &metadata.id, &new_metadata)?;
+        self.update_search_tag_index(
+            txn,
+            &metadata.id,
+            &new_tags.difference(&existing_tags).collect::<Vec<_>>(),
+            &existing_tags.difference(&new_tags).collect::<Vec<_>>(),
+        )?;
+        self.update_label_index(
+            txn,
+            &metadata.id,
+            new_label.as_ref(),
+            existing_label.as_ref(),
+        )?;
         if artifact_changed {
             change_batch.content_changes.push(metadata.id);
         }
@@ -251,7 +911,11 @@ impl AssetHub {
     ) -> Result<()> {
         let metadata = self.get_metadata(txn, id);
         let mut deps_to_delete = Vec::new();
+        let mut tags = HashSet::new();
+        let mut label = None;
         if let Some(metadata) = metadata {
+            tags = search_tag_keys(&metadata)?;
+            label = asset_label(&metadata)?;
             let metadata = metadata.get()?;
             if let latest_artifact::Artifact(Ok(artifact)) =
                 metadata.get_latest_artifact().which()?
@@ -264,6 +928,8 @@ impl AssetHub {
         if txn.delete(self.tables.asset_metadata, &id)? {
             change_batch.content_changes.push(*id);
         }
+        self.update_search_tag_index(txn, id, &[], &tags.iter().collect::<Vec<_>>())?;
+        self.update_label_index(txn, id, None, label.as_ref())?;
         for dep in deps_to_delete {
             let mut dependees = Vec::new();
             if let Some(existing_list) = self.get_build_deps_reverse(txn, &dep)? {
@@ -285,17 +951,22 @@ impl AssetHub {
         Ok(())
     }
 
+    /// Writes change log entries for the assets affected by the batch and returns how each of
+    /// them changed.
     pub fn add_changes(
         &self,
         txn: &mut RwTransaction<'_>,
         change_batch: ChangeBatch,
-    ) -> Result<bool> {
+    ) -> Result<Vec<AssetChange>> {
         // TODO find the set of all changed assets, check the build dependency index and emit changes for all
         // assets that have changed and all the assets where the build_dep_hash has changed.
         // dedupe change events
+        // the whole batch has been applied, so dependencies between its assets are resolved
+        self.check_dependencies(txn, &change_batch.content_changes)?;
         let mut to_check = VecDeque::new();
         let mut affected_assets = HashSet::new();
         let mut events = Vec::new();
+        let mut changes = Vec::new();
         for id in change_batch.content_changes {
             to_check.push_back(id);
         }
@@ -363,8 +1034,19 @@ impl AssetHub {
                     import_hash: Some(import_hash),
                     build_dep_hash: Some(Vec::from(&build_dep_hash.to_le_bytes() as &[u8])),
                 }));
+                if change_batch.added.contains(&asset) {
+                    let path = change_batch
+                        .asset_paths
+                        .get(&asset)
+                        .cloned()
+                        .unwrap_or_default();
+                    changes.push(AssetChange::Added(asset, path));
+                } else {
+                    changes.push(AssetChange::Changed(asset));
+                }
             } else {
                 events.push(ChangeEvent::Remove(asset));
+                changes.push(AssetChange::Removed(asset));
             }
         }
         if !events.is_empty() {
@@ -373,7 +1055,7 @@ impl AssetHub {
         for event in events.iter() {
             add_asset_changelog_entry(&self.tables, txn, event)?;
         }
-        Ok(!events.is_empty())
+        Ok(changes)
     }
 
     pub fn get_latest_asset_change<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
@@ -399,26 +1081,778 @@ impl AssetHub {
         Ok(cursor)
     }
 
-    pub fn notify_listeners(&self) {
-        let listeners = &mut *self.listeners.lock().unwrap();
-        let mut to_remove = Vec::new();
-        for (id, listener) in listeners.iter_mut() {
-            if listener.try_send(AssetBatchEvent::Commit).is_err() {
-                to_remove.push(*id);
-            }
+    /// Returns the change log entries with a sequence number greater than `since` in order,
+    /// so a client that reconnects can replay the changes it missed.
+    pub fn get_asset_changes_since<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
+        &self,
+        txn: &'a V,
+        since: u64,
+    ) -> Result<impl Iterator<Item = (u64, MessageReader<'a, asset_change_log_entry::Owned>)>> {
+        let start = since.saturating_add(1);
+        Ok(self
+            .get_asset_changes_iter(txn)?
+            .capnp_iter_from(&start.to_le_bytes())
+            .filter_map(|(key, value)| {
+                let seq = u64::from_le_bytes(utils::make_array(key));
+                let value = value
+                    .map_err(|err| log::error!("failed to read asset change {}: {}", seq, err))
+                    .ok()?;
+                Some((seq, value.into_typed::<asset_change_log_entry::Owned>()))
+            }))
+    }
+
+    /// Notifies listeners that a batch was committed, followed by the changes returned by
+    /// [`add_changes`](AssetHub::add_changes) for it.
+    pub fn notify_listeners(&self, changes: Vec<AssetChange>) {
+        self.send_event(AssetBatchEvent::Commit);
+        if !changes.is_empty() {
+            self.send_event(AssetBatchEvent::AssetChanges(changes));
         }
-        for id in to_remove {
-            listeners.remove(&id);
+    }
+
+    fn send_event(&self, event: AssetBatchEvent) {
+        for listener in self.listeners.lock().unwrap().values() {
+            listener.push(event.clone());
         }
     }
 
-    pub fn register_listener(&self, listener: Sender<AssetBatchEvent>) -> ListenerID {
+    /// Records that importing the source file at `path` failed and notifies listeners.
+    pub fn report_import_error(&self, path: PathBuf, message: String) {
+        self.import_errors
+            .lock()
+            .unwrap()
+            .insert(path.clone(), message.clone());
+        self.send_event(AssetBatchEvent::ImportError(path, message));
+    }
+
+    /// Forgets the recorded import error for `path`, if any.
+    pub fn clear_import_error(&self, path: &Path) {
+        self.import_errors.lock().unwrap().remove(path);
+    }
+
+    /// Returns the recorded errors of source files whose most recent import failed.
+    pub fn get_import_errors(&self) -> Vec<(PathBuf, String)> {
+        self.import_errors
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(path, message)| (path.clone(), message.clone()))
+            .collect()
+    }
+
+    /// Registers a listener for committed batches and import errors. Its queue starts with a
+    /// commit and the recorded import errors, so the listener starts from the current state.
+    pub fn register_listener(&self) -> (ListenerID, Arc<ListenerQueue>) {
         let id = self.id_gen.fetch_add(1, Ordering::Relaxed);
-        self.listeners.lock().unwrap().insert(id, listener);
-        id
+        let queue = Arc::new(ListenerQueue {
+            pending: Mutex::new(PendingEvents::default()),
+            ready: Event::new(),
+        });
+        queue.push(AssetBatchEvent::Commit);
+        // hold the listeners while reading the errors, so none reported in between is missed
+        let mut listeners = self.listeners.lock().unwrap();
+        for (path, message) in self.get_import_errors() {
+            queue.push(AssetBatchEvent::ImportError(path, message));
+        }
+        listeners.insert(id, queue.clone());
+        (id, queue)
     }
 
-    pub fn drop_listener(&self, listener: ListenerID) -> Option<Sender<AssetBatchEvent>> {
-        self.listeners.lock().unwrap().remove(&listener)
+    /// Removes a listener. Waiting in [`ListenerQueue::recv`] returns `None` afterwards.
+    pub fn drop_listener(&self, listener: ListenerID) {
+        if let Some(queue) = self.listeners.lock().unwrap().remove(&listener) {
+            queue.close();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use atelier_core::{ArtifactId, ArtifactMetadata, CompressionType};
+    use atelier_schema::parse_db_metadata;
+
+    fn test_metadata(id: u8) -> AssetMetadata {
+        let asset_id = AssetUuid([id; 16]);
+        AssetMetadata {
+            id: asset_id,
+            search_tags: Vec::new(),
+            build_pipeline: None,
+            artifact: Some(ArtifactMetadata {
+                id: ArtifactId(id as u64),
+                asset_id,
+                build_deps: Vec::new(),
+                load_deps: Vec::new(),
+                compression: CompressionType::None,
+                compressed_size: None,
+                uncompressed_size: None,
+                type_id: Default::default(),
+                content_hash: 0,
+                data_version: 0,
+                serialization_format: Default::default(),
+                wide_id: None,
+            }),
+            label: None,
+        }
+    }
+
+    #[test]
+    fn iter_metadata_enumerates_assets() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 21).unwrap());
+        let hub = AssetHub::new(db.clone()).unwrap();
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let mut txn = db.rw_txn().await.unwrap();
+            let mut change_batch = ChangeBatch::new();
+            for id in 1..=3 {
+                hub.update_asset(
+                    &mut txn,
+                    &test_metadata(id),
+                    data::AssetSource::File,
+                    &mut change_batch,
+                )
+                .unwrap();
+            }
+            txn.commit().unwrap();
+
+            let txn = db.ro_txn().await.unwrap();
+            let mut assets = hub
+                .iter_metadata(&txn)
+                .map(|(id, metadata)| {
+                    let metadata = parse_db_metadata(&metadata.get().unwrap());
+                    assert_eq!(id, metadata.id);
+                    (id, metadata.artifact.unwrap().id.0)
+                })
+                .collect::<Vec<_>>();
+            assets.sort_by_key(|(_, artifact_id)| *artifact_id);
+            assert_eq!(
+                assets,
+                vec![
+                    (AssetUuid([1; 16]), 1),
+                    (AssetUuid([2; 16]), 2),
+                    (AssetUuid([3; 16]), 3)
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn iter_metadata_pages_and_skips_corrupt_entries() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 21).unwrap());
+        let hub = AssetHub::new(db.clone()).unwrap();
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            add_assets(&hub, &db, &[1, 3, 4]).await;
+            let mut txn = db.rw_txn().await.unwrap();
+            txn.put_bytes(hub.tables.asset_metadata, &[2u8; 16], &[0xffu8; 3])
+                .unwrap();
+            txn.commit().unwrap();
+
+            let txn = db.ro_txn().await.unwrap();
+            let ids = |after: Option<&AssetUuid>| {
+                hub.iter_metadata_after(&txn, after)
+                    .map(|(id, _)| id.0[0])
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(vec![1, 3, 4], ids(None));
+            assert_eq!(vec![3, 4], ids(Some(&AssetUuid([1; 16]))));
+            assert_eq!(vec![3, 4], ids(Some(&AssetUuid([2; 16]))));
+            assert_eq!(Vec::<u8>::new(), ids(Some(&AssetUuid([4; 16]))));
+        });
+    }
+
+    async fn add_assets(hub: &AssetHub, db: &Environment, ids: &[u8]) {
+        let mut txn = db.rw_txn().await.unwrap();
+        let mut change_batch = ChangeBatch::new();
+        for id in ids {
+            hub.update_asset(
+                &mut txn,
+                &test_metadata(*id),
+                data::AssetSource::File,
+                &mut change_batch,
+            )
+            .unwrap();
+        }
+        hub.add_changes(&mut txn, change_batch).unwrap();
+        txn.commit().unwrap();
+    }
+
+    #[test]
+    fn changes_since_only_returns_newer_changes() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 21).unwrap());
+        let hub = AssetHub::new(db.clone()).unwrap();
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            add_assets(&hub, &db, &[1, 2]).await;
+            // a client disconnects after seeing the first changes and reconnects later
+            let last_seen = hub
+                .get_latest_asset_change(&db.ro_txn().await.unwrap())
+                .unwrap();
+            add_assets(&hub, &db, &[3]).await;
+
+            let txn = db.ro_txn().await.unwrap();
+            assert_eq!(2, last_seen);
+            let changes = hub
+                .get_asset_changes_since(&txn, last_seen)
+                .unwrap()
+                .map(|(seq, entry)| {
+                    let entry = entry.get().unwrap();
+                    assert_eq!(seq, entry.get_num());
+                    match entry.get_event().unwrap().which().unwrap() {
+                        data::asset_change_event::ContentUpdateEvent(evt) => {
+                            let id = evt.unwrap().get_id().unwrap().get_id().unwrap();
+                            (seq, utils::uuid_from_slice(id).unwrap())
+                        }
+                        data::asset_change_event::RemoveEvent(_) => panic!("unexpected remove"),
+                    }
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(changes, vec![(3, AssetUuid([3; 16]))]);
+            assert_eq!(0, hub.get_asset_changes_since(&txn, 3).unwrap().count());
+        });
+    }
+
+    #[test]
+    fn asset_changes_distinguish_added_changed_and_removed() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 21).unwrap());
+        let hub = AssetHub::new(db.clone()).unwrap();
+        let (_, queue) = hub.register_listener();
+        let id = AssetUuid([1; 16]);
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let mut txn = db.rw_txn().await.unwrap();
+            let mut change_batch = ChangeBatch::new();
+            hub.update_asset(
+                &mut txn,
+                &test_metadata(1),
+                data::AssetSource::File,
+                &mut change_batch,
+            )
+            .unwrap();
+            change_batch.set_asset_path(id, PathBuf::from("a.txt"));
+            let added = hub.add_changes(&mut txn, change_batch).unwrap();
+            txn.commit().unwrap();
+            assert_eq!(vec![AssetChange::Added(id, PathBuf::from("a.txt"))], added);
+
+            let mut txn = db.rw_txn().await.unwrap();
+            let mut change_batch = ChangeBatch::new();
+            let mut metadata = test_metadata(1);
+            metadata.artifact.as_mut().unwrap().id = ArtifactId(2);
+            hub.update_asset(
+                &mut txn,
+                &metadata,
+                data::AssetSource::File,
+                &mut change_batch,
+            )
+            .unwrap();
+            let changed = hub.add_changes(&mut txn, change_batch).unwrap();
+            txn.commit().unwrap();
+            assert_eq!(vec![AssetChange::Changed(id)], changed);
+
+            let mut txn = db.rw_txn().await.unwrap();
+            let mut change_batch = ChangeBatch::new();
+            hub.remove_asset(&mut txn, &id, &mut change_batch).unwrap();
+            let removed = hub.add_changes(&mut txn, change_batch).unwrap();
+            txn.commit().unwrap();
+            assert_eq!(vec![AssetChange::Removed(id)], removed);
+
+            hub.notify_listeners(removed);
+            let mut events = queue.try_recv().into_iter();
+            assert!(matches!(events.next(), Some(AssetBatchEvent::Commit)));
+            match events.next() {
+                Some(AssetBatchEvent::AssetChanges(changes)) => {
+                    assert_eq!(vec![AssetChange::Removed(id)], changes)
+                }
+                event => panic!("unexpected event {:?}", event),
+            }
+        });
+    }
+
+    #[test]
+    fn events_for_slow_listeners_are_merged() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 21).unwrap());
+        let hub = AssetHub::new(db).unwrap();
+        hub.report_import_error(PathBuf::from("a.txt"), "first".to_string());
+        let (_, queue) = hub.register_listener();
+        let added = AssetUuid([1; 16]);
+        let changed = AssetUuid([2; 16]);
+        for idx in 0..100 {
+            hub.notify_listeners(vec![
+                AssetChange::Added(added, PathBuf::from("b.txt")),
+                AssetChange::Changed(changed),
+            ]);
+            hub.notify_listeners(vec![AssetChange::Changed(added)]);
+            hub.report_import_error(PathBuf::from("a.txt"), format!("error {}", idx));
+        }
+
+        let mut events = queue.try_recv().into_iter();
+        assert!(matches!(events.next(), Some(AssetBatchEvent::Commit)));
+        match events.next() {
+            Some(AssetBatchEvent::AssetChanges(changes)) => assert_eq!(
+                vec![
+                    AssetChange::Added(added, PathBuf::from("b.txt")),
+                    AssetChange::Changed(changed)
+                ],
+                changes
+            ),
+            event => panic!("unexpected event {:?}", event),
+        }
+        match events.next() {
+            Some(AssetBatchEvent::ImportError(path, message)) => {
+                assert_eq!(PathBuf::from("a.txt"), path);
+                assert_eq!("error 99", message);
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+        assert!(events.next().is_none());
+
+        // the listener is still registered after falling behind
+        hub.notify_listeners(Vec::new());
+        assert!(matches!(
+            queue.try_recv().as_slice(),
+            [AssetBatchEvent::Commit]
+        ));
+    }
+
+    #[test]
+    fn finds_cyclic_load_deps() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 21).unwrap());
+        let hub = AssetHub::new(db.clone()).unwrap();
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let with_load_deps = |id: u8, deps: &[u8]| {
+            let mut metadata = test_metadata(id);
+            metadata.artifact.as_mut().unwrap().load_deps = deps
+                .iter()
+                .map(|dep| AssetRef::Uuid(AssetUuid([*dep; 16])))
+                .collect();
+            metadata
+        };
+        runtime.block_on(async {
+            let mut txn = db.rw_txn().await.unwrap();
+            let mut change_batch = ChangeBatch::new();
+            // 1 and 2 depend on each other, 3 depends on the cycle without being part of it
+            // and 4 has no dependencies
+            for metadata in &[
+                with_load_deps(1, &[2]),
+                with_load_deps(2, &[1]),
+                with_load_deps(3, &[1]),
+                with_load_deps(4, &[]),
+            ] {
+                hub.update_asset(
+                    &mut txn,
+                    metadata,
+                    data::AssetSource::File,
+                    &mut change_batch,
+                )
+                .unwrap();
+            }
+            hub.add_changes(&mut txn, change_batch).unwrap();
+            txn.commit().unwrap();
+
+            let txn = db.ro_txn().await.unwrap();
+            let cycles = |ids: &[u8]| {
+                let ids = ids
+                    .iter()
+                    .map(|id| AssetUuid([*id; 16]))
+                    .collect::<Vec<_>>();
+                hub.find_load_dep_cycles(&txn, &ids).unwrap()
+            };
+            let cycle = vec![AssetUuid([1; 16]), AssetUuid([2; 16])];
+            // the cycle starts with its smallest id, wherever the search entered it
+            assert_eq!(vec![cycle.clone()], cycles(&[1]));
+            assert_eq!(vec![cycle.clone()], cycles(&[2]));
+            assert_eq!(vec![cycle.clone()], cycles(&[3]));
+            assert!(cycles(&[4]).is_empty());
+            // the cycle is found once, however many of the assets reach it
+            assert_eq!(vec![cycle], cycles(&[1, 2, 3, 4]));
+        });
+    }
+
+    #[test]
+    fn finds_missing_deps() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 21).unwrap());
+        let hub = AssetHub::new(db.clone()).unwrap();
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let mut txn = db.rw_txn().await.unwrap();
+            let mut change_batch = ChangeBatch::new();
+            // 1 depends on 2, which exists, and on 3 and 4, which were never imported
+            let mut metadata = test_metadata(1);
+            let artifact = metadata.artifact.as_mut().unwrap();
+            artifact.load_deps = vec![
+                AssetRef::Uuid(AssetUuid([2; 16])),
+                AssetRef::Uuid(AssetUuid([3; 16])),
+            ];
+            artifact.build_deps = vec![
+                AssetRef::Uuid(AssetUuid([3; 16])),
+                AssetRef::Uuid(AssetUuid([4; 16])),
+            ];
+            for metadata in &[metadata, test_metadata(2)] {
+                hub.update_asset(
+                    &mut txn,
+                    metadata,
+                    data::AssetSource::File,
+                    &mut change_batch,
+                )
+                .unwrap();
+            }
+            hub.add_changes(&mut txn, change_batch).unwrap();
+            txn.commit().unwrap();
+
+            let txn = db.ro_txn().await.unwrap();
+            assert_eq!(
+                vec![AssetUuid([3; 16]), AssetUuid([4; 16])],
+                hub.find_missing_deps(&txn, &AssetUuid([1; 16])).unwrap()
+            );
+            assert!(hub
+                .find_missing_deps(&txn, &AssetUuid([2; 16]))
+                .unwrap()
+                .is_empty());
+        });
+    }
+
+    #[test]
+    fn metadata_with_load_deps_has_stable_order() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 21).unwrap());
+        let hub = AssetHub::new(db.clone()).unwrap();
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let with_load_deps = |id: u8, deps: &[u8]| {
+            let mut metadata = test_metadata(id);
+            metadata.artifact.as_mut().unwrap().load_deps = deps
+                .iter()
+                .map(|dep| AssetRef::Uuid(AssetUuid([*dep; 16])))
+                .collect();
+            metadata
+        };
+        runtime.block_on(async {
+            let mut txn = db.rw_txn().await.unwrap();
+            let mut change_batch = ChangeBatch::new();
+            for metadata in &[
+                with_load_deps(1, &[5, 3, 2]),
+                with_load_deps(2, &[]),
+                with_load_deps(3, &[4]),
+                with_load_deps(4, &[]),
+                with_load_deps(5, &[]),
+            ] {
+                hub.update_asset(
+                    &mut txn,
+                    metadata,
+                    data::AssetSource::File,
+                    &mut change_batch,
+                )
+                .unwrap();
+            }
+            hub.add_changes(&mut txn, change_batch).unwrap();
+            txn.commit().unwrap();
+
+            let txn = db.ro_txn().await.unwrap();
+            let ids = [AssetUuid([2; 16]), AssetUuid([1; 16])];
+            let get_ids = || {
+                hub.get_metadata_with_load_deps(&txn, &ids)
+                    .unwrap()
+                    .into_iter()
+                    .map(|(id, _)| id)
+                    .collect::<Vec<_>>()
+            };
+            let first = get_ids();
+            // requested assets first, then their direct dependencies in the order they are listed
+            assert_eq!(
+                vec![
+                    AssetUuid([2; 16]),
+                    AssetUuid([1; 16]),
+                    AssetUuid([5; 16]),
+                    AssetUuid([3; 16])
+                ],
+                first
+            );
+            for _ in 0..10 {
+                assert_eq!(first, get_ids());
+            }
+        });
+    }
+
+    #[test]
+    fn build_deps_are_returned_but_not_loaded() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 21).unwrap());
+        let hub = AssetHub::new(db.clone()).unwrap();
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let with_deps = |id: u8, build_deps: &[u8], load_deps: &[u8]| {
+            let to_refs = |deps: &[u8]| {
+                deps.iter()
+                    .map(|dep| AssetRef::Uuid(AssetUuid([*dep; 16])))
+                    .collect()
+            };
+            let mut metadata = test_metadata(id);
+            let artifact = metadata.artifact.as_mut().unwrap();
+            artifact.build_deps = to_refs(build_deps);
+            artifact.load_deps = to_refs(load_deps);
+            metadata
+        };
+        runtime.block_on(async {
+            let mut txn = db.rw_txn().await.unwrap();
+            let mut change_batch = ChangeBatch::new();
+            for metadata in &[
+                with_deps(1, &[2], &[4]),
+                with_deps(2, &[3], &[]),
+                with_deps(3, &[], &[]),
+                with_deps(4, &[], &[]),
+            ] {
+                hub.update_asset(
+                    &mut txn,
+                    metadata,
+                    data::AssetSource::File,
+                    &mut change_batch,
+                )
+                .unwrap();
+            }
+            hub.add_changes(&mut txn, change_batch).unwrap();
+            txn.commit().unwrap();
+
+            let txn = db.ro_txn().await.unwrap();
+            let ids = [AssetUuid([1; 16])];
+            let build_deps = hub
+                .get_metadata_with_build_deps(&txn, &ids)
+                .unwrap()
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>();
+            // build dependencies are followed transitively
+            assert_eq!(
+                vec![AssetUuid([1; 16]), AssetUuid([2; 16]), AssetUuid([3; 16])],
+                build_deps
+            );
+            // the query the loader uses to load assets doesn't include them
+            let load_deps = hub
+                .get_metadata_with_load_deps(&txn, &ids)
+                .unwrap()
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>();
+            assert_eq!(vec![AssetUuid([1; 16]), AssetUuid([4; 16])], load_deps);
+        });
+    }
+
+    #[test]
+    fn assets_are_found_by_search_tags() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 21).unwrap());
+        let hub = AssetHub::new(db.clone()).unwrap();
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let with_tags = |id: u8, tags: &[(&str, Option<&str>)]| {
+            let mut metadata = test_metadata(id);
+            metadata.search_tags = tags
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.map(str::to_string)))
+                .collect();
+            metadata
+        };
+        let update = |metadata: AssetMetadata| {
+            let hub = &hub;
+            let db = &db;
+            async move {
+                let mut txn = db.rw_txn().await.unwrap();
+                let mut change_batch = ChangeBatch::new();
+                hub.update_asset(
+                    &mut txn,
+                    &metadata,
+                    data::AssetSource::File,
+                    &mut change_batch,
+                )
+                .unwrap();
+                txn.commit().unwrap();
+            }
+        };
+        runtime.block_on(async {
+            update(with_tags(1, &[("kind", Some("texture")), ("hero", None)])).await;
+            update(with_tags(2, &[("kind", Some("mesh"))])).await;
+            update(with_tags(3, &[("kind", Some("texture"))])).await;
+
+            let txn = db.ro_txn().await.unwrap();
+            assert_eq!(
+                vec![AssetUuid([1; 16]), AssetUuid([2; 16]), AssetUuid([3; 16])],
+                hub.search_assets(&txn, "kind", None).unwrap()
+            );
+            assert_eq!(
+                vec![AssetUuid([1; 16]), AssetUuid([3; 16])],
+                hub.search_assets(&txn, "kind", Some("texture")).unwrap()
+            );
+            assert_eq!(
+                vec![AssetUuid([1; 16])],
+                hub.search_assets(&txn, "hero", None).unwrap()
+            );
+            // tags without a value don't match a query with a value
+            assert!(hub
+                .search_assets(&txn, "hero", Some(""))
+                .unwrap()
+                .is_empty());
+            assert!(hub.search_assets(&txn, "size", None).unwrap().is_empty());
+            drop(txn);
+
+            // the index follows reimports that change the tags, and removed assets
+            update(with_tags(1, &[("kind", Some("mesh"))])).await;
+            let mut txn = db.rw_txn().await.unwrap();
+            hub.remove_asset(&mut txn, &AssetUuid([2; 16]), &mut ChangeBatch::new())
+                .unwrap();
+            txn.commit().unwrap();
+
+            let txn = db.ro_txn().await.unwrap();
+            assert_eq!(
+                vec![AssetUuid([1; 16])],
+                hub.search_assets(&txn, "kind", Some("mesh")).unwrap()
+            );
+            assert_eq!(
+                vec![AssetUuid([3; 16])],
+                hub.search_assets(&txn, "kind", Some("texture")).unwrap()
+            );
+            assert!(hub.search_assets(&txn, "hero", None).unwrap().is_empty());
+        });
+    }
+
+    #[test]
+    fn assets_are_found_by_label() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 21).unwrap());
+        let hub = AssetHub::new(db.clone()).unwrap();
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let update = |id: u8, label: Option<&str>| {
+            let mut metadata = test_metadata(id);
+            metadata.label = label.map(str::to_string);
+            let hub = &hub;
+            let db = &db;
+            async move {
+                let mut txn = db.rw_txn().await.unwrap();
+                hub.update_asset(
+                    &mut txn,
+                    &metadata,
+                    data::AssetSource::File,
+                    &mut ChangeBatch::new(),
+                )?;
+                txn.commit()?;
+                Ok::<_, Error>(())
+            }
+        };
+        runtime.block_on(async {
+            update(1, Some("player_mesh")).await.unwrap();
+            update(2, Some("enemy_mesh")).await.unwrap();
+
+            let txn = db.ro_txn().await.unwrap();
+            let label_asset = |label| hub.get_asset_for_label(&txn, label).unwrap();
+            assert_eq!(Some(AssetUuid([1; 16])), label_asset("player_mesh"));
+            assert_eq!(Some(AssetUuid([2; 16])), label_asset("enemy_mesh"));
+            assert_eq!(None, label_asset("boss_mesh"));
+            assert_eq!(None, label_asset(""));
+            drop(txn);
+
+            // the label of asset 1 can't be taken, and is free once asset 1 is relabeled
+            match update(3, Some("player_mesh")).await {
+                Err(Error::LabelInUse(label, owner)) => {
+                    assert_eq!("player_mesh", label);
+                    assert_eq!(AssetUuid([1; 16]), owner);
+                }
+                other => panic!("label in use was not rejected: {:?}", other),
+            }
+            let txn = db.ro_txn().await.unwrap();
+            assert!(hub.get_metadata(&txn, &AssetUuid([3; 16])).is_none());
+            drop(txn);
+            update(1, Some("boss_mesh")).await.unwrap();
+            update(3, Some("player_mesh")).await.unwrap();
+            let mut txn = db.rw_txn().await.unwrap();
+            hub.remove_asset(&mut txn, &AssetUuid([2; 16]), &mut ChangeBatch::new())
+                .unwrap();
+            txn.commit().unwrap();
+
+            let txn = db.ro_txn().await.unwrap();
+            let label_asset = |label| hub.get_asset_for_label(&txn, label).unwrap();
+            assert_eq!(Some(AssetUuid([3; 16])), label_asset("player_mesh"));
+            assert_eq!(Some(AssetUuid([1; 16])), label_asset("boss_mesh"));
+            assert_eq!(None, label_asset("enemy_mesh"));
+            let metadata = hub.get_metadata(&txn, &AssetUuid([1; 16])).unwrap();
+            let metadata = parse_db_metadata(&metadata.get().unwrap());
+            assert_eq!(Some("boss_mesh".to_string()), metadata.label);
+        });
+    }
+
+    #[test]
+    fn metadata_changes_only_contain_changed_parts() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 21).unwrap());
+        let hub = AssetHub::new(db.clone()).unwrap();
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let id = AssetUuid([1; 16]);
+        let changes = |known: Option<AssetMetadataVersion>| {
+            let hub = &hub;
+            let db = &db;
+            async move {
+                let txn = db.ro_txn().await.unwrap();
+                hub.get_metadata_changes(&txn, &[(id, known)]).unwrap()
+            }
+        };
+        runtime.block_on(async {
+            let mut metadata = test_metadata(1);
+            metadata.search_tags = vec![("kind".to_string(), Some("rock".to_string()))];
+            let mut txn = db.rw_txn().await.unwrap();
+            hub.update_asset(
+                &mut txn,
+                &metadata,
+                data::AssetSource::File,
+                &mut ChangeBatch::new(),
+            )
+            .unwrap();
+            txn.commit().unwrap();
+
+            // without a known version, the whole metadata is returned
+            let version = match changes(None).await.as_slice() {
+                [AssetMetadataChange::Changed(diff)] => {
+                    assert!(diff.search_tags.is_some());
+                    assert!(diff.build_pipeline.is_some());
+                    assert!(diff.artifact.is_some());
+                    assert!(diff.label.is_some());
+                    diff.version
+                }
+                changes => panic!("unexpected changes {:?}", changes),
+            };
+            assert!(changes(Some(version)).await.is_empty());
+
+            metadata.search_tags = vec![("kind".to_string(), Some("stone".to_string()))];
+            let mut txn = db.rw_txn().await.unwrap();
+            hub.update_asset(
+                &mut txn,
+                &metadata,
+                data::AssetSource::File,
+                &mut ChangeBatch::new(),
+            )
+            .unwrap();
+            txn.commit().unwrap();
+
+            match changes(Some(version)).await.as_slice() {
+                [AssetMetadataChange::Changed(diff)] => {
+                    assert_eq!(Some(metadata.search_tags.clone()), diff.search_tags);
+                    assert!(diff.build_pipeline.is_none());
+                    assert!(diff.artifact.is_none());
+                    assert!(diff.label.is_none());
+                    assert_ne!(version, diff.version);
+                }
+                changes => panic!("unexpected changes {:?}", changes),
+            }
+
+            let mut txn = db.rw_txn().await.unwrap();
+            hub.remove_asset(&mut txn, &id, &mut ChangeBatch::new())
+                .unwrap();
+            txn.commit().unwrap();
+            match changes(Some(version)).await.as_slice() {
+                [AssetMetadataChange::Removed(removed)] => assert_eq!(id, *removed),
+                changes => panic!("unexpected changes {:?}", changes),
+            }
+            assert!(changes(None).await.is_empty());
+        });
     }
 }
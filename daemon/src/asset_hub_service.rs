@@ -1,6 +1,8 @@
 use crate::{
     artifact_cache::ArtifactCache,
-    asset_hub::{AssetBatchEvent, AssetHub},
+    asset_hub::{
+        AssetBatchEvent, AssetChange, AssetHub, AssetMetadataChange, AssetMetadataVersion,
+    },
     capnp_db::{CapnpCursor as _, Environment, RoTransaction},
     error::Error,
     file_asset_source::FileAssetSource,
@@ -11,28 +13,111 @@ use atelier_importer::SerializedAsset;
 use atelier_schema::{
     build_artifact_metadata,
     data::{
-        artifact, asset_change_log_entry,
+        artifact,
         asset_metadata::{self, latest_artifact},
         AssetSource,
     },
-    parse_artifact_metadata, parse_db_asset_ref,
-    service::asset_hub,
+    parse_artifact_metadata,
+    service::{asset_change, asset_hub},
 };
+use capnp::message::ReaderOptions;
 use capnp_rpc::{pry, rpc_twoparty_capnp, twoparty, RpcSystem};
 
+use futures_channel::oneshot;
+use futures_util::future::{self, Either};
 use futures_util::AsyncReadExt;
 use futures_util::TryFutureExt;
-use std::{
-    collections::{HashMap, HashSet},
-    path,
-    rc::Rc,
-    sync::Arc,
-};
+use std::{future::Future, net::SocketAddr, path, rc::Rc, sync::Arc};
 
 // crate::Error has `impl From<crate::Error> for capnp::Error`
 type Promise<T> = capnp::capability::Promise<T, capnp::Error>;
 type Result<T> = std::result::Result<T, Error>;
 
+/// Where the asset hub service accepts connections from loaders.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ServiceAddress {
+    /// A TCP socket address.
+    Tcp(SocketAddr),
+    /// *unix only.* The path of a Unix domain socket. A socket that is left at the path by a
+    /// daemon that did not shut down cleanly is replaced, any other file is an error.
+    #[cfg(unix)]
+    Unix(path::PathBuf),
+}
+
+impl From<SocketAddr> for ServiceAddress {
+    fn from(addr: SocketAddr) -> Self {
+        ServiceAddress::Tcp(addr)
+    }
+}
+
+/// *feature:* `tls`. Certificates that the asset hub service uses to encrypt connections from
+/// loaders. All files are PEM encoded.
+#[cfg(feature = "tls")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TlsConfig {
+    /// The certificate chain of the daemon, starting with its own certificate.
+    pub cert_path: path::PathBuf,
+    /// The private key of the daemon's certificate, in PKCS #8 or RSA format.
+    pub key_path: path::PathBuf,
+    /// CA certificates that sign the certificates of loaders. If set, loaders have to
+    /// authenticate with a certificate signed by one of them.
+    pub client_ca_path: Option<path::PathBuf>,
+}
+
+#[cfg(feature = "tls")]
+impl TlsConfig {
+    fn acceptor(&self) -> Result<tokio_rustls::TlsAcceptor> {
+        use tokio_rustls::rustls::{
+            internal::pemfile, AllowAnyAuthenticatedClient, NoClientAuth, RootCertStore,
+            ServerConfig,
+        };
+        let certs = read_pem(&self.cert_path, pemfile::certs, "certificate")?;
+        let mut keys = read_pem(&self.key_path, pemfile::pkcs8_private_keys, "private key")?;
+        if keys.is_empty() {
+            keys = read_pem(&self.key_path, pemfile::rsa_private_keys, "private key")?;
+        }
+        let key = keys.pop().ok_or_else(|| {
+            Error::Custom(format!(
+                "{} contains no private key",
+                self.key_path.display()
+            ))
+        })?;
+        let client_auth = match &self.client_ca_path {
+            Some(ca_path) => {
+                let mut roots = RootCertStore::empty();
+                for cert in read_pem(ca_path, pemfile::certs, "certificate")? {
+                    roots.add(&cert).map_err(|err| {
+                        Error::Custom(format!("invalid CA in {}: {}", ca_path.display(), err))
+                    })?;
+                }
+                AllowAnyAuthenticatedClient::new(roots)
+            }
+            None => NoClientAuth::new(),
+        };
+        let mut config = ServerConfig::new(client_auth);
+        config
+            .set_single_cert(certs, key)
+            .map_err(|err| Error::Custom(format!("invalid TLS certificate: {}", err)))?;
+        Ok(tokio_rustls::TlsAcceptor::from(Arc::new(config)))
+    }
+}
+
+#[cfg(feature = "tls")]
+fn read_pem<T>(
+    path: &path::Path,
+    read: fn(&mut dyn std::io::BufRead) -> std::result::Result<Vec<T>, ()>,
+    what: &str,
+) -> Result<Vec<T>> {
+    let mut reader = std::io::BufReader::new(std::fs::File::open(path)?);
+    read(&mut reader).map_err(|_| {
+        Error::Custom(format!(
+            "{} does not contain a valid {}",
+            path.display(),
+            what
+        ))
+    })
+}
+
 struct ServiceContext {
     hub: Arc<AssetHub>,
     file_source: Arc<FileAssetSource>,
@@ -43,6 +128,10 @@ struct ServiceContext {
 
 pub(crate) struct AssetHubService {
     ctx: Arc<ServiceContext>,
+    /// Limits for the messages read from loaders
+    reader_options: ReaderOptions,
+    #[cfg(feature = "tls")]
+    tls: Option<tokio_rustls::TlsAcceptor>,
 }
 
 struct SnapshotTxn {
@@ -103,6 +192,29 @@ fn artifact_to_serialized_asset<'a>(
     })
 }
 
+fn build_asset_changes(
+    changes: &[AssetChange],
+    mut builder: capnp::struct_list::Builder<'_, asset_change::Owned>,
+) {
+    for (idx, change) in changes.iter().enumerate() {
+        let mut change_builder = builder.reborrow().get(idx as u32);
+        match change {
+            AssetChange::Added(id, path) => {
+                change_builder.reborrow().init_id().set_id(&id.0);
+                change_builder.set_added(path.to_string_lossy().as_bytes());
+            }
+            AssetChange::Changed(id) => {
+                change_builder.reborrow().init_id().set_id(&id.0);
+                change_builder.set_changed(());
+            }
+            AssetChange::Removed(id) => {
+                change_builder.reborrow().init_id().set_id(&id.0);
+                change_builder.set_removed(());
+            }
+        }
+    }
+}
+
 impl AssetHubSnapshotImpl {
     async fn new(ctx: Arc<ServiceContext>) -> Self {
         Self {
@@ -144,43 +256,167 @@ impl AssetHubSnapshotImpl {
         let params = params.get()?;
         let ctx = self.txn.ctx();
         let txn = self.txn.txn();
-        let mut metadatas = HashMap::new();
+        let mut ids = Vec::new();
         for id in params.get_assets()? {
-            let id = utils::uuid_from_slice(id.get_id()?).ok_or(Error::UuidLength)?;
-            let value = ctx.hub.get_metadata(txn, &id);
-            if let Some(metadata) = value {
-                metadatas.insert(id, metadata);
-            }
+            ids.push(utils::uuid_from_slice(id.get_id()?).ok_or(Error::UuidLength)?);
         }
-        let mut missing_metadata = HashSet::new();
-        for metadata in metadatas.values() {
-            if let latest_artifact::Artifact(Ok(artifact)) =
-                metadata.get()?.get_latest_artifact().which()?
-            {
-                for dep in artifact.get_load_deps()? {
-                    let dep = *parse_db_asset_ref(&dep).expect_uuid();
-                    if !metadatas.contains_key(&dep) {
-                        missing_metadata.insert(dep);
-                    }
-                }
-            }
+        let metadatas = ctx.hub.get_metadata_with_load_deps(txn, &ids)?;
+        let mut results_builder = results.get();
+        let assets = results_builder
+            .reborrow()
+            .init_assets(metadatas.len() as u32);
+        for (idx, (_, metadata)) in metadatas.iter().enumerate() {
+            let metadata = metadata.get()?;
+            assets.set_with_caveats(idx as u32, metadata)?;
         }
-        for id in missing_metadata {
-            let value = ctx.hub.get_metadata(txn, &id);
-            if let Some(metadata) = value {
-                metadatas.insert(id, metadata);
-            }
+        Ok(())
+    }
+    fn get_asset_metadata_with_build_dependencies(
+        &mut self,
+        params: asset_hub::snapshot::GetAssetMetadataWithBuildDependenciesParams,
+        mut results: asset_hub::snapshot::GetAssetMetadataWithBuildDependenciesResults,
+    ) -> Result<()> {
+        let params = params.get()?;
+        let ctx = self.txn.ctx();
+        let txn = self.txn.txn();
+        let mut ids = Vec::new();
+        for id in params.get_assets()? {
+            ids.push(utils::uuid_from_slice(id.get_id()?).ok_or(Error::UuidLength)?);
         }
+        let metadatas = ctx.hub.get_metadata_with_build_deps(txn, &ids)?;
         let mut results_builder = results.get();
         let assets = results_builder
             .reborrow()
             .init_assets(metadatas.len() as u32);
-        for (idx, metadata) in metadatas.values().enumerate() {
+        for (idx, (_, metadata)) in metadatas.iter().enumerate() {
             let metadata = metadata.get()?;
             assets.set_with_caveats(idx as u32, metadata)?;
         }
         Ok(())
     }
+    fn search_assets(
+        &mut self,
+        params: asset_hub::snapshot::SearchAssetsParams,
+        mut results: asset_hub::snapshot::SearchAssetsResults,
+    ) -> Result<()> {
+        let params = params.get()?;
+        let ctx = self.txn.ctx();
+        let txn = self.txn.txn();
+        let key = std::str::from_utf8(params.get_key()?)?;
+        let value = if params.has_value() {
+            Some(std::str::from_utf8(params.get_value()?)?)
+        } else {
+            None
+        };
+        let ids = ctx.hub.search_assets(txn, key, value)?;
+        let mut results_builder = results.get();
+        let mut assets = results_builder.reborrow().init_assets(ids.len() as u32);
+        for (idx, id) in ids.iter().enumerate() {
+            assets.reborrow().get(idx as u32).set_id(&id.0);
+        }
+        Ok(())
+    }
+    fn get_asset_for_label(
+        &mut self,
+        params: asset_hub::snapshot::GetAssetForLabelParams,
+        mut results: asset_hub::snapshot::GetAssetForLabelResults,
+    ) -> Result<()> {
+        let params = params.get()?;
+        let ctx = self.txn.ctx();
+        let txn = self.txn.txn();
+        let label = std::str::from_utf8(params.get_label()?)?;
+        let ids: Vec<_> = ctx
+            .hub
+            .get_asset_for_label(txn, label)?
+            .into_iter()
+            .collect();
+        let mut results_builder = results.get();
+        let mut assets = results_builder.reborrow().init_assets(ids.len() as u32);
+        for (idx, id) in ids.iter().enumerate() {
+            assets.reborrow().get(idx as u32).set_id(&id.0);
+        }
+        Ok(())
+    }
+    fn get_asset_metadata_changes(
+        &mut self,
+        params: asset_hub::snapshot::GetAssetMetadataChangesParams,
+        mut results: asset_hub::snapshot::GetAssetMetadataChangesResults,
+    ) -> Result<()> {
+        let params = params.get()?;
+        let ctx = self.txn.ctx();
+        let txn = self.txn.txn();
+        let mut known_versions = Vec::new();
+        for known in params.get_assets()? {
+            let id = utils::uuid_from_slice(known.get_id()?.get_id()?).ok_or(Error::UuidLength)?;
+            let version = if known.has_version() {
+                let version = known.get_version()?;
+                Some(AssetMetadataVersion {
+                    search_tags: version.get_search_tags(),
+                    build_pipeline: version.get_build_pipeline(),
+                    artifact: version.get_artifact(),
+                    label: version.get_label(),
+                })
+            } else {
+                None
+            };
+            known_versions.push((id, version));
+        }
+        let changes = ctx.hub.get_metadata_changes(txn, &known_versions)?;
+        let mut results_builder = results.get();
+        let mut changes_builder = results_builder
+            .reborrow()
+            .init_changes(changes.len() as u32);
+        for (idx, change) in changes.iter().enumerate() {
+            let mut builder = changes_builder.reborrow().get(idx as u32);
+            let diff = match change {
+                AssetMetadataChange::Removed(id) => {
+                    builder.reborrow().init_id().set_id(&id.0);
+                    builder.set_removed(());
+                    continue;
+                }
+                AssetMetadataChange::Changed(diff) => diff,
+            };
+            builder.reborrow().init_id().set_id(&diff.id.0);
+            builder.set_changed(());
+            let mut version = builder.reborrow().init_version();
+            version.set_search_tags(diff.version.search_tags);
+            version.set_build_pipeline(diff.version.build_pipeline);
+            version.set_artifact(diff.version.artifact);
+            version.set_label(diff.version.label);
+            if let Some(search_tags) = &diff.search_tags {
+                builder.set_search_tags_changed(true);
+                let mut tags = builder
+                    .reborrow()
+                    .init_search_tags(search_tags.len() as u32);
+                for (idx, (key, value)) in search_tags.iter().enumerate() {
+                    let mut tag = tags.reborrow().get(idx as u32);
+                    tag.set_key(key.as_bytes());
+                    if let Some(value) = value {
+                        tag.set_value(value.as_bytes());
+                    }
+                }
+            }
+            if let Some(build_pipeline) = &diff.build_pipeline {
+                builder.set_build_pipeline_changed(true);
+                if let Some(pipeline) = build_pipeline {
+                    builder.reborrow().init_build_pipeline().set_id(&pipeline.0);
+                }
+            }
+            if let Some(artifact) = &diff.artifact {
+                builder.set_artifact_changed(true);
+                if let Some(artifact) = artifact {
+                    build_artifact_metadata(artifact, &mut builder.reborrow().init_artifact());
+                }
+            }
+            if let Some(label) = &diff.label {
+                builder.set_label_changed(true);
+                if let Some(label) = label {
+                    builder.set_label(label.as_bytes());
+                }
+            }
+        }
+        Ok(())
+    }
     fn get_all_asset_metadata(
         &mut self,
         _params: asset_hub::snapshot::GetAllAssetMetadataParams,
@@ -235,8 +471,13 @@ impl AssetHubSnapshotImpl {
                 if let latest_artifact::Artifact(Ok(artifact)) =
                     metadata.get()?.get_latest_artifact().which()?
                 {
-                    let hash = u64::from_le_bytes(utils::make_array(artifact.get_hash()?));
-                    if let Some(artifact) = ctx.artifact_cache.get(&cache_txn, hash).await {
+                    let hash = artifact.get_hash()?;
+                    // artifacts of an older importer version are imported again
+                    let cached = match ctx.file_source.importer_version(txn, &id) {
+                        Some(version) => ctx.artifact_cache.get(&cache_txn, hash, version).await,
+                        None => None,
+                    };
+                    if let Some(artifact) = cached {
                         cached_artifacts.push(artifact);
                         need_regen = false;
                     } else {
@@ -277,9 +518,10 @@ impl AssetHubSnapshotImpl {
                 .reborrow()
                 .set_with_caveats(idx as u32, artifact.get()?)?;
         }
+        // regenerated artifacts follow the cached ones
         for (idx, artifact) in regen_artifacts.iter().enumerate() {
             artifact_results.reborrow().set_with_caveats(
-                idx as u32,
+                (cached_artifacts.len() + idx) as u32,
                 artifact.get_root_as_reader::<artifact::Reader<'_>>()?,
             )?;
         }
@@ -304,18 +546,17 @@ impl AssetHubSnapshotImpl {
         let params = params.get()?;
         let ctx = self.txn.ctx();
         let txn = self.txn.txn();
-        let mut changes = Vec::new();
-        let iter = ctx.hub.get_asset_changes_iter(txn)?;
-        let iter = iter.capnp_iter_from(&params.get_start().to_le_bytes());
         let mut count = params.get_count() as usize;
         if count == 0 {
             count = std::usize::MAX;
         }
-        for (_, value) in iter.take(count) {
-            let value = value?;
-            let change = value.into_typed::<asset_change_log_entry::Owned>();
-            changes.push(change);
-        }
+        let since = params.get_start().saturating_sub(1);
+        let changes = ctx
+            .hub
+            .get_asset_changes_since(txn, since)?
+            .take(count)
+            .map(|(_, change)| change)
+            .collect::<Vec<_>>();
         let mut results_builder = results.get();
         let changes_results = results_builder
             .reborrow()
@@ -479,6 +720,39 @@ impl AssetHubSnapshotImpl {
             ))
         }
     }
+
+    async fn force_reimport(
+        snapshot: Arc<SnapshotTxn>,
+        params: asset_hub::snapshot::ForceReimportParams,
+        _results: asset_hub::snapshot::ForceReimportResults,
+    ) -> Result<()> {
+        let params = params.get()?;
+        let ctx = &snapshot.ctx;
+        let path_str = std::str::from_utf8(params.get_path()?)?.to_string();
+        let path = path::PathBuf::from(path_str);
+        let path = if path.is_relative() {
+            let txn = snapshot.txn();
+            ctx.file_tracker
+                .get_watch_dirs()
+                .into_iter()
+                .map(|dir| crate::watcher::canonicalize_path(&dir.join(&path)))
+                .find(|candidate| ctx.file_source.get_metadata(txn, candidate).is_some())
+                .unwrap_or(path)
+        } else {
+            crate::watcher::canonicalize_path(&path)
+        };
+        ctx.file_source.force_reimport(&path).await
+    }
+
+    async fn rebuild_all_artifacts(
+        snapshot: Arc<SnapshotTxn>,
+        _params: asset_hub::snapshot::RebuildAllArtifactsParams,
+        mut results: asset_hub::snapshot::RebuildAllArtifactsResults,
+    ) -> Result<()> {
+        let source_files = snapshot.ctx.file_source.rebuild_all_artifacts().await?;
+        results.get().set_source_files(source_files as u64);
+        Ok(())
+    }
 }
 
 #[allow(clippy::unit_arg)]
@@ -500,6 +774,14 @@ impl asset_hub::Server for AssetHubImpl {
         let fut = AssetHubImpl::get_snapshot(self.ctx.clone(), params, results);
         Promise::from_future(async { fut.await.map_err(|e| e.into()) })
     }
+    fn get_importers(
+        &mut self,
+        params: asset_hub::GetImportersParams,
+        results: asset_hub::GetImportersResults,
+    ) -> Promise<()> {
+        log::trace!("asset_hub::Server::get_importers");
+        Promise::ok(pry!(AssetHubImpl::get_importers(self, params, results)))
+    }
 }
 impl AssetHubImpl {
     fn register_listener(
@@ -510,24 +792,48 @@ impl AssetHubImpl {
         let params = params.get()?;
         let listener = Rc::new(params.get_listener()?);
         let ctx = self.ctx.clone();
-        let (tx, rx) = async_channel::bounded(16);
-        tx.try_send(AssetBatchEvent::Commit).unwrap();
-
-        let tx = self.ctx.hub.register_listener(tx);
+        let (listener_id, queue) = self.ctx.hub.register_listener();
 
         tokio::task::spawn_local(async move {
-            while rx.recv().await.is_ok() {
-                let mut request = listener.update_request();
-                let snapshot = AssetHubSnapshotImpl::new(ctx.clone()).await;
-                let latest_change = ctx
-                    .hub
-                    .get_latest_asset_change(snapshot.txn.txn())
-                    .expect("failed to get latest change");
-                request.get().set_latest_change(latest_change);
-                request.get().set_snapshot(capnp_rpc::new_client(snapshot));
-                if request.send().promise.await.is_err() {
-                    ctx.hub.drop_listener(tx);
-                    break;
+            'listen: while let Some(events) = queue.recv().await {
+                for event in events {
+                    let result = match event {
+                        AssetBatchEvent::Commit => {
+                            let mut request = listener.update_request();
+                            let snapshot = AssetHubSnapshotImpl::new(ctx.clone()).await;
+                            let latest_change = ctx
+                                .hub
+                                .get_latest_asset_change(snapshot.txn.txn())
+                                .expect("failed to get latest change");
+                            request.get().set_latest_change(latest_change);
+                            request.get().set_snapshot(capnp_rpc::new_client(snapshot));
+                            request.send().promise.await.map(|_| ())
+                        }
+                        AssetBatchEvent::ImportError(path, message) => {
+                            let mut request = listener.import_error_request();
+                            request.get().set_path(path.to_string_lossy().as_bytes());
+                            request.get().set_message(&message);
+                            match request.send().promise.await {
+                                // listeners that predate import error reporting don't implement it
+                                Err(err) if err.kind == capnp::ErrorKind::Unimplemented => Ok(()),
+                                result => result.map(|_| ()),
+                            }
+                        }
+                        AssetBatchEvent::AssetChanges(changes) => {
+                            let mut request = listener.assets_changed_request();
+                            let builder = request.get().init_changes(changes.len() as u32);
+                            build_asset_changes(&changes, builder);
+                            match request.send().promise.await {
+                                // listeners that only handle `update` don't implement it
+                                Err(err) if err.kind == capnp::ErrorKind::Unimplemented => Ok(()),
+                                result => result.map(|_| ()),
+                            }
+                        }
+                    };
+                    if result.is_err() {
+                        ctx.hub.drop_listener(listener_id);
+                        break 'listen;
+                    }
                 }
             }
         });
@@ -543,6 +849,23 @@ impl AssetHubImpl {
         results.get().set_snapshot(capnp_rpc::new_client(snapshot));
         Ok(())
     }
+
+    fn get_importers(
+        &mut self,
+        _params: asset_hub::GetImportersParams,
+        mut results: asset_hub::GetImportersResults,
+    ) -> Result<()> {
+        let importers = self.ctx.file_source.importers().describe();
+        let mut results = results.get().init_importers(importers.len() as u32);
+        for (idx, importer) in importers.iter().enumerate() {
+            let mut info = results.reborrow().get(idx as u32);
+            info.set_extension(&importer.extension);
+            info.set_importer_type(&importer.importer_type.0);
+            info.set_version(importer.version);
+            info.set_options_type(&importer.options_type.0);
+        }
+        Ok(())
+    }
 }
 
 fn spawn_rpc<
@@ -552,6 +875,7 @@ fn spawn_rpc<
     reader: R,
     writer: W,
     ctx: Arc<ServiceContext>,
+    reader_options: ReaderOptions,
 ) {
     let service_impl = AssetHubImpl { ctx };
     let hub_impl: asset_hub::Client = capnp_rpc::new_client(service_impl);
@@ -560,7 +884,7 @@ fn spawn_rpc<
         reader,
         writer,
         rpc_twoparty_capnp::Side::Server,
-        Default::default(),
+        reader_options,
     );
 
     let rpc_system = RpcSystem::new(Box::new(network), Some(hub_impl.client));
@@ -582,31 +906,151 @@ impl AssetHubService {
                 file_tracker,
                 artifact_cache,
             }),
+            reader_options: ReaderOptions::new(),
+            #[cfg(feature = "tls")]
+            tls: None,
+        }
+    }
+
+    /// Sets the limits of the messages read from loaders. Requests that exceed them fail and
+    /// close the connection.
+    pub fn with_reader_options(mut self, reader_options: ReaderOptions) -> Self {
+        self.reader_options = reader_options;
+        self
+    }
+
+    /// Encrypts the connections from loaders with the certificates in `config`.
+    #[cfg(feature = "tls")]
+    pub fn with_tls(mut self, config: &TlsConfig) -> Result<Self> {
+        self.tls = Some(config.acceptor()?);
+        Ok(self)
+    }
+
+    /// Accepts connections on `addr` until `shutdown` completes. Returns
+    /// [`Error::ServiceBind`] if it can't listen on `addr`.
+    pub async fn run<F>(&self, addr: ServiceAddress, shutdown: F) -> Result<()>
+    where
+        F: Future<Output = ()>,
+    {
+        self.run_and_notify(addr, shutdown, None).await
+    }
+
+    /// Like [`run`](Self::run), and sends the address that is listened on to `listening` once
+    /// connections are accepted. For a TCP port of 0 it contains the port that was picked.
+    pub(crate) async fn run_and_notify<F>(
+        &self,
+        addr: ServiceAddress,
+        shutdown: F,
+        listening: Option<oneshot::Sender<ServiceAddress>>,
+    ) -> Result<()>
+    where
+        F: Future<Output = ()>,
+    {
+        match addr {
+            ServiceAddress::Tcp(addr) => self.run_tcp(addr, shutdown, listening).await,
+            #[cfg(unix)]
+            ServiceAddress::Unix(path) => self.run_unix(&path, shutdown, listening).await,
+        }
+    }
+
+    async fn run_tcp<F>(
+        &self,
+        addr: SocketAddr,
+        shutdown: F,
+        listening: Option<oneshot::Sender<ServiceAddress>>,
+    ) -> Result<()>
+    where
+        F: Future<Output = ()>,
+    {
+        let mut listener = tokio::net::TcpListener::bind(&addr)
+            .await
+            .map_err(|err| Error::ServiceBind(addr.to_string(), err))?;
+        if let Some(listening) = listening {
+            let _ = listening.send(ServiceAddress::Tcp(listener.local_addr()?));
+        }
+        let mut shutdown = Box::pin(shutdown);
+
+        loop {
+            let accept = Box::pin(listener.accept());
+            let (stream, _) = match future::select(accept, shutdown.as_mut()).await {
+                Either::Left((accepted, _)) => accepted?,
+                Either::Right(_) => return Ok(()),
+            };
+            log::info!("tokio::net::TcpListener accepted");
+            // NOTE(happens): This will only fail if we can't set the stream
+            // parameters on startup, which is a cause for panic in any case.
+            stream.set_nodelay(true).unwrap();
+            stream.set_send_buffer_size(1 << 22).unwrap();
+            stream.set_recv_buffer_size(1 << 22).unwrap();
+            self.accept_connection(stream);
         }
     }
 
-    pub async fn run(&self, addr: std::net::SocketAddr) {
-        let result: std::result::Result<(), Box<dyn std::error::Error>> = async {
-            let mut listener: tokio::net::TcpListener =
-                tokio::net::TcpListener::bind(&addr).await?;
+    #[cfg(unix)]
+    async fn run_unix<F>(
+        &self,
+        path: &path::Path,
+        shutdown: F,
+        listening: Option<oneshot::Sender<ServiceAddress>>,
+    ) -> Result<()>
+    where
+        F: Future<Output = ()>,
+    {
+        use std::os::unix::fs::FileTypeExt;
+        if let Ok(metadata) = std::fs::symlink_metadata(path) {
+            // a socket that nothing listens on was left by a daemon that didn't shut down
+            if metadata.file_type().is_socket()
+                && std::os::unix::net::UnixStream::connect(path).is_err()
+            {
+                std::fs::remove_file(path)?;
+            }
+        }
+        let mut listener = tokio::net::UnixListener::bind(path)
+            .map_err(|err| Error::ServiceBind(path.display().to_string(), err))?;
+        if let Some(listening) = listening {
+            let _ = listening.send(ServiceAddress::Unix(path.to_path_buf()));
+        }
+        let mut shutdown = Box::pin(shutdown);
+
+        let result = loop {
+            let accept = Box::pin(listener.accept());
+            let stream = match future::select(accept, shutdown.as_mut()).await {
+                Either::Left((Ok((stream, _)), _)) => stream,
+                Either::Left((Err(err), _)) => break Err(err.into()),
+                Either::Right(_) => break Ok(()),
+            };
+            log::info!("tokio::net::UnixListener accepted");
+            self.accept_connection(stream);
+        };
+        let _ = std::fs::remove_file(path);
+        result
+    }
 
-            loop {
-                let (stream, _) = listener.accept().await?;
-                log::info!("tokio::net::TcpListener accepted");
-                stream.set_nodelay(true).unwrap();
-                stream.set_send_buffer_size(1 << 22).unwrap();
-                stream.set_recv_buffer_size(1 << 22).unwrap();
-                use tokio_util::compat::*;
-                let (reader, writer) = stream.compat().split();
-                spawn_rpc(reader, writer, self.ctx.clone());
+    fn accept_connection<S>(&self, stream: S)
+    where
+        S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin + Send + 'static,
+    {
+        use tokio_util::compat::*;
+        #[cfg(feature = "tls")]
+        {
+            if let Some(acceptor) = self.tls.clone() {
+                let ctx = self.ctx.clone();
+                let reader_options = self.reader_options;
+                // the handshake runs in its own task, so a slow client doesn't hold up others
+                tokio::task::spawn_local(async move {
+                    match acceptor.accept(stream).await {
+                        Ok(stream) => {
+                            let (reader, writer) = stream.compat().split();
+                            spawn_rpc(reader, writer, ctx, reader_options);
+                        }
+                        Err(err) => log::warn!("TLS handshake with loader failed: {}", err),
+                    }
+                });
+                return;
             }
         }
-        .await;
-        // NOTE(happens): This will only fail if we can't set the stream
-        // parameters on startup, which is a cause for panic in any case.
-        // NOTE(kabergstrom): It also seems to happen when the main thread
-        // is aborted and this is run on a background thread
-        result.expect("Failed to run tcp listener");
+        let (reader, writer) = stream.compat().split();
+        spawn_rpc(reader, writer, self.ctx.clone(), self.reader_options);
     }
 }
 
@@ -632,6 +1076,46 @@ impl asset_hub::snapshot::Server for AssetHubSnapshotImpl {
             AssetHubSnapshotImpl::get_asset_metadata_with_dependencies(self, params, results)
         ))
     }
+    fn get_asset_metadata_with_build_dependencies(
+        &mut self,
+        params: asset_hub::snapshot::GetAssetMetadataWithBuildDependenciesParams,
+        results: asset_hub::snapshot::GetAssetMetadataWithBuildDependenciesResults,
+    ) -> Promise<()> {
+        log::trace!("asset_hub::snapshot::Server::get_asset_metadata_with_build_dependencies");
+        Promise::ok(pry!(
+            AssetHubSnapshotImpl::get_asset_metadata_with_build_dependencies(self, params, results)
+        ))
+    }
+    fn search_assets(
+        &mut self,
+        params: asset_hub::snapshot::SearchAssetsParams,
+        results: asset_hub::snapshot::SearchAssetsResults,
+    ) -> Promise<()> {
+        log::trace!("asset_hub::snapshot::Server::search_assets");
+        Promise::ok(pry!(AssetHubSnapshotImpl::search_assets(
+            self, params, results
+        )))
+    }
+    fn get_asset_for_label(
+        &mut self,
+        params: asset_hub::snapshot::GetAssetForLabelParams,
+        results: asset_hub::snapshot::GetAssetForLabelResults,
+    ) -> Promise<()> {
+        log::trace!("asset_hub::snapshot::Server::get_asset_for_label");
+        Promise::ok(pry!(AssetHubSnapshotImpl::get_asset_for_label(
+            self, params, results
+        )))
+    }
+    fn get_asset_metadata_changes(
+        &mut self,
+        params: asset_hub::snapshot::GetAssetMetadataChangesParams,
+        results: asset_hub::snapshot::GetAssetMetadataChangesResults,
+    ) -> Promise<()> {
+        log::trace!("asset_hub::snapshot::Server::get_asset_metadata_changes");
+        Promise::ok(pry!(AssetHubSnapshotImpl::get_asset_metadata_changes(
+            self, params, results
+        )))
+    }
     fn get_all_asset_metadata(
         &mut self,
         params: asset_hub::snapshot::GetAllAssetMetadataParams,
@@ -700,4 +1184,119 @@ impl asset_hub::snapshot::Server for AssetHubSnapshotImpl {
         let fut = AssetHubSnapshotImpl::update_asset(self.txn.clone(), params, results);
         Promise::from_future(async { fut.await.map_err(|e| e.into()) })
     }
+    fn force_reimport(
+        &mut self,
+        params: asset_hub::snapshot::ForceReimportParams,
+        results: asset_hub::snapshot::ForceReimportResults,
+    ) -> Promise<()> {
+        log::trace!("asset_hub::snapshot::Server::force_reimport");
+        let fut = AssetHubSnapshotImpl::force_reimport(self.txn.clone(), params, results);
+        Promise::from_future(async { fut.await.map_err(|e| e.into()) })
+    }
+    fn rebuild_all_artifacts(
+        &mut self,
+        params: asset_hub::snapshot::RebuildAllArtifactsParams,
+        results: asset_hub::snapshot::RebuildAllArtifactsResults,
+    ) -> Promise<()> {
+        log::trace!("asset_hub::snapshot::Server::rebuild_all_artifacts");
+        let fut = AssetHubSnapshotImpl::rebuild_all_artifacts(self.txn.clone(), params, results);
+        Promise::from_future(async { fut.await.map_err(|e| e.into()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file_asset_source::tests::{test_importers, with_fixture, Fixture, Import};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[test]
+    fn cached_and_regenerated_artifacts_are_returned_together() {
+        static CACHED_IMPORTS: AtomicUsize = AtomicUsize::new(0);
+        static VOLATILE_IMPORTS: AtomicUsize = AtomicUsize::new(0);
+
+        let importers = test_importers(&[
+            ("counted", Import::Counted(&CACHED_IMPORTS)),
+            ("volatile", Import::Volatile(&VOLATILE_IMPORTS)),
+        ]);
+        with_fixture(importers, move |fixture| async move {
+            let Fixture {
+                tracker,
+                hub,
+                db,
+                artifact_cache,
+                asset_dir,
+                ..
+            } = &fixture;
+            let source = Arc::new(fixture.new_source());
+
+            let mut files = Vec::new();
+            for name in &["rock.counted", "clock.volatile"] {
+                let file = asset_dir.join(name);
+                tokio::fs::write(&file, "asset").await.unwrap();
+                files.push(crate::watcher::canonicalize_path(&file));
+            }
+            let ids = loop {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+                let txn = db.ro_txn().await.unwrap();
+                let ids = files
+                    .iter()
+                    .filter_map(|path| {
+                        let metadata = source.get_metadata(&txn, path)?;
+                        let assets = metadata.get().unwrap().get_assets().unwrap();
+                        let id = assets.iter().next()?.get_id().unwrap().get_id().unwrap();
+                        utils::uuid_from_slice(id)
+                    })
+                    .collect::<Vec<_>>();
+                if ids.len() == files.len() {
+                    break ids;
+                }
+            };
+            // the counted asset comes from the cache, the volatile one is imported again
+            let mut expected = vec![
+                (
+                    ids[0],
+                    format!("import {}", CACHED_IMPORTS.load(Ordering::SeqCst)),
+                ),
+                (
+                    ids[1],
+                    format!("import {}", VOLATILE_IMPORTS.load(Ordering::SeqCst) + 1),
+                ),
+            ];
+
+            let service = AssetHubService::new(
+                db.clone(),
+                hub.clone(),
+                source,
+                tracker.clone(),
+                artifact_cache.clone(),
+            );
+            let client: asset_hub::Client = capnp_rpc::new_client(AssetHubImpl {
+                ctx: service.ctx.clone(),
+            });
+            let response = client.get_snapshot_request().send().promise.await.unwrap();
+            let snapshot = response.get().unwrap().get_snapshot().unwrap();
+            let mut request = snapshot.get_import_artifacts_request();
+            let mut assets = request.get().init_assets(ids.len() as u32);
+            for (idx, id) in ids.iter().enumerate() {
+                assets.reborrow().get(idx as u32).set_id(&id.0);
+            }
+            let response = request.send().promise.await.unwrap();
+            let artifacts = response.get().unwrap().get_artifacts().unwrap();
+
+            let mut returned = artifacts
+                .iter()
+                .map(|artifact| {
+                    let artifact = artifact_to_serialized_asset(&artifact).unwrap();
+                    let value: String = bincode::deserialize(artifact.data).unwrap();
+                    (artifact.metadata.asset_id, value)
+                })
+                .collect::<Vec<_>>();
+            returned.sort();
+            expected.sort();
+            assert_eq!(expected, returned);
+        });
+    }
 }
@@ -2,15 +2,27 @@
 use crate::error::{Error, Result};
 use async_lock::{Semaphore, SemaphoreGuard};
 use lmdb::{self, Cursor, Transaction};
+use std::ffi::CString;
 use std::path::Path;
 use std::result::Result as StdResult;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
 
 pub type MessageReader<'a, T> = capnp::message::TypedReader<capnp::serialize::SliceSegments<'a>, T>;
 
+/// Wrapper around an LMDB environment.
+///
+/// All transactions must be created through `rw_txn` and `ro_txn`, since the semaphores
+/// guarding them are also used to ensure that no transactions are open while the memory map
+/// is resized in `write`.
 pub struct Environment {
-    env: lmdb::Environment,
+    // shared with the blocking tasks that copy and sync it
+    env: Arc<lmdb::Environment>,
     write_semaphore: Semaphore,
     read_semaphore: Semaphore,
+    map_size: AtomicUsize,
 }
 pub struct RoTransaction<'a> {
     txn: lmdb::RoTransaction<'a>,
@@ -93,6 +105,18 @@ pub trait DBTransaction<'a, T: lmdb::Transaction + 'a>: Sized {
         Ok(self.txn().open_ro_cursor(db)?)
     }
 
+    /// Returns the number of entries in `db`.
+    fn entries(&'a self, db: lmdb::Database) -> Result<usize> {
+        let mut stat = std::mem::MaybeUninit::<lmdb_sys::MDB_stat>::uninit();
+        let result = unsafe { lmdb_sys::mdb_stat(self.txn().txn(), db.dbi(), stat.as_mut_ptr()) };
+        if result == lmdb_sys::MDB_SUCCESS {
+            // safety: mdb_stat has initialized the stat on success
+            Ok(unsafe { stat.assume_init() }.ms_entries)
+        } else {
+            Err(lmdb::Error::from_err_code(result).into())
+        }
+    }
+
     fn get<V: for<'b> capnp::traits::Owned<'b>, K>(
         &'a self,
         db: lmdb::Database,
@@ -203,7 +227,7 @@ impl<'a> RwTransaction<'a> {
         match self.txn.del(db, key, Option::None) {
             Err(err) => match err {
                 lmdb::Error::NotFound => Ok(false),
-                _ => Err(Error::Lmdb(err)),
+                _ => Err(Error::from(err)),
             },
             Ok(_) => Ok(true),
         }
@@ -220,6 +244,32 @@ impl<'a> RwTransaction<'a> {
         Ok(())
     }
 
+    /// Replaces each entry of `db` for which `rewrite` returns a new key and value. All rewritten
+    /// entries are removed before any is put back, so a new key may be another entry's old key.
+    /// Returns the number of rewritten entries.
+    pub fn rewrite_entries<F>(&mut self, db: lmdb::Database, mut rewrite: F) -> Result<usize>
+    where
+        F: FnMut(&[u8], &[u8]) -> Option<(Vec<u8>, Vec<u8>)>,
+    {
+        let mut rewritten = Vec::new();
+        {
+            let mut cursor = self.txn.open_ro_cursor(db)?;
+            for entry in cursor.iter_start() {
+                let (key, value) = entry?;
+                if let Some(new_entry) = rewrite(key, value) {
+                    rewritten.push((key.to_vec(), new_entry));
+                }
+            }
+        }
+        for (key, _) in &rewritten {
+            self.delete(db, key)?;
+        }
+        for (_, (key, value)) in &rewritten {
+            self.put_bytes(db, key, value)?;
+        }
+        Ok(rewritten.len())
+    }
+
     pub fn clear_db(&mut self, db: lmdb::Database) -> Result<()> {
         self.txn.clear_db(db)?;
         Ok(())
@@ -237,14 +287,14 @@ impl<'a> RwTransaction<'a> {
     }
 }
 
+/// Default size of the memory map for an `Environment`, which is also the maximum size of the database.
+pub const DEFAULT_MAP_SIZE: usize = 1 << 31;
+
+const MAX_READERS: u32 = 126;
+
 impl Environment {
     pub fn new(path: &Path) -> Result<Environment> {
-        #[cfg(target_pointer_width = "32")]
-        let map_size = 1 << 31;
-        #[cfg(target_pointer_width = "64")]
-        let map_size = 1 << 31;
-
-        Self::with_map_size(path, map_size)
+        Self::with_map_size(path, DEFAULT_MAP_SIZE)
     }
 
     pub fn with_map_size(path: &Path, map_size: usize) -> Result<Environment> {
@@ -255,8 +305,6 @@ impl Environment {
         #[cfg(not(target_os = "macos"))]
         let flags = flags | lmdb::EnvironmentFlags::WRITE_MAP;
 
-        const MAX_READERS: u32 = 126;
-
         let env = lmdb::Environment::new()
             .set_max_dbs(64)
             .set_max_readers(MAX_READERS)
@@ -264,12 +312,18 @@ impl Environment {
             .set_flags(flags)
             .open(path)?;
         Ok(Environment {
-            env,
+            env: Arc::new(env),
             read_semaphore: Semaphore::new(MAX_READERS as _),
             write_semaphore: Semaphore::new(1),
+            map_size: AtomicUsize::new(map_size),
         })
     }
 
+    /// Returns the current size of the memory map in bytes.
+    pub fn map_size(&self) -> usize {
+        self.map_size.load(Ordering::Acquire)
+    }
+
     pub fn create_db(
         &self,
         name: Option<&str>,
@@ -278,6 +332,28 @@ impl Environment {
         Ok(self.env.create_db(name, flags)?)
     }
 
+    /// Deletes the named database and its entries, if it exists. Returns whether it existed.
+    ///
+    /// This is meant for removing tables of earlier versions when opening an environment, so it
+    /// fails rather than waits if a write transaction is open. Handles to the database must not
+    /// be used afterwards.
+    pub fn drop_db(&self, name: &str) -> Result<bool> {
+        let db = match self.env.open_db(Some(name)) {
+            Ok(db) => db,
+            Err(lmdb::Error::NotFound) => return Ok(false),
+            Err(err) => return Err(err.into()),
+        };
+        let _guard = self
+            .write_semaphore
+            .try_acquire()
+            .ok_or_else(|| Error::Custom(format!("can't drop {}: a write is in progress", name)))?;
+        let mut txn = self.env.begin_rw_txn()?;
+        // safety: the handle was opened above and is not used after this
+        unsafe { txn.drop_db(db)? };
+        txn.commit()?;
+        Ok(true)
+    }
+
     pub async fn rw_txn(&self) -> Result<RwTransaction<'_>> {
         Ok(RwTransaction {
             guard: self.write_semaphore.acquire().await,
@@ -292,4 +368,224 @@ impl Environment {
             txn: self.env.begin_ro_txn()?,
         })
     }
+
+    /// Writes a copy of the environment into the existing directory at `path`.
+    ///
+    /// The copy is consistent with the last committed write transaction and can be made while
+    /// the environment is in use. When `compact` is set, free pages are omitted and pages are
+    /// renumbered sequentially, which results in a smaller copy. The copy is written on a
+    /// blocking thread.
+    pub async fn copy_to(&self, path: &Path, compact: bool) -> Result<()> {
+        // the copy holds a read transaction internally
+        let _guard = self.read_semaphore.acquire().await;
+        let path = path
+            .to_str()
+            .and_then(|p| CString::new(p).ok())
+            .ok_or_else(|| Error::Custom(format!("invalid database copy path {:?}", path)))?;
+        let flags = if compact { lmdb_sys::MDB_CP_COMPACT } else { 0 };
+        let env = self.env.clone();
+        let result = tokio::task::spawn_blocking(move || unsafe {
+            lmdb_sys::mdb_env_copy2(env.env(), path.as_ptr(), flags)
+        })
+        .await
+        .map_err(|err| Error::Custom(format!("database copy failed: {}", err)))?;
+        if result == lmdb_sys::MDB_SUCCESS {
+            Ok(())
+        } else {
+            Err(lmdb::Error::from_err_code(result).into())
+        }
+    }
+
+    /// Flushes the data written by committed transactions to disk. Waits for the write
+    /// transaction in progress, if any, to finish first.
+    ///
+    /// Commits are flushed on their own unless the environment was opened with `NO_SYNC` or
+    /// `MAP_ASYNC`. With `force`, the flush is synchronous even then, otherwise it may not have
+    /// reached the disk when this returns. The data is written on a blocking thread.
+    pub async fn sync(&self, force: bool) -> Result<()> {
+        let _guard = self.write_semaphore.acquire().await;
+        let env = self.env.clone();
+        tokio::task::spawn_blocking(move || env.sync(force))
+            .await
+            .map_err(|err| Error::Custom(format!("database sync failed: {}", err)))??;
+        Ok(())
+    }
+
+    /// Runs `f` in a write transaction and commits it.
+    ///
+    /// If the transaction fails with `Error::MapFull`, it is aborted, the memory map is doubled
+    /// in size and `f` is retried once in a new transaction. `f` may therefore run twice, so
+    /// effects it has outside of the transaction must tolerate that. If the map can't grow any
+    /// further, `Error::MapFull` is returned.
+    ///
+    /// # Concurrency
+    ///
+    /// LMDB requires that no transactions are open in the process while the map is resized.
+    /// Growing the map therefore waits until every read and write transaction of this
+    /// `Environment` has been dropped, which means:
+    ///
+    /// * The caller must not hold any other transaction of this environment while calling `write`,
+    ///   or it will deadlock if the map needs to grow.
+    /// * Long-lived read transactions, such as asset hub snapshots held by RPC clients, delay the
+    ///   resize until they are released.
+    pub async fn write<F, R>(&self, mut f: F) -> Result<R>
+    where
+        F: FnMut(&mut RwTransaction<'_>) -> Result<R>,
+    {
+        match self.try_write(&mut f).await {
+            Err(Error::MapFull) => {
+                self.grow_map().await?;
+                self.try_write(&mut f).await
+            }
+            result => result,
+        }
+    }
+
+    async fn try_write<F, R>(&self, f: &mut F) -> Result<R>
+    where
+        F: FnMut(&mut RwTransaction<'_>) -> Result<R>,
+    {
+        let mut txn = self.rw_txn().await?;
+        let result = f(&mut txn)?;
+        txn.commit()?;
+        Ok(result)
+    }
+
+    async fn grow_map(&self) -> Result<()> {
+        // Hold every permit so no transactions can be opened during the resize.
+        let _write_guard = self.write_semaphore.acquire().await;
+        let mut read_guards = Vec::with_capacity(MAX_READERS as usize);
+        for _ in 0..MAX_READERS {
+            read_guards.push(self.read_semaphore.acquire().await);
+        }
+        // the map can't grow beyond the address space, which 32-bit targets reach quickly
+        let new_size = self.map_size().checked_mul(2).ok_or(Error::MapFull)?;
+        log::info!("growing database map size to {} bytes", new_size);
+        self.env.set_map_size(new_size)?;
+        self.map_size.store(new_size, Ordering::Release);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_full_is_reported() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let env = Environment::with_map_size(db_dir.path(), 1 << 20).unwrap();
+        let db = env
+            .create_db(Some("test"), lmdb::DatabaseFlags::default())
+            .unwrap();
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let result = runtime.block_on(async {
+            let value = vec![0u8; 4096];
+            for i in 0u32..1024 {
+                let mut txn = env.rw_txn().await?;
+                txn.put_bytes(db, &i.to_le_bytes(), &value)?;
+                txn.commit()?;
+            }
+            Ok::<_, Error>(())
+        });
+        match result {
+            Err(err @ Error::MapFull) => {
+                assert!(format!("{}", err).contains("with_db_map_size"));
+            }
+            other => panic!("expected Error::MapFull, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn write_grows_map_when_full() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let initial_size = 1 << 20;
+        let env = Environment::with_map_size(db_dir.path(), initial_size).unwrap();
+        let db = env
+            .create_db(Some("test"), lmdb::DatabaseFlags::default())
+            .unwrap();
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let value = vec![0u8; 4096];
+            for i in 0u32..1024 {
+                env.write(|txn| txn.put_bytes(db, &i.to_le_bytes(), &value))
+                    .await
+                    .unwrap();
+            }
+            let txn = env.ro_txn().await.unwrap();
+            for i in 0u32..1024 {
+                assert!(txn.get_as_bytes(db, &i.to_le_bytes()).unwrap().is_some());
+            }
+        });
+        assert!(env.map_size() > initial_size);
+    }
+
+    #[test]
+    fn synced_data_is_read_after_reopening() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let env = Environment::with_map_size(db_dir.path(), 1 << 21).unwrap();
+            let db = env
+                .create_db(Some("test"), lmdb::DatabaseFlags::default())
+                .unwrap();
+            env.write(|txn| txn.put_bytes(db, b"key", b"value"))
+                .await
+                .unwrap();
+            env.sync(true).await.unwrap();
+            drop(env);
+
+            let env = Environment::with_map_size(db_dir.path(), 1 << 21).unwrap();
+            let db = env
+                .create_db(Some("test"), lmdb::DatabaseFlags::default())
+                .unwrap();
+            let txn = env.ro_txn().await.unwrap();
+            assert_eq!(Some(&b"value"[..]), txn.get_as_bytes(db, b"key").unwrap());
+        });
+    }
+
+    #[test]
+    fn copy_to_produces_matching_environment() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let copy_dir = tempfile::tempdir().unwrap();
+        let env = Environment::with_map_size(db_dir.path(), 1 << 21).unwrap();
+        let db = env
+            .create_db(Some("test"), lmdb::DatabaseFlags::default())
+            .unwrap();
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let mut txn = env.rw_txn().await.unwrap();
+            for i in 0u32..64 {
+                txn.put_bytes(db, &i.to_le_bytes(), &i.to_be_bytes())
+                    .unwrap();
+            }
+            txn.commit().unwrap();
+            // deleted entries leave free pages behind which the compacted copy drops
+            let mut txn = env.rw_txn().await.unwrap();
+            for i in 32u32..64 {
+                txn.delete(db, &i.to_le_bytes()).unwrap();
+            }
+            txn.commit().unwrap();
+
+            env.copy_to(copy_dir.path(), true).await.unwrap();
+
+            let copy = Environment::with_map_size(copy_dir.path(), 1 << 21).unwrap();
+            let copy_db = copy
+                .create_db(Some("test"), lmdb::DatabaseFlags::default())
+                .unwrap();
+            let txn = copy.ro_txn().await.unwrap();
+            for i in 0u32..32 {
+                assert_eq!(
+                    txn.get_as_bytes(copy_db, &i.to_le_bytes()).unwrap(),
+                    Some(&i.to_be_bytes()[..])
+                );
+            }
+            for i in 32u32..64 {
+                assert!(txn
+                    .get_as_bytes(copy_db, &i.to_le_bytes())
+                    .unwrap()
+                    .is_none());
+            }
+        });
+    }
 }
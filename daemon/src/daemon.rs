@@ -1,32 +1,146 @@
 use crate::{
-    artifact_cache::ArtifactCache, asset_hub, asset_hub_service, capnp_db::Environment,
-    error::Result, file_asset_source, file_tracker::FileTracker,
+    artifact_cache::ArtifactCache,
+    asset_hub,
+    asset_hub_service::{self, ServiceAddress},
+    capnp_db::{Environment, DEFAULT_MAP_SIZE},
+    error::{Error, Result},
+    file_asset_source::{self, DryRunReport},
+    file_tracker::{FileTracker, FileTrackerEvent},
+    metrics::DaemonMetrics,
 };
-use atelier_importer::{BoxedImporter, ImporterContext};
+use atelier_core::{
+    utils::{ArtifactHasher, DefaultArtifactHasher},
+    ArtifactMetadata, AssetTypeId, AssetUuid, SerializationFormat,
+};
+use atelier_importer::{BoxedImporter, ImporterContext, SerdeObj, ServiceContext};
 use atelier_schema::data;
-use futures_util::future::FutureExt;
+use futures_channel::{mpsc, oneshot};
+use futures_util::{
+    future::{self, FutureExt},
+    StreamExt,
+};
 use std::{
     collections::HashMap,
     fs,
+    future::Future,
     net::SocketAddr,
     path::{Path, PathBuf},
     sync::Arc,
+    thread,
+    time::Duration,
 };
 
+type OptionsFactory = Box<dyn Fn() -> Box<dyn SerdeObj> + Send + Sync>;
+type ScanProgressCallback = Box<dyn Fn(&Path, u64) + Send>;
+/// Sent to a running daemon through a [`DaemonHandle`]
+enum DaemonCommand {
+    AddWatchDir(PathBuf, oneshot::Sender<bool>),
+    RemoveWatchDir(PathBuf, oneshot::Sender<bool>),
+    ListAssets(
+        Option<AssetUuid>,
+        usize,
+        oneshot::Sender<Result<Vec<AssetListEntry>>>,
+    ),
+}
+/// An asset with its latest artifact and the source file it was imported from
+pub type AssetListEntry = (AssetUuid, ArtifactMetadata, PathBuf);
+/// Sent to a running daemon through its [`ShutdownHandle`], and answered once done
+enum DbRequest {
+    Flush(oneshot::Sender<Result<()>>),
+    /// Copy the databases into a directory, compacted or not
+    Backup(PathBuf, bool, oneshot::Sender<Result<()>>),
+}
+
+/// A registered importer, as reported to editors that build import settings UIs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImporterDescription {
+    /// Lowercase extension of the source files the importer handles
+    pub extension: String,
+    pub importer_type: AssetTypeId,
+    pub version: u32,
+    /// Type of the importer's options, as stored in `.meta` files
+    pub options_type: AssetTypeId,
+}
+
 #[derive(Default)]
-pub struct ImporterMap(HashMap<String, Box<dyn BoxedImporter>>);
+pub struct ImporterMap {
+    importers: HashMap<String, Arc<dyn BoxedImporter>>,
+    /// Project-wide default options, keyed by importer type
+    default_options: HashMap<AssetTypeId, OptionsFactory>,
+}
 
 impl ImporterMap {
     pub fn insert(&mut self, ext: &str, importer: Box<dyn BoxedImporter>) {
-        self.0.insert(ext.to_lowercase(), importer);
+        self.importers
+            .insert(ext.to_lowercase(), Arc::from(importer));
+    }
+
+    /// Sets the options that importers of type `importer_type` use for source files whose `.meta`
+    /// file doesn't override them.
+    pub fn set_default_options<O>(&mut self, importer_type: AssetTypeId, options: O)
+    where
+        O: SerdeObj + Clone + Sync,
+    {
+        self.default_options.insert(
+            importer_type,
+            Box::new(move || -> Box<dyn SerdeObj> { Box::new(options.clone()) }),
+        );
+    }
+
+    /// Returns the options for a source file imported by `importer` that doesn't override them:
+    /// the project default registered for its type if there is one, otherwise
+    /// `Default::default()` of its options.
+    pub fn default_options(&self, importer: &dyn BoxedImporter) -> Box<dyn SerdeObj> {
+        let importer_default = importer.default_options();
+        match self.default_options.get(&AssetTypeId(importer.uuid())) {
+            Some(options) => {
+                let options = options();
+                if options.uuid() == importer_default.uuid() {
+                    options
+                } else {
+                    log::error!(
+                        "default options registered for importer {:?} have the wrong type",
+                        importer
+                    );
+                    importer_default
+                }
+            }
+            None => importer_default,
+        }
     }
 
     pub fn get_by_path<'a>(&'a self, path: &PathBuf) -> Option<&'a dyn BoxedImporter> {
+        self.get_entry(path).map(|i| i.as_ref())
+    }
+
+    /// Like `get_by_path`, but the importer can outlive the map, for imports that are abandoned
+    /// when they time out.
+    pub(crate) fn get_shared_by_path(&self, path: &PathBuf) -> Option<Arc<dyn BoxedImporter>> {
+        self.get_entry(path).cloned()
+    }
+
+    fn get_entry(&self, path: &PathBuf) -> Option<&Arc<dyn BoxedImporter>> {
         let lower_extension = path
             .extension()
             .map(|s| s.to_str().unwrap().to_lowercase())
             .unwrap_or_else(|| "".to_string());
-        self.0.get(lower_extension.as_str()).map(|i| i.as_ref())
+        self.importers.get(lower_extension.as_str())
+    }
+
+    /// Describes every registered importer, ordered by extension.
+    pub fn describe(&self) -> Vec<ImporterDescription> {
+        let mut importers: Vec<_> = self
+            .importers
+            .iter()
+            .map(|(ext, importer)| ImporterDescription {
+                extension: ext.clone(),
+                importer_type: AssetTypeId(importer.uuid()),
+                version: importer.version(),
+                options_type: AssetTypeId(importer.default_options().uuid()),
+            })
+            .collect();
+        importers.sort_by(|a, b| a.extension.cmp(&b.extension));
+        importers
     }
 }
 
@@ -43,13 +157,146 @@ impl AssetDaemonTables {
     }
 }
 
-const DAEMON_VERSION: u32 = 2;
+const DAEMON_VERSION: u32 = 5;
+
+/// Stops a daemon started with [`AssetDaemon::spawn`].
+///
+/// Dropping the handle also stops the daemon, but does not wait for it to finish.
+pub struct ShutdownHandle {
+    tx: oneshot::Sender<()>,
+    db_tx: mpsc::UnboundedSender<DbRequest>,
+    listening: future::Shared<oneshot::Receiver<ServiceAddress>>,
+    thread: thread::JoinHandle<()>,
+}
+
+impl ShutdownHandle {
+    /// Blocks until the asset hub service accepts connections and returns its address. With TCP
+    /// port 0 it contains the port that was picked. Fails if the daemon stopped before that, for
+    /// example because the address is in use; [`shutdown`](Self::shutdown) then reports why.
+    pub fn service_address(&self) -> Result<ServiceAddress> {
+        futures_executor::block_on(self.listening.clone())
+            .map_err(|_| Error::Custom("the daemon stopped before its service started".into()))
+    }
+
+    /// Blocks until everything the daemon has committed to its asset database and artifact
+    /// cache is on disk, for example before taking a backup of them. A write transaction in
+    /// progress is committed first.
+    pub fn flush(&self) -> Result<()> {
+        self.request(DbRequest::Flush)
+    }
+
+    /// Copies the asset database into `dir` and the artifact cache into `dir/cache` while the
+    /// daemon keeps running, so that `dir` can be used as the database directory of a daemon
+    /// later. The copies contain what was committed when the backup started. `dir` is created
+    /// if needed and must not contain databases already. With `compact`, free pages are left
+    /// out of the copies, which makes them smaller but takes longer.
+    pub fn backup<P: AsRef<Path>>(&self, dir: P, compact: bool) -> Result<()> {
+        let dir = dir.as_ref().to_path_buf();
+        self.request(|reply| DbRequest::Backup(dir, compact, reply))
+    }
+
+    fn request(
+        &self,
+        request: impl FnOnce(oneshot::Sender<Result<()>>) -> DbRequest,
+    ) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+        self.db_tx
+            .unbounded_send(request(tx))
+            .map_err(|_| Error::Custom("the daemon is not running".into()))?;
+        futures_executor::block_on(rx)
+            .map_err(|_| Error::Custom("the daemon stopped before answering".into()))?
+    }
+
+    /// Signals the daemon to stop and blocks until it has. The file tracker and its watcher
+    /// thread are stopped, and changes being processed are committed before this returns.
+    pub fn shutdown(self) {
+        let _ = self.tx.send(());
+        if let Err(panic) = self.thread.join() {
+            std::panic::resume_unwind(panic);
+        }
+    }
+}
+
+/// Changes the watched directories of a running daemon and lists its assets, see
+/// [`AssetDaemon::handle`].
+#[derive(Clone)]
+pub struct DaemonHandle {
+    commands_tx: mpsc::UnboundedSender<DaemonCommand>,
+}
+
+impl DaemonHandle {
+    /// Starts watching the asset directory `path` and imports its source files. Returns false if
+    /// the directory was already watched.
+    pub fn add_watch_dir<P: AsRef<Path>>(&self, path: P) -> Result<bool> {
+        self.request(|reply| DaemonCommand::AddWatchDir(path.as_ref().to_path_buf(), reply))
+    }
+
+    /// Stops watching the asset directory `path`. Its source files are removed from the daemon
+    /// as if they were deleted. Returns false if the directory was not watched.
+    pub fn remove_watch_dir<P: AsRef<Path>>(&self, path: P) -> Result<bool> {
+        self.request(|reply| DaemonCommand::RemoveWatchDir(path.as_ref().to_path_buf(), reply))
+    }
+
+    /// Returns up to `count` assets with an artifact, ordered by `AssetUuid` and starting after
+    /// the asset `after`. Pass the last asset of a page as `after` to get the next page.
+    pub fn list_assets(
+        &self,
+        after: Option<AssetUuid>,
+        count: usize,
+    ) -> Result<Vec<AssetListEntry>> {
+        self.request(|reply| DaemonCommand::ListAssets(after, count, reply))?
+    }
+
+    fn request<T>(&self, command: impl FnOnce(oneshot::Sender<T>) -> DaemonCommand) -> Result<T> {
+        let (tx, rx) = oneshot::channel();
+        self.commands_tx
+            .unbounded_send(command(tx))
+            .map_err(|_| Error::Custom("the daemon is not running".into()))?;
+        futures_executor::block_on(rx)
+            .map_err(|_| Error::Custom("the daemon stopped before answering".into()))
+    }
+}
+
 pub struct AssetDaemon {
     pub db_dir: PathBuf,
-    pub address: SocketAddr,
+    pub db_map_size: usize,
+    /// Directory of the artifact cache, `cache` in `db_dir` if not set
+    pub artifact_cache_dir: Option<PathBuf>,
+    pub artifact_cache_max_size: Option<u64>,
+    pub address: ServiceAddress,
     pub importers: ImporterMap,
     pub importer_contexts: Vec<Box<dyn ImporterContext>>,
+    pub artifact_hasher: Arc<dyn ArtifactHasher>,
+    pub serialization_format: SerializationFormat,
+    pub asset_uuid_namespace: Option<uuid::Uuid>,
+    pub import_concurrency: usize,
+    pub import_timeout: Option<Duration>,
+    pub case_insensitive_paths: bool,
+    /// Whether `.meta` files are written next to source files
+    pub meta_files: bool,
+    /// Source files larger than this many bytes are tracked but not imported
+    pub max_file_size: Option<u64>,
     pub asset_dirs: Vec<PathBuf>,
+    /// Moved asset directories as `(old, new)` pairs, relocated in the database on startup
+    pub relocated_roots: Vec<(PathBuf, PathBuf)>,
+    /// Whether symlinks to directories inside the asset directories are followed
+    pub follow_symlinks: bool,
+    /// Whether the asset directories are scanned for changes on startup
+    pub scan_at_startup: bool,
+    /// Capacity of the channel of file events from the watcher thread, unbounded if not set
+    pub file_event_capacity: Option<usize>,
+    /// Maximum number of 8 byte words in an RPC message read from a loader
+    pub rpc_traversal_limit_in_words: u64,
+    /// Maximum nesting depth of structs and lists in an RPC message read from a loader
+    pub rpc_nesting_limit: i32,
+    /// *feature:* `tls`. Certificates that encrypt the connections from loaders
+    #[cfg(feature = "tls")]
+    pub tls: Option<asset_hub_service::TlsConfig>,
+    pub metrics: Arc<DaemonMetrics>,
+    /// Called with the directory being scanned and the number of entries scanned so far
+    pub scan_progress: Option<ScanProgressCallback>,
+    commands_tx: mpsc::UnboundedSender<DaemonCommand>,
+    commands_rx: mpsc::UnboundedReceiver<DaemonCommand>,
 }
 
 pub fn default_importer_contexts() -> Vec<Box<dyn ImporterContext + 'static>> {
@@ -63,7 +310,9 @@ pub fn default_importers() -> Vec<(&'static str, Box<dyn BoxedImporter>)> {
     let mut importers: Vec<(&'static str, Box<dyn BoxedImporter>)> = vec![];
 
     atelier_importer::if_serde_importers!(
-        importers.push(("ron", Box::new(atelier_importer::RonImporter::default())))
+        importers.push(("ron", Box::new(atelier_importer::RonImporter::default())));
+        importers.push(("cbor", Box::new(atelier_importer::CborImporter::default())));
+        importers.push(("bin", Box::new(atelier_importer::RawImporter::default())))
     );
     importers
 }
@@ -74,12 +323,36 @@ impl Default for AssetDaemon {
             println!("ext {}", ext);
             importer_map.insert(ext, importer);
         }
+        let (commands_tx, commands_rx) = mpsc::unbounded();
         Self {
             db_dir: PathBuf::from(".assets_db"),
-            address: "127.0.0.1:9999".parse().unwrap(),
+            db_map_size: DEFAULT_MAP_SIZE,
+            artifact_cache_dir: None,
+            artifact_cache_max_size: None,
+            address: ServiceAddress::Tcp("127.0.0.1:9999".parse().unwrap()),
             importers: importer_map,
             importer_contexts: default_importer_contexts(),
+            artifact_hasher: Arc::new(DefaultArtifactHasher),
+            serialization_format: SerializationFormat::default(),
+            asset_uuid_namespace: None,
+            import_concurrency: num_cpus::get(),
+            import_timeout: None,
+            case_insensitive_paths: cfg!(any(windows, target_os = "macos")),
+            meta_files: true,
+            max_file_size: None,
             asset_dirs: vec![PathBuf::from("assets")],
+            relocated_roots: Vec::new(),
+            follow_symlinks: true,
+            scan_at_startup: true,
+            file_event_capacity: None,
+            rpc_traversal_limit_in_words: 64 * 1024 * 1024,
+            rpc_nesting_limit: 64,
+            #[cfg(feature = "tls")]
+            tls: None,
+            metrics: Arc::new(DaemonMetrics::default()),
+            scan_progress: None,
+            commands_tx,
+            commands_rx,
         }
     }
 }
@@ -90,11 +363,74 @@ impl AssetDaemon {
         self
     }
 
+    /// Sets the maximum size of the asset database in bytes.
+    /// Imports fail once the database grows beyond this size.
+    pub fn with_db_map_size(mut self, map_size: usize) -> Self {
+        self.db_map_size = map_size;
+        self
+    }
+
+    /// Stores cached artifacts in `path` instead of the `cache` directory in the database
+    /// directory.
+    pub fn with_artifact_cache_dir<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.artifact_cache_dir = Some(path.as_ref().to_owned());
+        self
+    }
+
+    /// Limits the total size of the cached artifacts to about `max_size` bytes. When an import
+    /// exceeds it, the artifacts that were least recently imported or loaded are removed, and are
+    /// imported again when they are requested. Unlimited by default.
+    pub fn with_artifact_cache_max_size(mut self, max_size: u64) -> Self {
+        self.artifact_cache_max_size = Some(max_size);
+        self
+    }
+
+    fn artifact_cache_dir(&self) -> PathBuf {
+        self.artifact_cache_dir
+            .clone()
+            .unwrap_or_else(|| self.db_dir.join("cache"))
+    }
+
+    /// Sets the TCP address that loaders connect to, `127.0.0.1:9999` by default. Daemons that
+    /// run at the same time need different addresses. If the address is in use, the daemon panics
+    /// with an error that names it.
     pub fn with_address(mut self, address: SocketAddr) -> Self {
+        self.address = ServiceAddress::Tcp(address);
+        self
+    }
+
+    /// Sets where loaders connect to the daemon, which can be a Unix domain socket on platforms
+    /// that support them. Defaults to TCP on `127.0.0.1:9999`.
+    pub fn with_service_address(mut self, address: ServiceAddress) -> Self {
         self.address = address;
         self
     }
 
+    /// Sets the limits of the RPC messages the daemon reads from loaders: the number of 8 byte
+    /// words that may be traversed in a message, and how deeply structs and lists may nest.
+    /// Messages that exceed them fail with an error and close the connection. The defaults are
+    /// 64Mi words (512MiB) and 64, the same as the limits `RpcIO` applies to the responses it
+    /// reads, which can be changed with `RpcIO::with_message_limits`. Cap'n Proto does not limit
+    /// the size of messages that are written.
+    pub fn with_rpc_message_limits(
+        mut self,
+        traversal_limit_in_words: u64,
+        nesting_limit: i32,
+    ) -> Self {
+        self.rpc_traversal_limit_in_words = traversal_limit_in_words;
+        self.rpc_nesting_limit = nesting_limit;
+        self
+    }
+
+    /// *feature:* `tls`. Encrypts the connections from loaders with TLS. Loaders then have to
+    /// connect with a matching TLS configuration, and the daemon panics on start if the
+    /// certificates can't be loaded.
+    #[cfg(feature = "tls")]
+    pub fn with_tls(mut self, tls: asset_hub_service::TlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
     pub fn with_importer<B>(mut self, ext: &str, importer: B) -> Self
     where
         B: BoxedImporter + 'static,
@@ -130,6 +466,24 @@ impl AssetDaemon {
         }
     }
 
+    /// Sets project-wide default options for importers of type `importer_type`.
+    ///
+    /// Options are chosen in this order of precedence: the options in a source file's `.meta`
+    /// file, then the project default set here, then `Default::default()` of the importer's
+    /// options type. The project default isn't written to `.meta` files, so a changed default
+    /// applies to the source files that don't override it the next time they are imported.
+    pub fn with_importer_default_options<O>(
+        mut self,
+        importer_type: AssetTypeId,
+        options: O,
+    ) -> Self
+    where
+        O: SerdeObj + Clone + Sync,
+    {
+        self.importers.set_default_options(importer_type, options);
+        self
+    }
+
     pub fn with_importer_context(mut self, context: Box<dyn ImporterContext>) -> Self {
         self.importer_contexts.push(context);
         self
@@ -143,34 +497,282 @@ impl AssetDaemon {
         self
     }
 
+    /// Makes `service` available to importers, which get it with
+    /// `ImportOp::service::<T>()` while they import.
+    pub fn with_importer_service<T: Send + Sync + 'static>(self, service: T) -> Self {
+        self.with_importer_context(Box::new(ServiceContext::new(service)))
+    }
+
+    /// Sets the hasher used to calculate artifact hashes, for example
+    /// `atelier_core::utils::WideArtifactHasher` for 128-bit hashes.
+    pub fn with_artifact_hasher<H>(mut self, hasher: H) -> Self
+    where
+        H: ArtifactHasher + 'static,
+    {
+        self.artifact_hasher = Arc::new(hasher);
+        self
+    }
+
+    /// Sets the format used to serialize imported artifacts. Loaders pick the matching
+    /// deserializer from `ArtifactMetadata::serialization_format`.
+    pub fn with_serialization_format(mut self, serialization_format: SerializationFormat) -> Self {
+        self.serialization_format = serialization_format;
+        self
+    }
+
+    /// Derives the UUIDs of newly imported assets from `namespace` and their source path relative
+    /// to the asset directory, instead of generating random ones. Machines importing the same
+    /// asset tree get the same UUIDs without sharing `.meta` files.
+    ///
+    /// Importers must generate UUIDs with `ImportOp::new_asset_uuid`.
+    pub fn with_deterministic_asset_uuids(mut self, namespace: uuid::Uuid) -> Self {
+        self.asset_uuid_namespace = Some(namespace);
+        self
+    }
+
+    /// Sets the maximum number of source files that are imported concurrently.
+    /// Defaults to the number of logical CPUs.
+    pub fn with_import_concurrency(mut self, concurrency: usize) -> Self {
+        self.import_concurrency = concurrency;
+        self
+    }
+
+    /// Fails imports that take longer than `timeout` with an "import timed out" error, so a stuck
+    /// importer doesn't hold up the other source files. By default imports never time out.
+    ///
+    /// Imports run on threads of the blocking pool, so the timeout also fires for importers that
+    /// block, such as implementations of `atelier_importer::Importer`. The thread of an import
+    /// that timed out keeps running until the importer returns, and its result is discarded.
+    pub fn with_import_timeout(mut self, timeout: Duration) -> Self {
+        self.import_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether source file paths are matched regardless of case and path separator.
+    /// Defaults to `true` on Windows and macOS, where filesystems are usually case-insensitive.
+    /// Changing this for an existing asset database requires deleting the database.
+    pub fn with_case_insensitive_paths(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive_paths = case_insensitive;
+        self
+    }
+
+    /// Sets whether `.meta` files are written next to source files, which they are by default.
+    /// Without them the importer options and state of each source file, including the UUIDs of
+    /// its assets, are only stored in the asset database. UUIDs then stay stable only as long as
+    /// the database is kept, and can't be shared with other machines through version control.
+    /// Existing `.meta` files are still read.
+    pub fn with_meta_files(mut self, meta_files: bool) -> Self {
+        self.meta_files = meta_files;
+        self
+    }
+
+    /// Skips importing source files larger than `max_file_size` bytes, such as huge videos
+    /// dropped into an asset directory, which would otherwise stall all other imports while
+    /// being hashed and imported. Skipped files are still tracked and are reported as import
+    /// errors, and are imported once they shrink below the limit.
+    pub fn with_max_file_size(mut self, max_file_size: u64) -> Self {
+        self.max_file_size = Some(max_file_size);
+        self
+    }
+
+    /// Tells the daemon that the directory at `old_root` was moved to `new_root` since it last
+    /// ran. The paths stored for the files under it are rewritten on startup, before the asset
+    /// directories are scanned, so their assets keep their UUIDs and artifacts instead of being
+    /// removed and imported again. `new_root` should also be one of the asset directories.
+    pub fn with_relocated_root<P: Into<PathBuf>>(mut self, old_root: P, new_root: P) -> Self {
+        self.relocated_roots
+            .push((old_root.into(), new_root.into()));
+        self
+    }
+
     pub fn with_asset_dirs(mut self, dirs: Vec<PathBuf>) -> Self {
         self.asset_dirs = dirs;
         self
     }
 
+    /// Sets whether symlinks to directories inside the asset directories are followed, which
+    /// they are by default. Asset directories that are symlinks are always followed.
+    pub fn with_follow_symlinks(mut self, follow_symlinks: bool) -> Self {
+        self.follow_symlinks = follow_symlinks;
+        self
+    }
+
+    /// Sets whether the asset directories are scanned on startup, which they are by default.
+    /// Without the scan, the daemon trusts the asset database of its previous run and only
+    /// imports files that change while it runs. Files added, changed or removed while the
+    /// daemon was not running are not detected until they are touched again.
+    pub fn with_scan_at_startup(mut self, scan_at_startup: bool) -> Self {
+        self.scan_at_startup = scan_at_startup;
+        self
+    }
+
+    /// Bounds the number of file events queued between the file watcher and the file tracker.
+    /// When the queue is full the watcher waits instead of dropping events, which keeps memory
+    /// in check when many files change at once, e.g. on a branch switch.
+    pub fn with_file_event_capacity(mut self, capacity: usize) -> Self {
+        self.file_event_capacity = Some(capacity);
+        self
+    }
+
+    /// Calls `callback` periodically while the asset directories are scanned, such as on the first
+    /// start against a large asset tree, so editors can show progress. It receives the directory
+    /// that is being scanned and the number of files and directories scanned so far in the scan.
+    /// Each scan counts from zero.
+    pub fn with_scan_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&Path, u64) + Send + 'static,
+    {
+        self.scan_progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Returns the counters the daemon updates while it runs. Keep the returned handle to read
+    /// them after the daemon was started with [`run`](Self::run) or [`spawn`](Self::spawn).
+    pub fn metrics(&self) -> Arc<DaemonMetrics> {
+        self.metrics.clone()
+    }
+
+    /// Returns a handle that changes the watched directories and lists the assets while the
+    /// daemon runs. Keep it to use it after the daemon was started with [`run`](Self::run) or
+    /// [`spawn`](Self::spawn).
+    pub fn handle(&self) -> DaemonHandle {
+        DaemonHandle {
+            commands_tx: self.commands_tx.clone(),
+        }
+    }
+
     pub fn run(self) {
+        let (_, db_rx) = mpsc::unbounded();
+        self.run_until(future::pending(), db_rx, None);
+    }
+
+    /// Runs the daemon on a new thread. The returned handle is used to flush and back up its
+    /// databases and to stop it.
+    pub fn spawn(self) -> ShutdownHandle {
+        let (tx, rx) = oneshot::channel();
+        let (db_tx, db_rx) = mpsc::unbounded();
+        let (listening_tx, listening_rx) = oneshot::channel();
+        let thread = thread::Builder::new()
+            .name("atelier-daemon".to_string())
+            .spawn(move || self.run_until(rx.map(|_| ()), db_rx, Some(listening_tx)))
+            .expect("Failed to spawn `atelier-daemon` thread.");
+        ShutdownHandle {
+            tx,
+            db_tx,
+            listening: listening_rx.shared(),
+            thread,
+        }
+    }
+
+    /// Reports which source files the daemon would import or remove when started, and which
+    /// imports fail, without writing to the asset database, the artifact cache or `.meta` files.
+    /// The importers run as they would in the daemon, but their results are discarded.
+    ///
+    /// The report is based on the asset database as it is, so it should have been written by a
+    /// daemon of the same version.
+    pub fn dry_run(self) -> Result<DryRunReport> {
+        use asset_hub::AssetHub;
+        use file_asset_source::FileAssetSource;
+
+        let cache_dir = self.artifact_cache_dir();
+        let _ = fs::create_dir(&self.db_dir);
+        let _ = fs::create_dir_all(&cache_dir);
+
+        let asset_db = Arc::new(Environment::with_map_size(&self.db_dir, self.db_map_size)?);
+        let to_watch = self.asset_dirs.iter().map(|p| p.to_str().unwrap());
+        let mut tracker = FileTracker::new_with_metrics(asset_db.clone(), to_watch, self.metrics)
+            .with_follow_symlinks(self.follow_symlinks)
+            .with_scan_at_startup(self.scan_at_startup);
+        if let Some(capacity) = self.file_event_capacity {
+            tracker = tracker.with_event_channel_capacity(capacity);
+        }
+        let tracker = Arc::new(tracker);
+        let hub = Arc::new(AssetHub::new(asset_db.clone())?);
+        let cache_db = Arc::new(Environment::new(&cache_dir)?);
+        let artifact_cache = Arc::new(ArtifactCache::new_with_max_size(
+            &cache_db,
+            self.artifact_cache_max_size,
+        )?);
+        let work_runtime = tokio::runtime::Builder::new()
+            .threaded_scheduler()
+            .core_threads(self.import_concurrency.max(1))
+            .enable_all()
+            .build()?;
+
+        let asset_source = FileAssetSource::new(
+            &tracker,
+            &hub,
+            &asset_db,
+            &Arc::new(self.importers),
+            &artifact_cache,
+            Arc::new(self.importer_contexts),
+            Arc::new(work_runtime),
+        )?
+        .with_artifact_hasher(self.artifact_hasher.clone())
+        .with_serialization_format(self.serialization_format)
+        .with_asset_uuid_namespace(self.asset_uuid_namespace)
+        .with_import_concurrency(self.import_concurrency)
+        .with_case_insensitive_paths(self.case_insensitive_paths)
+        .with_import_timeout(self.import_timeout)
+        .with_meta_files(self.meta_files)
+        .with_max_file_size(self.max_file_size);
+
+        let mut runtime = tokio::runtime::Builder::new()
+            .basic_scheduler()
+            .enable_all()
+            .build()?;
+        runtime.block_on(asset_source.dry_run())
+    }
+
+    fn run_until<F: Future<Output = ()> + 'static>(
+        self,
+        shutdown: F,
+        db_requests: mpsc::UnboundedReceiver<DbRequest>,
+        listening: Option<oneshot::Sender<ServiceAddress>>,
+    ) {
+        // A runtime can't be dropped from within another one, so the import runtime is kept
+        // here until the async part has returned and released its handles to it.
+        let work_runtime = tokio::runtime::Builder::new()
+            .threaded_scheduler()
+            .core_threads(self.import_concurrency.max(1))
+            .enable_all()
+            .build()
+            .unwrap();
+        let work_runtime = Arc::new(work_runtime);
         let mut rpc_runtime = tokio::runtime::Builder::new()
             .basic_scheduler()
             .enable_all()
             .build()
             .unwrap();
         let local = tokio::task::LocalSet::new();
-        rpc_runtime.block_on(local.run_until(async { self.run_rpc_runtime().await }));
+        rpc_runtime.block_on(local.run_until(async {
+            self.run_rpc_runtime(shutdown, db_requests, listening, work_runtime.clone())
+                .await
+        }));
+        drop(local);
+        drop(work_runtime);
     }
 
-    async fn run_rpc_runtime(self) {
+    async fn run_rpc_runtime<F: Future<Output = ()> + 'static>(
+        self,
+        shutdown: F,
+        mut db_requests: mpsc::UnboundedReceiver<DbRequest>,
+        listening: Option<oneshot::Sender<ServiceAddress>>,
+        work_runtime: Arc<tokio::runtime::Runtime>,
+    ) {
         use asset_hub::AssetHub;
         use asset_hub_service::AssetHubService;
         use file_asset_source::FileAssetSource;
 
-        let cache_dir = self.db_dir.join("cache");
+        let cache_dir = self.artifact_cache_dir();
         let _ = fs::create_dir(&self.db_dir);
-        let _ = fs::create_dir(&cache_dir);
+        let _ = fs::create_dir_all(&cache_dir);
         for dir in self.asset_dirs.iter() {
             let _ = fs::create_dir_all(dir);
         }
 
-        let asset_db = Environment::new(&self.db_dir).expect("failed to create asset db");
+        let asset_db = Environment::with_map_size(&self.db_dir, self.db_map_size)
+            .expect("failed to create asset db");
         let asset_db = Arc::new(asset_db);
 
         check_db_version(&asset_db)
@@ -178,8 +780,24 @@ impl AssetDaemon {
             .expect("failed to check daemon version in asset db");
 
         let to_watch = self.asset_dirs.iter().map(|p| p.to_str().unwrap());
-        let tracker = FileTracker::new(asset_db.clone(), to_watch);
+        let mut tracker = FileTracker::new_with_metrics(asset_db.clone(), to_watch, self.metrics)
+            .with_follow_symlinks(self.follow_symlinks)
+            .with_scan_at_startup(self.scan_at_startup);
+        if let Some(capacity) = self.file_event_capacity {
+            tracker = tracker.with_event_channel_capacity(capacity);
+        }
         let tracker = Arc::new(tracker);
+        if let Some(scan_progress) = self.scan_progress {
+            let (tx, mut rx) = futures_channel::mpsc::unbounded();
+            tracker.register_listener(tx);
+            tokio::task::spawn_local(async move {
+                while let Some(evt) = rx.next().await {
+                    if let FileTrackerEvent::ScanProgress(dir, scanned) = evt {
+                        scan_progress(&dir, scanned);
+                    }
+                }
+            });
+        }
 
         let hub = AssetHub::new(asset_db.clone()).expect("failed to create asset hub");
         let hub = Arc::new(hub);
@@ -189,15 +807,36 @@ impl AssetDaemon {
         let cache_db = Environment::new(&cache_dir).expect("failed to create asset db");
         let cache_db = Arc::new(cache_db);
         let artifact_cache =
-            ArtifactCache::new(&cache_db).expect("failed to create artifact cache");
+            ArtifactCache::new_with_max_size(&cache_db, self.artifact_cache_max_size)
+                .expect("failed to create artifact cache");
         let artifact_cache = Arc::new(artifact_cache);
 
-        let work_runtime = tokio::runtime::Builder::new()
-            .threaded_scheduler()
-            .enable_all()
-            .build()
-            .unwrap();
-        let work_runtime = Arc::new(work_runtime);
+        tokio::task::spawn_local({
+            let asset_db = asset_db.clone();
+            let cache_db = cache_db.clone();
+            async move {
+                while let Some(request) = db_requests.next().await {
+                    match request {
+                        DbRequest::Flush(reply) => {
+                            let result = match asset_db.sync(true).await {
+                                Ok(()) => cache_db.sync(true).await,
+                                err => err,
+                            };
+                            let _ = reply.send(result);
+                        }
+                        DbRequest::Backup(dir, compact, reply) => {
+                            let backup = async {
+                                let cache_dir = dir.join("cache");
+                                tokio::fs::create_dir_all(&cache_dir).await?;
+                                asset_db.copy_to(&dir, compact).await?;
+                                cache_db.copy_to(&cache_dir, compact).await
+                            };
+                            let _ = reply.send(backup.await);
+                        }
+                    }
+                }
+            }
+        });
 
         let asset_source = FileAssetSource::new(
             &tracker,
@@ -208,35 +847,113 @@ impl AssetDaemon {
             ctxs,
             work_runtime,
         )
-        .expect("failed to create asset source");
+        .expect("failed to create asset source")
+        .with_artifact_hasher(self.artifact_hasher.clone())
+        .with_serialization_format(self.serialization_format)
+        .with_asset_uuid_namespace(self.asset_uuid_namespace)
+        .with_import_concurrency(self.import_concurrency)
+        .with_case_insensitive_paths(self.case_insensitive_paths)
+        .with_import_timeout(self.import_timeout)
+        .with_meta_files(self.meta_files)
+        .with_max_file_size(self.max_file_size);
 
+        for (old_root, new_root) in &self.relocated_roots {
+            asset_source
+                .relocate_root(&absolute_path(old_root), &absolute_path(new_root))
+                .await
+                .expect("failed to relocate asset directory");
+        }
         let asset_source = Arc::new(asset_source);
 
+        // commands sent before this point wait in the channel until the asset source exists
+        tokio::task::spawn_local({
+            let tracker = tracker.clone();
+            let asset_db = asset_db.clone();
+            let asset_source = asset_source.clone();
+            let mut commands = self.commands_rx;
+            async move {
+                while let Some(command) = commands.next().await {
+                    match command {
+                        DaemonCommand::AddWatchDir(path, reply) => {
+                            let _ = reply.send(tracker.add_watch_dir(&path));
+                        }
+                        DaemonCommand::RemoveWatchDir(path, reply) => {
+                            let _ = reply.send(tracker.remove_watch_dir(&path));
+                        }
+                        DaemonCommand::ListAssets(after, count, reply) => {
+                            let assets = async {
+                                let txn = asset_db.ro_txn().await?;
+                                let assets: Vec<AssetListEntry> = asset_source
+                                    .iter_assets(&txn, after.as_ref())
+                                    .take(count)
+                                    .collect();
+                                Ok::<_, Error>(assets)
+                            };
+                            let _ = reply.send(assets.await);
+                        }
+                    }
+                }
+            }
+        });
+
         let service = AssetHubService::new(
             asset_db.clone(),
             hub.clone(),
             asset_source.clone(),
             tracker.clone(),
             artifact_cache.clone(),
+        )
+        .with_reader_options(
+            *capnp::message::ReaderOptions::new()
+                .traversal_limit_in_words(self.rpc_traversal_limit_in_words)
+                .nesting_limit(self.rpc_nesting_limit),
         );
+        #[cfg(feature = "tls")]
+        let service = match &self.tls {
+            Some(tls) => service
+                .with_tls(tls)
+                .expect("failed to load TLS certificates"),
+            None => service,
+        };
 
-        let addr = self.address;
-        let service_handle =
-            tokio::task::spawn_local(async move { service.run(addr).await }).fuse();
-        let tracker_handle = tokio::task::spawn_local(async move { tracker.run().await }).fuse(); // TODO: use tokio channel to make this Send
-        let asset_source_handle =
-            tokio::task::spawn_local(async move { asset_source.run().await }).fuse();
+        // the service and the file tracker stop on shutdown, which in turn stops the asset source
+        // once it has finished processing pending changes
+        let shutdown = shutdown.shared();
+        let addr = self.address.clone();
+        let service_shutdown = shutdown.clone();
+        let service_handle = tokio::task::spawn_local(async move {
+            service
+                .run_and_notify(addr, service_shutdown, listening)
+                .await
+        })
+        .fuse();
+        let tracker_stop = tracker.clone();
+        let tracker_handle = tokio::task::spawn_local(async move {
+            tracker.run().await;
+            Ok(())
+        })
+        .fuse(); // TODO: use tokio channel to make this Send
+        tokio::task::spawn_local(async move {
+            shutdown.await;
+            tracker_stop.stop().await;
+        });
+        let asset_source_handle = tokio::task::spawn_local(async move {
+            asset_source.run().await;
+            Ok(())
+        })
+        .fuse();
 
         let mut remaining_tasks = vec![service_handle, tracker_handle, asset_source_handle];
         loop {
             let (done, done_idx, rest) = futures_util::future::select_all(remaining_tasks).await;
-            if done.is_err() {
-                match done_idx {
-                    0 => done.expect("ServiceHandle panicked"),
-                    1 => done.expect("FileTracker panicked"),
-                    2 => done.expect("AssetSource panicked"),
-                    _ => panic!("unknown error"),
-                }
+            let result = match done_idx {
+                0 => done.expect("ServiceHandle panicked"),
+                1 => done.expect("FileTracker panicked"),
+                2 => done.expect("AssetSource panicked"),
+                _ => panic!("unknown error"),
+            };
+            if let Err(err) = result {
+                panic!("{}", err);
             }
             remaining_tasks = rest;
             if remaining_tasks.is_empty() {
@@ -288,14 +1005,197 @@ async fn check_db_version(env: &Environment) -> Result<()> {
         }
         txn.commit()?;
     }
-    let mut txn = env.rw_txn().await?;
+    // the read transaction must be closed before writing, since the write may need to grow the map
+    drop(txn);
     let mut value_builder = capnp::message::Builder::new_default();
     {
         let mut m = value_builder.init_root::<data::daemon_info::Builder<'_>>();
         m.set_version(DAEMON_VERSION);
     }
-    txn.put(tables.daemon_info, &info_key, &value_builder)?;
-    txn.commit()?;
+    env.write(|txn| txn.put(tables.daemon_info, &info_key, &value_builder))
+        .await
+}
+
+/// Makes `path` absolute and normalizes it the way the file tracker does for watched directories.
+fn absolute_path(path: &Path) -> PathBuf {
+    let path = if path.is_relative() {
+        std::env::current_dir()
+            .expect("failed to get current directory")
+            .join(path)
+    } else {
+        path.to_path_buf()
+    };
+    crate::watcher::canonicalize_path(&path)
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spawned_daemon_stops_cleanly_twice() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let asset_dir = tempfile::tempdir().unwrap();
+        std::fs::write(asset_dir.path().join("asset.txt"), "asset").unwrap();
+
+        // the second daemon reuses the address and database of the first one,
+        // which only works if both were released on shutdown
+        let mut address = ServiceAddress::Tcp("127.0.0.1:0".parse().unwrap());
+        for _ in 0..2 {
+            let handle = AssetDaemon::default()
+                .with_db_path(db_dir.path())
+                .with_service_address(address)
+                .with_asset_dirs(vec![asset_dir.path().to_path_buf()])
+                .spawn();
+            address = handle.service_address().unwrap();
+            handle.shutdown();
+        }
+    }
+
+    #[test]
+    fn spawned_daemon_flushes_its_databases() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let asset_dir = tempfile::tempdir().unwrap();
+        std::fs::write(asset_dir.path().join("asset.txt"), "asset").unwrap();
+
+        let handle = AssetDaemon::default()
+            .with_db_path(db_dir.path())
+            .with_address("127.0.0.1:0".parse().unwrap())
+            .with_asset_dirs(vec![asset_dir.path().to_path_buf()])
+            .spawn();
+        handle.service_address().unwrap();
+        handle.flush().unwrap();
+        handle.flush().unwrap();
+        handle.shutdown();
+
+        // the daemon wrote its version before it was flushed
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            use crate::capnp_db::DBTransaction;
+            let env = Environment::with_map_size(db_dir.path(), DEFAULT_MAP_SIZE).unwrap();
+            let tables = AssetDaemonTables::new(&env).unwrap();
+            let txn = env.ro_txn().await.unwrap();
+            let info_key: &[u8] = b"daemon_info";
+            let info = txn
+                .get::<data::daemon_info::Owned, &[u8]>(tables.daemon_info, &info_key)
+                .unwrap()
+                .expect("daemon info was not written");
+            assert_eq!(DAEMON_VERSION, info.get().unwrap().get_version());
+        });
+    }
+
+    #[test]
+    fn spawned_daemon_backs_up_its_databases() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let asset_dir = tempfile::tempdir().unwrap();
+        let backup_dir = tempfile::tempdir().unwrap();
+        let backup = backup_dir.path().join("backup");
+
+        let handle = AssetDaemon::default()
+            .with_db_path(db_dir.path())
+            .with_address("127.0.0.1:0".parse().unwrap())
+            .with_asset_dirs(vec![asset_dir.path().to_path_buf()])
+            .spawn();
+        handle.service_address().unwrap();
+        handle.backup(&backup, true).unwrap();
+        handle.shutdown();
+
+        assert!(backup.join("cache").join("data.mdb").is_file());
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            use crate::capnp_db::DBTransaction;
+            let env = Environment::with_map_size(&backup, DEFAULT_MAP_SIZE).unwrap();
+            let tables = AssetDaemonTables::new(&env).unwrap();
+            let txn = env.ro_txn().await.unwrap();
+            let info_key: &[u8] = b"daemon_info";
+            let info = txn
+                .get::<data::daemon_info::Owned, &[u8]>(tables.daemon_info, &info_key)
+                .unwrap()
+                .expect("daemon info was not backed up");
+            assert_eq!(DAEMON_VERSION, info.get().unwrap().get_version());
+        });
+    }
+
+    #[test]
+    fn watch_dirs_are_changed_through_the_handle() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let asset_dir = tempfile::tempdir().unwrap();
+        let new_dir = tempfile::tempdir().unwrap();
+
+        let daemon = AssetDaemon::default()
+            .with_db_path(db_dir.path())
+            .with_address("127.0.0.1:0".parse().unwrap())
+            .with_asset_dirs(vec![asset_dir.path().to_path_buf()]);
+        let handle = daemon.handle();
+        let shutdown = daemon.spawn();
+        assert!(handle.add_watch_dir(new_dir.path()).unwrap());
+        assert!(!handle.add_watch_dir(new_dir.path()).unwrap());
+        assert!(handle.remove_watch_dir(new_dir.path()).unwrap());
+        assert!(!handle.remove_watch_dir(new_dir.path()).unwrap());
+        shutdown.shutdown();
+
+        assert!(handle.add_watch_dir(new_dir.path()).is_err());
+    }
+
+    #[test]
+    fn daemons_need_different_addresses() {
+        let spawn = |address: ServiceAddress| {
+            let db_dir = tempfile::tempdir().unwrap();
+            let asset_dir = tempfile::tempdir().unwrap();
+            let handle = AssetDaemon::default()
+                .with_db_path(db_dir.path())
+                .with_service_address(address)
+                .with_asset_dirs(vec![asset_dir.path().to_path_buf()])
+                .spawn();
+            (handle, db_dir, asset_dir)
+        };
+        let any_port = || ServiceAddress::Tcp("127.0.0.1:0".parse().unwrap());
+
+        let first = spawn(any_port());
+        let second = spawn(any_port());
+        let first_address = first.0.service_address().unwrap();
+        assert_ne!(first_address, second.0.service_address().unwrap());
+        let conflicting = spawn(first_address.clone());
+        assert!(conflicting.0.service_address().is_err());
+
+        let panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            conflicting.0.shutdown();
+        }))
+        .expect_err("daemon on an address in use should fail");
+        let message = panic.downcast_ref::<String>().unwrap();
+        let first_address = match first_address {
+            ServiceAddress::Tcp(addr) => addr.to_string(),
+            #[cfg(unix)]
+            ServiceAddress::Unix(_) => unreachable!(),
+        };
+        assert!(message.contains(&first_address), "{}", message);
+        assert!(message.contains("with_address"), "{}", message);
+        first.0.shutdown();
+        second.0.shutdown();
+    }
+
+    #[cfg(feature = "serde_importers")]
+    #[test]
+    fn default_importers_are_described() {
+        use atelier_importer::{Importer, RonImporter};
+
+        let daemon = AssetDaemon::default();
+        let importers = daemon.importers.describe();
+        let ron = importers
+            .iter()
+            .find(|importer| importer.extension == "ron")
+            .expect("RON importer is registered by default");
+        let expected: Box<dyn BoxedImporter> = Box::new(RonImporter::default());
+        assert_eq!(AssetTypeId(expected.uuid()), ron.importer_type);
+        assert_eq!(RonImporter::version_static(), ron.version);
+        assert_eq!(
+            AssetTypeId(expected.default_options().uuid()),
+            ron.options_type
+        );
+
+        let extensions: Vec<_> = importers.iter().map(|i| i.extension.as_str()).collect();
+        let mut sorted = extensions.clone();
+        sorted.sort();
+        assert_eq!(sorted, extensions);
+    }
 }
@@ -1,4 +1,5 @@
-use std::{fmt, io, path::PathBuf, str};
+use atelier_core::AssetUuid;
+use std::{fmt, io, path::PathBuf, str, time::Duration};
 
 #[derive(Debug)]
 pub enum Error {
@@ -6,11 +7,15 @@ pub enum Error {
     IO(io::Error),
     RescanRequired,
     Lmdb(lmdb::Error),
+    MapFull,
     Capnp(capnp::Error),
+    /// An entry in a database table could not be decoded: (table, key, cause)
+    DbDecode(&'static str, String, Box<Error>),
     NotInSchema(capnp::NotInSchema),
     BincodeError(bincode::ErrorKind),
     RonSerError(ron::ser::Error),
     RonDeError(ron::de::Error),
+    JsonError(serde_json::Error),
     ErasedSerde(erased_serde::Error),
     MetaDeError(PathBuf, ron::de::Error),
     SetLoggerError(log::SetLoggerError),
@@ -19,7 +24,15 @@ pub enum Error {
     SendError,
     Exit,
     ImporterError(atelier_importer::Error),
+    /// An importer panicked, with the panic message if it had one
+    ImporterPanic(String),
+    /// An import took longer than the configured timeout
+    ImportTimeout(Duration),
     StrUtf8Error(str::Utf8Error),
+    /// The asset hub service could not listen for loaders: (address, cause)
+    ServiceBind(String, io::Error),
+    /// A label is already used by another asset: (label, owner)
+    LabelInUse(String, AssetUuid),
     Custom(String),
 }
 
@@ -32,12 +45,15 @@ impl std::error::Error for Error {
             Error::IO(ref e) => Some(e),
             Error::RescanRequired => None,
             Error::Lmdb(ref e) => Some(e),
+            Error::MapFull => None,
             Error::Capnp(ref e) => Some(e),
+            Error::DbDecode(_, _, ref e) => Some(e.as_ref()),
             Error::NotInSchema(ref e) => Some(e),
             Error::BincodeError(ref e) => Some(e),
             Error::ErasedSerde(ref e) => Some(e),
             Error::RonSerError(ref e) => Some(e),
             Error::RonDeError(ref e) => Some(e),
+            Error::JsonError(ref e) => Some(e),
             Error::MetaDeError(_, ref e) => Some(e),
             Error::SetLoggerError(ref e) => Some(e),
             Error::UuidLength => None,
@@ -45,7 +61,11 @@ impl std::error::Error for Error {
             Error::SendError => None,
             Error::Exit => None,
             Error::ImporterError(ref e) => Some(e),
+            Error::ImporterPanic(_) => None,
+            Error::ImportTimeout(_) => None,
             Error::StrUtf8Error(ref e) => Some(e),
+            Error::ServiceBind(_, ref e) => Some(e),
+            Error::LabelInUse(_, _) => None,
             Error::Custom(ref _e) => None,
         }
     }
@@ -57,12 +77,20 @@ impl fmt::Display for Error {
             Error::IO(ref e) => e.fmt(f),
             Error::RescanRequired => write!(f, "{}", self),
             Error::Lmdb(ref e) => e.fmt(f),
+            Error::MapFull => write!(
+                f,
+                "asset database is full (MDB_MAP_FULL), consider raising the limit with AssetDaemon::with_db_map_size"
+            ),
             Error::Capnp(ref e) => e.fmt(f),
+            Error::DbDecode(table, ref key, ref e) => {
+                write!(f, "failed to decode entry {:?} in table {}: {}", key, table, e)
+            }
             Error::NotInSchema(ref e) => e.fmt(f),
             Error::BincodeError(ref e) => e.fmt(f),
             Error::ErasedSerde(ref e) => e.fmt(f),
             Error::RonSerError(ref e) => e.fmt(f),
             Error::RonDeError(ref e) => e.fmt(f),
+            Error::JsonError(ref e) => e.fmt(f),
             Error::MetaDeError(ref path, ref e) => {
                 if let ron::de::Error::Parser(..) = e {
                     // Special case for display of errors with known line:column format.
@@ -79,7 +107,24 @@ impl fmt::Display for Error {
             Error::SendError => write!(f, "{}", self),
             Error::Exit => write!(f, "{}", self),
             Error::ImporterError(ref e) => e.fmt(f),
+            Error::ImporterPanic(ref message) => write!(f, "importer panicked: {}", message),
+            Error::ImportTimeout(ref timeout) => {
+                write!(f, "import timed out after {} ms", timeout.as_millis())
+            }
             Error::StrUtf8Error(ref e) => e.fmt(f),
+            Error::ServiceBind(ref address, ref e) => {
+                write!(f, "failed to listen for loaders on {}: {}", address, e)?;
+                if e.kind() == io::ErrorKind::AddrInUse {
+                    write!(
+                        f,
+                        ", another daemon may be running there; set a different address with AssetDaemon::with_address"
+                    )?;
+                }
+                Ok(())
+            }
+            Error::LabelInUse(ref label, ref owner) => {
+                write!(f, "label {:?} is already used by asset {:?}", label, owner)
+            }
             Error::Custom(ref s) => f.write_str(s.as_str()),
         }
     }
@@ -96,7 +141,10 @@ impl From<io::Error> for Error {
 }
 impl From<lmdb::Error> for Error {
     fn from(err: lmdb::Error) -> Error {
-        Error::Lmdb(err)
+        match err {
+            lmdb::Error::MapFull => Error::MapFull,
+            err => Error::Lmdb(err),
+        }
     }
 }
 impl From<capnp::Error> for Error {
@@ -124,6 +172,11 @@ impl From<ron::de::Error> for Error {
         Error::RonDeError(err)
     }
 }
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Error {
+        Error::JsonError(err)
+    }
+}
 
 impl From<erased_serde::Error> for Error {
     fn from(err: erased_serde::Error) -> Error {
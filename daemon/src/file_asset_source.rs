@@ -1,13 +1,19 @@
 use crate::artifact_cache::ArtifactCache;
-use crate::asset_hub::{self, AssetHub};
+use crate::asset_hub::{self, AssetChange, AssetHub};
 use crate::capnp_db::{CapnpCursor, DBTransaction, Environment, MessageReader, RwTransaction};
 use crate::daemon::ImporterMap;
 use crate::error::{Error, Result};
-use crate::file_tracker::{FileState, FileTracker, FileTrackerEvent};
+use crate::file_tracker::{self, FileState, FileTracker, FileTrackerEvent};
+use crate::metrics::DaemonMetrics;
+use crate::serialized_asset::ArtifactOptions;
 use crate::source_pair_import::{
-    self, hash_file, HashedSourcePair, SourceMetadata, SourcePair, SourcePairImport,
+    self, hash_file, HashedSourcePair, ImportSettings, SourceMetadata, SourcePair, SourcePairImport,
+};
+use async_lock::Semaphore;
+use atelier_core::{
+    utils::{self, ArtifactHasher},
+    AssetRef, AssetUuid, CompressionType, SerializationFormat,
 };
-use atelier_core::{utils, ArtifactId, AssetRef, AssetUuid, CompressionType};
 use atelier_importer::{
     ArtifactMetadata, AssetMetadata, BoxedImporter, ImporterContext, SerializedAsset,
 };
@@ -20,13 +26,30 @@ use bincode::config::Options;
 use futures_channel::mpsc::unbounded;
 use futures_util::lock::Mutex;
 use futures_util::stream::StreamExt;
-use log::{debug, error, info};
+use log::{debug, error, info, warn};
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
-use std::{path::PathBuf, str, sync::Arc, time::Instant};
+use std::{
+    path::{Path, PathBuf},
+    str,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use tokio::runtime::Runtime;
 
+/// The changes an update of the asset sources would make, see
+/// [`AssetDaemon::dry_run`](crate::AssetDaemon::dry_run).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DryRunReport {
+    /// Source files that would be imported
+    pub imports: Vec<PathBuf>,
+    /// Deleted source files whose assets would be removed
+    pub removals: Vec<PathBuf>,
+    /// Source files that failed to import, with the error message
+    pub errors: Vec<(PathBuf, String)>,
+}
+
 pub(crate) struct FileAssetSource {
     hub: Arc<AssetHub>,
     tracker: Arc<FileTracker>,
@@ -35,7 +58,29 @@ pub(crate) struct FileAssetSource {
     tables: FileAssetSourceTables,
     importers: Arc<ImporterMap>,
     importer_contexts: Arc<Vec<Box<dyn ImporterContext>>>,
+    artifact_hasher: Arc<dyn ArtifactHasher>,
+    /// Format used to serialize newly imported artifacts
+    serialization_format: SerializationFormat,
+    /// Namespace that new asset UUIDs are derived from, with the source path, instead of random
+    asset_uuid_namespace: Option<uuid::Uuid>,
     work_runtime: Arc<Runtime>,
+    /// Normalizes case and separators of path keys, for case-insensitive filesystems
+    case_insensitive_paths: bool,
+    /// Limits the number of source files being imported at the same time
+    import_semaphore: Semaphore,
+    /// Imports that take longer fail, so they can't stall the other imports
+    import_timeout: Option<Duration>,
+    /// Whether `.meta` files are written next to source files. Without them, importer options
+    /// and state, and with them the asset UUIDs, are only kept in `path_to_metadata`.
+    meta_files: bool,
+    /// Source files larger than this many bytes are tracked but not imported
+    max_file_size: Option<u64>,
+    /// Serializes updates triggered by file events and by `force_reimport`
+    update_lock: Mutex<()>,
+    /// Source paths that must run their importer on the next update, even if unchanged
+    forced_reimports: std::sync::Mutex<HashSet<PathBuf>>,
+    /// Shared with the tracker
+    metrics: Arc<DaemonMetrics>,
 }
 
 struct FileAssetSourceTables {
@@ -143,6 +188,62 @@ fn resolve_source_path(abs_source_path: &PathBuf, path: &PathBuf) -> PathBuf {
     crate::watcher::canonicalize_path(&absolute_path)
 }
 
+/// Returns the key used for a source file path in the metadata and path ref tables.
+/// With `case_insensitive` set, the key is lowercased and uses `/` as separator so that
+/// `Textures\Foo.png` and `textures/foo.png` refer to the same file.
+fn path_key(path: &Path, case_insensitive: bool) -> String {
+    let path_str = path.to_string_lossy();
+    if case_insensitive {
+        path_str.replace('\\', "/").to_lowercase()
+    } else {
+        path_str.into_owned()
+    }
+}
+
+/// Returns the serialized `PathRefs` with the paths under `old_root` moved to `new_root`, or
+/// `None` if none of its paths are under `old_root`.
+fn relocated_path_refs(value: &[u8], old_root: &Path, new_root: &Path) -> Option<Vec<u8>> {
+    let mut slice = value;
+    let message = capnp::serialize::read_message_from_flat_slice(
+        &mut slice,
+        capnp::message::ReaderOptions::default(),
+    )
+    .expect("capnp: failed to read path refs");
+    let paths: Vec<Vec<u8>> = message
+        .get_root::<path_refs::Reader<'_>>()
+        .and_then(|refs| refs.get_paths())
+        .expect("capnp: failed to read paths")
+        .iter()
+        .map(|path| path.expect("capnp: failed to read path ref").to_vec())
+        .collect();
+    let relocated: Vec<Option<String>> = paths
+        .iter()
+        .map(|path| file_tracker::relocated_path(path, old_root, new_root))
+        .collect();
+    if relocated.iter().all(Option::is_none) {
+        return None;
+    }
+    let mut message = capnp::message::Builder::new_default();
+    let mut list = message
+        .init_root::<path_refs::Builder<'_>>()
+        .init_paths(paths.len() as u32);
+    for (idx, (path, relocated)) in paths.iter().zip(&relocated).enumerate() {
+        let path = relocated.as_ref().map_or(path.as_slice(), |p| p.as_bytes());
+        list.set(idx as u32, path);
+    }
+    Some(capnp::serialize::write_message_to_words(&message))
+}
+
+/// Joins the components of a path relative to its asset directory with `/`, so the key is the same
+/// on every platform. Case is preserved, UUIDs must not depend on the file system's case handling.
+fn asset_uuid_source_key(relative_path: &Path) -> String {
+    relative_path
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
 impl FileAssetSource {
     pub fn new(
         tracker: &Arc<FileTracker>,
@@ -168,15 +269,131 @@ impl FileAssetSource {
             },
             importers: importers.clone(),
             importer_contexts,
+            artifact_hasher: Arc::new(utils::DefaultArtifactHasher),
+            serialization_format: SerializationFormat::Bincode,
+            asset_uuid_namespace: None,
             work_runtime,
+            case_insensitive_paths: false,
+            import_semaphore: Semaphore::new(1),
+            import_timeout: None,
+            meta_files: true,
+            max_file_size: None,
+            update_lock: Mutex::new(()),
+            forced_reimports: std::sync::Mutex::new(HashSet::new()),
+            metrics: tracker.metrics().clone(),
         })
     }
 
+    /// Sets the hasher that calculates artifact IDs, `DefaultArtifactHasher` by default.
+    pub fn with_artifact_hasher(mut self, artifact_hasher: Arc<dyn ArtifactHasher>) -> Self {
+        self.artifact_hasher = artifact_hasher;
+        self
+    }
+
+    /// Sets the format of newly imported artifacts, bincode by default.
+    pub fn with_serialization_format(mut self, serialization_format: SerializationFormat) -> Self {
+        self.serialization_format = serialization_format;
+        self
+    }
+
+    /// Derives new asset UUIDs from `namespace` and the source path instead of generating
+    /// random ones.
+    pub fn with_asset_uuid_namespace(mut self, namespace: Option<uuid::Uuid>) -> Self {
+        self.asset_uuid_namespace = namespace;
+        self
+    }
+
+    /// Sets how many source files are imported at the same time, one by default.
+    pub fn with_import_concurrency(mut self, import_concurrency: usize) -> Self {
+        self.import_semaphore = Semaphore::new(import_concurrency.max(1));
+        self
+    }
+
+    /// Sets whether path keys ignore case and separators, which they don't by default.
+    pub fn with_case_insensitive_paths(mut self, case_insensitive_paths: bool) -> Self {
+        self.case_insensitive_paths = case_insensitive_paths;
+        self
+    }
+
+    /// Fails imports that take longer than `import_timeout`. Imports have no timeout by default.
+    pub fn with_import_timeout(mut self, import_timeout: Option<Duration>) -> Self {
+        self.import_timeout = import_timeout;
+        self
+    }
+
+    /// Sets whether `.meta` files are written next to source files, which they are by default.
+    pub fn with_meta_files(mut self, meta_files: bool) -> Self {
+        self.meta_files = meta_files;
+        self
+    }
+
+    /// Skips importing source files larger than `max_file_size` bytes. There is no limit by
+    /// default.
+    pub fn with_max_file_size(mut self, max_file_size: Option<u64>) -> Self {
+        self.max_file_size = max_file_size;
+        self
+    }
+
+    fn path_key(&self, path: &Path) -> String {
+        path_key(path, self.case_insensitive_paths)
+    }
+
+    fn import_settings(&self, dry_run: bool) -> ImportSettings<'_> {
+        ImportSettings {
+            importer_map: &self.importers,
+            importer_contexts: &self.importer_contexts,
+            artifact_hasher: self.artifact_hasher.as_ref(),
+            serialization_format: self.serialization_format,
+            import_timeout: self.import_timeout,
+            write_meta_files: self.meta_files,
+            dry_run,
+        }
+    }
+
+    /// Returns why the pair's source file is skipped if it is larger than `max_file_size`.
+    fn oversized_source(&self, pair: &SourcePair) -> Option<(PathBuf, String)> {
+        let max_file_size = self.max_file_size?;
+        match pair.source {
+            Some(ref source)
+                if source.state == data::FileState::Exists && source.length > max_file_size =>
+            {
+                Some((
+                    source.path.clone(),
+                    format!(
+                        "file is {} bytes, over the maximum of {} bytes, not importing it",
+                        source.length, max_file_size
+                    ),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the asset directory that contains the source file.
+    fn source_root(&self, path: &Path) -> Option<PathBuf> {
+        self.tracker
+            .get_watch_dirs()
+            .into_iter()
+            .find(|dir| path.starts_with(dir))
+    }
+
+    /// Returns the namespace and source key that new asset UUIDs of the source file are derived
+    /// from, if deterministic UUIDs are enabled and the file is in an asset directory.
+    fn asset_uuid_source(&self, path: &Path) -> Option<(uuid::Uuid, String)> {
+        let namespace = self.asset_uuid_namespace?;
+        let source_root = self.source_root(path)?;
+        let relative_path = path.strip_prefix(&source_root).ok()?;
+        Some((namespace, asset_uuid_source_key(relative_path)))
+    }
+
+    /// Import errors are added to `import_errors`, to be reported once the transaction has
+    /// committed.
     fn put_metadata<'a>(
         &self,
         txn: &'a mut RwTransaction<'_>,
         path: &PathBuf,
         metadata: &SourceMetadata,
+        import_errors: &mut Vec<(PathBuf, String)>,
     ) -> Result<Vec<AssetUuid>> {
         let mut affected_assets = Vec::new();
         let (assets_to_remove, path_refs_to_remove): (Vec<AssetUuid>, Vec<PathBuf>) = self
@@ -216,8 +433,11 @@ impl FileAssetSource {
             .unwrap_or_default();
 
         for asset in assets_to_remove {
+            if !self.is_asset_owner(txn, &asset, path) {
+                continue;
+            }
             debug!("removing deleted asset {:?}", asset);
-            self.delete_asset_path(txn, &asset);
+            self.delete_asset_path(txn, &asset)?;
             affected_assets.push(asset);
         }
         for asset in metadata.assets.iter() {
@@ -225,21 +445,24 @@ impl FileAssetSource {
 
             match self.get_asset_path(txn, &asset.id) {
                 Some(ref old_path) if old_path != path => {
-                    error!(
-                        "asset {:?} already in DB with path {} expected {}",
+                    // usually a file that was copied together with its .meta file
+                    let message = format!(
+                        "asset {:?} is already imported from {}, give one of the files a new \
+                         UUID in its .meta file",
                         asset.id,
                         old_path.to_string_lossy(),
-                        path.to_string_lossy(),
                     );
+                    import_errors.push((path.clone(), message));
+                    continue;
                 }
                 Some(_) => {} // asset already in DB with correct path
-                _ => self.put_asset_path(txn, &asset.id, path),
+                _ => self.put_asset_path(txn, &asset.id, path)?,
             }
 
             affected_assets.push(asset.id);
         }
         for path_ref in path_refs_to_remove {
-            self.remove_path_ref(txn, path, &path_ref);
+            self.remove_path_ref(txn, path, &path_ref)?;
         }
 
         let new_path_refs = metadata
@@ -260,11 +483,12 @@ impl FileAssetSource {
                 } else {
                     None
                 }
-            });
+            })
+            .chain(metadata.dependencies.iter());
         let mut deduped_path_refs = HashSet::new();
         for path_ref in new_path_refs {
             if deduped_path_refs.insert(path_ref.clone()) {
-                self.add_path_ref(txn, path, &path_ref);
+                self.add_path_ref(txn, path, &path_ref)?;
             }
         }
         let mut value_builder = capnp::message::Builder::new_default();
@@ -279,10 +503,13 @@ impl FileAssetSource {
                 let mut state_buf = Vec::new();
                 bincode::serialize_into(&mut state_buf, &metadata.importer_state)?;
                 value.set_importer_state(&state_buf);
-                value.set_importer_options_type(&metadata.importer_options.uuid());
-                let mut options_buf = Vec::new();
-                bincode::serialize_into(&mut options_buf, &metadata.importer_options)?;
-                value.set_importer_options(&options_buf);
+                // options left to the project default are stored empty
+                if let Some(importer_options) = &metadata.importer_options {
+                    value.set_importer_options_type(&importer_options.uuid());
+                    let mut options_buf = Vec::new();
+                    bincode::serialize_into(&mut options_buf, importer_options)?;
+                    value.set_importer_options(&options_buf);
+                }
                 let hash_bytes = metadata
                     .import_hash
                     .expect("import hash not present")
@@ -328,21 +555,24 @@ impl FileAssetSource {
             }
         }
 
-        let key_str = path.to_string_lossy();
+        let key_str = self.path_key(path);
         let key = key_str.as_bytes();
 
-        txn.put(self.tables.path_to_metadata, &key, &value_builder)
-            .expect("db: Failed to put value to path_to_metadata");
+        txn.put(self.tables.path_to_metadata, &key, &value_builder)?;
 
         Ok(affected_assets)
     }
 
+    pub fn importers(&self) -> &ImporterMap {
+        &self.importers
+    }
+
     pub fn get_metadata<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
         &self,
         txn: &'a V,
         path: &PathBuf,
     ) -> Option<MessageReader<'a, source_metadata::Owned>> {
-        let key_str = path.to_string_lossy();
+        let key_str = self.path_key(path);
         let key = key_str.as_bytes();
         txn.get::<source_metadata::Owned, &[u8]>(self.tables.path_to_metadata, &key)
             .expect("db: Failed to get source metadata from path_to_metadata table")
@@ -365,7 +595,32 @@ impl FileAssetSource {
             })
     }
 
-    fn delete_metadata(&self, txn: &mut RwTransaction<'_>, path: &PathBuf) -> Vec<AssetUuid> {
+    /// Returns an iterator over every asset known to the asset hub along with its latest artifact
+    /// and source file path, ordered by `AssetUuid` and starting after the asset `after`. Assets
+    /// without an artifact or source path are skipped.
+    pub fn iter_assets<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
+        &'a self,
+        txn: &'a V,
+        after: Option<&AssetUuid>,
+    ) -> impl Iterator<Item = (AssetUuid, ArtifactMetadata, PathBuf)> + 'a {
+        self.hub
+            .iter_metadata_after(txn, after)
+            .filter_map(move |(id, metadata)| {
+                let metadata = metadata
+                    .get()
+                    .map_err(|err| log::error!("failed to read asset metadata {}: {}", id, err))
+                    .ok()?;
+                let artifact = parse_db_metadata(&metadata).artifact?;
+                let path = self.get_asset_path(txn, &id)?;
+                Some((id, artifact, path))
+            })
+    }
+
+    fn delete_metadata(
+        &self,
+        txn: &mut RwTransaction<'_>,
+        path: &PathBuf,
+    ) -> Result<Vec<AssetUuid>> {
         let to_remove: Vec<AssetUuid> = self
             .get_metadata(txn, path)
             .map(|existing| {
@@ -388,16 +643,20 @@ impl FileAssetSource {
             })
             .unwrap_or_default();
 
+        // assets whose UUID is imported from another source file stay with that file
+        let to_remove: Vec<AssetUuid> = to_remove
+            .into_iter()
+            .filter(|asset| self.is_asset_owner(txn, asset, path))
+            .collect();
         for asset in to_remove.iter() {
             debug!("remove asset {:?}", asset);
-            self.delete_asset_path(txn, &asset);
+            self.delete_asset_path(txn, &asset)?;
         }
 
-        let key_str = path.to_string_lossy();
+        let key_str = self.path_key(path);
         let key = key_str.as_bytes();
-        txn.delete(self.tables.path_to_metadata, &key)
-            .expect("db: Failed to delete metadata from path_to_metadata table");
-        to_remove
+        txn.delete(self.tables.path_to_metadata, &key)?;
+        Ok(to_remove)
     }
 
     pub fn resolve_asset_ref<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
@@ -444,11 +703,10 @@ impl FileAssetSource {
         txn: &'a mut RwTransaction<'_>,
         asset_id: &AssetUuid,
         path: &PathBuf,
-    ) {
+    ) -> Result<()> {
         let path_str = path.to_string_lossy();
         let path = path_str.as_bytes();
         txn.put_bytes(self.tables.asset_id_to_path, asset_id, &path)
-            .expect("db: Failed to put asset path to asset_id_to_path table");
     }
 
     pub fn get_asset_path<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
@@ -461,9 +719,31 @@ impl FileAssetSource {
             .map(|p| PathBuf::from(str::from_utf8(p).expect("utf8: Failed to parse path")))
     }
 
-    fn delete_asset_path(&self, txn: &mut RwTransaction<'_>, asset_id: &AssetUuid) -> bool {
+    /// Returns the version of the importer for the source file of an asset.
+    pub fn importer_version<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
+        &self,
+        txn: &'a V,
+        asset_id: &AssetUuid,
+    ) -> Option<u32> {
+        let path = self.get_asset_path(txn, asset_id)?;
+        self.importers
+            .get_by_path(&path)
+            .map(|importer| importer.version())
+    }
+
+    /// Returns whether the asset is imported from the source file at `path`. The first source file
+    /// imported with an asset's UUID keeps it until it is deleted or no longer has the asset.
+    fn is_asset_owner<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
+        &self,
+        txn: &'a V,
+        asset_id: &AssetUuid,
+        path: &PathBuf,
+    ) -> bool {
+        self.get_asset_path(txn, asset_id).as_ref() == Some(path)
+    }
+
+    fn delete_asset_path(&self, txn: &mut RwTransaction<'_>, asset_id: &AssetUuid) -> Result<bool> {
         txn.delete(self.tables.asset_id_to_path, asset_id)
-            .expect("db: Failed to delete asset_id from asset_id_to_path table")
     }
 
     fn add_path_ref<'a>(
@@ -471,9 +751,9 @@ impl FileAssetSource {
         txn: &'a mut RwTransaction<'_>,
         source: &PathBuf,
         path_ref: &PathBuf,
-    ) -> bool {
+    ) -> Result<bool> {
         let path_ref = resolve_source_path(source, path_ref);
-        let key_str = path_ref.to_string_lossy();
+        let key_str = self.path_key(&path_ref);
         let key = key_str.as_bytes();
         let existing_refs = txn
             .get::<path_refs::Owned, &[u8]>(self.tables.reverse_path_refs, &key)
@@ -491,7 +771,7 @@ impl FileAssetSource {
 
             for existing_path in existing_refs.iter() {
                 if existing_path.expect("capnp: failed to read path ref") == path_ref_bytes {
-                    return false; // already exists in the list
+                    return Ok(false); // already exists in the list
                 }
             }
             new_size += existing_refs.len();
@@ -507,9 +787,8 @@ impl FileAssetSource {
             list.init_paths(1)
         };
         paths.set(new_size - 1, &path_ref_bytes);
-        txn.put(self.tables.reverse_path_refs, &key, &message)
-            .expect("lmdb: failed to put path ref");
-        true
+        txn.put(self.tables.reverse_path_refs, &key, &message)?;
+        Ok(true)
     }
 
     pub fn get_path_refs<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
@@ -517,7 +796,7 @@ impl FileAssetSource {
         txn: &'a V,
         path: &PathBuf,
     ) -> Vec<PathBuf> {
-        let key_str = path.to_string_lossy();
+        let key_str = self.path_key(path);
         let key = key_str.as_bytes();
         txn.get::<path_refs::Owned, &[u8]>(self.tables.reverse_path_refs, &key)
             .expect("db: Failed to get asset_id from asset_id_to_path table")
@@ -547,9 +826,9 @@ impl FileAssetSource {
         txn: &mut RwTransaction<'_>,
         source: &PathBuf,
         path_ref: &PathBuf,
-    ) -> bool {
+    ) -> Result<bool> {
         let path_ref = resolve_source_path(source, path_ref);
-        let key_str = path_ref.to_string_lossy();
+        let key_str = self.path_key(&path_ref);
         let key = key_str.as_bytes();
         let existing_refs = txn
             .get::<path_refs::Owned, &[u8]>(self.tables.reverse_path_refs, &key)
@@ -569,12 +848,11 @@ impl FileAssetSource {
                 }
             }
             match remove_idx {
-                None => false, // does not exist in current list
+                None => Ok(false), // does not exist in current list
                 Some(remove_idx) => {
                     let new_size = existing_refs.len() - 1;
                     if new_size == 0 {
-                        txn.delete(self.tables.reverse_path_refs, &key)
-                            .expect("lmdb: failed to delete path ref");
+                        txn.delete(self.tables.reverse_path_refs, &key)?;
                     } else {
                         let mut message = capnp::message::Builder::new_default();
                         let list = message.init_root::<path_refs::Builder<'_>>();
@@ -589,14 +867,13 @@ impl FileAssetSource {
                                 insert_idx += 1;
                             }
                         }
-                        txn.put(self.tables.reverse_path_refs, &key, &message)
-                            .expect("db: failed to update path refs");
+                        txn.put(self.tables.reverse_path_refs, &key, &message)?;
                     }
-                    true
+                    Ok(true)
                 }
             }
         } else {
-            false
+            Ok(false)
         }
     }
 
@@ -625,6 +902,14 @@ impl FileAssetSource {
         let mut import = SourcePairImport::new(path.clone());
         import.set_importer_from_map(&self.importers);
         import.set_importer_contexts(&self.importer_contexts);
+        import.set_artifact_hasher(self.artifact_hasher.as_ref());
+        import.set_serialization_format(self.serialization_format);
+        if let Some((namespace, source_key)) = self.asset_uuid_source(&path) {
+            import.set_asset_uuid_source(namespace, source_key);
+        }
+        if let Some(source_root) = self.source_root(&path) {
+            import.set_source_root(source_root);
+        }
         import.generate_source_metadata(&cache);
         import.hash_source();
 
@@ -634,7 +919,6 @@ impl FileAssetSource {
             .importer_context_set
             .expect("importer context set required");
         let mut this_asset = None;
-        let mut rw_txn = self.artifact_cache.rw_txn().await?;
         for asset in imported_assets.assets {
             let mut build_deps = asset
                 .metadata
@@ -680,30 +964,49 @@ impl FileAssetSource {
             let import_hash = import
                 .import_hash()
                 .expect("Invalid: Import path should exist");
+            let importer_version = import
+                .importer_version()
+                .expect("Invalid: Import path should have an importer");
 
             context_set.begin_serialize_asset(asset.metadata.id);
             let asset_id = asset.metadata.id;
+            let cacheable = asset.cacheable;
+            let (data_version, serialization_format) = asset
+                .metadata
+                .artifact
+                .as_ref()
+                .map_or((0, self.serialization_format), |artifact| {
+                    (artifact.data_version, artifact.serialization_format)
+                });
 
             let pair: Result<(u64, SerializedAssetVec)> = context_set
                 .scope(async {
-                    let hash = utils::calc_import_artifact_hash(
-                        &asset.metadata.id,
-                        import_hash,
-                        load_deps.iter().chain(build_deps.iter()),
-                    );
-                    let serialized_asset = crate::serialized_asset::create(
-                        hash,
+                    let mut serialized_asset = crate::serialized_asset::create(
                         asset.metadata.id,
                         build_deps.into_iter().map(AssetRef::Uuid).collect(),
                         load_deps.into_iter().map(AssetRef::Uuid).collect(),
                         &*asset
                             .asset
                             .expect("expected asset obj when regenerating artifact"),
-                        CompressionType::None,
-                        scratch_buf,
+                        ArtifactOptions {
+                            compression: CompressionType::None,
+                            serialization_format,
+                            data_version,
+                        },
+                        Vec::new(),
                     )?;
-                    self.artifact_cache.insert(&mut rw_txn, &serialized_asset);
-                    Ok((hash, serialized_asset))
+                    serialized_asset
+                        .metadata
+                        .update_id(self.artifact_hasher.as_ref(), import_hash);
+                    if cacheable {
+                        self.artifact_cache
+                            .write(|txn| {
+                                self.artifact_cache
+                                    .insert(txn, &serialized_asset, importer_version)
+                            })
+                            .await?;
+                    }
+                    Ok((serialized_asset.metadata.id.0, serialized_asset))
                 })
                 .await;
             let pair = pair?;
@@ -713,7 +1016,9 @@ impl FileAssetSource {
 
             context_set.end_serialize_asset(asset_id);
         }
-        rw_txn.commit()?;
+        self.artifact_cache
+            .write(|txn| self.artifact_cache.evict(txn))
+            .await?;
         if let Some(asset) = this_asset {
             Ok(asset)
         } else {
@@ -763,18 +1068,21 @@ impl FileAssetSource {
         }
     }
 
+    /// Import errors are added to `import_errors`, to be reported once the transaction has
+    /// committed.
     fn process_metadata_changes(
         &self,
         txn: &mut RwTransaction<'_>,
         changes: &HashMap<PathBuf, Option<PairImportResultMetadata<'_>>>,
         change_batch: &mut asset_hub::ChangeBatch,
-    ) {
+        import_errors: &mut Vec<(PathBuf, String)>,
+    ) -> Result<()> {
         let mut affected_assets = HashMap::new();
 
         // delete metadata for deleted source pairs
         for (path, _) in changes.iter().filter(|(_, change)| change.is_none()) {
             debug!("deleting metadata for {}", path.to_string_lossy());
-            for asset in self.delete_metadata(txn, path) {
+            for asset in self.delete_metadata(txn, path)? {
                 affected_assets.entry(asset).or_insert(None);
             }
         }
@@ -790,16 +1098,17 @@ impl FileAssetSource {
                 .unwrap_or_else(|| panic!("Change for {:?} has no SourceMetadata", path));
             debug!("imported {}", path.to_string_lossy());
 
-            let changed_assets = self
-                .put_metadata(txn, path, &metadata)
-                .expect("Failed to put metadata");
+            let changed_assets = self.put_metadata(txn, path, &metadata, import_errors)?;
 
             for asset in changed_assets {
                 affected_assets.entry(asset).or_insert(None);
             }
 
+            // assets with a UUID that another source file owns were reported by put_metadata
             for asset in metadata.assets.iter() {
-                affected_assets.insert(asset.id, Some(asset.clone()));
+                if self.is_asset_owner(txn, &asset.id, path) {
+                    affected_assets.insert(asset.id, Some(asset.clone()));
+                }
             }
         }
 
@@ -807,6 +1116,9 @@ impl FileAssetSource {
         for (path, metadata) in changes.iter().filter(|(_, change)| change.is_some()) {
             let metadata = metadata.as_ref().unwrap();
             for asset in metadata.assets.iter() {
+                if !self.is_asset_owner(txn, &asset.metadata.id, path) {
+                    continue;
+                }
                 let asset_metadata = affected_assets
                     .get_mut(&asset.metadata.id)
                     .expect("asset in changes but not in affected_assets")
@@ -818,6 +1130,13 @@ impl FileAssetSource {
             }
         }
 
+        // labels may move between assets that change together, so the labels that change are
+        // released before any asset takes one over
+        for (asset, metadata) in affected_assets.iter() {
+            let label = metadata.as_ref().and_then(|m| m.label.as_deref());
+            self.hub.release_label(txn, asset, label)?;
+        }
+
         // push removals and updates into AssetHub database
         for (asset, maybe_metadata) in affected_assets.iter_mut() {
             match self.get_asset_path(txn, &asset) {
@@ -850,24 +1169,20 @@ impl FileAssetSource {
                             .collect();
                         a.load_deps.sort_unstable();
                         a.build_deps.sort_unstable();
-                        a.id = ArtifactId(utils::calc_import_artifact_hash(
-                            &asset,
-                            import_hash,
-                            a.load_deps
-                                .iter()
-                                .chain(a.build_deps.iter())
-                                .map(|dep| dep.expect_uuid()),
-                        ))
+                        a.update_id(self.artifact_hasher.as_ref(), import_hash);
                     }
+                    self.check_label_conflict(txn, path, asset_metadata, import_errors);
 
-                    self.hub
-                        .update_asset(txn, &asset_metadata, data::AssetSource::File, change_batch)
-                        .expect("hub: Failed to update asset in hub");
+                    self.hub.update_asset(
+                        txn,
+                        &asset_metadata,
+                        data::AssetSource::File,
+                        change_batch,
+                    )?;
+                    change_batch.set_asset_path(*asset, path.clone());
                 }
                 None => {
-                    self.hub
-                        .remove_asset(txn, &asset, change_batch)
-                        .expect("hub: Failed to remove asset");
+                    self.hub.remove_asset(txn, &asset, change_batch)?;
                 }
             }
         }
@@ -925,23 +1240,19 @@ impl FileAssetSource {
                                         .filter(|x| x.is_uuid())
                                         .cloned()
                                         .collect();
-                                    artifact.id = ArtifactId(utils::calc_import_artifact_hash(
-                                        &asset.metadata.id,
-                                        import_hash,
-                                        artifact
-                                            .load_deps
-                                            .iter()
-                                            .chain(artifact.build_deps.iter())
-                                            .map(|dep| dep.expect_uuid()),
-                                    ));
-                                    self.hub
-                                        .update_asset(
-                                            txn,
-                                            &asset.metadata,
-                                            data::AssetSource::File,
-                                            change_batch,
-                                        )
-                                        .expect("hub: Failed to update asset in hub");
+                                    artifact.update_id(self.artifact_hasher.as_ref(), import_hash);
+                                    self.check_label_conflict(
+                                        txn,
+                                        path_ref_source,
+                                        &mut asset.metadata,
+                                        import_errors,
+                                    );
+                                    self.hub.update_asset(
+                                        txn,
+                                        &asset.metadata,
+                                        data::AssetSource::File,
+                                        change_batch,
+                                    )?;
                                 }
                             }
                         }
@@ -952,9 +1263,51 @@ impl FileAssetSource {
                 }
             }
         }
+        Ok(())
+    }
+
+    /// Drops the label of an asset when another asset has it, and adds the conflict to
+    /// `import_errors` as an import error of the source file. The hub would reject the update
+    /// otherwise.
+    fn check_label_conflict(
+        &self,
+        txn: &RwTransaction<'_>,
+        path: &Path,
+        metadata: &mut AssetMetadata,
+        import_errors: &mut Vec<(PathBuf, String)>,
+    ) {
+        let label = match &metadata.label {
+            Some(label) => label,
+            None => return,
+        };
+        let owner = self
+            .hub
+            .get_asset_for_label(txn, label)
+            .expect("hub: Failed to look up asset label");
+        if let Some(owner) = owner.filter(|owner| *owner != metadata.id) {
+            let message = format!(
+                "label {:?} of asset {:?} is already used by asset {:?}",
+                label, metadata.id, owner
+            );
+            import_errors.push((path.to_path_buf(), message));
+            metadata.label = None;
+        }
+    }
+
+    /// Reports import errors that were found in a write transaction. This is done once the
+    /// transaction has committed, since [`Environment::write`] may run it twice.
+    fn report_import_errors(&self, import_errors: Vec<(PathBuf, String)>) {
+        for (path, message) in import_errors {
+            error!("{}: {}", path.to_string_lossy(), message);
+            self.hub.report_import_error(path, message);
+        }
     }
 
-    fn ack_dirty_file_states(&self, txn: &mut RwTransaction<'_>, pair: &HashedSourcePair) {
+    fn ack_dirty_file_states(
+        &self,
+        txn: &mut RwTransaction<'_>,
+        pair: &HashedSourcePair,
+    ) -> Result<()> {
         let mut skip_ack_dirty = false;
 
         {
@@ -974,23 +1327,26 @@ impl FileAssetSource {
         if !skip_ack_dirty {
             if pair.source.is_some() {
                 self.tracker
-                    .delete_dirty_file_state(txn, pair.source.as_ref().map(|p| &p.path).unwrap());
+                    .delete_dirty_file_state(txn, pair.source.as_ref().map(|p| &p.path).unwrap())?;
             }
 
             if pair.meta.is_some() {
                 self.tracker
-                    .delete_dirty_file_state(txn, pair.meta.as_ref().map(|p| &p.path).unwrap());
+                    .delete_dirty_file_state(txn, pair.meta.as_ref().map(|p| &p.path).unwrap())?;
             }
         }
+        Ok(())
     }
 
-    fn handle_rename_events(&self, txn: &mut RwTransaction<'_>) {
+    fn handle_rename_events(&self, txn: &mut RwTransaction<'_>) -> Result<()> {
         let rename_events = self.tracker.read_rename_events(txn);
         debug!("rename events");
 
         for (_, evt) in rename_events.iter() {
             let dst_str = evt.dst.to_string_lossy();
             let dst = dst_str.as_bytes();
+            let dst_key_str = self.path_key(&evt.dst);
+            let dst_key = dst_key_str.as_bytes();
             let mut asset_ids = Vec::new();
             let mut existing_metadata = None;
 
@@ -1013,24 +1369,29 @@ impl FileAssetSource {
                 }
             }
 
+            let src_str = evt.src.to_string_lossy();
             for asset in asset_ids {
-                txn.delete(self.tables.asset_id_to_path, &asset)
-                    .expect("db: Failed to delete from asset_id_to_path table");
-
-                txn.put_bytes(self.tables.asset_id_to_path, &asset, &dst)
-                    .expect("db: Failed to put to asset_id_to_path table");
+                // assets whose UUID is imported from another source file stay with that file
+                let owner = txn
+                    .get_as_bytes(self.tables.asset_id_to_path, &asset)
+                    .expect("db: Failed to get asset_id from asset_id_to_path table");
+                if owner.map_or(false, |owner| owner != src_str.as_bytes()) {
+                    continue;
+                }
+                txn.delete(self.tables.asset_id_to_path, &asset)?;
+                txn.put_bytes(self.tables.asset_id_to_path, &asset, &dst)?;
             }
 
             if let Some(existing_metadata) = existing_metadata {
-                self.delete_metadata(txn, &evt.src);
-                txn.put(self.tables.path_to_metadata, &dst, &existing_metadata)
-                    .expect("db: Failed to put to path_to_metadata table");
+                self.delete_metadata(txn, &evt.src)?;
+                txn.put(self.tables.path_to_metadata, &dst_key, &existing_metadata)?;
             }
         }
 
         if !rename_events.is_empty() {
-            self.tracker.clear_rename_events(txn);
+            self.tracker.clear_rename_events(txn)?;
         }
+        Ok(())
     }
 
     async fn check_for_importer_changes(&self) -> bool {
@@ -1072,7 +1433,8 @@ impl FileAssetSource {
                                 .expect("capnp: Failed to get importer type");
 
                             importer_version != importer.version()
-                                || options_type != importer.default_options().uuid()
+                                || (!options_type.is_empty()
+                                    && options_type != importer.default_options().uuid())
                                 || state_type != importer.default_state().uuid()
                                 || importer_type != importer.uuid()
                         }
@@ -1088,74 +1450,110 @@ impl FileAssetSource {
         };
         let has_changed_paths = !changed_paths.is_empty();
         if has_changed_paths {
-            let mut txn = self.db.rw_txn().await.expect("Failed to open rw txn");
-            for p in changed_paths.iter() {
-                self.tracker
-                    .add_dirty_file(&mut txn, &p)
-                    .await
-                    .unwrap_or_else(|err| error!("Failed to add dirty file, {}", err));
-            }
-            txn.commit().expect("Failed to commit txn");
+            self.db
+                .write(|txn| {
+                    for p in changed_paths.iter() {
+                        match self.tracker.add_dirty_file(txn, &p) {
+                            Err(Error::MapFull) => return Err(Error::MapFull),
+                            Err(err) => error!("Failed to add dirty file, {}", err),
+                            Ok(()) => {}
+                        }
+                    }
+                    Ok(())
+                })
+                .await
+                .expect("Failed to commit txn");
         }
 
         has_changed_paths
     }
 
-    fn handle_dirty_files(&self, txn: &mut RwTransaction<'_>) -> HashMap<PathBuf, SourcePair> {
+    /// Returns the changed file pairs, the number of dirty files and the source files that must
+    /// be imported again because they read one of the changed files.
+    fn handle_dirty_files(
+        &self,
+        txn: &mut RwTransaction<'_>,
+    ) -> (HashMap<PathBuf, SourcePair>, usize, Vec<PathBuf>) {
         let dirty_files = self.tracker.read_dirty_files(txn);
+        let dirty_file_count = dirty_files.len();
+        let (source_meta_pairs, dependents) = self.pair_changed_files(txn, dirty_files);
+
+        if !source_meta_pairs.is_empty() {
+            debug!("Processing {} changed file pairs", source_meta_pairs.len());
+        }
+
+        (source_meta_pairs, dirty_file_count, dependents)
+    }
+
+    /// Groups changed files by source file, filling in the other file of each pair from the
+    /// tracked files. Also returns the source files that read one of the changed files and must
+    /// be imported again.
+    fn pair_changed_files<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
+        &self,
+        txn: &'a V,
+        changed_files: Vec<FileState>,
+    ) -> (HashMap<PathBuf, SourcePair>, Vec<PathBuf>) {
         let mut source_meta_pairs: HashMap<PathBuf, SourcePair> = HashMap::new();
+        if changed_files.is_empty() {
+            return (source_meta_pairs, Vec::new());
+        }
 
-        if !dirty_files.is_empty() {
-            for state in dirty_files.into_iter() {
-                let mut is_meta = false;
-                if let Some(ext) = state.path.extension() {
-                    if let Some("meta") = ext.to_str() {
-                        is_meta = true;
-                    }
+        for state in changed_files.into_iter() {
+            let mut is_meta = false;
+            if let Some(ext) = state.path.extension() {
+                if let Some("meta") = ext.to_str() {
+                    is_meta = true;
                 }
-                let base_path = if is_meta {
-                    state.path.with_file_name(state.path.file_stem().unwrap())
-                } else {
-                    state.path.clone()
-                };
-                let mut pair = source_meta_pairs.entry(base_path).or_insert(SourcePair {
+            }
+            let base_path = if is_meta {
+                state.path.with_file_name(state.path.file_stem().unwrap())
+            } else {
+                state.path.clone()
+            };
+            let mut pair = source_meta_pairs.entry(base_path).or_insert(SourcePair {
+                source: Option::None,
+                meta: Option::None,
+            });
+            if is_meta {
+                pair.meta = Some(state.clone());
+            } else {
+                pair.source = Some(state.clone());
+            }
+        }
+
+        // source files that read a changed file, like the images of a sprite sheet, are imported
+        // again, whether or not the changed file is imported itself
+        let dependents: Vec<PathBuf> = source_meta_pairs
+            .keys()
+            .flat_map(|path| self.get_path_refs(txn, path))
+            .collect();
+        for dependent in dependents.iter() {
+            source_meta_pairs
+                .entry(dependent.clone())
+                .or_insert(SourcePair {
                     source: Option::None,
                     meta: Option::None,
                 });
-                if is_meta {
-                    pair.meta = Some(state.clone());
-                } else {
-                    pair.source = Some(state.clone());
-                }
-            }
-
-            for (path, pair) in source_meta_pairs.iter_mut() {
-                if pair.meta.is_none() {
-                    let path = utils::to_meta_path(&path);
-                    pair.meta = self.tracker.get_file_state(txn, &path);
-                }
+        }
 
-                if pair.source.is_none() {
-                    pair.source = self.tracker.get_file_state(txn, &path);
-                }
+        for (path, pair) in source_meta_pairs.iter_mut() {
+            if pair.meta.is_none() {
+                let path = utils::to_meta_path(&path);
+                pair.meta = self.tracker.get_file_state(txn, &path);
             }
 
-            debug!("Processing {} changed file pairs", source_meta_pairs.len());
+            if pair.source.is_none() {
+                pair.source = self.tracker.get_file_state(txn, &path);
+            }
         }
 
-        source_meta_pairs
+        (source_meta_pairs, dependents)
     }
 
-    // TODO(happens): Return for this is asset_metadata_changed. This function needs a lot
+    // TODO(happens): Return for this is the changed assets. This function needs a lot
     // of work, and in the process it will hopefully clear up and get a name that will
     // make the return value more obvious.
-    async fn process_asset_metadata(
-        &self,
-        txn: &mut RwTransaction<'_>,
-        hashed_files: &[HashedSourcePair],
-    ) -> bool {
-        let txn = Mutex::new(txn);
-        let txn_ref = &txn;
+    async fn process_asset_metadata(&self, hashed_files: &[HashedSourcePair]) -> Vec<AssetChange> {
         let metadata_changes = Mutex::new(HashMap::new());
         let metadata_changes_ref = &metadata_changes;
 
@@ -1165,12 +1563,30 @@ impl FileAssetSource {
         self.work_runtime.clone().enter(|| {
             for p in hashed_files {
                 let processed_pair = p.clone();
+                let asset_uuid_source = processed_pair
+                    .source
+                    .as_ref()
+                    .and_then(|source| self.asset_uuid_source(&source.path));
+                let source_root = processed_pair
+                    .source
+                    .as_ref()
+                    .and_then(|source| self.source_root(&source.path));
+                let force_import = processed_pair.source.as_ref().map_or(false, |source| {
+                    self.forced_reimports
+                        .lock()
+                        .unwrap()
+                        .remove(&source.path)
+                });
                 import_scope.spawn(async move {
+                    // bound the number of concurrent imports, metadata is committed once all have finished
+                    let _permit = self.import_semaphore.acquire().await;
                     let read_txn = self
                         .db
                         .ro_txn()
                         .await
                         .expect("failed to open RO transaction");
+                    let import_path = processed_pair.source.as_ref().map(|s| s.path.clone());
+                    let import_start = Instant::now();
                     let cache = DBSourceMetadataCache {
                         txn: &read_txn,
                         file_asset_source: &self,
@@ -1178,35 +1594,48 @@ impl FileAssetSource {
                     };
                     let result = source_pair_import::import_pair(
                         &cache,
-                        &self.importers,
-                        &self.importer_contexts,
+                        self.import_settings(false),
                         &processed_pair,
+                        asset_uuid_source,
+                        source_root,
+                        force_import,
                         &mut Vec::new(),
                     )
                     .await;
 
                     let result = match result {
-                        Err(e) => return (processed_pair, Err(e)),
+                        Err(e) => {
+                            self.metrics.record_import(
+                                import_path.as_deref(),
+                                false,
+                                import_start.elapsed(),
+                            );
+                            return (processed_pair, Err(e));
+                        }
                         Ok(result) => result,
                     };
 
                     if let Some((import, import_output)) = result {
                         let metadata = if let Some(mut import_output) = import_output {
+                            self.metrics.record_import(
+                                import_path.as_deref(),
+                                true,
+                                import_start.elapsed(),
+                            );
                             // put import artifact in cache if it doesn't have unresolved refs
                             if !import_output.assets.is_empty() {
-                                let mut txn = self
-                                    .artifact_cache
-                                    .rw_txn()
-                                    .await
-                                    .expect("failed to get cache txn");
+                                let importer_version = import.importer_version().unwrap();
+                                let mut cached = Vec::new();
                                 for asset in import_output.assets.iter_mut() {
-                                    if asset.is_fully_resolved() {
+                                    if !asset.cacheable {
+                                        log::trace!("asset {:?} from file {:?} is not cacheable", asset.metadata.id, p.source);
+                                    } else if asset.is_fully_resolved() {
                                         if let Some(serialized_asset) =
                                             asset.serialized_asset.as_mut()
                                         {
-                                            serialized_asset.metadata.id = ArtifactId(utils::calc_import_artifact_hash(&asset.metadata.id, import.import_hash().unwrap(), serialized_asset.metadata.load_deps.iter().chain(serialized_asset.metadata.build_deps.iter()).map(|dep| dep.expect_uuid())));
+                                            serialized_asset.metadata.update_id(self.artifact_hasher.as_ref(), import.import_hash().unwrap());
                                             log::trace!("caching asset {:?} from file {:?} with hash {:?}", asset.metadata.id, p.source, serialized_asset.metadata.id );
-                                            self.artifact_cache.insert(&mut txn, serialized_asset);
+                                            cached.push(&*serialized_asset);
                                         } else {
                                             log::trace!("asset {:?} from file {:?} did not return serialized asset: cannot cache", asset.metadata.id, p.source );
                                         }
@@ -1214,7 +1643,18 @@ impl FileAssetSource {
                                         log::trace!("asset {:?} from file {:?} not fully resolved: cannot cache", asset.metadata.id, p.source );
                                     }
                                 }
-                                txn.commit().expect("failed to commit cache txn");
+                                self.artifact_cache
+                                    .write(|txn| {
+                                        for serialized_asset in &cached {
+                                            self.artifact_cache.insert(txn, serialized_asset, importer_version)?;
+                                        }
+                                        self.artifact_cache.evict(txn)
+                                    })
+                                    .await
+                                    .expect("failed to write artifacts to cache");
+                                for serialized_asset in cached {
+                                    self.metrics.add_bytes_written(serialized_asset.data.len() as u64);
+                                }
                             }
 
                             Some(PairImportResultMetadata {
@@ -1251,50 +1691,179 @@ impl FileAssetSource {
             }
         });
 
+        let mut imported_pairs = Vec::new();
         while let Some((pair, maybe_result)) = import_scope.next().await {
+            let path = pair
+                .source
+                .as_ref()
+                .or_else(|| pair.meta.as_ref())
+                .map(|s| &s.path);
             match maybe_result {
                 // Successful import
                 Ok(()) => {
-                    let mut txn = txn_ref.lock().await;
-                    self.ack_dirty_file_states(&mut txn, &pair);
+                    if let Some(path) = path {
+                        self.hub.clear_import_error(path);
+                    }
+                    imported_pairs.push(pair);
+                }
+                Err(e) => {
+                    error!(
+                        "Error processing pair at {:?}: {}",
+                        pair.source.as_ref().map(|s| &s.path),
+                        e
+                    );
+                    if let Some(path) = path {
+                        self.hub.report_import_error(path.clone(), e.to_string());
+                    }
                 }
-                Err(e) => error!(
-                    "Error processing pair at {:?}: {}",
-                    pair.source.as_ref().map(|s| &s.path),
-                    e
-                ),
             }
         }
 
+        // the read transactions of the imports are dropped with the scope, so the write below
+        // can grow the map
         drop(import_scope);
 
-        let mut change_batch = asset_hub::ChangeBatch::new();
-
         // the locking should no longer be necessary, but there is no `into_inner()` on tokio mutex.
-        let mut txn = txn.lock().await;
         let metadata_changes = metadata_changes.lock().await;
 
-        self.process_metadata_changes(&mut txn, &metadata_changes, &mut change_batch);
-        self.hub
-            .add_changes(&mut txn, change_batch)
-            .expect("Failed to process metadata changes")
+        let (asset_changes, import_errors) = self
+            .db
+            .write(|txn| {
+                for pair in &imported_pairs {
+                    self.ack_dirty_file_states(txn, pair)?;
+                }
+                let mut change_batch = asset_hub::ChangeBatch::new();
+                let mut import_errors = Vec::new();
+                self.process_metadata_changes(
+                    txn,
+                    &metadata_changes,
+                    &mut change_batch,
+                    &mut import_errors,
+                )?;
+                Ok((self.hub.add_changes(txn, change_batch)?, import_errors))
+            })
+            .await
+            .expect("Failed to process metadata changes");
+        self.report_import_errors(import_errors);
+        asset_changes
     }
 
     async fn handle_update(&self) {
-        let start_time = Instant::now();
+        let _update_guard = self.update_lock.lock().await;
+        self.handle_update_locked().await;
+    }
+
+    /// Runs the importers for the files that changed since the last update, including changes on
+    /// disk that the file tracker has not seen yet. Nothing is written to the database, the
+    /// artifact cache or `.meta` files.
+    pub async fn dry_run(&self) -> Result<DryRunReport> {
+        let _update_guard = self.update_lock.lock().await;
+        let txn = self.db.ro_txn().await?;
+        let changed_files = self.tracker.scan_changes(&txn)?;
+        let (source_meta_pairs, dependents) = self.pair_changed_files(&txn, changed_files);
+        let mut report = DryRunReport::default();
         let mut changed_files = Vec::new();
+        for (_, pair) in source_meta_pairs {
+            match self.oversized_source(&pair) {
+                Some(skipped) => report.errors.push(skipped),
+                None => changed_files.push(pair),
+            }
+        }
 
-        let mut txn = self.db.rw_txn().await.expect("Failed to open rw txn");
+        let mut scratch_buf = Vec::new();
+        for (pair, hashed_pair) in changed_files.iter().zip(hash_files(&changed_files)) {
+            let path = match pair.source.as_ref().or_else(|| pair.meta.as_ref()) {
+                Some(state) => state.path.clone(),
+                None => continue,
+            };
+            let hashed_pair = match hashed_pair {
+                Ok(hashed_pair) => hashed_pair,
+                Err(err) => {
+                    report.errors.push((path, err.to_string()));
+                    continue;
+                }
+            };
+            if let Some(FileState {
+                state: data::FileState::Deleted,
+                ..
+            }) = hashed_pair.source
+            {
+                if self.get_metadata(&txn, &path).is_some() {
+                    report.removals.push(path.clone());
+                }
+            }
+            let force_import =
+                dependents.contains(&path) || self.forced_reimports.lock().unwrap().contains(&path);
+            let cache = DBSourceMetadataCache {
+                txn: &txn,
+                file_asset_source: &self,
+                _marker: std::marker::PhantomData,
+            };
+            let result = source_pair_import::import_pair(
+                &cache,
+                self.import_settings(true),
+                &hashed_pair,
+                self.asset_uuid_source(&path),
+                self.source_root(&path),
+                force_import,
+                &mut scratch_buf,
+            )
+            .await;
+            match result {
+                Ok(Some((import, _))) if import.source_imported() => report.imports.push(path),
+                Ok(_) => {}
+                Err(err) => report.errors.push((path, err.to_string())),
+            }
+        }
 
-        // Before reading the filesystem state we need to process rename events.
-        // This must be done in the same transaction to guarantee database consistency.
-        self.handle_rename_events(&mut txn);
-        let source_meta_pairs = self.handle_dirty_files(&mut txn);
+        report.imports.sort();
+        report.removals.sort();
+        report.errors.sort();
+        Ok(report)
+    }
 
-        // This looks a little stupid, since there is no `into_values`
-        changed_files.extend(source_meta_pairs.into_iter().map(|(_, v)| v));
+    async fn handle_update_locked(&self) {
+        let start_time = Instant::now();
 
-        txn.commit().expect("Failed to commit txn");
+        // the write may run twice, so its effects outside of the database happen afterwards
+        let (changed_files, dirty_file_count, dependents, oversized) = self
+            .db
+            .write(|txn| {
+                // Before reading the filesystem state we need to process rename events.
+                // This must be done in the same transaction to guarantee database consistency.
+                self.handle_rename_events(txn)?;
+                let (source_meta_pairs, dirty_file_count, dependents) =
+                    self.handle_dirty_files(txn);
+
+                let mut changed_files = Vec::new();
+                let mut oversized = Vec::new();
+                for (_, pair) in source_meta_pairs {
+                    match self.oversized_source(&pair) {
+                        Some(skipped) => {
+                            let unhashed_pair = HashedSourcePair {
+                                source: pair.source,
+                                meta: pair.meta,
+                                source_hash: None,
+                                meta_hash: None,
+                            };
+                            self.ack_dirty_file_states(txn, &unhashed_pair)?;
+                            oversized.push(skipped);
+                        }
+                        None => changed_files.push(pair),
+                    }
+                }
+                Ok((changed_files, dirty_file_count, dependents, oversized))
+            })
+            .await
+            .expect("Failed to commit txn");
+        self.metrics
+            .add_dirty_files_processed(dirty_file_count as u64);
+        self.forced_reimports.lock().unwrap().extend(dependents);
+        for (path, message) in oversized {
+            // The file stays tracked, so it is picked up again once it shrinks.
+            warn!("{}: {}", path.to_string_lossy(), message);
+            self.hub.report_import_error(path, message);
+        }
 
         let hashed_files = hash_files(&changed_files);
         debug!("Hashed {}", hashed_files.len());
@@ -1317,12 +1886,9 @@ impl FileAssetSource {
             elapsed.as_secs_f32()
         );
 
-        let mut txn = self.db.rw_txn().await.expect("Failed to open rw txn");
-        let asset_metadata_changed = self.process_asset_metadata(&mut txn, &hashed_files).await;
-
-        txn.commit().expect("Failed to commit txn");
-        if asset_metadata_changed {
-            self.hub.notify_listeners();
+        let asset_changes = self.process_asset_metadata(&hashed_files).await;
+        if !asset_changes.is_empty() {
+            self.hub.notify_listeners(asset_changes);
         }
 
         let elapsed = Instant::now().duration_since(start_time);
@@ -1354,20 +1920,132 @@ impl FileAssetSource {
                         self.handle_update().await;
                     }
                 }
+                FileTrackerEvent::ScanProgress(..) => {}
             }
         }
     }
 
+    /// Runs the importer for the source file at `path` again, even if neither the file nor its
+    /// .meta file changed, and waits for the resulting metadata to be committed. Returns the
+    /// import error if the importer failed.
+    pub async fn force_reimport(&self, path: &PathBuf) -> Result<()> {
+        let _update_guard = self.update_lock.lock().await;
+        self.db
+            .write(|txn| {
+                if self.get_metadata(txn, path).is_none() {
+                    return Err(Error::Custom(format!(
+                        "no imported source file at {}",
+                        path.to_string_lossy()
+                    )));
+                }
+                self.tracker.add_dirty_file(txn, path)
+            })
+            .await?;
+        self.forced_reimports.lock().unwrap().insert(path.clone());
+        self.handle_update_locked().await;
+        match self
+            .hub
+            .get_import_errors()
+            .into_iter()
+            .find(|(error_path, _)| error_path == path)
+        {
+            Some((_, message)) => Err(Error::Custom(message)),
+            None => Ok(()),
+        }
+    }
+
+    /// Moves the source files under `old_root` to the same relative paths under `new_root` in the
+    /// database, for a watch directory that was moved while the daemon was not running. Their
+    /// assets keep their UUIDs and artifacts instead of being removed and imported again.
+    /// Returns the number of moved tracked files, including `.meta` files.
+    ///
+    /// Only files the tracker has recorded are moved. Files under `old_root` it has not seen yet
+    /// are found by the tracker's scan of `new_root` and imported like any new file, so this must
+    /// run before the tracker starts watching `new_root`.
+    pub async fn relocate_root(&self, old_root: &Path, new_root: &Path) -> Result<usize> {
+        let _update_guard = self.update_lock.lock().await;
+        let old_key_root = PathBuf::from(self.path_key(old_root));
+        let relocate_key = |key: &[u8]| {
+            file_tracker::relocated_path(key, &old_key_root, new_root)
+                .map(|path| self.path_key(Path::new(&path)).into_bytes())
+        };
+        let relocated = self
+            .db
+            .write(|txn| {
+                let relocated = self.tracker.relocate_root(txn, old_root, new_root)?;
+                txn.rewrite_entries(self.tables.path_to_metadata, |key, value| {
+                    relocate_key(key).map(|key| (key, value.to_vec()))
+                })?;
+                txn.rewrite_entries(self.tables.asset_id_to_path, |key, value| {
+                    file_tracker::relocated_path(value, old_root, new_root)
+                        .map(|path| (key.to_vec(), path.into_bytes()))
+                })?;
+                txn.rewrite_entries(self.tables.reverse_path_refs, |key, value| {
+                    let new_key = relocate_key(key);
+                    let new_value = relocated_path_refs(value, old_root, new_root);
+                    if new_key.is_none() && new_value.is_none() {
+                        return None;
+                    }
+                    Some((
+                        new_key.unwrap_or_else(|| key.to_vec()),
+                        new_value.unwrap_or_else(|| value.to_vec()),
+                    ))
+                })?;
+                Ok(relocated)
+            })
+            .await?;
+        info!(
+            "Relocated {} files from {} to {}",
+            relocated,
+            old_root.to_string_lossy(),
+            new_root.to_string_lossy()
+        );
+        Ok(relocated)
+    }
+
+    /// Runs the importers of all imported source files again, for example after a change to how
+    /// artifacts are compressed. Asset UUIDs are kept since the `.meta` files are reused, unlike
+    /// when the database is deleted. Returns the number of source files that were reimported.
+    pub async fn rebuild_all_artifacts(&self) -> Result<usize> {
+        let _update_guard = self.update_lock.lock().await;
+        let paths = self
+            .db
+            .write(|txn| {
+                let paths: Vec<PathBuf> = self
+                    .tracker
+                    .read_all_files(txn)
+                    .into_iter()
+                    .filter(|file| {
+                        file.state == data::FileState::Exists
+                            && self.get_metadata(txn, &file.path).is_some()
+                    })
+                    .map(|file| file.path)
+                    .collect();
+                for path in &paths {
+                    self.tracker.add_dirty_file(txn, path)?;
+                }
+                Ok(paths)
+            })
+            .await?;
+        info!("Rebuilding artifacts of {} source files", paths.len());
+        self.forced_reimports
+            .lock()
+            .unwrap()
+            .extend(paths.iter().cloned());
+        self.handle_update_locked().await;
+        Ok(paths.len())
+    }
+
     pub async fn export_source(
         &self,
         path: PathBuf,
         assets: Vec<SerializedAssetVec>,
     ) -> Result<Vec<AssetMetadata>> {
-        let mut txn = self
+        let txn = self
             .db
-            .rw_txn()
+            .ro_txn()
             .await
-            .expect("failed to open RW transaction");
+            .expect("failed to open RO transaction");
         let cache = DBSourceMetadataCache {
             txn: &txn,
             file_asset_source: &self,
@@ -1377,13 +2055,15 @@ impl FileAssetSource {
         let result = source_pair_import::export_pair(
             assets,
             &cache,
-            &self.importers,
-            &self.importer_contexts,
+            self.import_settings(false),
             path.clone(),
             meta_path,
             &mut Vec::new(),
         )
         .await?;
+        // the read transaction must be closed before writing, since the write may need to grow
+        // the map
+        drop(txn);
         let new_asset_metadata: Vec<AssetImportResultMetadata> = result
             .1
             .assets
@@ -1403,28 +2083,37 @@ impl FileAssetSource {
                 assets: new_asset_metadata,
             }),
         );
-        let mut change_batch = asset_hub::ChangeBatch::new();
-        self.process_metadata_changes(&mut txn, &changes, &mut change_batch);
-        let asset_metadata_changed = self.hub.add_changes(&mut txn, change_batch)?;
-        let new_asset_metadata: Vec<AssetMetadata> = asset_ids
-            .into_iter()
-            .map(|a| {
-                parse_db_metadata(
-                    &self
-                        .hub
-                        .get_metadata(&txn, &a)
-                        .expect("Expected asset metadata in DB after metadata update")
-                        .get()
-                        .expect("capnp: metadata read failed"),
-                )
+        let (asset_changes, new_asset_metadata, import_errors) = self
+            .db
+            .write(|txn| {
+                let mut change_batch = asset_hub::ChangeBatch::new();
+                let mut import_errors = Vec::new();
+                self.process_metadata_changes(
+                    txn,
+                    &changes,
+                    &mut change_batch,
+                    &mut import_errors,
+                )?;
+                let asset_changes = self.hub.add_changes(txn, change_batch)?;
+                let new_asset_metadata: Vec<AssetMetadata> = asset_ids
+                    .iter()
+                    .map(|a| {
+                        parse_db_metadata(
+                            &self
+                                .hub
+                                .get_metadata(txn, a)
+                                .expect("Expected asset metadata in DB after metadata update")
+                                .get()
+                                .expect("capnp: metadata read failed"),
+                        )
+                    })
+                    .collect();
+                Ok((asset_changes, new_asset_metadata, import_errors))
             })
-            .collect();
-        if txn.dirty {
-            txn.commit().expect("Failed to commit txn");
-
-            if asset_metadata_changed {
-                self.hub.notify_listeners();
-            }
+            .await?;
+        self.report_import_errors(import_errors);
+        if !asset_changes.is_empty() {
+            self.hub.notify_listeners(asset_changes);
         }
         Ok(new_asset_metadata)
     }
@@ -1458,7 +2147,7 @@ where
                         .ok_or(Error::UuidLength)?,
                 );
             }
-            if saved_metadata.get_importer_options_type()? == metadata.importer_options.uuid() {
+            if saved_metadata.get_importer_options_type()? == importer.default_options().uuid() {
                 let mut deserializer = bincode::Deserializer::from_slice(
                     saved_metadata.get_importer_options()?,
                     bincode::options()
@@ -1468,7 +2157,7 @@ where
                 let mut deserializer = erased_serde::Deserializer::erase(&mut deserializer);
 
                 if let Ok(options) = importer.deserialize_options(&mut deserializer) {
-                    metadata.importer_options = options;
+                    metadata.importer_options = Some(options);
                 }
             }
             if saved_metadata.get_importer_state_type()? == metadata.importer_state.uuid() {
@@ -1495,3 +2184,1376 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::file_tracker::tests::{tracker_db, with_tracker};
+    use atelier_importer::{
+        AsyncImporter, ImportOp, ImportedAsset, ImporterValue, MetaFile, Result as ImportResult,
+    };
+    use futures_core::future::BoxFuture;
+    use futures_io::AsyncRead;
+    use futures_util::io::AsyncReadExt;
+    use serde_derive::{Deserialize, Serialize};
+    use std::future::Future;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+    use type_uuid::TypeUuid;
+
+    const FILE_COUNT: usize = 32;
+
+    #[derive(Clone, Default, Deserialize, Serialize, TypeUuid)]
+    #[uuid = "9a4c2e71-5b3d-4f86-a0e9-2c7b1d5f3e68"]
+    struct TestImporterOptions {
+        prefix: String,
+    }
+
+    #[derive(Default, Deserialize, Serialize, TypeUuid)]
+    #[uuid = "b2e4c8f1-3a5d-4e6b-8c7f-9d0a1b2c3d4e"]
+    struct TestImporterState {
+        id: Option<AssetUuid>,
+    }
+
+    type TestMetaFile = MetaFile<TestImporterOptions, TestImporterState>;
+
+    static COUNTED_IMPORTS: AtomicUsize = AtomicUsize::new(0);
+    static SLOW_IMPORTS: ImportsInFlight = ImportsInFlight::new();
+
+    /// Counts the imports of a `Slow` importer that run at the same time.
+    #[derive(Default)]
+    pub struct ImportsInFlight {
+        current: AtomicUsize,
+        max: AtomicUsize,
+    }
+
+    impl ImportsInFlight {
+        pub const fn new() -> Self {
+            Self {
+                current: AtomicUsize::new(0),
+                max: AtomicUsize::new(0),
+            }
+        }
+
+        /// The largest number of imports that ran at the same time.
+        pub fn max(&self) -> usize {
+            self.max.load(Ordering::SeqCst)
+        }
+    }
+    static REBUILT_IMPORTS: AtomicUsize = AtomicUsize::new(0);
+
+    const SPRITE_TYPE: atelier_core::AssetTypeId = atelier_core::AssetTypeId([9; 16]);
+    const SHEET_ID: AssetUuid = AssetUuid([7; 16]);
+
+    /// What a `TestImporter` imports from a source file.
+    #[derive(Clone, Copy)]
+    pub enum Import {
+        /// The file contents, after a delay that stands in for an expensive importer. The
+        /// imports that run at the same time are counted.
+        Slow(&'static ImportsInFlight),
+        /// `import <n>` where `n` counts the imports, so tests can tell a fresh import from a
+        /// cached result
+        Counted(&'static AtomicUsize),
+        /// Like `Counted`, but the asset is not cacheable
+        Volatile(&'static AtomicUsize),
+        /// The file contents with the prefix from the options, so tests can tell which options
+        /// were used
+        Prefixed,
+        /// Nothing, the import fails
+        Failing,
+        /// Nothing, the import waits far longer than any test runs
+        Stalling,
+        /// Nothing, the importer blocks its thread for longer than the import timeout of the tests
+        Blocking,
+        /// Nothing, the importer panics like a buggy third-party importer
+        Panicking,
+        /// The source file's path relative to its asset directory
+        SourcePath,
+        /// The file contents, labeled with the file contents
+        Labeled,
+        /// The file contents, and two generated sprite assets with already serialized data
+        Atlas,
+        /// A sheet that lists one image file per line, as the concatenated contents of the
+        /// images, which are read next to the sheet
+        SpriteSheet,
+        /// The `String` service of the importer contexts
+        Service,
+    }
+
+    #[derive(TypeUuid)]
+    #[uuid = "e7a1d9c3-6f2b-4a8e-b5c4-1d3f5a7b9c2e"]
+    struct TestImporter(Import);
+    impl AsyncImporter for TestImporter {
+        type State = TestImporterState;
+        type Options = TestImporterOptions;
+
+        fn version_static() -> u32
+        where
+            Self: Sized,
+        {
+            1
+        }
+        fn version(&self) -> u32 {
+            Self::version_static()
+        }
+
+        fn import<'a>(
+            &'a self,
+            op: &'a mut ImportOp,
+            source: &'a mut (dyn AsyncRead + Unpin + Send + Sync),
+            options: &'a Self::Options,
+            state: &'a mut Self::State,
+        ) -> BoxFuture<'a, ImportResult<ImporterValue>> {
+            Box::pin(async move {
+                if state.id.is_none() {
+                    state.id = Some(op.new_asset_uuid());
+                }
+                let mut contents = String::new();
+                source.read_to_string(&mut contents).await?;
+                let mut asset = ImportedAsset::new(
+                    state.id.expect("AssetUuid not generated"),
+                    Box::new(contents.clone()),
+                );
+                match self.0 {
+                    Import::Slow(in_flight) => {
+                        let current = in_flight.current.fetch_add(1, Ordering::SeqCst) + 1;
+                        in_flight.max.fetch_max(current, Ordering::SeqCst);
+                        tokio::time::delay_for(Duration::from_millis(25)).await;
+                        in_flight.current.fetch_sub(1, Ordering::SeqCst);
+                    }
+                    Import::Counted(count) | Import::Volatile(count) => {
+                        let count = count.fetch_add(1, Ordering::SeqCst) + 1;
+                        asset.asset_data = Box::new(format!("import {}", count));
+                        asset.cacheable = matches!(self.0, Import::Counted(_));
+                    }
+                    Import::Prefixed => {
+                        asset.asset_data = Box::new(format!("{}{}", options.prefix, contents));
+                    }
+                    Import::Failing => {
+                        return Err(atelier_importer::Error::Custom("broken file".to_string()));
+                    }
+                    Import::Stalling => {
+                        tokio::time::delay_for(Duration::from_secs(600)).await;
+                        return Err(atelier_importer::Error::Custom("stalled".to_string()));
+                    }
+                    Import::Blocking => {
+                        std::thread::sleep(Duration::from_secs(1));
+                        return Err(atelier_importer::Error::Custom("blocked".to_string()));
+                    }
+                    Import::Panicking => panic!("importer bug"),
+                    Import::SourcePath => {
+                        let source = op.source().expect("import source not set");
+                        let relative_path = source.path.strip_prefix(&source.root).unwrap();
+                        asset.asset_data = Box::new(asset_uuid_source_key(relative_path));
+                    }
+                    Import::Labeled => asset.label = Some(contents),
+                    Import::Atlas => {
+                        let mut value = ImporterValue {
+                            assets: vec![asset],
+                        };
+                        for sprite in 1..=2u8 {
+                            let data = bincode::serialize(&u32::from(sprite)).unwrap();
+                            value.add_generated_asset(AssetUuid([sprite; 16]), SPRITE_TYPE, data);
+                        }
+                        return Ok(value);
+                    }
+                    Import::SpriteSheet => {
+                        let mut images = String::new();
+                        for image in contents.lines() {
+                            let image = op.read_source_file(image)?;
+                            images.push_str(&String::from_utf8_lossy(&image));
+                        }
+                        asset.id = SHEET_ID;
+                        asset.asset_data = Box::new(images);
+                    }
+                    Import::Service => {
+                        let service = op.service::<String>().expect("service not provided");
+                        asset.asset_data = Box::new((*service).clone());
+                    }
+                }
+                Ok(ImporterValue {
+                    assets: vec![asset],
+                })
+            })
+        }
+    }
+
+    /// Returns a map that imports the files with each extension with a `TestImporter`.
+    pub fn test_importers(imports: &[(&str, Import)]) -> ImporterMap {
+        let mut importers = ImporterMap::default();
+        for (ext, import) in imports {
+            importers.insert(ext, Box::new(TestImporter(*import)));
+        }
+        importers
+    }
+
+    /// A tracker watching a new asset directory, with the databases and importers that asset
+    /// sources are created with.
+    pub struct Fixture {
+        pub tracker: Arc<FileTracker>,
+        pub hub: Arc<AssetHub>,
+        pub db: Arc<Environment>,
+        pub artifact_cache: Arc<ArtifactCache>,
+        importers: Arc<ImporterMap>,
+        work_runtime: Arc<Runtime>,
+        pub asset_dir: PathBuf,
+    }
+
+    impl Fixture {
+        pub fn new_source(&self) -> FileAssetSource {
+            self.new_source_with_contexts(Vec::new())
+        }
+
+        fn new_source_with_contexts(
+            &self,
+            importer_contexts: Vec<Box<dyn ImporterContext>>,
+        ) -> FileAssetSource {
+            FileAssetSource::new(
+                &self.tracker,
+                &self.hub,
+                &self.db,
+                &self.importers,
+                &self.artifact_cache,
+                Arc::new(importer_contexts),
+                self.work_runtime.clone(),
+            )
+            .unwrap()
+        }
+    }
+
+    /// Runs `f` with a new fixture. The import runtime is dropped after the tracker's runtime has
+    /// finished, since a runtime can't be dropped from within another one.
+    pub fn with_fixture<F, T>(importers: ImporterMap, f: F)
+    where
+        T: Future<Output = ()>,
+        F: FnOnce(Fixture) -> T,
+    {
+        let work_runtime = Arc::new(
+            tokio::runtime::Builder::new()
+                .threaded_scheduler()
+                .enable_all()
+                .build()
+                .unwrap(),
+        );
+        let cache_dir = tempfile::tempdir().unwrap();
+        let cache_db = Arc::new(Environment::with_map_size(cache_dir.path(), 1 << 21).unwrap());
+        let importers = Arc::new(importers);
+
+        with_tracker({
+            let work_runtime = work_runtime.clone();
+            move |tracker, _rx, asset_dir| {
+                let db = tracker_db(&tracker);
+                f(Fixture {
+                    hub: Arc::new(AssetHub::new(db.clone()).unwrap()),
+                    artifact_cache: Arc::new(ArtifactCache::new(&cache_db).unwrap()),
+                    tracker,
+                    db,
+                    importers,
+                    work_runtime,
+                    asset_dir,
+                })
+            }
+        });
+    }
+
+    /// Imports `FILE_COUNT` source files, counting the imports that run at once in `in_flight`.
+    fn import_test_files(import_concurrency: usize, in_flight: &'static ImportsInFlight) {
+        with_fixture(
+            test_importers(&[("slow", Import::Slow(in_flight))]),
+            move |fixture| async move {
+                let Fixture {
+                    tracker,
+                    hub,
+                    db,
+                    asset_dir,
+                    ..
+                } = &fixture;
+                let source = fixture
+                    .new_source()
+                    .with_import_concurrency(import_concurrency);
+
+                for i in 0..FILE_COUNT {
+                    tokio::fs::write(asset_dir.join(format!("{}.slow", i)), format!("{}", i))
+                        .await
+                        .unwrap();
+                }
+                while tracker.read_dirty_files(&tracker.get_ro_txn().await).len() < FILE_COUNT {
+                    tokio::time::delay_for(Duration::from_millis(10)).await;
+                }
+
+                source.handle_update().await;
+
+                let txn = db.ro_txn().await.unwrap();
+                assert_eq!(FILE_COUNT, hub.iter_metadata(&txn).count());
+                for i in 0..FILE_COUNT {
+                    let path =
+                        crate::watcher::canonicalize_path(&asset_dir.join(format!("{}.slow", i)));
+                    let metadata = source
+                        .get_metadata(&txn, &path)
+                        .unwrap_or_else(|| panic!("no metadata for {:?}", path));
+                    assert_eq!(1, metadata.get().unwrap().get_assets().unwrap().len());
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn path_key_ignores_case_and_separators() {
+        let stored = path_key(Path::new("assets/textures/foo.png"), true);
+        assert_eq!(stored, path_key(Path::new("assets/Textures/Foo.PNG"), true));
+        assert_eq!(
+            stored,
+            path_key(Path::new("assets\\textures\\foo.png"), true)
+        );
+        assert_eq!(
+            stored,
+            path_key(Path::new("Assets\\Textures/Foo.png"), true)
+        );
+    }
+
+    /// Imports `textures/rock.slow` in a new asset directory and returns the UUID of its asset.
+    fn import_in_new_asset_dir(asset_uuid_namespace: Option<uuid::Uuid>) -> AssetUuid {
+        let asset_id = Arc::new(std::sync::Mutex::new(None));
+
+        with_fixture(test_importers(&[("slow", Import::Slow(&SLOW_IMPORTS))]), {
+            let asset_id = asset_id.clone();
+            move |fixture| async move {
+                let Fixture { db, asset_dir, .. } = &fixture;
+                let source = fixture
+                    .new_source()
+                    .with_asset_uuid_namespace(asset_uuid_namespace);
+
+                tokio::fs::create_dir(asset_dir.join("textures"))
+                    .await
+                    .unwrap();
+                let file = asset_dir.join("textures").join("rock.slow");
+                tokio::fs::write(&file, "rock").await.unwrap();
+                let path = crate::watcher::canonicalize_path(&file);
+                loop {
+                    tokio::time::delay_for(Duration::from_millis(10)).await;
+                    source.handle_update().await;
+                    let txn = db.ro_txn().await.unwrap();
+                    if let Some(metadata) = source.get_metadata(&txn, &path) {
+                        let assets = metadata.get().unwrap().get_assets().unwrap();
+                        let id = assets.get(0).get_id().unwrap().get_id().unwrap();
+                        *asset_id.lock().unwrap() = utils::uuid_from_slice(id);
+                        break;
+                    }
+                }
+            }
+        });
+
+        let asset_id = asset_id.lock().unwrap().take();
+        asset_id.unwrap()
+    }
+
+    #[test]
+    fn asset_uuid_source_key_uses_forward_slashes() {
+        let relative_path = Path::new("textures").join("Rock.png");
+        assert_eq!("textures/Rock.png", asset_uuid_source_key(&relative_path));
+    }
+
+    #[test]
+    fn deterministic_asset_uuids_match_across_asset_dirs() {
+        let namespace = uuid::Uuid::from_bytes([3; 16]);
+        let first = import_in_new_asset_dir(Some(namespace));
+        assert_eq!(first, import_in_new_asset_dir(Some(namespace)));
+        assert_ne!(first, import_in_new_asset_dir(None));
+    }
+
+    #[test]
+    fn path_key_is_exact_when_case_sensitive() {
+        let stored = path_key(Path::new("assets/textures/foo.png"), false);
+        assert_eq!(
+            stored,
+            path_key(Path::new("assets/textures/foo.png"), false)
+        );
+        assert_ne!(
+            stored,
+            path_key(Path::new("assets/Textures/Foo.png"), false)
+        );
+    }
+
+    #[test]
+    fn imports_run_up_to_the_import_concurrency_at_once() {
+        static SERIAL: ImportsInFlight = ImportsInFlight::new();
+        static CONCURRENT: ImportsInFlight = ImportsInFlight::new();
+        import_test_files(1, &SERIAL);
+        assert_eq!(1, SERIAL.max());
+        import_test_files(8, &CONCURRENT);
+        let max = CONCURRENT.max();
+        assert!(1 < max && max <= 8, "{} imports ran at once", max);
+    }
+
+    #[test]
+    fn iter_assets_enumerates_imported_assets_in_pages() {
+        let importers = test_importers(&[("txt", Import::Prefixed)]);
+        with_fixture(importers, move |fixture| async move {
+            let Fixture {
+                tracker,
+                db,
+                asset_dir,
+                ..
+            } = &fixture;
+            let source = fixture.new_source();
+
+            let mut paths = Vec::new();
+            for name in &["a.txt", "b.txt", "c.txt"] {
+                let file = asset_dir.join(name);
+                tokio::fs::write(&file, *name).await.unwrap();
+                paths.push(crate::watcher::canonicalize_path(&file));
+            }
+            while tracker.read_dirty_files(&tracker.get_ro_txn().await).len() < paths.len() {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+            }
+            source.handle_update().await;
+
+            let txn = db.ro_txn().await.unwrap();
+            let assets = source.iter_assets(&txn, None).collect::<Vec<_>>();
+            let mut asset_paths = Vec::new();
+            for (id, artifact, path) in &assets {
+                assert_eq!(*id, artifact.asset_id);
+                asset_paths.push(path.clone());
+            }
+            asset_paths.sort();
+            assert_eq!(paths, asset_paths);
+            let ids = assets.iter().map(|(id, _, _)| *id).collect::<Vec<_>>();
+            let mut sorted_ids = ids.clone();
+            sorted_ids.sort();
+            assert_eq!(sorted_ids, ids);
+
+            let first_page = source.iter_assets(&txn, None).take(2).collect::<Vec<_>>();
+            let second_page = source
+                .iter_assets(&txn, Some(&first_page[1].0))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                ids,
+                first_page
+                    .iter()
+                    .chain(second_page.iter())
+                    .map(|(id, _, _)| *id)
+                    .collect::<Vec<_>>()
+            );
+        });
+    }
+
+    #[test]
+    fn force_reimport_runs_importer_for_unchanged_file() {
+        let importers = test_importers(&[("counted", Import::Counted(&COUNTED_IMPORTS))]);
+        with_fixture(importers, move |fixture| async move {
+            let Fixture {
+                tracker,
+                db,
+                asset_dir,
+                ..
+            } = &fixture;
+            let source = fixture.new_source();
+
+            let file = asset_dir.join("asset.counted");
+            tokio::fs::write(&file, "contents").await.unwrap();
+            while tracker
+                .read_dirty_files(&tracker.get_ro_txn().await)
+                .is_empty()
+            {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+            }
+            source.handle_update().await;
+            assert_eq!(1, COUNTED_IMPORTS.load(Ordering::SeqCst));
+
+            let path = crate::watcher::canonicalize_path(&file);
+            source.force_reimport(&path).await.unwrap();
+            assert_eq!(2, COUNTED_IMPORTS.load(Ordering::SeqCst));
+            {
+                let txn = db.ro_txn().await.unwrap();
+                let metadata = source.get_metadata(&txn, &path).unwrap();
+                assert_eq!(1, metadata.get().unwrap().get_assets().unwrap().len());
+            }
+
+            // the forced import is only done once, later updates use the import hash again
+            let mut txn = tracker.get_rw_txn().await;
+            tracker.add_dirty_file(&mut txn, &path).unwrap();
+            txn.commit().unwrap();
+            source.handle_update().await;
+            assert_eq!(2, COUNTED_IMPORTS.load(Ordering::SeqCst));
+
+            let missing = asset_dir.join("missing.counted");
+            assert!(source.force_reimport(&missing).await.is_err());
+        });
+    }
+
+    #[test]
+    fn relocated_roots_keep_their_assets() {
+        static IMPORTS: AtomicUsize = AtomicUsize::new(0);
+        let moved_dir = tempfile::tempdir().unwrap();
+
+        let importers = test_importers(&[("counted", Import::Counted(&IMPORTS))]);
+        with_fixture(importers, move |fixture| async move {
+            let Fixture {
+                tracker,
+                db,
+                asset_dir,
+                ..
+            } = &fixture;
+            let source = fixture.new_source();
+
+            let file = asset_dir.join("asset.counted");
+            tokio::fs::write(&file, "contents").await.unwrap();
+            while tracker
+                .read_dirty_files(&tracker.get_ro_txn().await)
+                .is_empty()
+            {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+            }
+            source.handle_update().await;
+            assert_eq!(1, IMPORTS.load(Ordering::SeqCst));
+            let old_root = crate::watcher::canonicalize_path(&asset_dir);
+            let old_path = crate::watcher::canonicalize_path(&file);
+            // the .meta file written by the import is only relocated once the tracker recorded it
+            let old_meta_path = utils::to_meta_path(&old_path);
+            while tracker
+                .read_all_files(&tracker.get_ro_txn().await)
+                .iter()
+                .all(|file| file.path != old_meta_path)
+            {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+            }
+            source.handle_update().await;
+            let id = {
+                let txn = db.ro_txn().await.unwrap();
+                let metadata = source.get_metadata(&txn, &old_path).unwrap();
+                let assets = metadata.get().unwrap().get_assets().unwrap();
+                let id = assets.get(0).get_id().unwrap().get_id().unwrap();
+                AssetUuid(utils::make_array(id))
+            };
+
+            let new_root = crate::watcher::canonicalize_path(&moved_dir.path().to_path_buf());
+            let relocated = source.relocate_root(&old_root, &new_root).await.unwrap();
+            assert_ne!(0, relocated);
+            let new_path = new_root.join("asset.counted");
+            {
+                let txn = db.ro_txn().await.unwrap();
+                assert!(source.get_metadata(&txn, &old_path).is_none());
+                let metadata = source.get_metadata(&txn, &new_path).unwrap();
+                let assets = metadata.get().unwrap().get_assets().unwrap();
+                assert_eq!(&id.0, assets.get(0).get_id().unwrap().get_id().unwrap());
+                assert_eq!(Some(new_path.clone()), source.get_asset_path(&txn, &id));
+                let files: Vec<PathBuf> = tracker
+                    .read_all_files(&txn)
+                    .into_iter()
+                    .map(|file| file.path)
+                    .collect();
+                assert!(files.contains(&new_path));
+                assert!(files.contains(&utils::to_meta_path(&new_path)));
+                assert!(files.iter().all(|path| !path.starts_with(&old_root)));
+            }
+
+            // nothing became dirty, so the asset is not imported again
+            source.handle_update().await;
+            assert_eq!(1, IMPORTS.load(Ordering::SeqCst));
+        });
+    }
+
+    #[test]
+    fn rebuild_all_artifacts_reimports_every_source_file() {
+        let importers = test_importers(&[("counted", Import::Counted(&REBUILT_IMPORTS))]);
+        with_fixture(importers, move |fixture| async move {
+            let Fixture {
+                tracker,
+                db,
+                asset_dir,
+                ..
+            } = &fixture;
+            let source = fixture.new_source();
+
+            let names = ["a.counted", "b.counted", "c.counted"];
+            for name in &names {
+                tokio::fs::write(asset_dir.join(name), "contents")
+                    .await
+                    .unwrap();
+            }
+            while tracker.read_dirty_files(&tracker.get_ro_txn().await).len() < names.len() {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+            }
+            source.handle_update().await;
+            assert_eq!(3, REBUILT_IMPORTS.load(Ordering::SeqCst));
+
+            let paths: Vec<PathBuf> = names
+                .iter()
+                .map(|name| crate::watcher::canonicalize_path(&asset_dir.join(name)))
+                .collect();
+            let asset_ids = |txn: &crate::capnp_db::RoTransaction<'_>| -> Vec<Vec<u8>> {
+                paths
+                    .iter()
+                    .map(|path| {
+                        let metadata = source.get_metadata(txn, path).unwrap();
+                        let assets = metadata.get().unwrap().get_assets().unwrap();
+                        assets.get(0).get_id().unwrap().get_id().unwrap().to_vec()
+                    })
+                    .collect()
+            };
+            let ids_before = asset_ids(&db.ro_txn().await.unwrap());
+
+            assert_eq!(3, source.rebuild_all_artifacts().await.unwrap());
+            assert_eq!(6, REBUILT_IMPORTS.load(Ordering::SeqCst));
+            // the assets keep their UUIDs, unlike when the database is deleted
+            assert_eq!(ids_before, asset_ids(&db.ro_txn().await.unwrap()));
+        });
+    }
+
+    #[test]
+    fn importer_options_are_read_from_meta_file() {
+        let importers = test_importers(&[("prefix", Import::Prefixed)]);
+        with_fixture(importers, move |fixture| async move {
+            let Fixture { db, asset_dir, .. } = &fixture;
+            let source = fixture.new_source();
+
+            let file = asset_dir.join("asset.prefix");
+            tokio::fs::write(&file, "rock").await.unwrap();
+            let meta_path = utils::to_meta_path(&crate::watcher::canonicalize_path(&file));
+            while !meta_path.exists() {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+            }
+
+            // edit the options in the .meta file the way an author would
+            let contents = tokio::fs::read_to_string(&meta_path).await.unwrap();
+            let mut meta = TestMetaFile::deserialize_meta(&contents).unwrap();
+            let asset_id = meta.importer_state.id.unwrap();
+            meta.importer_options = Some(TestImporterOptions {
+                prefix: "big ".to_string(),
+            });
+            tokio::fs::write(&meta_path, meta.serialize_meta().unwrap())
+                .await
+                .unwrap();
+
+            loop {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+                let txn = db.ro_txn().await.unwrap();
+                let (_, artifact) = source
+                    .regenerate_import_artifact(&txn, &asset_id, &mut Vec::new())
+                    .await
+                    .unwrap();
+                let value: String = bincode::deserialize(&artifact.data).unwrap();
+                if value == "big rock" {
+                    break;
+                }
+                assert_eq!("rock", value);
+            }
+        });
+    }
+
+    #[test]
+    fn import_errors_are_reported_to_listeners() {
+        let importers = test_importers(&[("broken", Import::Failing)]);
+        with_fixture(importers, move |fixture| async move {
+            let Fixture { hub, asset_dir, .. } = &fixture;
+            let source = fixture.new_source();
+            let (_, queue) = hub.register_listener();
+
+            let file = asset_dir.join("asset.broken");
+            tokio::fs::write(&file, "contents").await.unwrap();
+            let path = crate::watcher::canonicalize_path(&file);
+            let (error_path, message) = 'wait: loop {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+                for event in queue.try_recv() {
+                    if let asset_hub::AssetBatchEvent::ImportError(error_path, message) = event {
+                        break 'wait (error_path, message);
+                    }
+                }
+            };
+            assert_eq!(path, error_path);
+            assert!(message.contains("broken file"));
+            assert_eq!(vec![(path, message)], hub.get_import_errors());
+        });
+    }
+
+    #[test]
+    fn project_default_importer_options_apply_to_new_assets() {
+        let mut importers = test_importers(&[("prefix", Import::Prefixed)]);
+        importers.set_default_options(
+            atelier_core::AssetTypeId(TestImporter::UUID),
+            TestImporterOptions {
+                prefix: "small ".to_string(),
+            },
+        );
+        with_fixture(importers, move |fixture| async move {
+            let Fixture { db, asset_dir, .. } = &fixture;
+            let source = fixture.new_source();
+
+            let file = asset_dir.join("asset.prefix");
+            tokio::fs::write(&file, "rock").await.unwrap();
+            let meta_path = utils::to_meta_path(&crate::watcher::canonicalize_path(&file));
+            while !meta_path.exists() {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+            }
+
+            // the project default is resolved on import instead of being written to the .meta file
+            let contents = tokio::fs::read_to_string(&meta_path).await.unwrap();
+            let mut meta = TestMetaFile::deserialize_meta(&contents).unwrap();
+            assert!(meta.importer_options.is_none());
+            let asset_id = meta.importer_state.id.unwrap();
+
+            let txn = db.ro_txn().await.unwrap();
+            let (_, artifact) = source
+                .regenerate_import_artifact(&txn, &asset_id, &mut Vec::new())
+                .await
+                .unwrap();
+            let value: String = bincode::deserialize(&artifact.data).unwrap();
+            assert_eq!("small rock", value);
+            drop(txn);
+
+            // options in the .meta file override the project default
+            meta.importer_options = Some(TestImporterOptions {
+                prefix: "big ".to_string(),
+            });
+            tokio::fs::write(&meta_path, meta.serialize_meta().unwrap())
+                .await
+                .unwrap();
+            loop {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+                let txn = db.ro_txn().await.unwrap();
+                let (_, artifact) = source
+                    .regenerate_import_artifact(&txn, &asset_id, &mut Vec::new())
+                    .await
+                    .unwrap();
+                let value: String = bincode::deserialize(&artifact.data).unwrap();
+                if value == "big rock" {
+                    break;
+                }
+                assert_eq!("small rock", value);
+            }
+        });
+    }
+
+    #[test]
+    fn importer_panics_are_import_errors() {
+        let importers =
+            test_importers(&[("panic", Import::Panicking), ("prefix", Import::Prefixed)]);
+        with_fixture(importers, move |fixture| async move {
+            let Fixture {
+                hub, db, asset_dir, ..
+            } = &fixture;
+            let source = fixture.new_source();
+
+            let panicking_file = asset_dir.join("asset.panic");
+            let file = asset_dir.join("asset.prefix");
+            tokio::fs::write(&panicking_file, "contents").await.unwrap();
+            tokio::fs::write(&file, "rock").await.unwrap();
+            let path = crate::watcher::canonicalize_path(&file);
+            let errors = loop {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+                let txn = db.ro_txn().await.unwrap();
+                let errors = hub.get_import_errors();
+                if source.get_metadata(&txn, &path).is_some() && !errors.is_empty() {
+                    break errors;
+                }
+            };
+
+            let panicking_path = crate::watcher::canonicalize_path(&panicking_file);
+            let message = "importer panicked: importer bug".to_string();
+            assert_eq!(vec![(panicking_path, message)], errors);
+        });
+    }
+
+    #[test]
+    fn duplicate_labels_are_import_errors() {
+        let importers = test_importers(&[("label", Import::Labeled)]);
+        with_fixture(importers, move |fixture| async move {
+            let Fixture {
+                hub, db, asset_dir, ..
+            } = &fixture;
+            let source = fixture.new_source();
+
+            tokio::fs::write(asset_dir.join("player.label"), "player_mesh")
+                .await
+                .unwrap();
+            let owner = loop {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+                let txn = db.ro_txn().await.unwrap();
+                if let Some(owner) = hub.get_asset_for_label(&txn, "player_mesh").unwrap() {
+                    break owner;
+                }
+            };
+
+            let duplicate = asset_dir.join("copy.label");
+            tokio::fs::write(&duplicate, "player_mesh").await.unwrap();
+            let errors = loop {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+                let errors = hub.get_import_errors();
+                if !errors.is_empty() {
+                    break errors;
+                }
+            };
+
+            let duplicate = crate::watcher::canonicalize_path(&duplicate);
+            assert_eq!(1, errors.len());
+            assert_eq!(duplicate, errors[0].0);
+            assert!(errors[0].1.contains("player_mesh"));
+            let txn = db.ro_txn().await.unwrap();
+            assert_eq!(
+                Some(owner),
+                hub.get_asset_for_label(&txn, "player_mesh").unwrap()
+            );
+        });
+    }
+
+    #[test]
+    fn duplicate_asset_uuids_are_import_errors() {
+        let importers = test_importers(&[("prefix", Import::Prefixed)]);
+        with_fixture(importers, move |fixture| async move {
+            let Fixture {
+                hub, db, asset_dir, ..
+            } = &fixture;
+            let source = fixture.new_source();
+
+            let file = asset_dir.join("rock.prefix");
+            tokio::fs::write(&file, "rock").await.unwrap();
+            let path = crate::watcher::canonicalize_path(&file);
+            let meta_path = utils::to_meta_path(&path);
+            while !meta_path.exists() {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+            }
+            let meta = tokio::fs::read_to_string(&meta_path).await.unwrap();
+            let id = TestMetaFile::deserialize_meta(&meta)
+                .unwrap()
+                .importer_state
+                .id
+                .unwrap();
+
+            // a copy of the file together with its .meta file
+            let copy = asset_dir.join("copy.prefix");
+            tokio::fs::write(utils::to_meta_path(&copy), &meta)
+                .await
+                .unwrap();
+            tokio::fs::write(&copy, "stone").await.unwrap();
+            let errors = loop {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+                let errors = hub.get_import_errors();
+                if !errors.is_empty() {
+                    break errors;
+                }
+            };
+
+            let copy = crate::watcher::canonicalize_path(&copy);
+            assert_eq!(1, errors.len());
+            assert_eq!(copy, errors[0].0);
+            assert!(errors[0].1.contains(&*path.to_string_lossy()));
+
+            // the asset stays with the file that was imported first
+            let txn = db.ro_txn().await.unwrap();
+            assert_eq!(Some(path), source.get_asset_path(&txn, &id));
+            let (_, artifact) = source
+                .regenerate_import_artifact(&txn, &id, &mut Vec::new())
+                .await
+                .unwrap();
+            let value: String = bincode::deserialize(&artifact.data).unwrap();
+            assert_eq!("rock", value);
+        });
+    }
+
+    #[test]
+    fn non_cacheable_assets_are_imported_on_every_request() {
+        static IMPORTS: AtomicUsize = AtomicUsize::new(0);
+
+        let importers = test_importers(&[("volatile", Import::Volatile(&IMPORTS))]);
+        with_fixture(importers, move |fixture| async move {
+            let Fixture {
+                db,
+                artifact_cache,
+                asset_dir,
+                ..
+            } = &fixture;
+            let source = fixture.new_source();
+
+            let file = asset_dir.join("clock.volatile");
+            tokio::fs::write(&file, "tick").await.unwrap();
+            let meta_path = utils::to_meta_path(&crate::watcher::canonicalize_path(&file));
+            while !meta_path.exists() {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+            }
+            let meta = tokio::fs::read_to_string(&meta_path).await.unwrap();
+            let id = TestMetaFile::deserialize_meta(&meta)
+                .unwrap()
+                .importer_state
+                .id
+                .unwrap();
+            let imports = IMPORTS.load(Ordering::SeqCst);
+
+            let txn = db.ro_txn().await.unwrap();
+            let mut values = Vec::new();
+            for _ in 0..2 {
+                let (_, artifact) = source
+                    .regenerate_import_artifact(&txn, &id, &mut Vec::new())
+                    .await
+                    .unwrap();
+                let value: String = bincode::deserialize(&artifact.data).unwrap();
+                values.push(value);
+                let cache_txn = artifact_cache.ro_txn().await.unwrap();
+                let hash = artifact.metadata.hash_bytes();
+                assert!(artifact_cache.get(&cache_txn, &hash, 1).await.is_none());
+            }
+
+            // every request ran the importer again
+            assert_eq!(
+                vec![
+                    format!("import {}", imports + 1),
+                    format!("import {}", imports + 2)
+                ],
+                values
+            );
+        });
+    }
+
+    #[test]
+    fn oversized_files_are_tracked_but_not_imported() {
+        let importers = test_importers(&[("prefix", Import::Prefixed)]);
+        with_fixture(importers, move |fixture| async move {
+            let Fixture {
+                tracker,
+                hub,
+                db,
+                asset_dir,
+                ..
+            } = &fixture;
+            let source = fixture.new_source().with_max_file_size(Some(8));
+
+            let small = asset_dir.join("small.prefix");
+            let big = asset_dir.join("big.prefix");
+            tokio::fs::write(&small, "rock").await.unwrap();
+            tokio::fs::write(&big, "a rock much larger than the limit")
+                .await
+                .unwrap();
+            let small = crate::watcher::canonicalize_path(&small);
+            let big = crate::watcher::canonicalize_path(&big);
+            let errors = loop {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+                let errors = hub.get_import_errors();
+                if !errors.is_empty() && utils::to_meta_path(&small).exists() {
+                    break errors;
+                }
+            };
+
+            assert_eq!(1, errors.len());
+            assert_eq!(big, errors[0].0);
+            assert!(errors[0].1.contains("maximum of 8 bytes"));
+            assert!(!utils::to_meta_path(&big).exists());
+
+            let txn = db.ro_txn().await.unwrap();
+            assert!(source.get_metadata(&txn, &small).is_some());
+            assert!(source.get_metadata(&txn, &big).is_none());
+            let state = tracker.get_file_state(&txn, &big).unwrap();
+            assert_eq!(data::FileState::Exists, state.state);
+            assert!(tracker
+                .read_dirty_files(&txn)
+                .iter()
+                .all(|dirty| dirty.path != big));
+        });
+    }
+
+    #[test]
+    fn asset_uuids_are_kept_in_db_without_meta_files() {
+        with_fixture(
+            test_importers(&[("prefix", Import::Prefixed)]),
+            |fixture| async move {
+                let Fixture {
+                    tracker,
+                    db,
+                    asset_dir,
+                    ..
+                } = &fixture;
+                let new_source = || fixture.new_source().with_meta_files(false);
+
+                let file = asset_dir.join("rock.prefix");
+                let path = crate::watcher::canonicalize_path(&file);
+                let asset_id =
+                    |source: &FileAssetSource, txn: &crate::capnp_db::RoTransaction<'_>| {
+                        let metadata = source.get_metadata(txn, &path)?;
+                        let assets = metadata.get().unwrap().get_assets().unwrap();
+                        utils::uuid_from_slice(assets.get(0).get_id().unwrap().get_id().unwrap())
+                    };
+
+                let source = new_source();
+                tokio::fs::write(&file, "rock").await.unwrap();
+                let id = loop {
+                    tokio::time::delay_for(Duration::from_millis(10)).await;
+                    source.handle_update().await;
+                    let txn = db.ro_txn().await.unwrap();
+                    if tracker.read_dirty_files(&txn).is_empty() {
+                        if let Some(id) = asset_id(&source, &txn) {
+                            break id;
+                        }
+                    }
+                };
+                assert!(!utils::to_meta_path(&path).exists());
+
+                // a new source reads the UUID from the database when the file changes
+                drop(source);
+                let source = new_source();
+                tokio::fs::write(&file, "stone").await.unwrap();
+                while tracker
+                    .read_dirty_files(&tracker.get_ro_txn().await)
+                    .is_empty()
+                {
+                    tokio::time::delay_for(Duration::from_millis(10)).await;
+                }
+                source.handle_update().await;
+
+                let txn = db.ro_txn().await.unwrap();
+                assert_eq!(Some(id), asset_id(&source, &txn));
+                let (_, artifact) = source
+                    .regenerate_import_artifact(&txn, &id, &mut Vec::new())
+                    .await
+                    .unwrap();
+                let value: String = bincode::deserialize(&artifact.data).unwrap();
+                assert_eq!("stone", value);
+                assert!(!utils::to_meta_path(&path).exists());
+            },
+        );
+    }
+
+    #[test]
+    fn importers_see_source_path_and_root() {
+        let importers = test_importers(&[("path", Import::SourcePath)]);
+        with_fixture(importers, move |fixture| async move {
+            let Fixture { db, asset_dir, .. } = &fixture;
+            let source = fixture.new_source();
+
+            let atlas_dir = asset_dir.join("atlas");
+            tokio::fs::create_dir(&atlas_dir).await.unwrap();
+            let file = atlas_dir.join("sprites.path");
+            tokio::fs::write(&file, "contents").await.unwrap();
+            let meta_path = utils::to_meta_path(&crate::watcher::canonicalize_path(&file));
+            while !meta_path.exists() {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+            }
+
+            let contents = tokio::fs::read_to_string(&meta_path).await.unwrap();
+            let meta = TestMetaFile::deserialize_meta(&contents).unwrap();
+            let txn = db.ro_txn().await.unwrap();
+            let (_, artifact) = source
+                .regenerate_import_artifact(&txn, &meta.importer_state.id.unwrap(), &mut Vec::new())
+                .await
+                .unwrap();
+            let value: String = bincode::deserialize(&artifact.data).unwrap();
+            assert_eq!("atlas/sprites.path", value);
+        });
+    }
+
+    #[test]
+    fn importers_get_services_of_the_importer_contexts() {
+        let importers = test_importers(&[("shader", Import::Service)]);
+        with_fixture(importers, move |fixture| async move {
+            let Fixture { db, asset_dir, .. } = &fixture;
+            let compiler = atelier_importer::ServiceContext::new(String::from("glslc"));
+            let source = fixture.new_source_with_contexts(vec![Box::new(compiler)]);
+
+            let file = asset_dir.join("lit.shader");
+            tokio::fs::write(&file, "contents").await.unwrap();
+            let meta_path = utils::to_meta_path(&crate::watcher::canonicalize_path(&file));
+            while !meta_path.exists() {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+            }
+
+            let contents = tokio::fs::read_to_string(&meta_path).await.unwrap();
+            let meta = TestMetaFile::deserialize_meta(&contents).unwrap();
+            let txn = db.ro_txn().await.unwrap();
+            let (_, artifact) = source
+                .regenerate_import_artifact(&txn, &meta.importer_state.id.unwrap(), &mut Vec::new())
+                .await
+                .unwrap();
+            let value: String = bincode::deserialize(&artifact.data).unwrap();
+            assert_eq!("glslc", value);
+        });
+    }
+
+    #[test]
+    fn importers_can_generate_assets() {
+        let importers = test_importers(&[("atlas", Import::Atlas)]);
+        with_fixture(importers, move |fixture| async move {
+            let Fixture { db, asset_dir, .. } = &fixture;
+            let source = fixture.new_source();
+
+            let file = asset_dir.join("sprites.atlas");
+            tokio::fs::write(&file, "sprites").await.unwrap();
+            let path = crate::watcher::canonicalize_path(&file);
+            let meta_path = utils::to_meta_path(&path);
+            while !meta_path.exists() {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+            }
+
+            let txn = db.ro_txn().await.unwrap();
+            let metadata = source.get_metadata(&txn, &path).unwrap();
+            assert_eq!(3, metadata.get().unwrap().get_assets().unwrap().len());
+            for sprite in 1..=2u8 {
+                let (_, artifact) = source
+                    .regenerate_import_artifact(&txn, &AssetUuid([sprite; 16]), &mut Vec::new())
+                    .await
+                    .unwrap();
+                assert_eq!(SPRITE_TYPE, artifact.metadata.type_id);
+                let value: u32 = bincode::deserialize(&artifact.data).unwrap();
+                assert_eq!(u32::from(sprite), value);
+            }
+        });
+    }
+
+    #[test]
+    fn metrics_count_imports() {
+        let importers =
+            test_importers(&[("broken", Import::Failing), ("prefix", Import::Prefixed)]);
+        with_fixture(importers, move |fixture| async move {
+            let Fixture {
+                tracker, asset_dir, ..
+            } = &fixture;
+            let source = fixture.new_source();
+            let metrics = tracker.metrics();
+
+            tokio::fs::write(asset_dir.join("a.prefix"), "rock")
+                .await
+                .unwrap();
+            tokio::fs::write(asset_dir.join("b.prefix"), "stone")
+                .await
+                .unwrap();
+            tokio::fs::write(asset_dir.join("c.broken"), "contents")
+                .await
+                .unwrap();
+            while metrics.imports_succeeded() < 2 || metrics.imports_failed() < 1 {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+            }
+
+            // the broken file is retried on every update until it is fixed
+            assert_eq!(
+                metrics.imports_succeeded() + metrics.imports_failed(),
+                metrics.import_durations().iter().sum::<u64>()
+            );
+            assert!(metrics.files_tracked() >= 3);
+            assert!(metrics.dirty_files_processed() >= 3);
+            assert!(metrics.bytes_written() > 0);
+        });
+    }
+
+    #[test]
+    fn metrics_record_slowest_imports() {
+        let importers = test_importers(&[
+            ("slow", Import::Slow(&SLOW_IMPORTS)),
+            ("prefix", Import::Prefixed),
+        ]);
+        with_fixture(importers, move |fixture| async move {
+            let Fixture {
+                tracker, asset_dir, ..
+            } = &fixture;
+            let source = fixture.new_source();
+            let metrics = tracker.metrics();
+
+            let slow = asset_dir.join("a.slow");
+            tokio::fs::write(&slow, "rock").await.unwrap();
+            tokio::fs::write(asset_dir.join("b.prefix"), "stone")
+                .await
+                .unwrap();
+            while metrics.slowest_imports(usize::MAX).len() < 2 {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+            }
+
+            // the slow importer waits 25ms before returning
+            let slowest = metrics.slowest_imports(1);
+            assert_eq!(1, slowest.len());
+            assert_eq!(crate::watcher::canonicalize_path(&slow), slowest[0].0);
+            assert!(slowest[0].1 >= Duration::from_millis(25));
+            let all = metrics.slowest_imports(usize::MAX);
+            assert_eq!(2, all.len());
+            assert!(all[0].1 >= all[1].1);
+        });
+    }
+
+    #[test]
+    fn sources_are_imported_again_when_files_they_read_change() {
+        expect_sheet_reimport_on_image_change(test_importers(&[("sheet", Import::SpriteSheet)]));
+    }
+
+    #[test]
+    fn sources_are_imported_again_when_imported_files_they_read_change() {
+        expect_sheet_reimport_on_image_change(test_importers(&[
+            ("sheet", Import::SpriteSheet),
+            ("img", Import::Slow(&SLOW_IMPORTS)),
+        ]));
+    }
+
+    fn expect_sheet_reimport_on_image_change(importers: ImporterMap) {
+        with_fixture(importers, move |fixture| async move {
+            let Fixture {
+                db, hub, asset_dir, ..
+            } = &fixture;
+            let source = fixture.new_source();
+
+            let image_dir = asset_dir.join("images");
+            tokio::fs::create_dir(&image_dir).await.unwrap();
+            tokio::fs::write(image_dir.join("a.img"), "A")
+                .await
+                .unwrap();
+            tokio::fs::write(image_dir.join("b.img"), "B")
+                .await
+                .unwrap();
+            let file = asset_dir.join("sprites.sheet");
+            tokio::fs::write(&file, "images/a.img\nimages/b.img")
+                .await
+                .unwrap();
+            let meta_path = utils::to_meta_path(&crate::watcher::canonicalize_path(&file));
+            while !meta_path.exists() {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+            }
+            let contents = tokio::fs::read_to_string(&meta_path).await.unwrap();
+            let meta = TestMetaFile::deserialize_meta(&contents).unwrap();
+            assert_eq!(
+                vec![PathBuf::from("images/a.img"), PathBuf::from("images/b.img")],
+                meta.dependencies
+            );
+
+            let old_artifact = sheet_artifact(db, hub).await;
+
+            // only the image changes, the sheet itself is unchanged
+            tokio::fs::write(image_dir.join("a.img"), "C")
+                .await
+                .unwrap();
+            let mut updates = 0;
+            while sheet_artifact(db, hub).await == old_artifact {
+                assert!(updates < 500, "the sheet was not imported again");
+                updates += 1;
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+            }
+            let txn = db.ro_txn().await.unwrap();
+            let (_, artifact) = source
+                .regenerate_import_artifact(&txn, &SHEET_ID, &mut Vec::new())
+                .await
+                .unwrap();
+            let value: String = bincode::deserialize(&artifact.data).unwrap();
+            assert_eq!("CB", value);
+        });
+    }
+
+    /// Returns the content hash of the sheet's artifact that is recorded in the asset hub.
+    async fn sheet_artifact(db: &Environment, hub: &AssetHub) -> Option<u64> {
+        let txn = db.ro_txn().await.unwrap();
+        let metadata = hub.get_metadata(&txn, &SHEET_ID)?;
+        let metadata = parse_db_metadata(&metadata.get().unwrap());
+        metadata.artifact.map(|artifact| artifact.content_hash)
+    }
+
+    /// Returns the tracked files, the dirty files and the number of source metadata entries.
+    async fn snapshot(
+        db: &Environment,
+        tracker: &FileTracker,
+        source: &FileAssetSource,
+    ) -> (Vec<FileState>, Vec<FileState>, usize) {
+        let txn = db.ro_txn().await.unwrap();
+        let metadata = source.iter_metadata(&txn).count();
+        (
+            tracker.read_all_files(&txn),
+            tracker.read_dirty_files(&txn),
+            metadata,
+        )
+    }
+
+    #[test]
+    fn dry_run_reports_imports_without_writing() {
+        let importers =
+            test_importers(&[("broken", Import::Failing), ("prefix", Import::Prefixed)]);
+        with_fixture(importers, move |fixture| async move {
+            let Fixture {
+                tracker,
+                db,
+                asset_dir,
+                ..
+            } = &fixture;
+            let source = fixture.new_source();
+
+            let names = ["a.prefix", "b.prefix", "c.prefix", "d.broken"];
+            for name in names.iter() {
+                tokio::fs::write(asset_dir.join(name), "rock")
+                    .await
+                    .unwrap();
+            }
+            // wait for the tracker to record the files, the asset source is not running
+            loop {
+                tokio::time::delay_for(Duration::from_millis(50)).await;
+                let txn = db.ro_txn().await.unwrap();
+                if tracker.read_dirty_files(&txn).len() == names.len() {
+                    break;
+                }
+            }
+            tokio::time::delay_for(Duration::from_millis(200)).await;
+            let before = snapshot(&db, &tracker, &source).await;
+
+            let report = source.dry_run().await.unwrap();
+
+            let path = |name: &str| crate::watcher::canonicalize_path(&asset_dir.join(name));
+            assert_eq!(
+                vec![path("a.prefix"), path("b.prefix"), path("c.prefix")],
+                report.imports
+            );
+            assert_eq!(1, report.errors.len());
+            assert_eq!(path("d.broken"), report.errors[0].0);
+            assert!(report.removals.is_empty());
+            assert_eq!(before, snapshot(&db, &tracker, &source).await);
+            assert_eq!(0, before.2);
+            for name in names.iter() {
+                assert!(!utils::to_meta_path(&path(name)).exists());
+            }
+        });
+    }
+
+    #[test]
+    fn stuck_imports_time_out() {
+        let importers = test_importers(&[
+            ("stall", Import::Stalling),
+            ("block", Import::Blocking),
+            ("prefix", Import::Prefixed),
+        ]);
+        with_fixture(importers, move |fixture| async move {
+            let Fixture { hub, asset_dir, .. } = &fixture;
+            let source = fixture
+                .new_source()
+                .with_import_timeout(Some(Duration::from_millis(100)));
+            let (_, queue) = hub.register_listener();
+
+            // one importer waits without blocking, the other blocks its thread
+            let stuck = asset_dir.join("stuck.stall");
+            tokio::fs::write(&stuck, "contents").await.unwrap();
+            let busy = asset_dir.join("busy.block");
+            tokio::fs::write(&busy, "contents").await.unwrap();
+            let file = asset_dir.join("asset.prefix");
+            tokio::fs::write(&file, "rock").await.unwrap();
+            let meta_path = utils::to_meta_path(&crate::watcher::canonicalize_path(&file));
+            let mut errors = HashMap::new();
+            while errors.len() < 2 || !meta_path.exists() {
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+                source.handle_update().await;
+                for event in queue.try_recv() {
+                    if let asset_hub::AssetBatchEvent::ImportError(path, message) = event {
+                        errors.insert(path, message);
+                    }
+                }
+            }
+            for path in &[stuck, busy] {
+                let message = &errors[&crate::watcher::canonicalize_path(path)];
+                assert!(message.contains("import timed out"), "{}", message);
+            }
+        });
+    }
+}
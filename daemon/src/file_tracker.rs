@@ -2,17 +2,18 @@ use crate::capnp_db::{
     CapnpCursor, DBTransaction, Environment, MessageReader, RoTransaction, RwTransaction,
 };
 use crate::error::{Error, Result};
+use crate::metrics::DaemonMetrics;
 use crate::watcher::{self, FileEvent, FileMetadata};
 use atelier_core::utils;
 use atelier_schema::data::{self, dirty_file_info, rename_file_event, source_file_info, FileType};
 use event_listener::Event;
-use futures_channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures_channel::mpsc::{channel, unbounded, UnboundedReceiver, UnboundedSender};
 use futures_util::future::{Fuse, FusedFuture, FutureExt};
 use futures_util::lock::Mutex;
 use futures_util::select;
-use futures_util::stream::StreamExt;
+use futures_util::stream::{BoxStream, StreamExt};
 use lmdb::Cursor;
-use log::{debug, info};
+use log::{debug, error, info};
 use std::{
     cell::Cell,
     cmp::PartialEq,
@@ -20,7 +21,7 @@ use std::{
     fs,
     iter::FromIterator,
     ops::IndexMut,
-    path::PathBuf,
+    path::{Path, PathBuf},
     str,
     sync::atomic::{AtomicBool, Ordering},
     sync::Arc,
@@ -28,6 +29,25 @@ use std::{
 };
 use tokio::time::{self, Duration};
 
+/// How long the tracker waits for more file events after one arrives before it records them, so
+/// that the steps of an editor's save end up in one batch.
+const FILE_EVENT_QUIET_PERIOD: Duration = Duration::from_millis(50);
+/// Upper bound on how long a batch of file events is held back while events keep arriving.
+const FILE_EVENT_MAX_DELAY: Duration = Duration::from_millis(500);
+
+/// Returns `path` at the same position relative to `new_root` as it has to `old_root`, or `None`
+/// if it is not under `old_root`.
+pub(crate) fn relocated_path(path: &[u8], old_root: &Path, new_root: &Path) -> Option<String> {
+    let path = Path::new(str::from_utf8(path).ok()?);
+    let relative = path.strip_prefix(old_root).ok()?;
+    let relocated = if relative.as_os_str().is_empty() {
+        new_root.to_path_buf()
+    } else {
+        new_root.join(relative)
+    };
+    Some(relocated.to_string_lossy().into_owned())
+}
+
 #[derive(Clone)]
 struct FileTrackerTables {
     /// Contains Path -> SourceFileInfo
@@ -37,10 +57,13 @@ struct FileTrackerTables {
     /// Contains SequenceNum -> DirtyFileInfo
     rename_file_events: lmdb::Database,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub enum FileTrackerEvent {
     Start,
     Update,
+    /// Sent while watched directories are scanned, with the directory that is being scanned and
+    /// the number of files and directories scanned so far
+    ScanProgress(PathBuf, u64),
 }
 pub struct FileTracker {
     db: Arc<Environment>,
@@ -49,7 +72,19 @@ pub struct FileTracker {
     listener_tx: UnboundedSender<UnboundedSender<FileTrackerEvent>>,
     is_running: AtomicBool,
     stopping_event: event_listener::Event,
-    watch_dirs: Vec<PathBuf>,
+    /// Notified when `run` has returned
+    stopped_event: event_listener::Event,
+    watch_dirs: std::sync::Mutex<WatchDirs>,
+    metrics: Arc<DaemonMetrics>,
+    follow_symlinks: bool,
+    scan_at_startup: bool,
+    event_channel_capacity: Option<usize>,
+}
+
+struct WatchDirs {
+    dirs: Vec<PathBuf>,
+    /// Set while `run` is watching the directories
+    handle: Option<watcher::WatchHandle>,
 }
 #[derive(Clone, Debug)]
 pub struct FileState {
@@ -73,6 +108,7 @@ pub struct RenameFileEvent {
     pub dst: PathBuf,
 }
 
+#[derive(Clone)]
 struct ScanContext {
     path: PathBuf,
     files: HashMap<PathBuf, FileMetadata>,
@@ -105,7 +141,7 @@ impl ListenersList {
     }
     fn send_event(&mut self, event: FileTrackerEvent) {
         self.listeners.retain(|listener| {
-            match listener.unbounded_send(event) {
+            match listener.unbounded_send(event.clone()) {
                 Ok(()) => {
                     debug!("Sent to listener");
                     true
@@ -177,6 +213,62 @@ fn build_source_info(
     value_builder
 }
 
+fn decode_error<E: Into<Error>>(table: &'static str, key: &[u8], err: E) -> Error {
+    Error::DbDecode(
+        table,
+        String::from_utf8_lossy(key).into_owned(),
+        Box::new(err.into()),
+    )
+}
+
+/// Logs entries that failed to decode so that a corrupt entry is skipped instead of
+/// stopping iteration over the whole table.
+fn skip_decode_error<T>(result: Result<T>) -> Option<T> {
+    result.map_err(|err| error!("{}", err)).ok()
+}
+
+type EntryValue<'a> = capnp::Result<capnp::message::Reader<capnp::serialize::SliceSegments<'a>>>;
+
+fn parse_rename_event(key: &[u8], val: EntryValue<'_>) -> Result<(u64, RenameFileEvent)> {
+    if key.len() != 8 {
+        return Err(Error::Custom(format!(
+            "invalid sequence number length {}",
+            key.len()
+        )));
+    }
+    let seq_num = u64::from_le_bytes(utils::make_array(key));
+    let val = val?;
+    let evt = val.get_root::<rename_file_event::Reader<'_>>()?;
+    let src = PathBuf::from(str::from_utf8(evt.get_src()?)?);
+    let dst = PathBuf::from(str::from_utf8(evt.get_dst()?)?);
+    Ok((seq_num, RenameFileEvent { src, dst }))
+}
+
+fn parse_dirty_file_state(key: &[u8], val: EntryValue<'_>) -> Result<FileState> {
+    let path = PathBuf::from(str::from_utf8(key)?);
+    let val = val?;
+    let info = val.get_root::<dirty_file_info::Reader<'_>>()?;
+    let source_info = info.get_source_info()?;
+    Ok(FileState {
+        path,
+        state: info.get_state()?,
+        last_modified: source_info.get_last_modified(),
+        length: source_info.get_length(),
+    })
+}
+
+fn parse_source_file_state(key: &[u8], val: EntryValue<'_>) -> Result<FileState> {
+    let path = PathBuf::from(str::from_utf8(key)?);
+    let val = val?;
+    let info = val.get_root::<source_file_info::Reader<'_>>()?;
+    Ok(FileState {
+        path,
+        state: data::FileState::Exists,
+        last_modified: info.get_last_modified(),
+        length: info.get_length(),
+    })
+}
+
 fn update_deleted_dirty_entry<K>(
     txn: &mut RwTransaction<'_>,
     tables: &FileTrackerTables,
@@ -187,10 +279,13 @@ where
 {
     let dirty_value = {
         txn.get::<source_file_info::Owned, K>(tables.source_files, key)?
-            .map(|v| {
-                let info = v.get().expect("failed to get source_file_info");
-                build_dirty_file_info(data::FileState::Deleted, info)
+            .map(|v| -> Result<_> {
+                let info = v
+                    .get()
+                    .map_err(|err| decode_error("source_files", key.as_ref(), err))?;
+                Ok(build_dirty_file_info(data::FileState::Deleted, info))
             })
+            .transpose()?
     };
     if dirty_value.is_some() {
         txn.put(tables.dirty_files, key, &dirty_value.unwrap())?;
@@ -201,6 +296,41 @@ where
 // TODO(happens): Improve error handling for event handlers
 mod events {
     use super::*;
+
+    /// Drops the events of a batch that later events for the same path make redundant: updates
+    /// and removals of a path that a later update or rename writes again. Editors often save by
+    /// writing a temporary file, deleting the target and renaming the temporary file over it, and
+    /// the target should end up dirty as `Exists` rather than being seen as deleted.
+    pub(super) fn coalesce_file_events(events: Vec<FileEvent>) -> Vec<FileEvent> {
+        // whether the next event that touches a path (re)creates it
+        let mut next_is_write = HashMap::new();
+        let mut coalesced = Vec::with_capacity(events.len());
+        for evt in events.into_iter().rev() {
+            match &evt {
+                FileEvent::Updated(path, _) => {
+                    if next_is_write.get(path) == Some(&true) {
+                        continue;
+                    }
+                    next_is_write.insert(path.clone(), true);
+                }
+                FileEvent::Removed(path) => {
+                    if next_is_write.get(path) == Some(&true) {
+                        debug!("coalesced removal of {}", path.to_string_lossy());
+                        continue;
+                    }
+                    next_is_write.insert(path.clone(), false);
+                }
+                FileEvent::Renamed(src, dst, _) => {
+                    next_is_write.insert(src.clone(), false);
+                    next_is_write.insert(dst.clone(), true);
+                }
+                _ => {}
+            }
+            coalesced.push(evt);
+        }
+        coalesced.reverse();
+        coalesced
+    }
     fn handle_update(
         txn: &mut RwTransaction<'_>,
         tables: &FileTrackerTables,
@@ -246,26 +376,26 @@ mod events {
     pub(super) fn handle_file_event(
         txn: &mut RwTransaction<'_>,
         tables: &FileTrackerTables,
-        evt: watcher::FileEvent,
+        evt: &watcher::FileEvent,
         scan_stack: &mut Vec<ScanContext>,
     ) -> Result<Option<FileTrackerEvent>> {
         match evt {
             FileEvent::Updated(path, metadata) => {
-                handle_update(txn, tables, &path, &metadata, scan_stack)?;
+                handle_update(txn, tables, path, metadata, scan_stack)?;
             }
             FileEvent::Renamed(src, dst, metadata) => {
                 if !scan_stack.is_empty() {
                     let head_idx = scan_stack.len() - 1;
                     let scan_ctx = scan_stack.index_mut(head_idx);
                     scan_ctx.files.insert(dst.clone(), metadata.clone());
-                    scan_ctx.files.remove(&src);
+                    scan_ctx.files.remove(src);
                 }
                 let src_str = src.to_string_lossy();
                 let src_key = src_str.as_bytes();
                 let dst_str = dst.to_string_lossy();
                 let dst_key = dst_str.as_bytes();
                 debug!("rename {} to {} metadata {:?}", src_str, dst_str, metadata);
-                let value = build_source_info(&metadata);
+                let value = build_source_info(metadata);
                 txn.delete(tables.source_files, &src_key)?;
                 txn.put(tables.source_files, &dst_key, &value)?;
                 let dirty_value_new = build_dirty_file_info(
@@ -284,7 +414,7 @@ mod events {
                 if !scan_stack.is_empty() {
                     let head_idx = scan_stack.len() - 1;
                     let scan_ctx = scan_stack.index_mut(head_idx);
-                    scan_ctx.files.remove(&path);
+                    scan_ctx.files.remove(path);
                 }
                 let path_str = path.to_string_lossy();
                 let key = path_str.as_bytes();
@@ -294,15 +424,18 @@ mod events {
             }
             FileEvent::FileError(err) => {
                 debug!("file event error: {}", err);
-                return Err(err);
+                return Err(Error::Custom(err.to_string()));
             }
             FileEvent::ScanStart(path) => {
                 debug!("scan start: {}", path.to_string_lossy());
                 scan_stack.push(ScanContext {
-                    path,
+                    path: path.clone(),
                     files: HashMap::new(),
                 });
             }
+            FileEvent::ScanProgress(dir, scanned) => {
+                return Ok(Some(FileTrackerEvent::ScanProgress(dir.clone(), *scanned)));
+            }
             FileEvent::ScanEnd(path, watched_dirs) => {
                 // When we finish a scan, we know which files exist in the subdirectories.
                 // This means we can scan our DB for files we've tracked and delete removed files from DB
@@ -315,8 +448,14 @@ mod events {
                     let cursor = txn
                         .open_ro_cursor(tables.source_files)
                         .expect("Failed to open RO cursor for source_files table");
-                    for (key, _) in cursor.capnp_iter_from(&key) {
-                        let key = str::from_utf8(key).expect("Encoded key was invalid utf8");
+                    for (key_bytes, _) in cursor.capnp_iter_from(&key) {
+                        let key = match str::from_utf8(key_bytes) {
+                            Ok(key) => key,
+                            Err(err) => {
+                                error!("{}", decode_error("source_files", key_bytes, err));
+                                continue;
+                            }
+                        };
                         if !key.starts_with(&path_string) {
                             break;
                         }
@@ -347,16 +486,20 @@ mod events {
                             .expect("Failed to open RO cursor for source_files table");
                         let dirs_as_strings = Vec::from_iter(
                             watched_dirs
-                                .into_iter()
+                                .iter()
                                 .map(|f| f.to_string_lossy().into_owned()),
                         );
                         for iter_result in cursor.iter_start() {
-                            let (key_bytes, _) =
-                                iter_result.expect("Error while iterating source file metadata");
-                            let key =
-                                str::from_utf8(key_bytes).expect("Encoded key was invalid utf8");
-                            if !dirs_as_strings.iter().any(|dir| key.starts_with(dir)) {
-                                to_delete.push(key);
+                            let (key_bytes, _) = iter_result?;
+                            // keys that are not valid paths can never be in a watched directory
+                            match str::from_utf8(key_bytes) {
+                                Ok(key)
+                                    if dirs_as_strings.iter().any(|dir| key.starts_with(dir)) => {}
+                                Ok(_) => to_delete.push(key_bytes),
+                                Err(err) => {
+                                    error!("{}", decode_error("source_files", key_bytes, err));
+                                    to_delete.push(key_bytes);
+                                }
                             }
                         }
                     }
@@ -368,30 +511,80 @@ mod events {
                 debug!("scan end: {}", path.to_string_lossy());
                 return Ok(Some(FileTrackerEvent::Start));
             }
+            FileEvent::Unwatched(path, watched_dirs) => {
+                // The files of the directory are found by their key prefix rather than by
+                // scanning it, as the directory may have changed since it was last scanned.
+                let path_str = path.to_string_lossy();
+                let mut to_delete = Vec::new();
+                {
+                    let cursor = txn
+                        .open_ro_cursor(tables.source_files)
+                        .expect("Failed to open RO cursor for source_files table");
+                    for (key_bytes, _) in cursor.capnp_iter_from(&path_str.as_bytes()) {
+                        if !key_bytes.starts_with(path_str.as_bytes()) {
+                            break;
+                        }
+                        let file_path = match str::from_utf8(key_bytes) {
+                            Ok(key) => Path::new(key),
+                            Err(err) => {
+                                error!("{}", decode_error("source_files", key_bytes, err));
+                                continue;
+                            }
+                        };
+                        // files in a nested directory that is still watched are kept
+                        if file_path.starts_with(path)
+                            && !watched_dirs.iter().any(|dir| file_path.starts_with(dir))
+                        {
+                            to_delete.push(key_bytes.to_vec());
+                        }
+                    }
+                }
+                debug!("unwatched {}, removed {} files", path_str, to_delete.len());
+                for key in to_delete {
+                    update_deleted_dirty_entry(txn, &tables, &key)?;
+                    txn.delete(tables.source_files, &key)?;
+                }
+            }
         }
         Ok(None)
     }
 }
 
+fn watch_dir_path(path: &Path) -> PathBuf {
+    let path = if path.is_relative() {
+        std::env::current_dir()
+            .expect("failed to get current dir")
+            .join(path)
+    } else {
+        path.to_path_buf()
+    };
+    // a symlinked directory is watched at its target, as some platforms report events there
+    let path = fs::canonicalize(&path).unwrap_or(path);
+    watcher::canonicalize_path(&path)
+}
+
 impl FileTracker {
     pub fn new<'a, I, T>(db: Arc<Environment>, to_watch: I) -> FileTracker
+    where
+        I: IntoIterator<Item = &'a str, IntoIter = T>,
+        T: Iterator<Item = &'a str>,
+    {
+        Self::new_with_metrics(db, to_watch, Arc::new(DaemonMetrics::default()))
+    }
+
+    /// Creates a tracker that counts its files in `metrics`.
+    pub fn new_with_metrics<'a, I, T>(
+        db: Arc<Environment>,
+        to_watch: I,
+        metrics: Arc<DaemonMetrics>,
+    ) -> FileTracker
     where
         I: IntoIterator<Item = &'a str, IntoIter = T>,
         T: Iterator<Item = &'a str>,
     {
         let watch_dirs: Vec<PathBuf> = to_watch
             .into_iter()
-            .map(|s| {
-                let path = PathBuf::from(s);
-                let path = if path.is_relative() {
-                    std::env::current_dir()
-                        .expect("failed to get current dir")
-                        .join(path)
-                } else {
-                    path
-                };
-                watcher::canonicalize_path(&path)
-            })
+            .map(|s| watch_dir_path(Path::new(s)))
             .collect();
 
         let source_files = db
@@ -411,6 +604,7 @@ impl FileTracker {
         FileTracker {
             is_running: AtomicBool::new(false),
             stopping_event: Event::new(),
+            stopped_event: Event::new(),
             tables: FileTrackerTables {
                 source_files,
                 dirty_files,
@@ -419,14 +613,83 @@ impl FileTracker {
             db,
             listener_rx: Mutex::new(Cell::new(listener_rx)),
             listener_tx,
-            watch_dirs,
+            watch_dirs: std::sync::Mutex::new(WatchDirs {
+                dirs: watch_dirs,
+                handle: None,
+            }),
+            metrics,
+            follow_symlinks: true,
+            scan_at_startup: true,
+            event_channel_capacity: None,
         }
     }
 
-    pub fn get_watch_dirs(&self) -> impl Iterator<Item = &'_ PathBuf> {
-        self.watch_dirs.iter()
+    /// Sets whether symlinks to directories inside the watched directories are followed, which
+    /// they are by default. Symlinks that lead back into a watched directory are not followed.
+    /// Watched directories that are symlinks themselves are always resolved to their target.
+    pub fn with_follow_symlinks(mut self, follow_symlinks: bool) -> Self {
+        self.follow_symlinks = follow_symlinks;
+        self
+    }
+
+    /// Sets whether the watched directories are scanned when the tracker starts, which they are
+    /// by default. Without the scan, the tracker relies on the files recorded by a previous run
+    /// and only sees changes reported by the file system while it runs: files that changed while
+    /// the tracker was stopped are not noticed until they change again. Listeners receive
+    /// [`FileTrackerEvent::Start`] as soon as they are registered instead of after the scan.
+    pub fn with_scan_at_startup(mut self, scan_at_startup: bool) -> Self {
+        self.scan_at_startup = scan_at_startup;
+        self
+    }
+
+    /// Bounds the channel of file events from the watcher thread. While the channel is full the
+    /// watcher waits for the tracker to catch up, so a burst of file changes cannot grow the
+    /// queue without limit. Events are never dropped. The channel is unbounded by default.
+    pub fn with_event_channel_capacity(mut self, capacity: usize) -> Self {
+        self.event_channel_capacity = Some(capacity);
+        self
+    }
+
+    pub fn metrics(&self) -> &Arc<DaemonMetrics> {
+        &self.metrics
+    }
+
+    pub fn get_watch_dirs(&self) -> Vec<PathBuf> {
+        self.watch_dirs.lock().unwrap().dirs.clone()
     }
 
+    /// Starts tracking the files in `path`. If the tracker is running, the directory is scanned
+    /// right away and listeners are notified of its files. Returns false if it was already watched.
+    pub fn add_watch_dir(&self, path: &Path) -> bool {
+        let path = watch_dir_path(path);
+        let mut watch_dirs = self.watch_dirs.lock().unwrap();
+        if watch_dirs.dirs.contains(&path) {
+            return false;
+        }
+        if let Some(handle) = &watch_dirs.handle {
+            handle.watch(path.clone());
+        }
+        watch_dirs.dirs.push(path);
+        true
+    }
+
+    /// Stops tracking the files in `path` and removes them from the tracked source files, as if
+    /// they were deleted. Returns false if the directory was not watched.
+    pub fn remove_watch_dir(&self, path: &Path) -> bool {
+        let path = watch_dir_path(path);
+        let mut watch_dirs = self.watch_dirs.lock().unwrap();
+        let len = watch_dirs.dirs.len();
+        watch_dirs.dirs.retain(|dir| *dir != path);
+        if watch_dirs.dirs.len() == len {
+            return false;
+        }
+        if let Some(handle) = &watch_dirs.handle {
+            handle.unwatch(path);
+        }
+        true
+    }
+
+    #[cfg(test)]
     pub async fn get_rw_txn(&self) -> RwTransaction<'_> {
         self.db.rw_txn().await.expect("db: Failed to open rw txn")
     }
@@ -445,29 +708,36 @@ impl FileTracker {
             .expect("db: Failed to open ro cursor for rename_file_events table")
             .capnp_iter_start()
             .filter_map(|(key, val)| {
-                let val = val.ok()?;
-                let evt = val.into_typed::<rename_file_event::Owned>();
-                let evt = evt.get().ok()?;
-                let seq_num = u64::from_le_bytes(utils::make_array(key));
-
-                let src_raw = evt.get_src().ok()?;
-                let src = PathBuf::from(str::from_utf8(src_raw).ok()?);
-
-                let dst_raw = evt.get_dst().ok()?;
-                let dst = PathBuf::from(str::from_utf8(dst_raw).ok()?);
-
-                Some((seq_num, RenameFileEvent { src, dst }))
+                skip_decode_error(
+                    parse_rename_event(key, val)
+                        .map_err(|err| decode_error("rename_file_events", key, err)),
+                )
             })
             .collect()
     }
 
-    pub fn clear_rename_events(&self, txn: &mut RwTransaction<'_>) {
+    pub fn clear_rename_events(&self, txn: &mut RwTransaction<'_>) -> Result<()> {
         txn.clear_db(self.tables.rename_file_events)
-            .expect("db: Failed to clear rename_file_events table");
     }
 
-    pub async fn add_dirty_file(&self, txn: &mut RwTransaction<'_>, path: &PathBuf) -> Result<()> {
-        let metadata = match tokio::fs::metadata(path).await {
+    /// Moves the tracked and dirty files under `old_root` to the same relative paths under
+    /// `new_root`. Returns the number of moved tracked files.
+    pub fn relocate_root(
+        &self,
+        txn: &mut RwTransaction<'_>,
+        old_root: &Path,
+        new_root: &Path,
+    ) -> Result<usize> {
+        let relocate = |key: &[u8], value: &[u8]| {
+            relocated_path(key, old_root, new_root).map(|path| (path.into_bytes(), value.to_vec()))
+        };
+        let relocated = txn.rewrite_entries(self.tables.source_files, relocate)?;
+        txn.rewrite_entries(self.tables.dirty_files, relocate)?;
+        Ok(relocated)
+    }
+
+    pub fn add_dirty_file(&self, txn: &mut RwTransaction<'_>, path: &PathBuf) -> Result<()> {
+        let metadata = match fs::metadata(path) {
             Err(ref e) if e.kind() == std::io::ErrorKind::NotFound => None,
             Err(e) => return Err(Error::IO(e)),
             Ok(metadata) => Some(watcher::file_metadata(&metadata)),
@@ -500,56 +770,100 @@ impl FileTracker {
             .expect("db: Failed to open ro cursor for dirty_files table")
             .capnp_iter_start()
             .filter_map(|(key, val)| {
-                // TODO(happens): Do we want logging on why things are skipped here?
-                // We could map to Result<_> first, and then log any errors in a filter_map
-                // that just calls ok() afterwards.
-                let key = str::from_utf8(key).expect("utf8: Failed to parse file path");
-                let val = val.expect("capnp: Failed to get value in iterator");
-                let info = val.get_root::<dirty_file_info::Reader<'_>>().ok()?;
-                let source_info = info
-                    .get_source_info()
-                    .expect("capnp: Failed to get source info");
-
-                Some(FileState {
-                    path: PathBuf::from(key),
-                    state: info.get_state().ok()?,
-                    last_modified: source_info.get_last_modified(),
-                    length: source_info.get_length(),
-                })
+                skip_decode_error(
+                    parse_dirty_file_state(key, val)
+                        .map_err(|err| decode_error("dirty_files", key, err)),
+                )
             })
             .collect()
     }
 
-    pub fn read_all_files(&self, iter_txn: &RoTransaction<'_>) -> Vec<FileState> {
+    pub fn read_all_files<'a, V: DBTransaction<'a, T>, T: lmdb::Transaction + 'a>(
+        &self,
+        iter_txn: &'a V,
+    ) -> Vec<FileState> {
         iter_txn
             .open_ro_cursor(self.tables.source_files)
             .expect("db: Failed to open ro cursor for source_files table")
             .capnp_iter_start()
             .filter_map(|(key, val)| {
-                let key = str::from_utf8(key).expect("utf8: Failed to parse file path");
-                let val = val.expect("capnp: Failed to get value in iterator");
-                let info = val.get_root::<source_file_info::Reader<'_>>().ok()?;
-
-                Some(FileState {
-                    path: PathBuf::from(key),
-                    state: data::FileState::Exists,
-                    last_modified: info.get_last_modified(),
-                    length: info.get_length(),
-                })
+                skip_decode_error(
+                    parse_source_file_state(key, val)
+                        .map_err(|err| decode_error("source_files", key, err)),
+                )
             })
             .collect()
     }
 
+    /// Compares the watched directories with the tracked files without writing to the database.
+    /// Returns the files that are still dirty, and the files that `run` would mark as dirty
+    /// because they were created, changed or deleted while the tracker was not running.
+    pub fn scan_changes(&self, iter_txn: &RoTransaction<'_>) -> Result<Vec<FileState>> {
+        let mut changes: HashMap<PathBuf, FileState> = self
+            .read_dirty_files(iter_txn)
+            .into_iter()
+            .map(|state| (state.path.clone(), state))
+            .collect();
+        let mut tracked: HashMap<PathBuf, FileState> = self
+            .read_all_files(iter_txn)
+            .into_iter()
+            .map(|state| (state.path.clone(), state))
+            .collect();
+
+        let mut dirs = self.get_watch_dirs();
+        while let Some(dir) = dirs.pop() {
+            let entries = match fs::read_dir(&dir) {
+                Err(ref e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(Error::IO(e)),
+                Ok(entries) => entries,
+            };
+            for entry in entries {
+                let entry = entry?;
+                let metadata = match entry.metadata() {
+                    Err(ref e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                    Err(e) => return Err(Error::IO(e)),
+                    Ok(metadata) => watcher::file_metadata(&metadata),
+                };
+                let path = watcher::canonicalize_path(&entry.path());
+                if metadata.file_type.is_dir() {
+                    dirs.push(path.clone());
+                }
+                let unchanged = tracked.remove(&path).map_or(false, |state| {
+                    state.length == metadata.length && state.last_modified == metadata.last_modified
+                });
+                if !unchanged {
+                    changes.entry(path.clone()).or_insert(FileState {
+                        path,
+                        state: data::FileState::Exists,
+                        last_modified: metadata.last_modified,
+                        length: metadata.length,
+                    });
+                }
+            }
+        }
+
+        // tracked files that were not found on disk have been deleted
+        for (path, state) in tracked {
+            changes.entry(path).or_insert(FileState {
+                state: data::FileState::Deleted,
+                ..state
+            });
+        }
+
+        let mut changes: Vec<FileState> = changes.into_iter().map(|(_, state)| state).collect();
+        changes.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(changes)
+    }
+
     pub fn delete_dirty_file_state<'a>(
         &self,
         txn: &'a mut RwTransaction<'_>,
         path: &PathBuf,
-    ) -> bool {
+    ) -> Result<bool> {
         let key_str = path.to_string_lossy();
         let key = key_str.as_bytes();
 
         txn.delete(self.tables.dirty_files, &key)
-            .expect("db: Failed to delete entry from dirty_files table")
     }
 
     #[allow(dead_code)] // used for tests
@@ -563,19 +877,19 @@ impl FileTracker {
 
         txn.get::<dirty_file_info::Owned, &[u8]>(self.tables.dirty_files, &key)
             .expect("db: Failed to get entry from dirty_files table")
-            .map(|value| {
-                let value = value.get().expect("capnp: Failed to get dirty file info");
-
+            .and_then(|value| {
                 let info = value
-                    .get_source_info()
-                    .expect("capnp: Failed to get source info");
+                    .get()
+                    .and_then(|value| value.get_source_info())
+                    .map_err(|err| decode_error("dirty_files", key, err));
+                let info = skip_decode_error(info)?;
 
-                FileState {
+                Some(FileState {
                     path: path.clone(),
                     state: data::FileState::Exists,
                     last_modified: info.get_last_modified(),
                     length: info.get_length(),
-                }
+                })
             })
     }
 
@@ -589,15 +903,18 @@ impl FileTracker {
 
         txn.get::<source_file_info::Owned, &[u8]>(self.tables.source_files, &key)
             .expect("db: Failed to get entry from source_files table")
-            .map(|value| {
-                let info = value.get().expect("capnp: Failed to get source file info");
+            .and_then(|value| {
+                let info = value
+                    .get()
+                    .map_err(|err| decode_error("source_files", key, err));
+                let info = skip_decode_error(info)?;
 
-                FileState {
+                Some(FileState {
                     path: path.clone(),
                     state: data::FileState::Exists,
                     last_modified: info.get_last_modified(),
                     length: info.get_length(),
-                }
+                })
             })
     }
 
@@ -607,12 +924,13 @@ impl FileTracker {
             .expect("Failed registering listener")
     }
 
-    #[allow(dead_code)]
+    /// Stops a running tracker and waits until `run` has returned.
     pub async fn stop(&self) {
+        // listen before checking the flag so that the notification at the end of `run` isn't missed
+        let stopped = self.stopped_event.listen();
         if self.is_running() {
             self.stopping_event.notify(std::usize::MAX);
-            self.listener_rx.lock().await;
-            assert_eq!(false, self.is_running.load(Ordering::Acquire));
+            stopped.await;
         }
     }
 
@@ -620,29 +938,50 @@ impl FileTracker {
         self.is_running.load(Ordering::Acquire)
     }
 
-    pub async fn run(&self) {
+    /// Watches the directories and records file changes until `stop` is called.
+    /// Returns `false` without doing anything if the tracker is already running.
+    pub async fn run(&self) -> bool {
         let stopping = self.stopping_event.listen().fuse();
 
-        let already_running = self
+        if self
             .is_running
-            .compare_and_swap(false, true, Ordering::AcqRel);
-
-        if already_running {
-            return;
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            return false;
         }
 
-        let (watcher_tx, mut watcher_rx) = unbounded();
-        let to_watch = self
-            .watch_dirs
-            .iter()
-            .map(|p| p.to_str().expect("Invalid path"));
-
-        // NOTE(happens): If we can't watch the dir, we want to abort
-        let mut watcher = watcher::DirWatcher::from_path_iter(to_watch, watcher_tx)
-            .expect("watcher: Failed to watch specified path");
+        let (watcher_tx, watcher_rx): (watcher::FileEventSender, BoxStream<'_, FileEvent>) =
+            match self.event_channel_capacity {
+                Some(capacity) => {
+                    let (tx, rx) = channel(capacity);
+                    (tx.into(), rx.boxed())
+                }
+                None => {
+                    let (tx, rx) = unbounded();
+                    (tx.into(), rx.boxed())
+                }
+            };
+        let mut watcher_rx = watcher_rx.fuse();
+        let mut watcher = {
+            // locked until the handle is set, so directories added meanwhile aren't missed
+            let mut watch_dirs = self.watch_dirs.lock().unwrap();
+            let to_watch = watch_dirs
+                .dirs
+                .iter()
+                .map(|p| p.to_str().expect("Invalid path"));
+
+            // NOTE(happens): If we can't watch the dir, we want to abort
+            let watcher = watcher::DirWatcher::from_path_iter(to_watch, watcher_tx)
+                .expect("watcher: Failed to watch specified path")
+                .with_follow_symlinks(self.follow_symlinks)
+                .with_scan_at_startup(self.scan_at_startup);
+            watch_dirs.handle = Some(watcher.watch_handle());
+            watcher
+        };
 
         let stop_handle = watcher.stop_handle();
-        thread::spawn(move || watcher.run());
+        let watcher_thread = thread::spawn(move || watcher.run());
 
         let mut listeners = ListenersList::new();
         let mut scan_stack = Vec::new();
@@ -655,32 +994,75 @@ impl FileTracker {
 
         loop {
             select! {
-                new_listener = listener_tx.next() => listeners.register(new_listener),
-                _ = update_debounce => listeners.send_event(FileTrackerEvent::Update),
-                mut maybe_file_event = watcher_rx.next() => {
-                    if maybe_file_event.is_none() {
-                        debug!("FileTracker: stopping due to exhausted watcher");
-                        break;
+                new_listener = listener_tx.next() => {
+                    // without a startup scan the recorded files are taken as they are
+                    if !self.scan_at_startup {
+                        if let Some(listener) = &new_listener {
+                            let _ = listener.unbounded_send(FileTrackerEvent::Start);
+                        }
                     }
-
-                    let mut txn = self.get_rw_txn().await;
-                    // batch watcher events into single transaction and update
-                    while let Some(file_event) = maybe_file_event {
-                        match events::handle_file_event(&mut txn, &self.tables, file_event, &mut scan_stack) {
-                            Ok(Some(evt)) => listeners.send_event(evt),
-                            Ok(None) => {},
-                            Err(err) => panic!("Error while handling file event: {}", err),
+                    listeners.register(new_listener)
+                }
+                _ = update_debounce => listeners.send_event(FileTrackerEvent::Update),
+                maybe_file_event = watcher_rx.next() => {
+                    let mut file_events = match maybe_file_event {
+                        Some(file_event) => vec![file_event],
+                        None => {
+                            debug!("FileTracker: stopping due to exhausted watcher");
+                            break;
                         }
+                    };
 
+                    // wait until events stop arriving, so a save that touches a path several
+                    // times is recorded as its net effect
+                    let mut max_delay = time::delay_for(FILE_EVENT_MAX_DELAY).fuse();
+                    loop {
+                        let mut quiet_period = time::delay_for(FILE_EVENT_QUIET_PERIOD).fuse();
                         select! {
-                            next_file_event = watcher_rx.next() => maybe_file_event = next_file_event,
-                            default => maybe_file_event = None,
+                            next_file_event = watcher_rx.next() => match next_file_event {
+                                Some(file_event) => file_events.push(file_event),
+                                None => break,
+                            },
+                            _ = quiet_period => break,
+                            _ = max_delay => break,
                         }
                     }
 
-                    if txn.dirty {
-                        txn.commit().expect("Failed to commit");
-                        update_debounce = time::delay_for(Duration::from_millis(50)).fuse();
+                    let file_events = events::coalesce_file_events(file_events);
+                    // a retry after growing the map replays the batch from the same scan state
+                    let scan_stack_before = scan_stack.clone();
+                    let mut tracker_events = Vec::new();
+                    let mut retry = false;
+                    // batch watcher events into single transaction and update
+                    let result = self.db.write(|txn| {
+                        if retry {
+                            scan_stack.clone_from(&scan_stack_before);
+                            tracker_events.clear();
+                        }
+                        retry = true;
+                        for file_event in &file_events {
+                            if let Some(evt) = events::handle_file_event(txn, &self.tables, file_event, &mut scan_stack)? {
+                                tracker_events.push(evt);
+                            }
+                        }
+                        if txn.dirty {
+                            match txn.entries(self.tables.source_files) {
+                                Ok(files) => self.metrics.set_files_tracked(files as u64),
+                                Err(err) => error!("Failed to count tracked files: {}", err),
+                            }
+                        }
+                        Ok(txn.dirty)
+                    }).await;
+                    match result {
+                        Ok(dirty) => {
+                            for evt in tracker_events {
+                                listeners.send_event(evt);
+                            }
+                            if dirty {
+                                update_debounce = time::delay_for(Duration::from_millis(50)).fuse();
+                            }
+                        }
+                        Err(err) => panic!("Error while handling file event: {}", err),
                     }
                 }
                 _ = stopping => {
@@ -693,9 +1075,19 @@ impl FileTracker {
         if !update_debounce.is_terminated() {
             listeners.send_event(FileTrackerEvent::Update);
         }
+        // listeners that registered since the last event are dropped too, so they see the stop
+        while let Ok(Some(_)) = listener_tx.try_next() {}
 
+        self.watch_dirs.lock().unwrap().handle = None;
         drop(stop_handle);
+        // unblocks the watcher if it is waiting for room in a bounded channel
+        drop(watcher_rx);
+        if watcher_thread.join().is_err() {
+            error!("FileTracker: watcher thread panicked");
+        }
         self.is_running.store(false, Ordering::Release);
+        self.stopped_event.notify(std::usize::MAX);
+        true
     }
 }
 
@@ -717,15 +1109,36 @@ pub mod tests {
     where
         T: Future<Output = ()>,
         F: FnOnce(Arc<FileTracker>, UnboundedReceiver<FileTrackerEvent>, PathBuf) -> T,
+    {
+        let asset_dir = tempfile::tempdir().unwrap();
+        with_tracker_watching(asset_dir.into_path(), true, f);
+    }
+
+    fn with_tracker_watching<F, T>(asset_dir: PathBuf, follow_symlinks: bool, f: F)
+    where
+        T: Future<Output = ()>,
+        F: FnOnce(Arc<FileTracker>, UnboundedReceiver<FileTrackerEvent>, PathBuf) -> T,
+    {
+        with_configured_tracker(
+            asset_dir,
+            |tracker| tracker.with_follow_symlinks(follow_symlinks),
+            f,
+        );
+    }
+
+    fn with_configured_tracker<C, F, T>(asset_dir: PathBuf, configure: C, f: F)
+    where
+        T: Future<Output = ()>,
+        C: FnOnce(FileTracker) -> FileTracker,
+        F: FnOnce(Arc<FileTracker>, UnboundedReceiver<FileTrackerEvent>, PathBuf) -> T,
     {
         let mut runtime = tokio::runtime::Runtime::new().unwrap();
         let local = tokio::task::LocalSet::new();
 
         let db_dir = tempfile::tempdir().unwrap();
-        let asset_dir = tempfile::tempdir().unwrap();
         {
             let _ = fs::create_dir(db_dir.path());
-            let asset_paths = vec![asset_dir.path().to_str().unwrap()];
+            let asset_paths = vec![asset_dir.to_str().unwrap()];
             let db = Arc::new(
                 Environment::with_map_size(db_dir.path(), 1 << 21).expect(
                     format!(
@@ -735,18 +1148,20 @@ pub mod tests {
                     .as_str(),
                 ),
             );
-            let tracker = Arc::new(FileTracker::new(db, asset_paths));
+            let tracker = Arc::new(configure(FileTracker::new(db, asset_paths)));
             let (tx, mut rx) = unbounded();
             tracker.register_listener(tx);
 
             runtime.block_on(local.run_until(async move {
                 let handle = tokio::task::spawn_local({
                     let tracker = tracker.clone();
-                    async move { tracker.run().await }
+                    async move {
+                        tracker.run().await;
+                    }
                 });
                 expect_event(&mut rx).await;
 
-                f(tracker.clone(), rx, asset_dir.into_path()).await;
+                f(tracker.clone(), rx, asset_dir).await;
 
                 tracker.stop().await;
                 handle.await.unwrap();
@@ -754,6 +1169,10 @@ pub mod tests {
         }
     }
 
+    pub fn tracker_db(tracker: &FileTracker) -> Arc<Environment> {
+        tracker.db.clone()
+    }
+
     async fn expect_no_event(rx: &mut UnboundedReceiver<FileTrackerEvent>) {
         let mut timeout = time::delay_for(Duration::from_millis(1000)).fuse();
         select! {
@@ -841,8 +1260,186 @@ pub mod tests {
     async fn clear_dirty_file_state(t: &FileTracker) {
         let mut txn = t.get_rw_txn().await;
         for f in t.read_dirty_files(&txn) {
-            t.delete_dirty_file_state(&mut txn, &f.path);
+            t.delete_dirty_file_state(&mut txn, &f.path).unwrap();
+        }
+    }
+
+    #[test]
+    fn concurrent_run_only_runs_once() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let asset_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 21).unwrap());
+        let tracker = FileTracker::new(db, vec![asset_dir.path().to_str().unwrap()]);
+
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let (first, second) = futures_util::future::join(tracker.run(), async {
+                let second = tracker.run().await;
+                tracker.stop().await;
+                second
+            })
+            .await;
+            assert!(first);
+            assert!(!second);
+            assert!(!tracker.is_running());
+        });
+    }
+
+    #[test]
+    fn stop_right_after_run_starts_waits_for_run() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let asset_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 21).unwrap());
+        let tracker = Arc::new(FileTracker::new(
+            db,
+            vec![asset_dir.path().to_str().unwrap()],
+        ));
+
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let local = tokio::task::LocalSet::new();
+        runtime.block_on(local.run_until(async {
+            let handle = tokio::task::spawn_local({
+                let tracker = tracker.clone();
+                async move { tracker.run().await }
+            });
+            while !tracker.is_running() {
+                tokio::task::yield_now().await;
+            }
+            tracker.stop().await;
+            assert!(!tracker.is_running());
+            assert!(handle.await.unwrap());
+        }));
+    }
+
+    #[test]
+    fn bounded_event_channel_does_not_drop_events() {
+        const NUM_FILES: usize = 200;
+        let db_dir = tempfile::tempdir().unwrap();
+        let asset_dir = tempfile::tempdir().unwrap();
+        for i in 0..NUM_FILES {
+            fs::write(asset_dir.path().join(format!("{}.txt", i)), "test").unwrap();
         }
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 21).unwrap());
+        let tracker = Arc::new(
+            FileTracker::new(db, vec![asset_dir.path().to_str().unwrap()])
+                .with_event_channel_capacity(4),
+        );
+        let (tx, mut rx) = unbounded();
+        tracker.register_listener(tx);
+
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let local = tokio::task::LocalSet::new();
+        runtime.block_on(local.run_until(async {
+            let handle = tokio::task::spawn_local({
+                let tracker = tracker.clone();
+                async move { tracker.run().await }
+            });
+            // the scan is spread over several batches while the watcher waits for room
+            loop {
+                expect_event(&mut rx).await;
+                let txn = tracker.get_ro_txn().await;
+                if tracker.read_all_files(&txn).len() >= NUM_FILES {
+                    break;
+                }
+            }
+            for i in 0..NUM_FILES {
+                expect_file_state(&tracker, asset_dir.path(), &format!("{}.txt", i)).await;
+            }
+            tracker.stop().await;
+            assert!(handle.await.unwrap());
+        }));
+    }
+
+    #[test]
+    fn read_dirty_files_skips_malformed_entries() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let asset_dir = tempfile::tempdir().unwrap();
+        let db = Arc::new(Environment::with_map_size(db_dir.path(), 1 << 21).unwrap());
+        let tracker = FileTracker::new(db, vec![asset_dir.path().to_str().unwrap()]);
+        let file_path = asset_dir.path().join("test.txt");
+        fs::write(&file_path, "test").unwrap();
+
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let mut txn = tracker.get_rw_txn().await;
+            // a value that is not a capnp message and a key that is not a valid path
+            txn.put_bytes(tracker.tables.dirty_files, b"corrupt", &[0xffu8; 3])
+                .unwrap();
+            txn.put_bytes(tracker.tables.dirty_files, &[0xffu8, 0xfe], &[0u8; 16])
+                .unwrap();
+            tracker.add_dirty_file(&mut txn, &file_path).unwrap();
+            txn.commit().unwrap();
+
+            let txn = tracker.get_ro_txn().await;
+            let dirty_files = tracker.read_dirty_files(&txn);
+            assert_eq!(1, dirty_files.len());
+            assert_eq!(file_path, dirty_files[0].path);
+        });
+    }
+
+    #[test]
+    fn test_add_watch_dir() {
+        with_tracker(|t, mut rx, _| async move {
+            let new_dir = tempfile::tempdir().unwrap();
+            add_test_file(new_dir.path(), "test.txt").await;
+            assert!(t.add_watch_dir(new_dir.path()));
+            assert!(!t.add_watch_dir(new_dir.path()));
+            expect_event(&mut rx).await;
+            expect_file_state(&t, new_dir.path(), "test.txt").await;
+            expect_dirty_file_state(&t, new_dir.path(), "test.txt").await;
+            assert_eq!(2, t.get_watch_dirs().len());
+
+            // changes after the directory was added are tracked too
+            add_test_dir(new_dir.path(), "testdir").await;
+            expect_event(&mut rx).await;
+            expect_file_state(&t, new_dir.path(), "testdir").await;
+        });
+    }
+
+    #[test]
+    fn test_remove_watch_dir() {
+        with_tracker(|t, mut rx, asset_dir| async move {
+            add_test_file(&asset_dir, "test.txt").await;
+            expect_event(&mut rx).await;
+            let new_dir = tempfile::tempdir().unwrap();
+            add_test_file(new_dir.path(), "test.txt").await;
+            t.add_watch_dir(new_dir.path());
+            expect_event(&mut rx).await;
+            expect_file_state(&t, new_dir.path(), "test.txt").await;
+            clear_dirty_file_state(&t).await;
+
+            assert!(t.remove_watch_dir(new_dir.path()));
+            assert!(!t.remove_watch_dir(new_dir.path()));
+            expect_event(&mut rx).await;
+            expect_no_file_state(&t, new_dir.path(), "test.txt").await;
+            expect_dirty_file_state(&t, new_dir.path(), "test.txt").await;
+            expect_file_state(&t, &asset_dir, "test.txt").await;
+            assert_eq!(
+                vec![watcher::canonicalize_path(&asset_dir)],
+                t.get_watch_dirs()
+            );
+        });
+    }
+
+    #[test]
+    fn test_remove_watch_dir_keeps_sibling_with_same_prefix() {
+        with_tracker(|t, mut rx, _| async move {
+            let parent = tempfile::tempdir().unwrap();
+            let removed_dir = parent.path().join("dir");
+            let kept_dir = parent.path().join("dir2");
+            for dir in &[&removed_dir, &kept_dir] {
+                fs::create_dir(dir).unwrap();
+                add_test_file(dir, "test.txt").await;
+                t.add_watch_dir(dir);
+                expect_event(&mut rx).await;
+                expect_file_state(&t, dir, "test.txt").await;
+            }
+
+            assert!(t.remove_watch_dir(&removed_dir));
+            expect_event(&mut rx).await;
+            expect_no_file_state(&t, &removed_dir, "test.txt").await;
+            expect_file_state(&t, &kept_dir, "test.txt").await;
+        });
     }
 
     #[test]
@@ -888,6 +1485,190 @@ pub mod tests {
         })
     }
 
+    #[test]
+    fn test_save_by_replacing_file() {
+        with_tracker(|t, mut rx, asset_dir| async move {
+            add_test_file(&asset_dir, "test.txt").await;
+            expect_event(&mut rx).await;
+            clear_dirty_file_state(&t).await;
+            // write a temporary file, delete the target and move the temporary file over it
+            tokio::fs::write(asset_dir.join("test.txt.tmp"), "saved")
+                .await
+                .expect("write temp file");
+            delete_test_file(&asset_dir, "test.txt").await;
+            tokio::fs::rename(asset_dir.join("test.txt.tmp"), asset_dir.join("test.txt"))
+                .await
+                .expect("rename temp file");
+            expect_event(&mut rx).await;
+            expect_no_event(&mut rx).await;
+            expect_file_state(&t, &asset_dir, "test.txt").await;
+            expect_no_file_state(&t, &asset_dir, "test.txt.tmp").await;
+            let txn = t.get_ro_txn().await;
+            let path = watcher::canonicalize_path(&asset_dir.join("test.txt"));
+            let dirty_state = t.get_dirty_file_state(&txn, &path).unwrap();
+            assert!(dirty_state.state == data::FileState::Exists);
+            assert_eq!(5, dirty_state.length);
+        })
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlinked_watch_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let real_dir = dir.path().join("real");
+        fs::create_dir(&real_dir).unwrap();
+        let link = dir.path().join("link");
+        std::os::unix::fs::symlink(&real_dir, &link).unwrap();
+        with_tracker_watching(link, false, |t, mut rx, asset_dir| async move {
+            add_test_file(&asset_dir, "test.txt").await;
+            expect_event(&mut rx).await;
+            let real_dir = watcher::canonicalize_path(&fs::canonicalize(&asset_dir).unwrap());
+            assert_eq!(vec![real_dir.clone()], t.get_watch_dirs());
+            expect_file_state(&t, &real_dir, "test.txt").await;
+            expect_dirty_file_state(&t, &real_dir, "test.txt").await;
+        });
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlink_cycle() {
+        let asset_dir = tempfile::tempdir().unwrap().into_path();
+        let dir = asset_dir.join("dir");
+        fs::create_dir(&dir).unwrap();
+        fs::copy("tests/file_tracker/test.txt", dir.join("test.txt")).unwrap();
+        std::os::unix::fs::symlink(&asset_dir, dir.join("back")).unwrap();
+        std::os::unix::fs::symlink("../dir", dir.join("self")).unwrap();
+        with_tracker_watching(asset_dir, true, |t, mut rx, asset_dir| async move {
+            // the files found by the scan are recorded once it has finished
+            while let FileTrackerEvent::Start = expect_event(&mut rx).await {}
+            expect_file_state(&t, &asset_dir, "dir/test.txt").await;
+            expect_file_state(&t, &asset_dir, "dir/back").await;
+            expect_file_state(&t, &asset_dir, "dir/self").await;
+            expect_no_file_state(&t, &asset_dir, "dir/back/dir/test.txt").await;
+            expect_no_file_state(&t, &asset_dir, "dir/self/test.txt").await;
+        });
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlinks_are_followed_if_enabled() {
+        for &follow_symlinks in &[true, false] {
+            let other_dir = tempfile::tempdir().unwrap().into_path();
+            fs::copy("tests/file_tracker/test.txt", other_dir.join("test.txt")).unwrap();
+            let asset_dir = tempfile::tempdir().unwrap().into_path();
+            std::os::unix::fs::symlink(&other_dir, asset_dir.join("link")).unwrap();
+            with_tracker_watching(
+                asset_dir,
+                follow_symlinks,
+                |t, mut rx, asset_dir| async move {
+                    // a followed symlink is scanned within the scan of the asset directory
+                    while let FileTrackerEvent::Start = expect_event(&mut rx).await {}
+                    expect_file_state(&t, &asset_dir, "link").await;
+                    if follow_symlinks {
+                        expect_file_state(&t, &other_dir, "test.txt").await;
+                    } else {
+                        expect_no_file_state(&t, &other_dir, "test.txt").await;
+                    }
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn test_scan_reports_progress() {
+        let asset_dir = tempfile::tempdir().unwrap().into_path();
+        let many_dir = asset_dir.join("many");
+        fs::create_dir(&many_dir).unwrap();
+        let files = 3 * watcher::SCAN_PROGRESS_INTERVAL + 10;
+        for i in 0..files {
+            fs::write(many_dir.join(format!("{}.txt", i)), "a").unwrap();
+        }
+        with_tracker_watching(asset_dir, true, |_t, mut rx, asset_dir| async move {
+            // the first event is taken by `with_tracker_watching`, so at least the later progress
+            // events arrive before the scan has finished
+            let mut progress = Vec::new();
+            loop {
+                match expect_event(&mut rx).await {
+                    FileTrackerEvent::ScanProgress(dir, scanned) => progress.push((dir, scanned)),
+                    FileTrackerEvent::Start => break,
+                    FileTrackerEvent::Update => {}
+                }
+            }
+            assert!(
+                progress.len() >= 2,
+                "expected progress events, got {:?}",
+                progress
+            );
+            let many_dir = watcher::canonicalize_path(&asset_dir.join("many"));
+            for (idx, (dir, scanned)) in progress.iter().enumerate() {
+                assert_eq!(&many_dir, dir);
+                assert_eq!(0, scanned % watcher::SCAN_PROGRESS_INTERVAL);
+                assert!(*scanned <= files + 1);
+                if idx > 0 {
+                    assert!(*scanned > progress[idx - 1].1);
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn test_scan_at_startup_can_be_disabled() {
+        let asset_dir = tempfile::tempdir().unwrap().into_path();
+        let many_dir = asset_dir.join("many");
+        fs::create_dir(&many_dir).unwrap();
+        for i in 0..2 * watcher::SCAN_PROGRESS_INTERVAL {
+            fs::write(many_dir.join(format!("{}.txt", i)), "a").unwrap();
+        }
+        with_configured_tracker(
+            asset_dir,
+            |tracker| tracker.with_scan_at_startup(false),
+            |t, mut rx, asset_dir| async move {
+                // `Start` was sent right away, and no scan follows it
+                expect_no_event(&mut rx).await;
+                expect_no_file_state(&t, &asset_dir, "many/0.txt").await;
+
+                // files that change while the tracker runs are still tracked
+                add_test_file(&asset_dir, "test.txt").await;
+                expect_event(&mut rx).await;
+                expect_file_state(&t, &asset_dir, "test.txt").await;
+            },
+        );
+    }
+
+    #[test]
+    fn coalesced_events_keep_net_effect_per_path() {
+        let metadata = || watcher::file_metadata(&fs::metadata(".").unwrap());
+        let target = PathBuf::from("/assets/test.txt");
+        let temp = PathBuf::from("/assets/test.txt.tmp");
+        let events = events::coalesce_file_events(vec![
+            FileEvent::Updated(target.clone(), metadata()),
+            FileEvent::Updated(temp.clone(), metadata()),
+            FileEvent::Removed(target.clone()),
+            FileEvent::Renamed(temp.clone(), target.clone(), metadata()),
+            FileEvent::Removed(temp.clone()),
+        ]);
+        let events = events
+            .iter()
+            .map(|evt| match evt {
+                FileEvent::Updated(path, _) => format!("updated {}", path.display()),
+                FileEvent::Renamed(src, dst, _) => {
+                    format!("renamed {} {}", src.display(), dst.display())
+                }
+                FileEvent::Removed(path) => format!("removed {}", path.display()),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        // the rename writes the target again, so its earlier update and removal are dropped
+        assert_eq!(
+            vec![
+                "updated /assets/test.txt.tmp",
+                "renamed /assets/test.txt.tmp /assets/test.txt",
+                "removed /assets/test.txt.tmp",
+            ],
+            events
+        );
+    }
+
     #[test]
     fn test_create_dir() {
         with_tracker(|t, mut rx, asset_dir| async move {
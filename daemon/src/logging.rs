@@ -0,0 +1,201 @@
+//! The daemon only logs through the `log` facade. Applications that embed the daemon and
+//! already install their own logger do not need to call [`init_logging`] at all, since it is
+//! never called by [`AssetDaemon`](crate::AssetDaemon) itself.
+//!
+//! To keep the daemon's output format as part of an existing setup, install the logger returned
+//! by [`logger`] yourself, for example by chaining it into a `fern::Dispatch`.
+
+use crate::error::Result;
+use std::{
+    io::{self, Write},
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+#[cfg(debug_assertions)]
+const DEFAULT_LOGGING_LEVEL: log::LevelFilter = log::LevelFilter::Debug;
+#[cfg(not(debug_assertions))]
+const DEFAULT_LOGGING_LEVEL: log::LevelFilter = log::LevelFilter::Info;
+
+mod simple_logger {
+    use log::{Level, Metadata, Record};
+
+    pub struct SimpleLogger;
+
+    impl log::Log for SimpleLogger {
+        fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+            metadata.level() <= Level::Info
+        }
+
+        fn log(&self, record: &Record<'_>) {
+            if self.enabled(record.metadata()) {
+                println!("{} - {}", record.level(), record.args());
+            }
+        }
+
+        fn flush(&self) {}
+    }
+}
+
+/// Output format of the daemon's logger.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogFormat {
+    /// `[timestamp][level][target] message` with the `pretty_log` feature,
+    /// `level - message` otherwise.
+    Text,
+    /// One JSON object per line with `timestamp` (milliseconds since the Unix epoch),
+    /// `level`, `target` and `message` fields.
+    Json,
+}
+
+impl Default for LogFormat {
+    fn default() -> Self {
+        LogFormat::Text
+    }
+}
+
+/// Writes each record as a single line JSON object.
+pub struct JsonLogger<W: Write + Send> {
+    level: log::LevelFilter,
+    out: Mutex<W>,
+}
+
+impl<W: Write + Send> JsonLogger<W> {
+    pub fn new(level: log::LevelFilter, out: W) -> Self {
+        Self {
+            level,
+            out: Mutex::new(out),
+        }
+    }
+}
+
+impl<W: Write + Send> log::Log for JsonLogger<W> {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        metadata.level() <= self.level
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        let line = serde_json::json!({
+            "timestamp": timestamp,
+            "level": record.level().to_string(),
+            "target": record.target(),
+            "message": record.args().to_string(),
+        });
+        let mut out = self.out.lock().unwrap();
+        let _ = writeln!(out, "{}", line);
+    }
+
+    fn flush(&self) {
+        let _ = self.out.lock().unwrap().flush();
+    }
+}
+
+/// Returns the daemon's logger in the given format along with its maximum level,
+/// without installing it.
+pub fn logger_with_format(format: LogFormat) -> (log::LevelFilter, Box<dyn log::Log>) {
+    match format {
+        LogFormat::Text => logger(),
+        LogFormat::Json => (
+            DEFAULT_LOGGING_LEVEL,
+            Box::new(JsonLogger::new(DEFAULT_LOGGING_LEVEL, io::stdout())),
+        ),
+    }
+}
+
+/// Returns the daemon's logger along with its maximum level, without installing it.
+#[cfg(not(feature = "pretty_log"))]
+pub fn logger() -> (log::LevelFilter, Box<dyn log::Log>) {
+    (DEFAULT_LOGGING_LEVEL, Box::new(simple_logger::SimpleLogger))
+}
+/// Returns the daemon's logger along with its maximum level, without installing it.
+#[cfg(feature = "pretty_log")]
+pub fn logger() -> (log::LevelFilter, Box<dyn log::Log>) {
+    fern::Dispatch::new()
+        .format(|out, message, record| {
+            out.finish(format_args!(
+                "[{timestamp}][{level}][{target}] {message}",
+                level = record.level(),
+                timestamp = chrono::Local::now().format("%Y-%m-%dT%H:%M:%S%.3f"),
+                target = record.target(),
+                message = message,
+            ))
+        })
+        .chain(std::io::stdout())
+        .level(DEFAULT_LOGGING_LEVEL)
+        .level_for("mio", log::LevelFilter::Info)
+        .level_for("tokio_core", log::LevelFilter::Info)
+        // .chain(fern::log_file("output.log")?)
+        .into_log()
+}
+
+/// Installs the daemon's logger as the global logger.
+///
+/// If the application has already installed a logger, it is left in place and this
+/// does nothing, so output is neither clobbered nor duplicated.
+pub fn init_logging() -> Result<()> {
+    init_logging_with_format(LogFormat::default())
+}
+
+/// Installs the daemon's logger in the given format as the global logger.
+/// See [`init_logging`].
+pub fn init_logging_with_format(format: LogFormat) -> Result<()> {
+    let (level, logger) = logger_with_format(format);
+    if log::set_boxed_logger(logger).is_ok() {
+        log::set_max_level(level);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::Log;
+    use std::sync::Arc;
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn json_logger_writes_one_object_per_line() {
+        let buf = SharedBuf::default();
+        let logger = JsonLogger::new(log::LevelFilter::Info, buf.clone());
+        logger.log(
+            &log::Record::builder()
+                .level(log::Level::Warn)
+                .target("atelier_daemon::test")
+                .args(format_args!("imported {} assets", 3))
+                .build(),
+        );
+        logger.log(
+            &log::Record::builder()
+                .level(log::Level::Debug)
+                .args(format_args!("filtered out"))
+                .build(),
+        );
+
+        let output = String::from_utf8(buf.0.lock().unwrap().clone()).unwrap();
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(1, lines.len());
+        let value: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert!(value["timestamp"].as_u64().unwrap() > 0);
+        assert_eq!("WARN", value["level"]);
+        assert_eq!("atelier_daemon::test", value["target"]);
+        assert_eq!("imported 3 assets", value["message"]);
+    }
+}
@@ -0,0 +1,112 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+/// Upper bounds of the import duration buckets in milliseconds. Imports that take longer than the
+/// last bound are counted in an extra bucket.
+pub const IMPORT_DURATION_BUCKETS_MS: [u64; 8] = [1, 5, 10, 50, 100, 500, 1000, 5000];
+
+/// Counters of the work done by a daemon, which embedders can read while it runs.
+///
+/// Get them from [`AssetDaemon::metrics`](crate::AssetDaemon::metrics) before the daemon is
+/// started. All counters start at zero and only grow, except `files_tracked`.
+#[derive(Debug, Default)]
+pub struct DaemonMetrics {
+    files_tracked: AtomicU64,
+    dirty_files_processed: AtomicU64,
+    imports_succeeded: AtomicU64,
+    imports_failed: AtomicU64,
+    bytes_written: AtomicU64,
+    import_durations: [AtomicU64; IMPORT_DURATION_BUCKETS_MS.len() + 1],
+    /// Duration of the most recent import of each source file
+    source_import_durations: Mutex<HashMap<PathBuf, Duration>>,
+}
+
+impl DaemonMetrics {
+    /// Number of files and directories in the watched directories.
+    pub fn files_tracked(&self) -> u64 {
+        self.files_tracked.load(Ordering::Relaxed)
+    }
+
+    /// Number of changed files that were picked up for importing.
+    pub fn dirty_files_processed(&self) -> u64 {
+        self.dirty_files_processed.load(Ordering::Relaxed)
+    }
+
+    /// Number of source files that were imported.
+    pub fn imports_succeeded(&self) -> u64 {
+        self.imports_succeeded.load(Ordering::Relaxed)
+    }
+
+    /// Number of source files whose import returned an error.
+    pub fn imports_failed(&self) -> u64 {
+        self.imports_failed.load(Ordering::Relaxed)
+    }
+
+    /// Number of artifact bytes written to the artifact cache.
+    pub fn bytes_written(&self) -> u64 {
+        self.bytes_written.load(Ordering::Relaxed)
+    }
+
+    /// Number of imports per duration bucket, see [`IMPORT_DURATION_BUCKETS_MS`].
+    pub fn import_durations(&self) -> Vec<u64> {
+        self.import_durations
+            .iter()
+            .map(|count| count.load(Ordering::Relaxed))
+            .collect()
+    }
+
+    /// Returns the `n` source files whose most recent import took longest, slowest first, with
+    /// the wall-clock duration of that import. Failed imports are included.
+    pub fn slowest_imports(&self, n: usize) -> Vec<(PathBuf, Duration)> {
+        let mut durations: Vec<(PathBuf, Duration)> = self
+            .source_import_durations
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(path, duration)| (path.clone(), *duration))
+            .collect();
+        durations.sort_by(|(a_path, a), (b_path, b)| b.cmp(a).then_with(|| a_path.cmp(b_path)));
+        durations.truncate(n);
+        durations
+    }
+
+    pub(crate) fn set_files_tracked(&self, files: u64) {
+        self.files_tracked.store(files, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_dirty_files_processed(&self, files: u64) {
+        self.dirty_files_processed
+            .fetch_add(files, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_bytes_written(&self, bytes: u64) {
+        self.bytes_written.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_import(&self, path: Option<&Path>, succeeded: bool, duration: Duration) {
+        if let Some(path) = path {
+            self.source_import_durations
+                .lock()
+                .unwrap()
+                .insert(path.to_path_buf(), duration);
+        }
+        if succeeded {
+            self.imports_succeeded.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.imports_failed.fetch_add(1, Ordering::Relaxed);
+        }
+        let millis = duration.as_millis();
+        let bucket = IMPORT_DURATION_BUCKETS_MS
+            .iter()
+            .position(|bound| millis <= u128::from(*bound))
+            .unwrap_or(IMPORT_DURATION_BUCKETS_MS.len());
+        self.import_durations[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+}
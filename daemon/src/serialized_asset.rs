@@ -1,38 +1,270 @@
-use crate::Result;
-use atelier_core::{ArtifactId, AssetRef, AssetTypeId, AssetUuid, CompressionType};
-use atelier_importer::{ArtifactMetadata, SerdeObj, SerializedAsset};
+use crate::{Error, Result};
+use atelier_core::{
+    utils, ArtifactId, AssetRef, AssetTypeId, AssetUuid, CompressionType, SerializationFormat,
+};
+use atelier_importer::{ArtifactMetadata, GeneratedAssetData, SerdeObj, SerializedAsset};
+use std::io::Write;
 
-pub fn create(
-    hash: u64,
+/// A buffer that [`create`] writes artifact data into. Passing in a buffer whose allocation is
+/// reused across artifacts avoids allocating a new one per artifact.
+pub trait ArtifactBuffer: AsRef<[u8]> + Write {
+    /// Removes the contents of the buffer, keeping its allocation.
+    fn clear(&mut self);
+    /// Makes room for at least `additional` more bytes.
+    fn reserve(&mut self, additional: usize);
+}
+
+impl ArtifactBuffer for Vec<u8> {
+    fn clear(&mut self) {
+        Vec::clear(self);
+    }
+    fn reserve(&mut self, additional: usize) {
+        Vec::reserve(self, additional);
+    }
+}
+
+/// Appends `value` serialized in `serialization_format` to `buf`.
+fn serialize<B: ArtifactBuffer>(
+    value: &dyn SerdeObj,
+    serialization_format: SerializationFormat,
+    buf: &mut B,
+) -> Result<()> {
+    if let Some(generated) = value.any().downcast_ref::<GeneratedAssetData>() {
+        // already serialized by the importer
+        buf.write_all(&generated.data)?;
+    } else {
+        match serialization_format {
+            SerializationFormat::Bincode => {
+                buf.reserve(bincode::serialized_size(value)? as usize);
+                bincode::serialize_into(&mut *buf, value)?;
+            }
+            SerializationFormat::Json => serde_json::to_writer(&mut *buf, value)?,
+        }
+    }
+    Ok(())
+}
+
+/// How [`create`] writes an artifact.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ArtifactOptions {
+    pub compression: CompressionType,
+    pub serialization_format: SerializationFormat,
+    /// Version of the asset's data format, see `ImportedAsset::data_version`
+    pub data_version: u32,
+}
+
+/// Serializes `value` into an artifact of the asset `id`. The artifact's id is left at zero until
+/// the import hash is known, see `ArtifactMetadata::update_id`.
+///
+/// Compression is not supported yet, so creating an artifact with `CompressionType::Lz4` fails.
+pub fn create<B: ArtifactBuffer>(
     id: AssetUuid,
     build_deps: Vec<AssetRef>,
     load_deps: Vec<AssetRef>,
     value: &dyn SerdeObj,
-    compression: CompressionType,
-    scratch_buf: &mut Vec<u8>,
-) -> Result<SerializedAsset<Vec<u8>>> {
-    let size = bincode::serialized_size(value)? as usize;
-    scratch_buf.clear();
-    scratch_buf.resize(size, 0);
-    bincode::serialize_into(scratch_buf.as_mut_slice(), value)?;
-    let asset_buf = {
-        match compression {
-            CompressionType::None => scratch_buf.clone(),
-            CompressionType::Lz4 => unimplemented!(),
-        }
-    };
+    options: ArtifactOptions,
+    mut asset_buf: B,
+) -> Result<SerializedAsset<B>> {
+    let ArtifactOptions {
+        compression,
+        serialization_format,
+        data_version,
+    } = options;
+    if let CompressionType::Lz4 = compression {
+        return Err(Error::Custom(
+            "LZ4 compression of artifacts is not supported".to_string(),
+        ));
+    }
+    asset_buf.clear();
+    serialize(value, serialization_format, &mut asset_buf)?;
+    let size = asset_buf.as_ref().len();
 
     Ok(SerializedAsset {
         metadata: ArtifactMetadata {
-            id: ArtifactId(hash),
+            id: ArtifactId(0),
             asset_id: id,
             build_deps,
             load_deps,
             compression,
             uncompressed_size: Some(size as u64),
-            compressed_size: Some(asset_buf.len() as u64),
+            compressed_size: Some(asset_buf.as_ref().len() as u64),
             type_id: AssetTypeId(value.uuid()),
+            content_hash: utils::content_hash(asset_buf.as_ref()),
+            data_version,
+            serialization_format,
+            wide_id: None,
         },
         data: asset_buf,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use atelier_core::TypeUuidDynamic;
+    use serde_derive::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct TestAsset {
+        value: u32,
+    }
+    impl TypeUuidDynamic for TestAsset {
+        fn uuid(&self) -> [u8; 16] {
+            [1; 16]
+        }
+    }
+
+    #[test]
+    fn identical_data_shares_content_hash() {
+        let create_asset = |id: u8, value: u32| {
+            create(
+                AssetUuid([id; 16]),
+                Vec::new(),
+                Vec::new(),
+                &TestAsset { value },
+                ArtifactOptions::default(),
+                Vec::new(),
+            )
+            .unwrap()
+        };
+        let a = create_asset(1, 5);
+        let b = create_asset(2, 5);
+        let c = create_asset(3, 6);
+        assert_ne!(a.metadata.asset_id, b.metadata.asset_id);
+        assert_eq!(a.metadata.content_hash, b.metadata.content_hash);
+        assert_ne!(a.metadata.content_hash, c.metadata.content_hash);
+    }
+
+    #[test]
+    fn json_artifacts_round_trip() {
+        let asset = create(
+            AssetUuid([1; 16]),
+            Vec::new(),
+            Vec::new(),
+            &TestAsset { value: 5 },
+            ArtifactOptions {
+                serialization_format: SerializationFormat::Json,
+                ..Default::default()
+            },
+            Vec::new(),
+        )
+        .unwrap();
+        assert_eq!(
+            asset.metadata.serialization_format,
+            SerializationFormat::Json
+        );
+        assert_eq!(
+            asset.metadata.uncompressed_size,
+            Some(asset.data.len() as u64)
+        );
+        let value: TestAsset = serde_json::from_slice(&asset.data).unwrap();
+        assert_eq!(value, TestAsset { value: 5 });
+    }
+
+    #[test]
+    fn unsupported_compression_is_an_error() {
+        let result = create(
+            AssetUuid([1; 16]),
+            Vec::new(),
+            Vec::new(),
+            &TestAsset { value: 5 },
+            ArtifactOptions {
+                compression: CompressionType::Lz4,
+                ..Default::default()
+            },
+            Vec::new(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn artifacts_have_the_data_version_of_their_options() {
+        let asset = create(
+            AssetUuid([1; 16]),
+            Vec::new(),
+            Vec::new(),
+            &TestAsset { value: 5 },
+            ArtifactOptions {
+                data_version: 3,
+                ..Default::default()
+            },
+            Vec::new(),
+        )
+        .unwrap();
+        assert_eq!(3, asset.metadata.data_version);
+    }
+
+    #[test]
+    fn artifacts_reuse_the_provided_buffer() {
+        let mut asset_buf = Vec::with_capacity(64);
+        for value in 0..3 {
+            let ptr = asset_buf.as_ptr();
+            let asset = create(
+                AssetUuid([1; 16]),
+                Vec::new(),
+                Vec::new(),
+                &TestAsset { value },
+                ArtifactOptions::default(),
+                asset_buf,
+            )
+            .unwrap();
+            assert_eq!(ptr, asset.data.as_ptr());
+            let deserialized: TestAsset = bincode::deserialize(&asset.data).unwrap();
+            assert_eq!(deserialized, TestAsset { value });
+            asset_buf = asset.data;
+        }
+    }
+
+    /// Counts how often the buffer has to grow its allocation.
+    #[derive(Default)]
+    struct CountingBuffer {
+        data: Vec<u8>,
+        allocations: usize,
+    }
+    impl CountingBuffer {
+        fn grow(&mut self, additional: usize) {
+            if self.data.len() + additional > self.data.capacity() {
+                self.allocations += 1;
+            }
+            self.data.reserve(additional);
+        }
+    }
+    impl AsRef<[u8]> for CountingBuffer {
+        fn as_ref(&self) -> &[u8] {
+            &self.data
+        }
+    }
+    impl Write for CountingBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.grow(buf.len());
+            self.data.write(buf)
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+    impl ArtifactBuffer for CountingBuffer {
+        fn clear(&mut self) {
+            self.data.clear();
+        }
+        fn reserve(&mut self, additional: usize) {
+            self.grow(additional);
+        }
+    }
+
+    #[test]
+    fn uncompressed_artifacts_allocate_once() {
+        let asset = create(
+            AssetUuid([1; 16]),
+            Vec::new(),
+            Vec::new(),
+            &TestAsset { value: 5 },
+            ArtifactOptions::default(),
+            CountingBuffer::default(),
+        )
+        .unwrap();
+        assert_eq!(1, asset.data.allocations);
+        let deserialized: TestAsset = bincode::deserialize(asset.data.as_ref()).unwrap();
+        assert_eq!(deserialized, TestAsset { value: 5 });
+    }
+}
@@ -1,24 +1,31 @@
 use crate::daemon::ImporterMap;
 use crate::error::{Error, Result};
 use crate::file_tracker::FileState;
+use crate::serialized_asset::ArtifactOptions;
 use crate::watcher::file_metadata;
-use atelier_core::{utils, ArtifactId, AssetRef, AssetTypeId, AssetUuid, CompressionType};
+use atelier_core::{
+    utils::{self, ArtifactHasher, DefaultArtifactHasher},
+    ArtifactId, AssetRef, AssetTypeId, AssetUuid, CompressionType, SerializationFormat,
+};
 use atelier_importer::{
-    ArtifactMetadata, AssetMetadata, BoxedImporter, ExportAsset, ImportedAsset, ImporterContext,
-    ImporterContextHandle, SerdeObj, SerializedAsset, SourceMetadata as ImporterSourceMetadata,
-    SOURCEMETADATA_VERSION,
+    ArtifactMetadata, AssetMetadata, BoxedImporter, ExportAsset, ImportOp, ImportSource,
+    ImportedAsset, ImporterContext, ImporterContextHandle, SerdeObj, SerializedAsset,
+    SourceMetadata as ImporterSourceMetadata, SOURCEMETADATA_VERSION,
 };
 use atelier_schema::data;
 use futures_core::future::{BoxFuture, Future};
+use futures_util::FutureExt;
 use log::{debug, error};
 use std::io::Read;
 use std::{
+    any::Any,
     collections::HashSet,
     fs,
-    hash::{Hash, Hasher},
-    io::{BufRead, Write},
-    path::PathBuf,
-    time::Instant,
+    io::Write,
+    panic::AssertUnwindSafe,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, Instant},
 };
 use tokio::{fs::File, prelude::*};
 
@@ -48,7 +55,10 @@ pub(crate) struct AssetImportResult {
     pub unresolved_load_refs: Vec<AssetRef>,
     pub unresolved_build_refs: Vec<AssetRef>,
     pub asset: Option<Box<dyn SerdeObj>>,
+    /// The artifact to put in the artifact cache, `None` if the asset is not cacheable
     pub serialized_asset: Option<SerializedAsset<Vec<u8>>>,
+    /// Whether the artifact may be stored in the artifact cache, see `ImportedAsset::cacheable`
+    pub cacheable: bool,
 }
 
 impl AssetImportResult {
@@ -68,12 +78,24 @@ impl AssetImportResult {
 #[derive(Default)]
 pub(crate) struct SourcePairImport<'a> {
     source: PathBuf,
-    importer: Option<&'a dyn BoxedImporter>,
+    importer: Option<Arc<dyn BoxedImporter>>,
+    importer_map: Option<&'a ImporterMap>,
     importer_contexts: Option<&'a [Box<dyn ImporterContext>]>,
+    artifact_hasher: Option<&'a dyn ArtifactHasher>,
+    serialization_format: SerializationFormat,
+    /// Namespace and source key that new asset UUIDs are derived from
+    asset_uuid_source: Option<(uuid::Uuid, String)>,
+    /// The asset directory that contains the source file
+    source_root: Option<PathBuf>,
     source_hash: Option<u64>,
     meta_hash: Option<u64>,
     import_hash: Option<u64>,
     source_metadata: Option<SourceMetadata>,
+    force_import: bool,
+    /// Fails `import_source` if the importer takes longer
+    import_timeout: Option<Duration>,
+    /// Set once `import_source` has run the importer
+    source_imported: bool,
 }
 
 pub(crate) trait SourceMetadataCache {
@@ -152,6 +174,10 @@ impl<'a> SourcePairImport<'a> {
     pub fn set_meta_hash(&mut self, meta_hash: u64) {
         self.meta_hash = Some(meta_hash);
     }
+    /// Makes `needs_source_import` return true regardless of the stored import hash.
+    pub fn set_force_import(&mut self, force_import: bool) {
+        self.force_import = force_import;
+    }
 
     pub fn hash_source(&mut self) {
         let state = FileState {
@@ -168,7 +194,8 @@ impl<'a> SourcePairImport<'a> {
 
     /// Returns true if an appropriate importer was found, otherwise false.
     pub fn set_importer_from_map(&mut self, importers: &'a ImporterMap) -> bool {
-        self.importer = importers.get_by_path(&self.source);
+        self.importer = importers.get_shared_by_path(&self.source);
+        self.importer_map = Some(importers);
         self.importer.is_some()
     }
 
@@ -176,7 +203,31 @@ impl<'a> SourcePairImport<'a> {
         self.importer_contexts = Some(importer_contexts);
     }
 
+    pub fn set_artifact_hasher(&mut self, artifact_hasher: &'a dyn ArtifactHasher) {
+        self.artifact_hasher = Some(artifact_hasher);
+    }
+
+    pub fn set_serialization_format(&mut self, serialization_format: SerializationFormat) {
+        self.serialization_format = serialization_format;
+    }
+    /// Derives new asset UUIDs from `namespace` and `source_key` instead of generating random ones.
+    pub fn set_asset_uuid_source(&mut self, namespace: uuid::Uuid, source_key: String) {
+        self.asset_uuid_source = Some((namespace, source_key));
+    }
+    pub fn set_source_root(&mut self, source_root: PathBuf) {
+        self.source_root = Some(source_root);
+    }
+    pub fn set_import_timeout(&mut self, import_timeout: Duration) {
+        self.import_timeout = Some(import_timeout);
+    }
+    fn artifact_hasher(&self) -> &dyn ArtifactHasher {
+        self.artifact_hasher.unwrap_or(&DefaultArtifactHasher)
+    }
+
     pub fn needs_source_import(&mut self, scratch_buf: &mut Vec<u8>) -> Result<bool> {
+        if self.force_import {
+            return Ok(true);
+        }
         if let Some(ref metadata) = self.source_metadata {
             if metadata.version != SOURCEMETADATA_VERSION {
                 return Ok(true);
@@ -184,6 +235,7 @@ impl<'a> SourcePairImport<'a> {
             if metadata.importer_version
                 != self
                     .importer
+                    .as_ref()
                     .expect("need importer to determine if source import is required")
                     .version()
             {
@@ -193,8 +245,16 @@ impl<'a> SourcePairImport<'a> {
                 return Ok(true);
             }
             if self.import_hash.is_none() {
+                let default_options;
+                let options = match &metadata.importer_options {
+                    Some(options) => options.as_ref(),
+                    None => {
+                        default_options = self.default_importer_options();
+                        default_options.as_ref()
+                    }
+                };
                 self.import_hash = Some(self.calc_import_hash(
-                    metadata.importer_options.as_ref(),
+                    options,
                     metadata.importer_state.as_ref(),
                     metadata.importer_version,
                     metadata.importer_type.0,
@@ -215,18 +275,23 @@ impl<'a> SourcePairImport<'a> {
         importer_type: [u8; 16],
         scratch_buf: &mut Vec<u8>,
     ) -> Result<u64> {
-        let mut hasher = ::std::collections::hash_map::DefaultHasher::new();
+        let mut hasher = utils::ContentHasher::new();
+        // the serialized options and state are length prefixed, so their bytes can't shift
+        // from one into the other
         scratch_buf.clear();
         bincode::serialize_into(&mut *scratch_buf, &options)?;
-        scratch_buf.hash(&mut hasher);
+        hasher.update(&(scratch_buf.len() as u64).to_le_bytes());
+        hasher.update(scratch_buf);
         scratch_buf.clear();
         bincode::serialize_into(&mut *scratch_buf, &state)?;
-        scratch_buf.hash(&mut hasher);
-        self.source_hash
-            .expect("cannot calculate import hash without source hash")
-            .hash(&mut hasher);
-        importer_version.hash(&mut hasher);
-        importer_type.hash(&mut hasher);
+        hasher.update(&(scratch_buf.len() as u64).to_le_bytes());
+        hasher.update(scratch_buf);
+        let source_hash = self
+            .source_hash
+            .expect("cannot calculate import hash without source hash");
+        hasher.update(&source_hash.to_le_bytes());
+        hasher.update(&importer_version.to_le_bytes());
+        hasher.update(&importer_type);
         Ok(hasher.finish())
     }
 
@@ -234,9 +299,18 @@ impl<'a> SourcePairImport<'a> {
         self.import_hash
     }
 
+    pub fn source_imported(&self) -> bool {
+        self.source_imported
+    }
+
+    pub fn importer_version(&self) -> Option<u32> {
+        self.importer.as_ref().map(|importer| importer.version())
+    }
+
     pub async fn read_metadata_from_file(&mut self, scratch_buf: &mut Vec<u8>) -> Result<()> {
         let importer = self
             .importer
+            .clone()
             .expect("cannot read metadata without an importer");
         let meta = utils::to_meta_path(&self.source);
         let mut f = File::open(&meta).await?;
@@ -251,6 +325,7 @@ impl<'a> SourcePairImport<'a> {
     pub fn generate_source_metadata<C: SourceMetadataCache>(&mut self, metadata_cache: &C) {
         let importer = self
             .importer
+            .as_deref()
             // TODO(happens): Do we need to handle this?
             .expect("cannot create metadata without an importer");
 
@@ -258,10 +333,11 @@ impl<'a> SourcePairImport<'a> {
             version: SOURCEMETADATA_VERSION,
             importer_version: importer.version(),
             importer_type: AssetTypeId(importer.uuid()),
-            importer_options: importer.default_options(),
+            importer_options: None,
             importer_state: importer.default_state(),
             import_hash: None,
             assets: Vec::new(),
+            dependencies: Vec::new(),
         };
 
         let restored =
@@ -271,6 +347,19 @@ impl<'a> SourcePairImport<'a> {
         }
     }
 
+    /// Returns the options that the source file is imported with if its metadata doesn't override
+    /// them: the project default for the importer, or the importer's own default options.
+    fn default_importer_options(&self) -> Box<dyn SerdeObj> {
+        let importer = self
+            .importer
+            .as_deref()
+            .expect("cannot get default options without an importer");
+        match self.importer_map {
+            Some(importer_map) => importer_map.default_options(importer),
+            None => importer.default_options(),
+        }
+    }
+
     fn get_importer_context_set(
         import_contexts: Option<&[Box<dyn ImporterContext>]>,
     ) -> ImporterContextHandleSet {
@@ -325,6 +414,7 @@ impl<'a> SourcePairImport<'a> {
                 unresolved_build_refs: unresolved_build_refs.into_iter().collect(),
                 asset: None,
                 serialized_asset: None,
+                cacheable: true,
             });
         }
         Ok(PairImportResult {
@@ -333,15 +423,22 @@ impl<'a> SourcePairImport<'a> {
         })
     }
 
+    /// Builds the result of an import or export with `options`, which are stored in the source
+    /// metadata only if `options_overridden`.
     async fn build_import_result(
         &mut self,
-        importer: &dyn BoxedImporter,
         options: Box<dyn SerdeObj>,
+        options_overridden: bool,
         state: Box<dyn SerdeObj>,
         scratch_buf: &mut Vec<u8>,
         assets: Vec<ImportedAsset>,
+        dependencies: Vec<PathBuf>,
         mut ctx: ImporterContextHandleSet,
     ) -> Result<PairImportResult> {
+        let importer = self
+            .importer
+            .clone()
+            .expect("cannot build import result without importer");
         let mut imported_assets = Vec::new();
         let import_hash = self.calc_import_hash(
             options.as_ref(),
@@ -351,6 +448,8 @@ impl<'a> SourcePairImport<'a> {
             scratch_buf,
         )?;
         self.import_hash = Some(import_hash);
+        // every asset is serialized into this buffer, only the data of cacheable assets is kept
+        let mut asset_buf = Vec::new();
         for mut asset in assets {
             asset.search_tags.push((
                 "file_name".to_string(),
@@ -363,18 +462,24 @@ impl<'a> SourcePairImport<'a> {
                 ),
             ));
             ctx.begin_serialize_asset(asset.id);
+            // copied so the future doesn't borrow `self`, which can't be shared between threads
+            let artifact_options = ArtifactOptions {
+                compression: CompressionType::None,
+                serialization_format: self.serialization_format,
+                data_version: asset.data_version,
+            };
+            let buf = std::mem::take(&mut asset_buf);
             let scope_result: Result<_> = ctx
                 .scope(async {
                     // We need to serialize each asset to gather references.
                     // TODO write a dummy serializer that doesn't output anything to optimize this
                     let serialized_asset = crate::serialized_asset::create(
-                        0,
                         asset.id,
                         Vec::new(),
                         Vec::new(),
                         asset.asset_data.as_ref(),
-                        CompressionType::None,
-                        scratch_buf,
+                        artifact_options,
+                        buf,
                     )?;
                     Ok((asset, serialized_asset))
                 })
@@ -414,40 +519,45 @@ impl<'a> SourcePairImport<'a> {
             }
             asset.load_deps = load_deps.into_iter().collect();
             asset.build_deps = build_deps.into_iter().collect();
+            let mut artifact = ArtifactMetadata {
+                asset_id: asset.id,
+                id: ArtifactId::default(),
+                load_deps: asset.load_deps.clone(),
+                build_deps: asset.build_deps.clone(),
+                compression: serialized_asset.metadata.compression,
+                compressed_size: serialized_asset.metadata.compressed_size,
+                uncompressed_size: serialized_asset.metadata.uncompressed_size,
+                type_id: AssetTypeId(asset.asset_data.uuid()),
+                content_hash: serialized_asset.metadata.content_hash,
+                data_version: asset.data_version,
+                serialization_format: serialized_asset.metadata.serialization_format,
+                wide_id: None,
+            };
+            artifact.update_id(self.artifact_hasher(), import_hash);
+            let SerializedAsset { metadata, data } = serialized_asset;
+            // only cacheable artifacts are written to the artifact cache after the import
+            let serialized_asset = if asset.cacheable {
+                Some(SerializedAsset {
+                    metadata,
+                    data: data.clone(),
+                })
+            } else {
+                None
+            };
+            asset_buf = data;
             imported_assets.push(AssetImportResult {
                 metadata: AssetMetadata {
                     id: asset.id,
                     search_tags: asset.search_tags,
-                    artifact: Some(ArtifactMetadata {
-                        asset_id: asset.id,
-                        id: ArtifactId(utils::calc_import_artifact_hash(
-                            &asset.id,
-                            import_hash,
-                            asset
-                                .load_deps
-                                .iter()
-                                .chain(asset.build_deps.iter())
-                                .filter_map(|dep| {
-                                    if dep.is_uuid() {
-                                        Some(dep.expect_uuid())
-                                    } else {
-                                        None
-                                    }
-                                }),
-                        )),
-                        load_deps: asset.load_deps.clone(),
-                        build_deps: asset.build_deps.clone(),
-                        compression: serialized_asset.metadata.compression,
-                        compressed_size: serialized_asset.metadata.compressed_size,
-                        uncompressed_size: serialized_asset.metadata.uncompressed_size,
-                        type_id: AssetTypeId(asset.asset_data.uuid()),
-                    }),
+                    artifact: Some(artifact),
                     build_pipeline: asset.build_pipeline,
+                    label: asset.label,
                 },
                 unresolved_load_refs,
                 unresolved_build_refs,
                 asset: Some(asset.asset_data),
-                serialized_asset: Some(serialized_asset),
+                serialized_asset,
+                cacheable: asset.cacheable,
             });
         }
         self.source_metadata = Some(SourceMetadata {
@@ -455,9 +565,14 @@ impl<'a> SourcePairImport<'a> {
             import_hash: Some(import_hash),
             importer_version: importer.version(),
             importer_type: AssetTypeId(importer.uuid()),
-            importer_options: options,
+            importer_options: if options_overridden {
+                Some(options)
+            } else {
+                None
+            },
             importer_state: state,
             assets: imported_assets.iter().map(|m| m.metadata.clone()).collect(),
+            dependencies,
         });
 
         Ok(PairImportResult {
@@ -474,10 +589,18 @@ impl<'a> SourcePairImport<'a> {
         let start_time = Instant::now();
         let importer = self
             .importer
+            .clone()
             .expect("cannot export source without importer");
 
-        let metadata = std::mem::replace(&mut self.source_metadata, None)
+        let mut metadata = std::mem::replace(&mut self.source_metadata, None)
             .expect("cannot export source file without source_metadata");
+        // exporting doesn't read other files, so the dependencies of the last import are kept
+        let dependencies = std::mem::take(&mut metadata.dependencies);
+        let options_overridden = metadata.importer_options.is_some();
+        let options = metadata
+            .importer_options
+            .unwrap_or_else(|| self.default_importer_options());
+        let state = metadata.importer_state;
 
         let mut ctx = Self::get_importer_context_set(self.importer_contexts);
 
@@ -489,8 +612,8 @@ impl<'a> SourcePairImport<'a> {
                 importer
                     .export_boxed(
                         &mut f.compat(),
-                        metadata.importer_options,
-                        metadata.importer_state,
+                        options,
+                        state,
                         assets
                             .into_iter()
                             .map(|asset| ExportAsset { asset })
@@ -505,7 +628,15 @@ impl<'a> SourcePairImport<'a> {
         let imported = exported.value;
 
         let result = self
-            .build_import_result(importer, options, state, scratch_buf, imported.assets, ctx)
+            .build_import_result(
+                options,
+                options_overridden,
+                state,
+                scratch_buf,
+                imported.assets,
+                dependencies,
+                ctx,
+            )
             .await?;
         log::info!(
             "Exported pair in {}",
@@ -519,46 +650,86 @@ impl<'a> SourcePairImport<'a> {
         let start_time = Instant::now();
         let importer = self
             .importer
+            .clone()
             .expect("cannot import source without importer");
 
         let metadata = std::mem::replace(&mut self.source_metadata, None)
             .expect("cannot import source file without source_metadata");
+        let options_overridden = metadata.importer_options.is_some();
+        let options = metadata
+            .importer_options
+            .unwrap_or_else(|| self.default_importer_options());
+        let state = metadata.importer_state;
 
         let mut ctx = Self::get_importer_context_set(self.importer_contexts);
 
-        let source = &self.source;
+        let source = self.source.clone();
+        let import_timeout = self.import_timeout;
+        let mut op = ImportOp::new(ImportSource {
+            root: self
+                .source_root
+                .clone()
+                .unwrap_or_else(|| source.parent().map(Path::to_path_buf).unwrap_or_default()),
+            path: source.clone(),
+        })
+        .with_contexts(self.importer_contexts.unwrap_or_default());
+        if let Some((namespace, source_key)) = self.asset_uuid_source.clone() {
+            op = op.with_source_uuids(namespace, source_key);
+        }
 
-        let imported = ctx
-            .scope(async move {
+        // The import runs on a thread of its own, so the timeout also fires for importers that
+        // block or never yield. An import that timed out is abandoned: it runs until the importer
+        // returns, and its result is dropped.
+        let import = tokio::task::spawn_blocking(move || {
+            let imported = futures_executor::block_on(ctx.scope(async move {
                 //This is broken on tokio 0.2.14 and later (concurrent file loads endlessly yield to
                 // each other.
                 // let mut f = File::open(source).await?;
                 // let result = importer
-                //     .import_boxed(&mut f, metadata.importer_options, metadata.importer_state)
+                //     .import_boxed(&mut f, options, state)
                 //     .await;
 
-                // Non-async work-around
-                let mut f = std::fs::File::open(source)?;
-                let mut contents = vec![];
-                f.read_to_end(&mut contents)?;
-                let cursor = std::io::Cursor::new(contents);
-
-                use tokio_util::compat::*;
-                importer
-                    .import_boxed(
-                        &mut cursor.compat(),
-                        metadata.importer_options,
-                        metadata.importer_state,
-                    )
-                    .await
-            })
-            .await?;
+                // Non-async work-around. The file is read in chunks as the importer consumes it,
+                // rather than into memory as a whole, which is fine on this blocking thread.
+                let f = std::fs::File::open(source)?;
+                let mut reader = futures_util::io::AllowStdIo::new(std::io::BufReader::new(f));
+                let import = importer.import_boxed(&mut op, &mut reader, options, state);
+                // A panicking importer fails the import of this file instead of unwinding through
+                // the daemon. The importer isn't really unwind safe and is used again afterwards,
+                // see `AsyncImporter::import`.
+                match AssertUnwindSafe(import).catch_unwind().await {
+                    Ok(result) => result
+                        .map(|imported| (imported, op.into_dependencies()))
+                        .map_err(Error::ImporterError),
+                    Err(panic) => Err(Error::ImporterPanic(panic_message(panic.as_ref()))),
+                }
+            }));
+            (ctx, imported)
+        });
+        let joined = match import_timeout {
+            Some(timeout) => tokio::time::timeout(timeout, import)
+                .await
+                .map_err(|_| Error::ImportTimeout(timeout))?,
+            None => import.await,
+        };
+        let (ctx, imported) = joined.map_err(|err| Error::ImporterPanic(err.to_string()))?;
+        let imported = imported?;
         log::trace!("import_source building result {:?}", self.source);
+        let (imported, dependencies) = imported;
+        self.source_imported = true;
         let options = imported.options;
         let state = imported.state;
         let imported = imported.value;
         let result = self
-            .build_import_result(importer, options, state, scratch_buf, imported.assets, ctx)
+            .build_import_result(
+                options,
+                options_overridden,
+                state,
+                scratch_buf,
+                imported.assets,
+                dependencies,
+                ctx,
+            )
             .await?;
         log::info!(
             "Imported pair {:?} in {}",
@@ -569,13 +740,11 @@ impl<'a> SourcePairImport<'a> {
     }
 
     pub fn write_metadata(&self) -> Result<()> {
-        let serialized_metadata = ron::ser::to_string_pretty(
-            self.source_metadata
-                .as_ref()
-                .expect("source_metadata missing"),
-            ron::ser::PrettyConfig::default(),
-        )
-        .unwrap();
+        let serialized_metadata = self
+            .source_metadata
+            .as_ref()
+            .expect("source_metadata missing")
+            .serialize_meta()?;
         let meta_path = utils::to_meta_path(&self.source);
         let mut meta_file = fs::File::create(meta_path)?;
         meta_file.write_all(serialized_metadata.as_bytes())?;
@@ -583,13 +752,61 @@ impl<'a> SourcePairImport<'a> {
     }
 }
 
+fn panic_message(panic: &(dyn Any + Send)) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Settings shared by the imports and exports of the source pairs of a `FileAssetSource`.
+#[derive(Clone, Copy)]
+pub(crate) struct ImportSettings<'a> {
+    pub importer_map: &'a ImporterMap,
+    pub importer_contexts: &'a [Box<dyn ImporterContext>],
+    pub artifact_hasher: &'a dyn ArtifactHasher,
+    pub serialization_format: SerializationFormat,
+    /// Fails imports that take longer
+    pub import_timeout: Option<Duration>,
+    /// Whether `.meta` files are written next to source files
+    pub write_meta_files: bool,
+    /// Neither writes nor removes `.meta` files
+    pub dry_run: bool,
+}
+
+impl<'a> ImportSettings<'a> {
+    fn apply(&self, import: &mut SourcePairImport<'a>) {
+        import.set_importer_contexts(self.importer_contexts);
+        import.set_artifact_hasher(self.artifact_hasher);
+        import.set_serialization_format(self.serialization_format);
+        if let Some(import_timeout) = self.import_timeout {
+            import.set_import_timeout(import_timeout);
+        }
+    }
+}
+
+/// Imports a changed pair of source and `.meta` file. With `settings.dry_run`, `.meta` files are
+/// neither written nor removed. Without `settings.write_meta_files`, existing `.meta` files are
+/// read but none are written, and importer options and state are restored from `metadata_cache`
+/// instead.
 pub(crate) async fn import_pair<'a, C: SourceMetadataCache>(
     metadata_cache: &C,
-    importer_map: &'a ImporterMap,
-    importer_contexts: &'a [Box<dyn ImporterContext>],
+    settings: ImportSettings<'a>,
     pair: &HashedSourcePair,
+    asset_uuid_source: Option<(uuid::Uuid, String)>,
+    source_root: Option<PathBuf>,
+    force_import: bool,
     scratch_buf: &mut Vec<u8>,
 ) -> Result<Option<(SourcePairImport<'a>, Option<PairImportResult>)>> {
+    let ImportSettings {
+        importer_map,
+        write_meta_files,
+        dry_run,
+        ..
+    } = settings;
     let original_pair = pair.clone();
     let mut pair = pair.clone();
     // When source or meta gets deleted, the FileState has a `state` of `Deleted`.
@@ -649,7 +866,14 @@ pub(crate) async fn import_pair<'a, C: SourceMetadataCache>(
             let mut import = SourcePairImport::new(source.path);
             import.set_source_hash(source_hash);
             import.set_meta_hash(meta_hash);
-            import.set_importer_contexts(importer_contexts);
+            settings.apply(&mut import);
+            if let Some((namespace, source_key)) = asset_uuid_source.clone() {
+                import.set_asset_uuid_source(namespace, source_key);
+            }
+            if let Some(source_root) = source_root.clone() {
+                import.set_source_root(source_root);
+            }
+            import.set_force_import(force_import);
             if !import.set_importer_from_map(&importer_map) {
                 Ok(None)
             } else {
@@ -657,7 +881,9 @@ pub(crate) async fn import_pair<'a, C: SourceMetadataCache>(
                 if import.needs_source_import(scratch_buf)? {
                     debug!("needs source import {:?}", import.source);
                     let imported_assets = import.import_source(scratch_buf).await?;
-                    import.write_metadata()?;
+                    if write_meta_files && !dry_run {
+                        import.write_metadata()?;
+                    }
                     Ok(Some((import, Some(imported_assets))))
                 } else {
                     debug!("does not need source import {:?}", import.source);
@@ -676,7 +902,14 @@ pub(crate) async fn import_pair<'a, C: SourceMetadataCache>(
             debug!("file without meta {}", source.path.to_string_lossy());
             let mut import = SourcePairImport::new(source.path);
             import.set_source_hash(hash);
-            import.set_importer_contexts(importer_contexts);
+            settings.apply(&mut import);
+            if let Some((namespace, source_key)) = asset_uuid_source.clone() {
+                import.set_asset_uuid_source(namespace, source_key);
+            }
+            if let Some(source_root) = source_root.clone() {
+                import.set_source_root(source_root);
+            }
+            import.set_force_import(force_import);
             if !import.set_importer_from_map(&importer_map) {
                 debug!("file has no importer registered");
                 Ok(Some((import, None)))
@@ -685,12 +918,16 @@ pub(crate) async fn import_pair<'a, C: SourceMetadataCache>(
                 if import.needs_source_import(scratch_buf)? {
                     debug!("running importer for source file..");
                     let imported_assets = import.import_source(scratch_buf).await?;
-                    import.write_metadata()?;
+                    if write_meta_files && !dry_run {
+                        import.write_metadata()?;
+                    }
                     Ok(Some((import, Some(imported_assets))))
                 } else {
                     debug!("using cached metadata for source file");
                     let imported_assets = import.import_result_from_metadata()?;
-                    import.write_metadata()?;
+                    if write_meta_files && !dry_run {
+                        import.write_metadata()?;
+                    }
                     Ok(Some((import, Some(imported_assets))))
                 }
             }
@@ -747,7 +984,9 @@ pub(crate) async fn import_pair<'a, C: SourceMetadataCache>(
                 "meta file without source file {}",
                 meta.path.to_string_lossy()
             );
-            fs::remove_file(&meta.path)?;
+            if !dry_run {
+                fs::remove_file(&meta.path)?;
+            }
             Ok(None)
         }
         _ => {
@@ -774,8 +1013,7 @@ fn get_path_file_state(path: PathBuf) -> Result<Option<FileState>> {
 pub(crate) async fn export_pair<'a, C: SourceMetadataCache>(
     assets: Vec<SerializedAsset<Vec<u8>>>,
     metadata_cache: &C,
-    importer_map: &'a ImporterMap,
-    importer_contexts: &'a [Box<dyn ImporterContext>],
+    settings: ImportSettings<'a>,
     source_path: PathBuf,
     meta_path: PathBuf,
     scratch_buf: &mut Vec<u8>,
@@ -827,8 +1065,8 @@ pub(crate) async fn export_pair<'a, C: SourceMetadataCache>(
             if let Some(source_hash) = source_hash {
                 op.set_source_hash(source_hash);
             }
-            op.set_importer_contexts(importer_contexts);
-            if !op.set_importer_from_map(&importer_map) {
+            settings.apply(&mut op);
+            if !op.set_importer_from_map(settings.importer_map) {
                 Err(Error::Custom(format!(
                     "no importer registered for extension {:?}",
                     source_path.extension()
@@ -842,7 +1080,9 @@ pub(crate) async fn export_pair<'a, C: SourceMetadataCache>(
                     op.generate_source_metadata(metadata_cache);
                 }
                 let exported_assets = op.export_source(scratch_buf, assets).await?;
-                op.write_metadata()?;
+                if settings.write_meta_files && !settings.dry_run {
+                    op.write_metadata()?;
+                }
                 Ok((op, exported_assets))
             }
         }
@@ -862,29 +1102,83 @@ pub(crate) fn hash_file(state: &FileState) -> Result<(FileState, Option<u64>)> {
     Ok(fs::OpenOptions::new()
         .read(true)
         .open(&state.path)
-        .and_then(|f| {
-            let mut hasher = ::std::collections::hash_map::DefaultHasher::new();
-            let mut reader = ::std::io::BufReader::with_capacity(64000, f);
-            loop {
-                let length = {
-                    let buffer = reader.fill_buf()?;
-                    hasher.write(buffer);
-                    buffer.len()
-                };
-                if length == 0 {
-                    break;
-                }
-                reader.consume(length);
-            }
-            Ok((
+        .and_then(hash_reader)
+        .map(|hash| {
+            (
                 FileState {
                     path: state.path.clone(),
                     state: data::FileState::Exists,
                     last_modified: metadata.last_modified,
                     length: metadata.length,
                 },
-                Some(hasher.finish()),
-            ))
+                Some(hash),
+            )
         })
         .map_err(Error::IO)?)
 }
+
+/// Size of the chunks that source files are read in while they are hashed.
+const HASH_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Returns the [`utils::content_hash`] of everything `reader` returns. The data is hashed one
+/// chunk at a time, so memory use doesn't depend on the size of the file.
+fn hash_reader<R: Read>(mut reader: R) -> std::io::Result<u64> {
+    let mut hasher = utils::ContentHasher::new();
+    let mut buffer = vec![0; HASH_CHUNK_SIZE];
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(length) => hasher.update(&buffer[..length]),
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Records the largest read from the wrapped reader.
+    struct LargestRead<R> {
+        inner: R,
+        largest: usize,
+    }
+
+    impl<R: Read> Read for LargestRead<R> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.largest = self.largest.max(buf.len());
+            self.inner.read(buf)
+        }
+    }
+
+    #[test]
+    fn large_files_are_hashed_in_chunks() {
+        let contents = (0..5 * HASH_CHUNK_SIZE + 123)
+            .map(|i| (i * 31 % 251) as u8)
+            .collect::<Vec<_>>();
+        let mut reader = LargestRead {
+            inner: std::io::Cursor::new(&contents),
+            largest: 0,
+        };
+        assert_eq!(
+            utils::content_hash(&contents),
+            hash_reader(&mut reader).unwrap()
+        );
+        assert!(reader.largest <= HASH_CHUNK_SIZE);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("large.bin");
+        fs::write(&path, &contents).unwrap();
+        let state = FileState {
+            path,
+            state: data::FileState::Exists,
+            last_modified: 0,
+            length: 0,
+        };
+        let (state, hash) = hash_file(&state).unwrap();
+        assert_eq!(Some(utils::content_hash(&contents)), hash);
+        assert_eq!(contents.len() as u64, state.length);
+    }
+}
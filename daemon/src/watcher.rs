@@ -1,5 +1,6 @@
 use crate::error::{Error, Result};
-use futures_channel::mpsc::UnboundedSender;
+use atelier_core::utils;
+use futures_channel::mpsc::{self, UnboundedSender};
 use notify::{watcher, DebouncedEvent, RecommendedWatcher, RecursiveMode, Watcher};
 use std::collections::HashMap;
 use std::fs;
@@ -8,6 +9,9 @@ use std::path::PathBuf;
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::time::{Duration, UNIX_EPOCH};
 
+/// Number of directory entries between [`FileEvent::ScanProgress`] events of a scan.
+pub const SCAN_PROGRESS_INTERVAL: u64 = 100;
+
 /// The purpose of DirWatcher is to provide enough information to
 /// determine which files may be candidates for going through the asset import process.
 /// It handles updating watches for directories behind symlinks and scans directories on create/delete.
@@ -18,13 +22,64 @@ pub struct DirWatcher {
     dirs: Vec<PathBuf>,
     rx: Receiver<DebouncedEvent>,
     tx: Sender<DebouncedEvent>,
-    asset_tx: UnboundedSender<FileEvent>,
+    asset_tx: FileEventSender,
+    commands_rx: Receiver<WatchCommand>,
+    commands_tx: Sender<WatchCommand>,
+    follow_symlinks: bool,
+    scan_at_startup: bool,
 }
 
 pub struct StopHandle {
     tx: Sender<DebouncedEvent>,
 }
 
+enum WatchCommand {
+    Watch(PathBuf),
+    Unwatch(PathBuf),
+}
+
+/// Adds and removes watched directories while the watcher is running.
+#[derive(Clone)]
+pub struct WatchHandle {
+    tx: Sender<DebouncedEvent>,
+    commands_tx: Sender<WatchCommand>,
+}
+
+/// Sends file events from the watcher thread to the tracker. A bounded channel blocks the
+/// watcher thread while it is full, so events are never dropped.
+#[derive(Clone)]
+pub enum FileEventSender {
+    Unbounded(UnboundedSender<FileEvent>),
+    Bounded(mpsc::Sender<FileEvent>),
+}
+
+impl FileEventSender {
+    fn send(&mut self, event: FileEvent) -> Result<()> {
+        match self {
+            FileEventSender::Unbounded(tx) => {
+                tx.unbounded_send(event).map_err(|_| Error::SendError)
+            }
+            FileEventSender::Bounded(tx) => {
+                futures_executor::block_on(futures_util::future::poll_fn(|cx| tx.poll_ready(cx)))
+                    .and_then(|_| tx.start_send(event))
+                    .map_err(|_| Error::SendError)
+            }
+        }
+    }
+}
+
+impl From<UnboundedSender<FileEvent>> for FileEventSender {
+    fn from(tx: UnboundedSender<FileEvent>) -> Self {
+        FileEventSender::Unbounded(tx)
+    }
+}
+
+impl From<mpsc::Sender<FileEvent>> for FileEventSender {
+    fn from(tx: mpsc::Sender<FileEvent>) -> Self {
+        FileEventSender::Bounded(tx)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FileMetadata {
     pub file_type: fs::FileType,
@@ -40,8 +95,13 @@ pub enum FileEvent {
     // ScanStart is called when a directory is about to be scanned.
     // Scanning can be recursive
     ScanStart(PathBuf),
+    // ScanProgress is sent periodically during a scan with the directory that is being scanned
+    // and the number of entries scanned so far
+    ScanProgress(PathBuf, u64),
     // ScanEnd indicates the end of a scan. The set of all watched directories is also sent
     ScanEnd(PathBuf, Vec<PathBuf>),
+    // Unwatched is sent when a directory is no longer watched, with the remaining watched directories
+    Unwatched(PathBuf, Vec<PathBuf>),
 }
 pub(crate) fn file_metadata(metadata: &fs::Metadata) -> FileMetadata {
     let modify_time = metadata.modified().unwrap_or(UNIX_EPOCH);
@@ -56,18 +116,19 @@ pub(crate) fn file_metadata(metadata: &fs::Metadata) -> FileMetadata {
     }
 }
 
+/// Normalizes `path` the same way as paths in requests, see [`utils::normalize_path`].
 pub fn canonicalize_path(path: &PathBuf) -> PathBuf {
-    use path_slash::PathBufExt;
-    let cleaned_path = PathBuf::from_slash(path_clean::clean(&path.to_slash_lossy()));
+    let cleaned_path = utils::normalize_path(path);
     PathBuf::from(dunce::simplified(&cleaned_path))
 }
 
 impl DirWatcher {
-    pub fn from_path_iter<'a, T>(paths: T, chan: UnboundedSender<FileEvent>) -> Result<DirWatcher>
+    pub fn from_path_iter<'a, T>(paths: T, chan: impl Into<FileEventSender>) -> Result<DirWatcher>
     where
         T: IntoIterator<Item = &'a str>,
     {
         let (tx, rx) = channel();
+        let (commands_tx, commands_rx) = channel();
         let mut asset_watcher = DirWatcher {
             watcher: watcher(tx.clone(), Duration::from_millis(50))?,
             symlink_map: HashMap::new(),
@@ -75,7 +136,11 @@ impl DirWatcher {
             dirs: Vec::new(),
             rx,
             tx,
-            asset_tx: chan,
+            asset_tx: chan.into(),
+            commands_rx,
+            commands_tx,
+            follow_symlinks: true,
+            scan_at_startup: true,
         };
         for path in paths {
             let path = PathBuf::from(path);
@@ -90,26 +155,53 @@ impl DirWatcher {
         Ok(asset_watcher)
     }
 
+    /// Sets whether symlinks to directories inside the watched directories are followed, which
+    /// they are by default. A symlink is not followed if its target is already watched, or contains
+    /// a watched directory, so cycles of symlinks are only scanned once.
+    pub fn with_follow_symlinks(mut self, follow_symlinks: bool) -> Self {
+        self.follow_symlinks = follow_symlinks;
+        self
+    }
+
+    /// Sets whether the watched directories are scanned when the watcher starts running, which
+    /// they are by default. Directories watched later are always scanned.
+    pub fn with_scan_at_startup(mut self, scan_at_startup: bool) -> Self {
+        self.scan_at_startup = scan_at_startup;
+        self
+    }
+
     pub fn stop_handle(&self) -> StopHandle {
         StopHandle {
             tx: self.tx.clone(),
         }
     }
+
+    pub fn watch_handle(&self) -> WatchHandle {
+        WatchHandle {
+            tx: self.tx.clone(),
+            commands_tx: self.commands_tx.clone(),
+        }
+    }
+
     fn scan_directory<F>(&mut self, dir: &PathBuf, evt_create: &F) -> Result<()>
     where
         F: Fn(PathBuf) -> DebouncedEvent,
     {
         let canonical_dir = canonicalize_path(dir);
         self.asset_tx
-            .unbounded_send(FileEvent::ScanStart(canonical_dir.clone()))
-            .map_err(|_| Error::SendError)?;
-        let result = self.scan_directory_recurse(&canonical_dir, evt_create);
+            .send(FileEvent::ScanStart(canonical_dir.clone()))?;
+        let mut scanned = 0;
+        let result = self.scan_directory_recurse(&canonical_dir, evt_create, &mut scanned);
         self.asset_tx
-            .unbounded_send(FileEvent::ScanEnd(canonical_dir, self.dirs.clone()))
-            .map_err(|_| Error::SendError)?;
+            .send(FileEvent::ScanEnd(canonical_dir, self.dirs.clone()))?;
         result
     }
-    fn scan_directory_recurse<F>(&mut self, dir: &PathBuf, evt_create: &F) -> Result<()>
+    fn scan_directory_recurse<F>(
+        &mut self,
+        dir: &PathBuf,
+        evt_create: &F,
+        scanned: &mut u64,
+    ) -> Result<()>
     where
         F: Fn(PathBuf) -> DebouncedEvent,
     {
@@ -122,11 +214,14 @@ impl DirWatcher {
                         Err(ref e) if e.kind() == io::ErrorKind::NotFound => continue,
                         Err(e) => return Err(Error::IO(e)),
                         Ok(entry) => {
+                            *scanned += 1;
+                            if *scanned % SCAN_PROGRESS_INTERVAL == 0 {
+                                self.asset_tx
+                                    .send(FileEvent::ScanProgress(dir.clone(), *scanned))?;
+                            }
                             let evt = self.handle_notify_event(evt_create(entry.path()), true)?;
                             if let Some(evt) = evt {
-                                self.asset_tx
-                                    .unbounded_send(evt)
-                                    .map_err(|_| Error::SendError)?;
+                                self.asset_tx.send(evt)?;
                             }
                             let metadata;
                             match entry.metadata() {
@@ -136,7 +231,7 @@ impl DirWatcher {
                             }
                             let is_dir = metadata.is_dir();
                             if is_dir {
-                                self.scan_directory_recurse(&entry.path(), evt_create)?;
+                                self.scan_directory_recurse(&entry.path(), evt_create, scanned)?;
                             }
                         }
                     }
@@ -146,52 +241,81 @@ impl DirWatcher {
         Ok(())
     }
 
+    /// Scans the watched directories, unless disabled, and then forwards file events until
+    /// stopped. Returns early if the receiving end of the event channel is dropped.
     pub fn run(&mut self) {
-        for dir in &self.dirs.clone() {
+        let to_scan = if self.scan_at_startup {
+            self.dirs.clone()
+        } else {
+            Vec::new()
+        };
+        for dir in &to_scan {
             if let Err(err) = self.scan_directory(&dir, &|path| DebouncedEvent::Create(path)) {
-                self.asset_tx
-                    .unbounded_send(FileEvent::FileError(err))
-                    .expect("Failed to send file error event. Ironic...");
+                if self.asset_tx.send(FileEvent::FileError(err)).is_err() {
+                    return;
+                }
             }
         }
 
         loop {
-            match self.rx.recv() {
-                Ok(event) => match self.handle_notify_event(event, false) {
-                    Ok(maybe_event) => {
-                        if let Some(evt) = maybe_event {
+            let sent = match self.rx.recv() {
+                Ok(event) => {
+                    if self.handle_commands().is_err() {
+                        return;
+                    }
+                    match self.handle_notify_event(event, false) {
+                        Ok(Some(evt)) => {
                             log::debug!("File event: {:?}", evt);
-                            self.asset_tx.unbounded_send(evt).unwrap();
+                            self.asset_tx.send(evt)
                         }
+                        Ok(None) => Ok(()),
+                        Err(Error::RescanRequired) => self.rescan(),
+                        Err(Error::Exit) => break,
+                        Err(err) => self.asset_tx.send(FileEvent::FileError(err)),
                     }
-                    Err(err) => match err {
-                        Error::RescanRequired => {
-                            for dir in &self.dirs.clone() {
-                                if let Err(err) =
-                                    self.scan_directory(&dir, &|path| DebouncedEvent::Create(path))
-                                {
-                                    self.asset_tx
-                                        .unbounded_send(FileEvent::FileError(err))
-                                        .expect("Failed to send file error event");
-                                }
-                            }
-                        }
-                        Error::Exit => break,
-                        _ => self
-                            .asset_tx
-                            .unbounded_send(FileEvent::FileError(err))
-                            .expect("Failed to send file error event"),
-                    },
-                },
+                }
                 Err(_) => {
-                    self.asset_tx
-                        .unbounded_send(FileEvent::FileError(Error::RecvError))
-                        .expect("Failed to send file error event");
-
+                    let _ = self.asset_tx.send(FileEvent::FileError(Error::RecvError));
                     return;
                 }
+            };
+            if sent.is_err() {
+                return;
+            }
+        }
+    }
+
+    fn rescan(&mut self) -> Result<()> {
+        for dir in &self.dirs.clone() {
+            if let Err(err) = self.scan_directory(&dir, &|path| DebouncedEvent::Create(path)) {
+                self.asset_tx.send(FileEvent::FileError(err))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_commands(&mut self) -> Result<()> {
+        while let Ok(command) = self.commands_rx.try_recv() {
+            let result = match command {
+                WatchCommand::Watch(path) => self.watch(&path).and_then(|added| {
+                    if added {
+                        self.scan_directory(&path, &|p| DebouncedEvent::Create(p))?;
+                    }
+                    Ok(())
+                }),
+                WatchCommand::Unwatch(path) => self.unwatch(&path).and_then(|removed| {
+                    if removed {
+                        self.asset_tx
+                            .send(FileEvent::Unwatched(path, self.dirs.clone()))?;
+                    }
+                    Ok(())
+                }),
+            };
+            if let Err(err) = result {
+                self.asset_tx.send(FileEvent::FileError(err))?;
             }
         }
+        Ok(())
     }
 
     fn watch(&mut self, path: &PathBuf) -> Result<bool> {
@@ -244,9 +368,29 @@ impl DirWatcher {
             }
         }
         if let Some(dst) = dst {
+            if !self.follow_symlinks {
+                return Ok(());
+            }
             let link = fs::read_link(&dst);
             if let Ok(link_path) = link {
-                let link_path = canonicalize_path(&dst.join(link_path));
+                // relative targets are relative to the directory that contains the symlink
+                let link_dir = dst.parent().unwrap_or(dst);
+                let link_path = canonicalize_path(&link_dir.join(link_path));
+                if !link_path.is_dir() {
+                    return Ok(());
+                }
+                if self
+                    .dirs
+                    .iter()
+                    .any(|dir| link_path.starts_with(dir) || dir.starts_with(&link_path))
+                {
+                    log::debug!(
+                        "not following symlink {} to watched directory {}",
+                        dst.to_string_lossy(),
+                        link_path.to_string_lossy()
+                    );
+                    return Ok(());
+                }
                 if self.watch(&link_path)? {
                     self.scan_directory(&link_path, &|p| DebouncedEvent::Create(p))?;
                 }
@@ -307,6 +451,25 @@ impl DirWatcher {
     }
 }
 
+impl WatchHandle {
+    /// Starts watching `path` and scans it for files.
+    pub fn watch(&self, path: PathBuf) {
+        self.send(WatchCommand::Watch(path));
+    }
+
+    /// Stops watching `path`. Its files are reported as removed.
+    pub fn unwatch(&self, path: PathBuf) {
+        self.send(WatchCommand::Unwatch(path));
+    }
+
+    fn send(&self, command: WatchCommand) {
+        if self.commands_tx.send(command).is_ok() {
+            // wakes the watcher thread, which ignores the event itself
+            let _ = self.tx.send(DebouncedEvent::NoticeWrite(PathBuf::new()));
+        }
+    }
+}
+
 impl Drop for StopHandle {
     fn drop(&mut self) {
         let _ = self.tx.send(DebouncedEvent::Error(
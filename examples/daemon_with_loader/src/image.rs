@@ -1,6 +1,6 @@
 use atelier_assets::{
     core::AssetUuid,
-    importer::{AsyncImporter, Error, ImportedAsset, ImporterValue, Result},
+    importer::{AsyncImporter, Error, ImportOp, ImportedAsset, ImporterValue, Result},
 };
 use futures_core::future::BoxFuture;
 use futures_io::AsyncRead;
@@ -40,28 +40,20 @@ impl AsyncImporter for ImageImporter {
     /// Reads the given bytes and produces assets.
     fn import<'a>(
         &'a self,
+        op: &'a mut ImportOp,
         source: &'a mut (dyn AsyncRead + Unpin + Send + Sync),
         _options: &Self::Options,
         state: &'a mut Self::State,
     ) -> BoxFuture<'a, Result<ImporterValue>> {
         Box::pin(async move {
-            let id = state
-                .0
-                .unwrap_or_else(|| AssetUuid(*uuid::Uuid::new_v4().as_bytes()));
+            let id = state.0.unwrap_or_else(|| op.new_asset_uuid());
             *state = SimpleState(Some(id));
             let mut bytes = Vec::new();
             source.read_to_end(&mut bytes).await?;
             let asset =
                 Image::Rgb8(image2::io::decode(&bytes).map_err(|e| Error::Boxed(Box::new(e)))?);
             Ok(ImporterValue {
-                assets: vec![ImportedAsset {
-                    id,
-                    search_tags: vec![],
-                    build_deps: vec![],
-                    load_deps: vec![],
-                    build_pipeline: None,
-                    asset_data: Box::new(asset),
-                }],
+                assets: vec![ImportedAsset::new(id, Box::new(asset))],
             })
         })
     }
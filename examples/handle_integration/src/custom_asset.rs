@@ -5,7 +5,7 @@ use atelier_assets::{
 use serde::{Deserialize, Serialize};
 use type_uuid::TypeUuid;
 
-#[derive(Serialize, Deserialize, TypeUuid, SerdeImportable, Debug)]
+#[derive(Serialize, Deserialize, TypeUuid, SerdeImportable, Clone, Debug)]
 #[uuid = "fab4249b-f95d-411d-a017-7549df090a4f"]
 pub struct BigPerf {
     pub cool_string: String,
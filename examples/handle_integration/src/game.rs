@@ -50,6 +50,14 @@ pub fn run() {
             custom_asset.handle_made_from_path.load_handle(),
             custom_asset.handle_made_from_uuid.load_handle()
         );
+        // A clone of the asset does not borrow the storage and can be moved to another thread.
+        let owned_asset: BigPerf = game
+            .storage
+            .get_cloned(&handle)
+            .expect("failed to get asset");
+        std::thread::spawn(move || log::info!("Cloned asset says {:?}", owned_asset.cool_string))
+            .join()
+            .expect("failed to join thread");
         // Handle is automatically refcounted, so it will be dropped at the end of this scope,
         // causing the asset and its dependencies to be unloaded.
         // We return a WeakHandle of the image dependency to be able to track the unload of the dependency,
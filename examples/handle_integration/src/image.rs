@@ -1,6 +1,6 @@
 use atelier_assets::{
     core::AssetUuid,
-    importer::{Error, ImportedAsset, Importer, ImporterValue, Result},
+    importer::{Error, ImportOp, ImportedAsset, Importer, ImporterValue, Result},
 };
 use image2::{color, ImageBuf};
 use serde::{Deserialize, Serialize};
@@ -37,26 +37,18 @@ impl Importer for ImageImporter {
     /// Reads the given bytes and produces assets.
     fn import(
         &self,
+        op: &mut ImportOp,
         source: &mut dyn std::io::Read,
         _options: &Self::Options,
         state: &mut Self::State,
     ) -> Result<ImporterValue> {
-        let id = state
-            .0
-            .unwrap_or_else(|| AssetUuid(*uuid::Uuid::new_v4().as_bytes()));
+        let id = state.0.unwrap_or_else(|| op.new_asset_uuid());
         *state = SimpleState(Some(id));
         let mut bytes = Vec::new();
         source.read_to_end(&mut bytes)?;
         let asset = Image::Rgb8(image2::io::decode(&bytes).map_err(|e| Error::Boxed(Box::new(e)))?);
         Ok(ImporterValue {
-            assets: vec![ImportedAsset {
-                id,
-                search_tags: vec![],
-                build_deps: vec![],
-                load_deps: vec![],
-                build_pipeline: None,
-                asset_data: Box::new(asset),
-            }],
+            assets: vec![ImportedAsset::new(id, Box::new(asset))],
         })
     }
 }
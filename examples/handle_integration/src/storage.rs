@@ -32,6 +32,31 @@ impl GenericAssetStorage {
             )),
         );
     }
+
+    /// Returns a clone of the asset, which unlike [`TypedAssetStorage::get`] does not borrow
+    /// the storage and can be sent to other threads.
+    pub fn get_cloned<A, T>(&self, handle: &T) -> Option<A>
+    where
+        A: TypeUuid + Clone + for<'a> serde::Deserialize<'a> + 'static,
+        T: AssetHandle,
+    {
+        self.with_storage(|storage: &Storage<A>| storage.get(handle).cloned())
+    }
+
+    fn with_storage<A, R>(&self, f: impl FnOnce(&Storage<A>) -> R) -> R
+    where
+        A: TypeUuid + for<'a> serde::Deserialize<'a> + 'static,
+    {
+        f(self
+            .storage
+            .borrow()
+            .get(&AssetTypeId(A::UUID))
+            .expect("unknown asset type")
+            .as_ref()
+            .any()
+            .downcast_ref::<Storage<A>>()
+            .expect("failed to downcast"))
+    }
 }
 
 struct AssetState<A> {
@@ -82,7 +107,9 @@ impl<A: TypeUuid + for<'a> serde::Deserialize<'a> + 'static> TypedAssetStorage<A
     for GenericAssetStorage
 {
     fn get<T: AssetHandle>(&self, handle: &T) -> Option<&A> {
-        // This transmute can probably be unsound, but I don't have the energy to fix it right now
+        // The transmute extends the returned reference past the `RefCell` borrow. This is unsound
+        // if the reference is held across a call to `update_asset`, `commit_asset_version` or
+        // `free`, since those may move or drop the asset. Use `get_cloned` when that can happen.
         unsafe {
             std::mem::transmute(
                 self.storage
@@ -98,18 +125,10 @@ impl<A: TypeUuid + for<'a> serde::Deserialize<'a> + 'static> TypedAssetStorage<A
         }
     }
     fn get_version<T: AssetHandle>(&self, handle: &T) -> Option<u32> {
-        self.storage
-            .borrow()
-            .get(&AssetTypeId(A::UUID))
-            .expect("unknown asset type")
-            .as_ref()
-            .any()
-            .downcast_ref::<Storage<A>>()
-            .expect("failed to downcast")
-            .get_version(handle)
+        self.with_storage(|storage: &Storage<A>| storage.get_version(handle))
     }
     fn get_asset_with_version<T: AssetHandle>(&self, handle: &T) -> Option<(&A, u32)> {
-        // This transmute can probably be unsound, but I don't have the energy to fix it right now
+        // See `get` for when this transmute is unsound.
         unsafe {
             std::mem::transmute(
                 self.storage
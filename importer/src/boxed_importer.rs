@@ -1,9 +1,10 @@
-use crate::{error::Result, AsyncImporter, ExportAsset, ImporterValue, SerdeObj};
+use crate::{error::Result, AsyncImporter, ExportAsset, ImportOp, ImporterValue, SerdeObj};
 use atelier_core::{AssetMetadata, AssetTypeId, TypeUuidDynamic};
 use erased_serde::Deserializer;
 use futures_core::future::BoxFuture;
 use futures_io::{AsyncRead, AsyncWrite};
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 /// Version of the SourceMetadata struct.
 /// Used for forward compatibility to enable changing the .meta file format
@@ -21,12 +22,48 @@ pub struct SourceMetadata<Options: 'static, State: 'static> {
     /// The [`TypeUuidDynamic::uuid`] used to import the source file.
     #[serde(default)]
     pub importer_type: AssetTypeId,
-    /// The [`crate::Importer::Options`] used to import the source file.
-    pub importer_options: Options,
+    /// The [`crate::Importer::Options`] used to import the source file. If `None`, the project
+    /// default options of the importer are used, which the asset daemon resolves on every import.
+    #[serde(
+        default = "Option::default",
+        skip_serializing_if = "Option::is_none",
+        with = "overridden_options",
+        bound(
+            serialize = "Options: Serialize",
+            deserialize = "Options: Deserialize<'de>"
+        )
+    )]
+    pub importer_options: Option<Options>,
     /// The [`crate::Importer::State`] generated when importing the source file.
     pub importer_state: State,
     /// Metadata for assets generated when importing the source file.
     pub assets: Vec<AssetMetadata>,
+    /// Files that the importer read with [`ImportOp::read_source_file`] or
+    /// [`ImportOp::read_source_dir`], relative to the directory of the source file.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<PathBuf>,
+}
+
+/// Reads and writes overridden options without wrapping them in `Some`, so `.meta` files written
+/// before options could be left to the project default stay readable.
+mod overridden_options {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<O: Serialize, S: Serializer>(
+        options: &Option<O>,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match options {
+            Some(options) => options.serialize(serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, O: Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Option<O>, D::Error> {
+        O::deserialize(deserializer).map(Some)
+    }
 }
 
 /// Trait object wrapper for [`crate::Importer`] implementations.
@@ -35,6 +72,7 @@ pub struct SourceMetadata<Options: 'static, State: 'static> {
 pub trait BoxedImporter: TypeUuidDynamic + Send + Sync + 'static {
     fn import_boxed<'a>(
         &'a self,
+        op: &'a mut ImportOp,
         source: &'a mut (dyn AsyncRead + Unpin + Send + Sync),
         options: Box<dyn SerdeObj>,
         state: Box<dyn SerdeObj>,
@@ -87,6 +125,7 @@ where
 {
     fn import_boxed<'a>(
         &'a self,
+        op: &'a mut ImportOp,
         source: &'a mut (dyn AsyncRead + Unpin + Send + Sync),
         options: Box<dyn SerdeObj>,
         mut state: Box<dyn SerdeObj>,
@@ -107,7 +146,7 @@ where
             };
 
             log::trace!("import_boxed about to import");
-            let result = self.import(source, o, s).await?;
+            let result = self.import(op, source, o, s).await?;
             log::trace!("import_boxed imported");
             Ok(BoxedImporterValue {
                 value: result,
@@ -169,9 +208,12 @@ where
             import_hash: metadata.import_hash,
             importer_version: metadata.importer_version,
             importer_type: metadata.importer_type,
-            importer_options: Box::new(metadata.importer_options),
+            importer_options: metadata
+                .importer_options
+                .map(|options| -> Box<dyn SerdeObj> { Box::new(options) }),
             importer_state: Box::new(metadata.importer_state),
             assets: metadata.assets,
+            dependencies: metadata.dependencies,
         })
     }
 
@@ -0,0 +1,163 @@
+use crate::{ImportOp, ImportedAsset, Importer, ImporterValue, Result, SerdeImportable};
+use atelier_core::AssetUuid;
+use serde::{Deserialize, Serialize};
+use serde_cbor::from_reader;
+use std::io::Read;
+use type_uuid::*;
+
+#[derive(Default, Deserialize, Serialize, TypeUuid, Clone, Copy)]
+#[uuid = "8bd1bc10-6b2d-4a8e-a0c4-52c2e0c9a3f1"]
+pub struct CborImporterOptions {}
+
+/// A simple state for Importer to retain the same UUID between imports
+/// for all single-asset source files
+#[derive(Default, Deserialize, Serialize, TypeUuid)]
+#[uuid = "3f8a2a8e-0f5d-4d7c-9d0e-6a5b1c27e4b9"]
+pub struct CborImporterState {
+    pub id: Option<AssetUuid>,
+}
+
+/// Imports a [`SerdeImportable`] from a CBOR source file, the binary counterpart of
+/// [`RonImporter`](crate::RonImporter).
+///
+/// The file must contain a map with a single entry, just like a `.ron` file. Its key is a text
+/// string holding the `#[uuid = "..."]` attribute of the importable type exactly as written,
+/// such as `"36fb2083-7195-4583-8af9-0965f10ae60d"`, and its value is the serialized value of
+/// that type. Serializing a one-entry map from the UUID string to the value with any CBOR
+/// library produces this layout.
+#[derive(Default, TypeUuid)]
+#[uuid = "c1d6f5a4-5e2b-4b0a-8f6e-2d9c7a3b1e04"]
+pub struct CborImporter;
+
+impl Importer for CborImporter {
+    type Options = CborImporterOptions;
+    type State = CborImporterState;
+
+    fn version_static() -> u32 {
+        1
+    }
+
+    fn version(&self) -> u32 {
+        Self::version_static()
+    }
+
+    fn import(
+        &self,
+        op: &mut ImportOp,
+        source: &mut dyn Read,
+        _: &Self::Options,
+        state: &mut Self::State,
+    ) -> Result<ImporterValue> {
+        if state.id.is_none() {
+            state.id = Some(op.new_asset_uuid());
+        }
+        let de: Box<dyn SerdeImportable> = from_reader(source)?;
+
+        Ok(ImporterValue {
+            assets: vec![ImportedAsset::new(
+                state.id.expect("AssetUuid not generated"),
+                de.into_serde_obj(),
+            )],
+        })
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as atelier_importer;
+    use crate::*;
+    use std::collections::HashMap;
+
+    #[derive(Serialize, Deserialize, TypeUuid, SerdeImportable, PartialEq, Eq)]
+    #[uuid = "5b0e3f7c-2a41-4c8e-9d6b-0f1e2a3b4c5d"]
+    struct A {
+        x: u32,
+    }
+
+    #[derive(Serialize, Deserialize, TypeUuid, SerdeImportable, PartialEq)]
+    #[uuid = "9e4d2c1b-7a6f-4e3d-8c2b-1a0f9e8d7c6b"]
+    struct B {
+        s: String,
+        a: A,
+        m: HashMap<String, String>,
+    }
+
+    fn to_cbor<T: Serialize>(tag: &str, value: &T) -> Vec<u8> {
+        let mut tagged = HashMap::new();
+        tagged.insert(tag, value);
+        serde_cbor::to_vec(&tagged).unwrap()
+    }
+
+    #[test]
+    fn cbor_importer_simple_test() {
+        let importer: Box<dyn BoxedImporter> = Box::new(CborImporter::default());
+
+        let bytes = to_cbor("5b0e3f7c-2a41-4c8e-9d6b-0f1e2a3b4c5d", &A { x: 30 });
+        let mut a = bytes.as_slice();
+
+        let a_boxed_res = futures_executor::block_on(importer.import_boxed(
+            &mut ImportOp::default(),
+            &mut a,
+            Box::new(CborImporterOptions {}),
+            Box::new(CborImporterState { id: None }),
+        ))
+        .unwrap();
+        let a_serde_obj = a_boxed_res
+            .value
+            .assets
+            .into_iter()
+            .nth(0)
+            .unwrap()
+            .asset_data;
+
+        let a_downcast = a_serde_obj.any().downcast_ref::<A>();
+        match a_downcast {
+            Some(a) => assert_eq!(a.x, 30),
+            None => panic!("Expected serde_obj to be downcast to `A`."),
+        }
+    }
+
+    #[test]
+    fn cbor_importer_complex_test() {
+        let importer: Box<dyn BoxedImporter> = Box::new(CborImporter::default());
+
+        let mut m = HashMap::new();
+        m.insert("lorem".to_string(), "ipsum".to_string());
+        m.insert("dolor".to_string(), "sim".to_string());
+        let bytes = to_cbor(
+            "9e4d2c1b-7a6f-4e3d-8c2b-1a0f9e8d7c6b",
+            &B {
+                s: "Ferris".to_string(),
+                a: A { x: 30 },
+                m,
+            },
+        );
+        let mut b = bytes.as_slice();
+
+        let b_boxed_res = futures_executor::block_on(importer.import_boxed(
+            &mut ImportOp::default(),
+            &mut b,
+            Box::new(CborImporterOptions {}),
+            Box::new(CborImporterState { id: None }),
+        ))
+        .unwrap();
+        let b_serde_obj = b_boxed_res
+            .value
+            .assets
+            .into_iter()
+            .nth(0)
+            .unwrap()
+            .asset_data;
+        let b_downcast = b_serde_obj.any().downcast_ref::<B>();
+        match b_downcast {
+            Some(b) => {
+                assert_eq!(b.s, "Ferris");
+                assert_eq!(b.a.x, 30);
+                assert_eq!(b.m["lorem"], "ipsum");
+                assert_eq!(b.m["dolor"], "sim");
+                assert_eq!(b.m.len(), 2);
+            }
+            None => panic!("Expected serde_obj to be downcast to `B`."),
+        }
+    }
+}
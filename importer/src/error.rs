@@ -7,8 +7,10 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub enum Error {
     IoError(std::io::Error),
     ErasedSerde(erased_serde::Error),
-    #[cfg(feature = "serde_importers")]
     RonDe(ron::de::Error),
+    RonSer(ron::ser::Error),
+    #[cfg(feature = "serde_importers")]
+    Cbor(serde_cbor::Error),
     Boxed(Box<dyn std::error::Error + Send>),
     ExportUnsupported,
     Custom(String),
@@ -19,8 +21,10 @@ impl std::error::Error for Error {
         match *self {
             Error::IoError(ref e) => Some(e),
             Error::ErasedSerde(ref e) => Some(e),
-            #[cfg(feature = "serde_importers")]
             Error::RonDe(ref e) => Some(e),
+            Error::RonSer(ref e) => Some(e),
+            #[cfg(feature = "serde_importers")]
+            Error::Cbor(ref e) => Some(e),
             Error::Boxed(ref e) => e.source(),
             Error::ExportUnsupported => None,
             Error::Custom(_) => None,
@@ -33,8 +37,10 @@ impl fmt::Display for Error {
         match *self {
             Error::IoError(ref e) => e.fmt(f),
             Error::ErasedSerde(ref e) => e.fmt(f),
-            #[cfg(feature = "serde_importers")]
             Error::RonDe(ref e) => e.fmt(f),
+            Error::RonSer(ref e) => e.fmt(f),
+            #[cfg(feature = "serde_importers")]
+            Error::Cbor(ref e) => e.fmt(f),
             Error::Boxed(ref e) => e.fmt(f),
             Error::ExportUnsupported => write!(f, "{:?}", self),
             Error::Custom(ref e) => write!(f, "{}", e),
@@ -53,13 +59,25 @@ impl From<erased_serde::Error> for Error {
     }
 }
 
-#[cfg(feature = "serde_importers")]
 impl From<ron::de::Error> for Error {
     fn from(err: ron::de::Error) -> Error {
         Error::RonDe(err)
     }
 }
 
+impl From<ron::ser::Error> for Error {
+    fn from(err: ron::ser::Error) -> Error {
+        Error::RonSer(err)
+    }
+}
+
+#[cfg(feature = "serde_importers")]
+impl From<serde_cbor::Error> for Error {
+    fn from(err: serde_cbor::Error) -> Error {
+        Error::Cbor(err)
+    }
+}
+
 impl From<Box<dyn std::error::Error + Send>> for Error {
     fn from(err: Box<dyn std::error::Error + Send>) -> Error {
         Error::Boxed(err)
@@ -0,0 +1,39 @@
+use crate::{ImportedAsset, ImporterValue};
+use atelier_core::{AssetTypeId, AssetUuid, TypeUuidDynamic};
+use serde::{Serialize, Serializer};
+
+/// Data of an asset that the importer generated and serialized itself, such as a sprite cut from
+/// an atlas or a compressed texture.
+///
+/// The daemon stores `data` as the artifact without serializing it again, so it must be in the
+/// artifact serialization format the daemon is configured with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneratedAssetData {
+    pub asset_type: AssetTypeId,
+    pub data: Vec<u8>,
+}
+
+impl TypeUuidDynamic for GeneratedAssetData {
+    fn uuid(&self) -> [u8; 16] {
+        self.asset_type.0
+    }
+}
+
+impl Serialize for GeneratedAssetData {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.data)
+    }
+}
+
+impl ImporterValue {
+    /// Adds an asset that has no source file of its own, with data of type `asset_type` that is
+    /// already serialized. The asset is tracked like the other assets of the source file.
+    ///
+    /// `id` should be kept stable between imports, for example in the importer's state.
+    pub fn add_generated_asset(&mut self, id: AssetUuid, asset_type: AssetTypeId, data: Vec<u8>) {
+        self.assets.push(ImportedAsset::new(
+            id,
+            Box::new(GeneratedAssetData { asset_type, data }),
+        ));
+    }
+}
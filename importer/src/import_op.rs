@@ -0,0 +1,232 @@
+use atelier_core::{importer_context::ImporterContext, utils::normalize_path, AssetUuid};
+use std::{
+    any::Any,
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// The source file an import reads from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImportSource {
+    /// Absolute path of the source file.
+    pub path: PathBuf,
+    /// The asset directory that contains the source file. Importers can use it to resolve paths
+    /// that are relative to the project, or look up files next to the source with `path`.
+    pub root: PathBuf,
+}
+
+struct SourceUuids {
+    namespace: uuid::Uuid,
+    source_key: String,
+    generated: u32,
+}
+
+/// Context of a running import that is passed to [`Importer::import`](crate::Importer::import).
+///
+/// `ImportOp::default()` has no source file, generates random UUIDs and provides no services,
+/// which is enough for importers that only read their source.
+#[derive(Default)]
+pub struct ImportOp {
+    source: Option<ImportSource>,
+    source_uuids: Option<SourceUuids>,
+    services: Vec<Arc<dyn Any + Send + Sync>>,
+    /// Files read with `read_source_file` and `read_source_dir`
+    dependencies: Vec<PathBuf>,
+}
+
+impl ImportOp {
+    /// Creates the context of an import of `source`.
+    pub fn new(source: ImportSource) -> Self {
+        Self {
+            source: Some(source),
+            ..Self::default()
+        }
+    }
+
+    /// Derives the UUIDs of [`ImportOp::new_asset_uuid`] from `namespace` and `source_key`, which
+    /// identifies the source file, for example its path relative to the asset directory.
+    pub fn with_source_uuids(mut self, namespace: uuid::Uuid, source_key: String) -> Self {
+        self.source_uuids = Some(SourceUuids {
+            namespace,
+            source_key,
+            generated: 0,
+        });
+        self
+    }
+
+    /// Makes the services of `contexts` available through [`ImportOp::service`].
+    pub fn with_contexts(mut self, contexts: &[Box<dyn ImporterContext>]) -> Self {
+        self.services
+            .extend(contexts.iter().filter_map(|context| context.service()));
+        self
+    }
+
+    /// Returns the source file of the import, if the importer was invoked by the asset daemon or
+    /// [`import_source_file`](crate::import_source_file).
+    pub fn source(&self) -> Option<&ImportSource> {
+        self.source.as_ref()
+    }
+
+    /// Returns a new [`AssetUuid`] for an asset imported from the source file.
+    ///
+    /// UUIDs are random, unless the op was created [`with_source_uuids`](Self::with_source_uuids).
+    /// Then they are derived from the namespace, the source key and the number of UUIDs generated
+    /// so far during the import, so importers that produce several assets must generate their
+    /// UUIDs in a stable order.
+    pub fn new_asset_uuid(&mut self) -> AssetUuid {
+        match &mut self.source_uuids {
+            Some(source) => {
+                let name = format!("{}#{}", source.source_key, source.generated);
+                source.generated += 1;
+                AssetUuid(*uuid::Uuid::new_v5(&source.namespace, name.as_bytes()).as_bytes())
+            }
+            None => AssetUuid(*uuid::Uuid::new_v4().as_bytes()),
+        }
+    }
+
+    /// Returns the service of type `T`, such as a shader compiler or a texture settings provider,
+    /// that an importer context of the import provides. If several contexts provide a `T`, the
+    /// one that was added last is returned.
+    pub fn service<T: Send + Sync + 'static>(&self) -> Option<Arc<T>> {
+        self.services
+            .iter()
+            .rev()
+            .find_map(|service| service.clone().downcast::<T>().ok())
+    }
+
+    /// Reads a file that the source file refers to, such as an image of a sprite sheet. `path` is
+    /// relative to the directory of the source file and must be inside its asset directory.
+    ///
+    /// The file is recorded as a dependency of the import, so the asset daemon imports the
+    /// source file again when the file changes. This blocks the importer while the file is read.
+    pub fn read_source_file(&mut self, path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+        let path = self.add_dependency(path.as_ref())?;
+        std::fs::read(path)
+    }
+
+    /// Returns the paths of the entries in a directory next to the source file, relative to the
+    /// directory of the source file, in sorted order. They can be passed to
+    /// [`ImportOp::read_source_file`].
+    ///
+    /// The directory is recorded as a dependency of the import, so the source file is imported
+    /// again when entries are added to or removed from it.
+    pub fn read_source_dir(&mut self, path: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+        let relative_dir = normalize_path(path.as_ref());
+        let dir = self.add_dependency(path.as_ref())?;
+        let mut entries = std::fs::read_dir(dir)?
+            .map(|entry| entry.map(|entry| normalize_path(&relative_dir.join(entry.file_name()))))
+            .collect::<io::Result<Vec<_>>>()?;
+        entries.sort();
+        Ok(entries)
+    }
+
+    /// Returns the paths that the importer read with [`ImportOp::read_source_file`] and
+    /// [`ImportOp::read_source_dir`], relative to the directory of the source file.
+    pub fn into_dependencies(self) -> Vec<PathBuf> {
+        self.dependencies
+    }
+
+    /// Records `path` as a dependency of the import and returns its absolute path.
+    fn add_dependency(&mut self, path: &Path) -> io::Result<PathBuf> {
+        let source = self
+            .source
+            .as_ref()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "the import has no source file"))?;
+        let relative_path = normalize_path(path);
+        let source_dir = source.path.parent().unwrap_or_else(|| Path::new(""));
+        let absolute_path = normalize_path(&source_dir.join(&relative_path));
+        if !absolute_path.starts_with(&source.root) {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("{} is outside of the asset directory", path.display()),
+            ));
+        }
+        if !self.dependencies.contains(&relative_path) {
+            self.dependencies.push(relative_path);
+        }
+        Ok(absolute_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ServiceContext;
+
+    #[test]
+    fn source_uuids_are_deterministic() {
+        let import = |namespace: uuid::Uuid, source_key: &str| {
+            let mut op = ImportOp::default().with_source_uuids(namespace, source_key.to_string());
+            (op.new_asset_uuid(), op.new_asset_uuid())
+        };
+        let namespace = uuid::Uuid::from_bytes([7; 16]);
+        let (first, second) = import(namespace, "textures/rock.png");
+
+        assert_eq!((first, second), import(namespace, "textures/rock.png"));
+        assert_ne!(first, second);
+        assert_ne!(first, import(namespace, "textures/sand.png").0);
+        assert_ne!(
+            first,
+            import(uuid::Uuid::from_bytes([8; 16]), "textures/rock.png").0
+        );
+        // without a source key, UUIDs are random
+        let mut op = ImportOp::default();
+        assert_ne!(op.new_asset_uuid(), op.new_asset_uuid());
+    }
+
+    struct ShaderCompiler(&'static str);
+
+    #[test]
+    fn importers_see_services_of_their_contexts() {
+        let contexts: Vec<Box<dyn ImporterContext>> = vec![
+            Box::new(ServiceContext::new(ShaderCompiler("glslc"))),
+            Box::new(ServiceContext::new(String::from("settings"))),
+            Box::new(ServiceContext::new(String::from("overridden settings"))),
+        ];
+        let op = ImportOp::default().with_contexts(&contexts);
+
+        assert_eq!(
+            Some("glslc"),
+            op.service::<ShaderCompiler>().map(|compiler| compiler.0)
+        );
+        assert_eq!(
+            Some(String::from("overridden settings")),
+            op.service::<String>().map(|settings| (*settings).clone())
+        );
+        assert!(op.service::<u32>().is_none());
+        assert!(ImportOp::default().service::<ShaderCompiler>().is_none());
+    }
+
+    #[test]
+    fn source_files_are_recorded_as_dependencies() {
+        let dir = tempfile::tempdir().unwrap();
+        let sprites_dir = dir.path().join("sprites");
+        std::fs::create_dir(&sprites_dir).unwrap();
+        std::fs::write(sprites_dir.join("a.png"), "a").unwrap();
+        let source = ImportSource {
+            path: sprites_dir.join("sheet.json"),
+            root: dir.path().to_path_buf(),
+        };
+        let mut op = ImportOp::new(source.clone());
+
+        assert_eq!(Some(&source), op.source());
+        assert_eq!(
+            vec![PathBuf::from("a.png")],
+            op.read_source_dir(".").unwrap()
+        );
+        assert_eq!(
+            b"a".to_vec(),
+            op.read_source_file("./sub/../a.png").unwrap()
+        );
+        assert_eq!(
+            io::ErrorKind::PermissionDenied,
+            op.read_source_file("../../outside.png").unwrap_err().kind()
+        );
+        assert_eq!(
+            vec![PathBuf::from("."), PathBuf::from("a.png")],
+            op.into_dependencies()
+        );
+        assert!(ImportOp::default().read_source_file("a.png").is_err());
+    }
+}
@@ -0,0 +1,51 @@
+use atelier_core::{
+    importer_context::{ImporterContext, ImporterContextHandle},
+    AssetRef, AssetUuid,
+};
+use futures_core::future::BoxFuture;
+use std::{any::Any, collections::HashSet, sync::Arc};
+
+type Service = Arc<dyn Any + Send + Sync>;
+
+/// Importer context that makes a service, such as a shader compiler or a texture settings
+/// provider, available to importers through [`ImportOp::service`](crate::ImportOp::service).
+pub struct ServiceContext {
+    service: Service,
+}
+
+impl ServiceContext {
+    pub fn new<T: Send + Sync + 'static>(service: T) -> Self {
+        Self::from_arc(Arc::new(service))
+    }
+
+    /// Shares a service that the embedder keeps a handle to.
+    pub fn from_arc<T: Send + Sync + 'static>(service: Arc<T>) -> Self {
+        Self { service }
+    }
+}
+
+impl ImporterContext for ServiceContext {
+    fn handle(&self) -> Box<dyn ImporterContextHandle> {
+        Box::new(ServiceContextHandle)
+    }
+
+    fn service(&self) -> Option<Service> {
+        Some(self.service.clone())
+    }
+}
+
+struct ServiceContextHandle;
+
+impl ImporterContextHandle for ServiceContextHandle {
+    fn scope<'a>(&'a self, fut: BoxFuture<'a, ()>) -> BoxFuture<'a, ()> {
+        fut
+    }
+
+    fn begin_serialize_asset(&mut self, _asset: AssetUuid) {}
+
+    fn end_serialize_asset(&mut self, _asset: AssetUuid) -> HashSet<AssetRef> {
+        HashSet::new()
+    }
+
+    fn resolve_ref(&mut self, _asset_ref: &AssetRef, _asset: AssetUuid) {}
+}
@@ -1,11 +1,24 @@
 mod boxed_importer;
 mod error;
+mod generated_asset;
+mod import_op;
+mod importer_service;
+mod meta_file;
 mod serde_obj;
 mod serialized_asset;
+mod source_file;
 
+#[cfg(feature = "serde_importers")]
+mod cbor_importer;
+#[cfg(feature = "serde_importers")]
+mod raw_importer;
 #[cfg(feature = "serde_importers")]
 mod ron_importer;
 #[cfg(feature = "serde_importers")]
+pub use crate::cbor_importer::{CborImporter, CborImporterOptions, CborImporterState};
+#[cfg(feature = "serde_importers")]
+pub use crate::raw_importer::{RawAsset, RawImporter, RawImporterOptions, RawImporterState};
+#[cfg(feature = "serde_importers")]
 pub use crate::ron_importer::{RonImporter, RonImporterOptions, RonImporterState};
 #[doc(hidden)]
 #[cfg(feature = "serde_importers")]
@@ -26,8 +39,13 @@ pub use self::error::{Error, Result};
 pub use crate::serde_obj::SerdeImportable;
 pub use crate::{
     boxed_importer::{BoxedImporter, SourceMetadata, SOURCEMETADATA_VERSION},
+    generated_asset::GeneratedAssetData,
+    import_op::{ImportOp, ImportSource},
+    importer_service::ServiceContext,
+    meta_file::MetaFile,
     serde_obj::{IntoSerdeObj, SerdeObj},
     serialized_asset::SerializedAsset,
+    source_file::import_source_file,
 };
 pub use atelier_core::{
     importer_context::{ImporterContext, ImporterContextHandle},
@@ -58,8 +76,14 @@ pub trait Importer: Send + 'static {
     type State: Serialize + Send + 'static;
 
     /// Reads the given bytes and produces assets.
+    ///
+    /// `op` gives access to the source path, files next to the source, deterministic asset
+    /// UUIDs and services of the importer contexts.
+    ///
+    /// A panic is reported as an import error for the source file, see [AsyncImporter::import].
     fn import(
         &self,
+        op: &mut ImportOp,
         source: &mut dyn Read,
         options: &Self::Options,
         state: &mut Self::State,
@@ -100,9 +124,14 @@ pub trait AsyncImporter: Send + 'static {
     /// by storing generated AssetUuids with mappings to format-internal identifiers.
     type State: Serialize + Send + 'static;
 
-    /// Reads the given bytes and produces assets.
+    /// Reads the given bytes and produces assets, see [Importer::import] for `op`.
+    ///
+    /// The daemon catches a panic while the returned future is polled and reports it as an
+    /// import error for the source file. The importer is not `UnwindSafe` and is used again for
+    /// other files afterwards, so it should not panic while shared state is partially updated.
     fn import<'a>(
         &'a self,
+        op: &'a mut ImportOp,
         source: &'a mut (dyn AsyncRead + Unpin + Send + Sync),
         options: &'a Self::Options,
         state: &'a mut Self::State,
@@ -144,6 +173,7 @@ impl<T: Importer + Sync> AsyncImporter for T {
     /// Reads the given bytes and produces assets.
     fn import<'a>(
         &'a self,
+        op: &'a mut ImportOp,
         source: &'a mut (dyn AsyncRead + Unpin + Send + Sync),
         options: &'a Self::Options,
         state: &'a mut Self::State,
@@ -153,7 +183,7 @@ impl<T: Importer + Sync> AsyncImporter for T {
             let mut bytes = Vec::new();
             source.read_to_end(&mut bytes).await?;
             let mut reader = bytes.as_slice();
-            <T as Importer>::import(self, &mut reader, options, state)
+            <T as Importer>::import(self, op, &mut reader, options, state)
         })
     }
 
@@ -190,6 +220,34 @@ pub struct ImportedAsset {
     pub build_pipeline: Option<AssetUuid>,
     /// The actual asset data used by tools and Builder.
     pub asset_data: Box<dyn SerdeObj>,
+    /// Layout version of `asset_data`'s type. Increase it when the type's serialized form changes
+    /// so loaders can migrate artifacts produced before the change.
+    pub data_version: u32,
+    /// Unique name that loaders can use to refer to the asset instead of its UUID or path. The
+    /// asset daemon reports an import error when another asset already has the label.
+    pub label: Option<String>,
+    /// Whether the asset daemon may keep the asset's artifact in its cache and serve it again.
+    /// Set it to `false` for assets whose data changes on every import, such as ones containing
+    /// timestamps or random values, so they are imported again whenever they are requested.
+    pub cacheable: bool,
+}
+
+impl ImportedAsset {
+    /// Creates an asset without tags, dependencies, build pipeline or label, with data version 0
+    /// that may be cached. Set the public fields to change any of these.
+    pub fn new(id: AssetUuid, asset_data: Box<dyn SerdeObj>) -> Self {
+        Self {
+            id,
+            search_tags: Vec::new(),
+            build_deps: Vec::new(),
+            load_deps: Vec::new(),
+            build_pipeline: None,
+            asset_data,
+            data_version: 0,
+            label: None,
+            cacheable: true,
+        }
+    }
 }
 
 /// Return value for Importers containing all imported assets.
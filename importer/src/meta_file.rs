@@ -0,0 +1,117 @@
+use crate::{error::Result, SourceMetadata, SOURCEMETADATA_VERSION};
+use atelier_core::AssetTypeId;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Contents of a `.meta` file, which the daemon stores next to each source file (see
+/// [`atelier_core::utils::to_meta_path`]).
+///
+/// `.meta` files are [RON](https://github.com/ron-rs/ron) documents. The daemon honors the
+/// importer options, importer state and asset UUIDs in a `.meta` file written by other tools, as
+/// long as `importer_type` and `importer_version` match the importer registered for the source
+/// file's extension.
+pub type MetaFile<Options, State> = SourceMetadata<Options, State>;
+
+impl<Options, State> SourceMetadata<Options, State> {
+    /// Returns a `.meta` file for a source file that has not been imported yet. The daemon
+    /// imports the source file with `importer_options` instead of the project default options,
+    /// using the asset UUIDs in `importer_state`.
+    pub fn new(
+        importer_type: AssetTypeId,
+        importer_version: u32,
+        importer_options: Options,
+        importer_state: State,
+    ) -> Self {
+        SourceMetadata {
+            version: SOURCEMETADATA_VERSION,
+            import_hash: None,
+            importer_version,
+            importer_type,
+            importer_options: Some(importer_options),
+            importer_state,
+            assets: Vec::new(),
+            dependencies: Vec::new(),
+        }
+    }
+}
+
+impl<Options: Serialize, State: Serialize> SourceMetadata<Options, State> {
+    /// Serializes the `.meta` file the way the daemon writes it.
+    pub fn serialize_meta(&self) -> Result<String> {
+        Ok(ron::ser::to_string_pretty(
+            self,
+            ron::ser::PrettyConfig::default(),
+        )?)
+    }
+}
+
+impl<Options: DeserializeOwned, State: DeserializeOwned> SourceMetadata<Options, State> {
+    /// Parses the contents of a `.meta` file.
+    pub fn deserialize_meta(contents: &str) -> Result<Self> {
+        Ok(ron::de::from_str(contents)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use atelier_core::{AssetMetadata, AssetUuid};
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct ImageOptions {
+        mip_levels: u32,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct ImageState {
+        id: Option<AssetUuid>,
+    }
+
+    #[test]
+    fn meta_file_round_trip() {
+        let mut meta: MetaFile<ImageOptions, ImageState> = MetaFile::new(
+            AssetTypeId([4; 16]),
+            2,
+            ImageOptions { mip_levels: 3 },
+            ImageState {
+                id: Some(AssetUuid([5; 16])),
+            },
+        );
+        meta.assets.push(AssetMetadata {
+            id: AssetUuid([5; 16]),
+            ..Default::default()
+        });
+
+        let contents = meta.serialize_meta().unwrap();
+        let parsed = MetaFile::<ImageOptions, ImageState>::deserialize_meta(&contents).unwrap();
+
+        assert_eq!(SOURCEMETADATA_VERSION, parsed.version);
+        assert_eq!(None, parsed.import_hash);
+        assert_eq!(2, parsed.importer_version);
+        assert_eq!(AssetTypeId([4; 16]), parsed.importer_type);
+        assert_eq!(
+            Some(ImageOptions { mip_levels: 3 }),
+            parsed.importer_options
+        );
+        assert_eq!(meta.importer_state, parsed.importer_state);
+        assert_eq!(1, parsed.assets.len());
+        assert_eq!(AssetUuid([5; 16]), parsed.assets[0].id);
+    }
+
+    #[test]
+    fn meta_files_without_options_use_the_project_default() {
+        let mut meta: MetaFile<ImageOptions, ImageState> = MetaFile::new(
+            AssetTypeId([4; 16]),
+            2,
+            ImageOptions { mip_levels: 3 },
+            ImageState { id: None },
+        );
+        meta.importer_options = None;
+
+        let contents = meta.serialize_meta().unwrap();
+        let parsed = MetaFile::<ImageOptions, ImageState>::deserialize_meta(&contents).unwrap();
+
+        assert!(!contents.contains("importer_options"));
+        assert_eq!(None, parsed.importer_options);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{ImportOp, ImportedAsset, Importer, ImporterValue, Result};
+use atelier_core::AssetUuid;
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+use type_uuid::*;
+
+#[derive(Default, Deserialize, Serialize, TypeUuid, Clone, Copy)]
+#[uuid = "2b1b3d0e-5e48-4a9b-9a0c-6f3bd7f1c7a1"]
+pub struct RawImporterOptions {}
+
+/// A simple state for Importer to retain the same UUID between imports
+/// for all single-asset source files
+#[derive(Default, Deserialize, Serialize, TypeUuid)]
+#[uuid = "8e7e3a5c-6a2b-4d0f-b1c4-0a6f1d3e9b52"]
+pub struct RawImporterState {
+    pub id: Option<AssetUuid>,
+}
+
+/// The untouched contents of a source file imported by [`RawImporter`].
+#[derive(Default, Deserialize, Serialize, TypeUuid, Clone, PartialEq, Eq, Debug)]
+#[uuid = "c5a4b9d2-1f3e-4c87-a2d6-93e0b8f47d15"]
+pub struct RawAsset {
+    pub data: Vec<u8>,
+}
+
+/// Imports the whole source file as a single [`RawAsset`], for opaque binary formats that
+/// are passed through to the loader without processing.
+#[derive(Default, TypeUuid)]
+#[uuid = "5f0a8c3d-9b7e-4e21-8d4a-c1b6e2f0a937"]
+pub struct RawImporter;
+
+impl Importer for RawImporter {
+    type Options = RawImporterOptions;
+    type State = RawImporterState;
+
+    fn version_static() -> u32 {
+        1
+    }
+
+    fn version(&self) -> u32 {
+        Self::version_static()
+    }
+
+    fn import(
+        &self,
+        op: &mut ImportOp,
+        source: &mut dyn Read,
+        _: &Self::Options,
+        state: &mut Self::State,
+    ) -> Result<ImporterValue> {
+        if state.id.is_none() {
+            state.id = Some(op.new_asset_uuid());
+        }
+        let mut data = Vec::new();
+        source.read_to_end(&mut data)?;
+
+        Ok(ImporterValue {
+            assets: vec![ImportedAsset::new(
+                state.id.expect("AssetUuid not generated"),
+                Box::new(RawAsset { data }),
+            )],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BoxedImporter;
+
+    #[test]
+    fn raw_importer_round_trip() {
+        let importer: Box<dyn BoxedImporter> = Box::new(RawImporter::default());
+        let bytes: Vec<u8> = (0..=255u8).chain(0..=255u8).collect();
+
+        let result = futures_executor::block_on(importer.import_boxed(
+            &mut ImportOp::default(),
+            &mut bytes.as_slice(),
+            Box::new(RawImporterOptions {}),
+            Box::new(RawImporterState { id: None }),
+        ))
+        .unwrap();
+        let state = result
+            .state
+            .any()
+            .downcast_ref::<RawImporterState>()
+            .expect("Expected state to be downcast to `RawImporterState`.");
+        assert!(state.id.is_some());
+
+        let asset = result.value.assets.into_iter().nth(0).unwrap();
+        assert_eq!(asset.id, state.id.unwrap());
+        match asset.asset_data.any().downcast_ref::<RawAsset>() {
+            Some(raw) => assert_eq!(raw.data, bytes),
+            None => panic!("Expected serde_obj to be downcast to `RawAsset`."),
+        }
+    }
+}
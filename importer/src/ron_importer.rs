@@ -1,4 +1,4 @@
-use crate::{ImportedAsset, Importer, ImporterValue, Result, SerdeImportable};
+use crate::{ImportOp, ImportedAsset, Importer, ImporterValue, Result, SerdeImportable};
 use atelier_core::AssetUuid;
 use ron::de::from_reader;
 use serde::{Deserialize, Serialize};
@@ -35,24 +35,21 @@ impl Importer for RonImporter {
 
     fn import(
         &self,
+        op: &mut ImportOp,
         source: &mut dyn Read,
         _: &Self::Options,
         state: &mut Self::State,
     ) -> Result<ImporterValue> {
         if state.id.is_none() {
-            state.id = Some(AssetUuid(*uuid::Uuid::new_v4().as_bytes()));
+            state.id = Some(op.new_asset_uuid());
         }
         let de: Box<dyn SerdeImportable> = from_reader(source)?;
 
         Ok(ImporterValue {
-            assets: vec![ImportedAsset {
-                id: state.id.expect("AssetUuid not generated"),
-                search_tags: Vec::new(),
-                build_deps: Vec::new(),
-                load_deps: Vec::new(),
-                asset_data: de.into_serde_obj(),
-                build_pipeline: None,
-            }],
+            assets: vec![ImportedAsset::new(
+                state.id.expect("AssetUuid not generated"),
+                de.into_serde_obj(),
+            )],
         })
     }
 }
@@ -90,6 +87,7 @@ mod tests {
         .as_bytes();
 
         let a_boxed_res = futures_executor::block_on(importer.import_boxed(
+            &mut ImportOp::default(),
             &mut a,
             Box::new(RonImporterOptions {}),
             Box::new(RonImporterState { id: None }),
@@ -130,6 +128,7 @@ mod tests {
         .as_bytes();
 
         let b_boxed_res = futures_executor::block_on(importer.import_boxed(
+            &mut ImportOp::default(),
             &mut b,
             Box::new(RonImporterOptions {}),
             Box::new(RonImporterState { id: None }),
@@ -0,0 +1,100 @@
+use crate::{AsyncImporter, ImportOp, ImportSource, ImporterValue, Result};
+use std::path::Path;
+
+/// Imports the file at `path` with `importer` and `options`, starting from the importer's default
+/// state, and blocks until the import has finished.
+///
+/// This runs an importer without an asset daemon, for example in importer tests or command line
+/// tools. UUIDs from [`ImportOp::new_asset_uuid`] are random, since no `.meta` file is read or
+/// written. The file's directory is used as the root of its [`ImportSource`].
+pub fn import_source_file<I>(
+    path: impl AsRef<Path>,
+    importer: &I,
+    options: &I::Options,
+) -> Result<ImporterValue>
+where
+    I: AsyncImporter,
+    I::State: Default,
+{
+    let path = std::env::current_dir()?.join(path);
+    let contents = std::fs::read(&path)?;
+    let mut source = futures_lite::io::Cursor::new(contents);
+    let mut state = I::State::default();
+    let mut op = ImportOp::new(ImportSource {
+        root: path.parent().map(Path::to_path_buf).unwrap_or_default(),
+        path,
+    });
+    futures_lite::future::block_on(importer.import(&mut op, &mut source, options, &mut state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ImportedAsset, Importer};
+    use atelier_core::{AssetUuid, TypeUuidDynamic};
+    use serde::{Deserialize, Serialize};
+    use std::io::Read;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Rock {
+        name: String,
+        weight: u32,
+    }
+
+    impl TypeUuidDynamic for Rock {
+        fn uuid(&self) -> [u8; 16] {
+            [3; 16]
+        }
+    }
+
+    #[derive(Default, Serialize)]
+    struct RockImporterState {
+        id: Option<AssetUuid>,
+    }
+
+    struct RockImporter;
+    impl Importer for RockImporter {
+        type Options = ();
+        type State = RockImporterState;
+
+        fn version_static() -> u32 {
+            1
+        }
+        fn version(&self) -> u32 {
+            <Self as Importer>::version_static()
+        }
+
+        fn import(
+            &self,
+            op: &mut ImportOp,
+            source: &mut dyn Read,
+            _options: &Self::Options,
+            state: &mut Self::State,
+        ) -> Result<ImporterValue> {
+            let id = *state.id.get_or_insert_with(|| op.new_asset_uuid());
+            let rock: Rock = ron::de::from_reader(source)?;
+            Ok(ImporterValue {
+                assets: vec![ImportedAsset::new(id, Box::new(rock))],
+            })
+        }
+    }
+
+    #[test]
+    fn imports_ron_file_from_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("rock.ron");
+        std::fs::write(&path, r#"(name: "granite", weight: 7)"#).unwrap();
+
+        let value = import_source_file(&path, &RockImporter, &()).unwrap();
+
+        assert_eq!(1, value.assets.len());
+        let rock = value.assets[0].asset_data.any().downcast_ref::<Rock>();
+        assert_eq!(
+            Some(&Rock {
+                name: "granite".to_string(),
+                weight: 7,
+            }),
+            rock
+        );
+    }
+}
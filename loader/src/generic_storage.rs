@@ -0,0 +1,766 @@
+use crate::{
+    handle::{AssetHandle, RefOp, SerdeContext},
+    storage::{AssetLoadOp, AssetStorage, IndirectionTable, LoadHandle, LoaderInfoProvider},
+    AssetTypeId,
+};
+use atelier_core::SerializationFormat;
+use crossbeam_channel::Sender;
+use serde::de::DeserializeOwned;
+use std::{
+    any::Any,
+    collections::HashMap,
+    error::Error,
+    sync::{Arc, RwLock},
+};
+use type_uuid::TypeUuid;
+
+/// Converts artifact data serialized with an older layout of the asset type. Receives the
+/// artifact's `ArtifactMetadata::data_version` and its data.
+pub type MigrateFn<A> = fn(u32, &[u8]) -> Result<A, Box<dyn Error + Send>>;
+
+/// An [`AssetStorage`] for any number of asset types. Artifacts are deserialized with the
+/// format recorded in their `ArtifactMetadata::serialization_format`.
+///
+/// Assets are kept in [`Arc`]s, so [`GenericAssetStorage::get`] returns an owned reference that
+/// stays valid when the asset is reloaded or freed, and can be sent to other threads.
+pub struct GenericAssetStorage {
+    storage: RwLock<HashMap<AssetTypeId, Box<dyn TypedStorage>>>,
+    refop_sender: Sender<RefOp>,
+    indirection_table: IndirectionTable,
+}
+
+impl GenericAssetStorage {
+    pub fn new(refop_sender: Sender<RefOp>, indirection_table: IndirectionTable) -> Self {
+        Self {
+            storage: RwLock::new(HashMap::new()),
+            refop_sender,
+            indirection_table,
+        }
+    }
+
+    /// Registers storage for assets of type `A`. Loading an asset of an unregistered type fails.
+    pub fn add_storage<A: TypeUuid + DeserializeOwned + Send + Sync + 'static>(&self) {
+        self.storage
+            .write()
+            .unwrap()
+            .insert(AssetTypeId(A::UUID), Box::new(Storage::<A>::new(None)));
+    }
+
+    /// Registers storage for assets of type `A`, whose current layout is `data_version`.
+    /// Artifacts with an older `data_version` are passed to `migrate` instead of being
+    /// deserialized directly.
+    pub fn add_storage_with_migration<A: TypeUuid + DeserializeOwned + Send + Sync + 'static>(
+        &self,
+        data_version: u32,
+        migrate: MigrateFn<A>,
+    ) {
+        self.storage.write().unwrap().insert(
+            AssetTypeId(A::UUID),
+            Box::new(Storage::<A>::new(Some(Migration {
+                data_version,
+                migrate,
+            }))),
+        );
+    }
+
+    /// Registers storage for assets of type `A` that keeps their states in a contiguous slab
+    /// instead of a hash map. Slots of freed assets are reused by later loads,
+    /// which suits hot asset types that are loaded and freed often.
+    pub fn add_slab_storage<A: TypeUuid + DeserializeOwned + Send + Sync + 'static>(&self) {
+        self.storage
+            .write()
+            .unwrap()
+            .insert(AssetTypeId(A::UUID), Box::new(SlabStorage::<A>::new(None)));
+    }
+
+    /// Registers slab storage for assets of type `A`, whose current layout is `data_version`.
+    /// Artifacts with an older `data_version` are migrated as with
+    /// [`add_storage_with_migration`](Self::add_storage_with_migration).
+    pub fn add_slab_storage_with_migration<
+        A: TypeUuid + DeserializeOwned + Send + Sync + 'static,
+    >(
+        &self,
+        data_version: u32,
+        migrate: MigrateFn<A>,
+    ) {
+        self.storage.write().unwrap().insert(
+            AssetTypeId(A::UUID),
+            Box::new(SlabStorage::<A>::new(Some(Migration {
+                data_version,
+                migrate,
+            }))),
+        );
+    }
+
+    /// Returns the committed asset for the given handle, or `None` if it has not completed loading.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no storage was added for `A`.
+    pub fn get<A: TypeUuid + 'static, T: AssetHandle>(&self, handle: &T) -> Option<Arc<A>> {
+        self.get_asset_with_version(handle).map(|(asset, _)| asset)
+    }
+
+    /// Returns the version of the committed asset, or `None` if it has not completed loading.
+    pub fn get_version<A: TypeUuid + 'static, T: AssetHandle>(&self, handle: &T) -> Option<u32> {
+        self.get_asset_with_version::<A, T>(handle)
+            .map(|(_, version)| version)
+    }
+
+    /// Returns the committed asset and its version, or `None` if it has not completed loading.
+    pub fn get_asset_with_version<A: TypeUuid + 'static, T: AssetHandle>(
+        &self,
+        handle: &T,
+    ) -> Option<(Arc<A>, u32)> {
+        let handle = if handle.load_handle().is_indirect() {
+            self.indirection_table.resolve(handle.load_handle())?
+        } else {
+            handle.load_handle()
+        };
+        let storage = self.storage.read().unwrap();
+        let storage = storage
+            .get(&AssetTypeId(A::UUID))
+            .expect("unknown asset type")
+            .any();
+        let state = match storage.downcast_ref::<Storage<A>>() {
+            Some(storage) => storage.assets.get(&handle),
+            None => storage
+                .downcast_ref::<SlabStorage<A>>()
+                .expect("failed to downcast")
+                .get(handle),
+        };
+        state.map(|state| (state.asset.clone(), state.version))
+    }
+}
+
+struct AssetState<A> {
+    version: u32,
+    asset: Arc<A>,
+}
+
+struct Migration<A> {
+    data_version: u32,
+    migrate: MigrateFn<A>,
+}
+
+struct Storage<A> {
+    assets: HashMap<LoadHandle, AssetState<A>>,
+    uncommitted: HashMap<LoadHandle, AssetState<A>>,
+    migration: Option<Migration<A>>,
+}
+
+impl<A> Storage<A> {
+    fn new(migration: Option<Migration<A>>) -> Self {
+        Self {
+            assets: HashMap::new(),
+            uncommitted: HashMap::new(),
+            migration,
+        }
+    }
+}
+
+/// Deserializes an asset of type `A` from artifact data, migrating it if its data version is
+/// older than the current one.
+fn deserialize_asset<A: DeserializeOwned>(
+    migration: Option<&Migration<A>>,
+    loader_info: &dyn LoaderInfoProvider,
+    refop_sender: &Sender<RefOp>,
+    data: &[u8],
+) -> Result<A, Box<dyn Error + Send + 'static>> {
+    let (data_version, format) = loader_info
+        .current_artifact_metadata()
+        .map_or((0, SerializationFormat::default()), |artifact| {
+            (artifact.data_version, artifact.serialization_format)
+        });
+    let migration = migration.filter(|migration| data_version < migration.data_version);
+    // Handles in the asset are deserialized through the SerdeContext
+    futures_executor::block_on(SerdeContext::with(
+        loader_info,
+        refop_sender.clone(),
+        async {
+            match migration {
+                Some(migration) => (migration.migrate)(data_version, data),
+                None => match format {
+                    SerializationFormat::Bincode => bincode::deserialize::<A>(data)
+                        .map_err(|err| -> Box<dyn Error + Send> { err }),
+                    SerializationFormat::Json => serde_json::from_slice::<A>(data)
+                        .map_err(|err| -> Box<dyn Error + Send> { Box::new(err) }),
+                },
+            }
+        },
+    ))
+}
+
+/// Storage for the assets of one type that keeps their states in a contiguous slab of slots
+/// rather than in hash maps. Each loaded handle owns a slot until both of its versions are
+/// freed, then the slot is reused, so the slab only grows to the number of assets that are
+/// loaded at the same time.
+struct SlabStorage<A> {
+    slots: Vec<Slot<A>>,
+    free_slots: Vec<usize>,
+    slot_indices: HashMap<LoadHandle, usize>,
+    migration: Option<Migration<A>>,
+}
+
+struct Slot<A> {
+    committed: Option<AssetState<A>>,
+    uncommitted: Option<AssetState<A>>,
+}
+
+impl<A> SlabStorage<A> {
+    fn new(migration: Option<Migration<A>>) -> Self {
+        Self {
+            slots: Vec::new(),
+            free_slots: Vec::new(),
+            slot_indices: HashMap::new(),
+            migration,
+        }
+    }
+
+    fn get(&self, load_handle: LoadHandle) -> Option<&AssetState<A>> {
+        let slot = *self.slot_indices.get(&load_handle)?;
+        self.slots[slot].committed.as_ref()
+    }
+
+    fn slot_for(&mut self, load_handle: LoadHandle) -> usize {
+        if let Some(slot) = self.slot_indices.get(&load_handle) {
+            return *slot;
+        }
+        let slot = match self.free_slots.pop() {
+            Some(slot) => slot,
+            None => {
+                self.slots.push(Slot {
+                    committed: None,
+                    uncommitted: None,
+                });
+                self.slots.len() - 1
+            }
+        };
+        self.slot_indices.insert(load_handle, slot);
+        slot
+    }
+}
+
+/// A deserialized asset of the type of the [`TypedStorage`] that returned it
+type AnyAsset = Box<dyn Any + Send + Sync>;
+
+trait TypedStorage: Any + Send + Sync {
+    fn any(&self) -> &dyn Any;
+    /// Deserializes artifact data into an asset, which is then passed to `insert_uncommitted`.
+    /// Only needs shared access, so assets can be read while an artifact deserializes.
+    fn deserialize(
+        &self,
+        loader_info: &dyn LoaderInfoProvider,
+        refop_sender: &Sender<RefOp>,
+        data: &[u8],
+    ) -> Result<AnyAsset, Box<dyn Error + Send + 'static>>;
+    fn insert_uncommitted(&mut self, load_handle: LoadHandle, version: u32, asset: AnyAsset);
+    fn commit_asset_version(&mut self, load_handle: LoadHandle, version: u32);
+    fn free(&mut self, load_handle: LoadHandle, version: u32);
+}
+
+impl<A: DeserializeOwned + Send + Sync + 'static> TypedStorage for Storage<A> {
+    fn any(&self) -> &dyn Any {
+        self
+    }
+
+    fn deserialize(
+        &self,
+        loader_info: &dyn LoaderInfoProvider,
+        refop_sender: &Sender<RefOp>,
+        data: &[u8],
+    ) -> Result<AnyAsset, Box<dyn Error + Send + 'static>> {
+        let asset: A = deserialize_asset(self.migration.as_ref(), loader_info, refop_sender, data)?;
+        Ok(Box::new(asset))
+    }
+
+    fn insert_uncommitted(&mut self, load_handle: LoadHandle, version: u32, asset: AnyAsset) {
+        let asset = asset.downcast::<A>().expect("asset of another type");
+        self.uncommitted.insert(
+            load_handle,
+            AssetState {
+                version,
+                asset: Arc::from(asset),
+            },
+        );
+    }
+
+    fn commit_asset_version(&mut self, load_handle: LoadHandle, version: u32) {
+        // The previous version stays available until the new one is committed,
+        // so a reload never leaves a frame without the asset.
+        if let Some(state) = self.uncommitted.remove(&load_handle) {
+            debug_assert_eq!(state.version, version);
+            self.assets.insert(load_handle, state);
+        }
+    }
+
+    fn free(&mut self, load_handle: LoadHandle, version: u32) {
+        if let Some(state) = self.uncommitted.get(&load_handle) {
+            if state.version == version {
+                self.uncommitted.remove(&load_handle);
+            }
+        }
+        if let Some(state) = self.assets.get(&load_handle) {
+            if state.version == version {
+                self.assets.remove(&load_handle);
+            }
+        }
+    }
+}
+
+impl<A: DeserializeOwned + Send + Sync + 'static> TypedStorage for SlabStorage<A> {
+    fn any(&self) -> &dyn Any {
+        self
+    }
+
+    fn deserialize(
+        &self,
+        loader_info: &dyn LoaderInfoProvider,
+        refop_sender: &Sender<RefOp>,
+        data: &[u8],
+    ) -> Result<AnyAsset, Box<dyn Error + Send + 'static>> {
+        let asset: A = deserialize_asset(self.migration.as_ref(), loader_info, refop_sender, data)?;
+        Ok(Box::new(asset))
+    }
+
+    fn insert_uncommitted(&mut self, load_handle: LoadHandle, version: u32, asset: AnyAsset) {
+        let asset = asset.downcast::<A>().expect("asset of another type");
+        let slot = self.slot_for(load_handle);
+        self.slots[slot].uncommitted = Some(AssetState {
+            version,
+            asset: Arc::from(asset),
+        });
+    }
+
+    fn commit_asset_version(&mut self, load_handle: LoadHandle, version: u32) {
+        if let Some(&slot) = self.slot_indices.get(&load_handle) {
+            let slot = &mut self.slots[slot];
+            if let Some(state) = slot.uncommitted.take() {
+                debug_assert_eq!(state.version, version);
+                slot.committed = Some(state);
+            }
+        }
+    }
+
+    fn free(&mut self, load_handle: LoadHandle, version: u32) {
+        let index = match self.slot_indices.get(&load_handle) {
+            Some(&index) => index,
+            None => return,
+        };
+        let slot = &mut self.slots[index];
+        if slot.uncommitted.as_ref().map(|state| state.version) == Some(version) {
+            slot.uncommitted = None;
+        }
+        if slot.committed.as_ref().map(|state| state.version) == Some(version) {
+            slot.committed = None;
+        }
+        if slot.committed.is_none() && slot.uncommitted.is_none() {
+            self.slot_indices.remove(&load_handle);
+            self.free_slots.push(index);
+        }
+    }
+}
+
+impl AssetStorage for GenericAssetStorage {
+    fn update_asset(
+        &self,
+        loader_info: &dyn LoaderInfoProvider,
+        asset_type_id: &AssetTypeId,
+        data: Vec<u8>,
+        load_handle: LoadHandle,
+        load_op: AssetLoadOp,
+        version: u32,
+    ) -> Result<(), Box<dyn Error + Send + 'static>> {
+        // Deserializing can take a while, so other threads only wait for the insertion
+        let asset = self
+            .storage
+            .read()
+            .unwrap()
+            .get(asset_type_id)
+            .ok_or_else(|| unknown_asset_type(asset_type_id))?
+            .deserialize(loader_info, &self.refop_sender, &data)?;
+        self.storage
+            .write()
+            .unwrap()
+            .get_mut(asset_type_id)
+            .ok_or_else(|| unknown_asset_type(asset_type_id))?
+            .insert_uncommitted(load_handle, version, asset);
+        load_op.complete();
+        Ok(())
+    }
+
+    fn commit_asset_version(
+        &self,
+        asset_type_id: &AssetTypeId,
+        load_handle: LoadHandle,
+        version: u32,
+    ) {
+        // an asset of an unknown type failed to update, so there is nothing to commit
+        if let Some(storage) = self.storage.write().unwrap().get_mut(asset_type_id) {
+            storage.commit_asset_version(load_handle, version);
+        }
+    }
+
+    fn free(&self, asset_type_id: &AssetTypeId, load_handle: LoadHandle, version: u32) {
+        if let Some(storage) = self.storage.write().unwrap().get_mut(asset_type_id) {
+            storage.free(load_handle, version);
+        }
+    }
+}
+
+fn unknown_asset_type(asset_type_id: &AssetTypeId) -> Box<dyn Error + Send + 'static> {
+    Box::new(std::io::Error::new(
+        std::io::ErrorKind::Other,
+        format!("no storage was added for asset type {:?}", asset_type_id),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{handle::WeakHandle, storage::HandleOp, AssetRef, AssetUuid};
+    use atelier_core::ArtifactMetadata;
+    use crossbeam_channel::unbounded;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize, TypeUuid)]
+    #[uuid = "6a3f1d2e-9b47-4c58-8e0a-1f2d3c4b5a69"]
+    struct Mesh {
+        vertices: Vec<u32>,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize, TypeUuid)]
+    #[uuid = "c41e8a7b-2d35-4f96-b0c1-7e8d9f0a1b2c"]
+    struct Material {
+        name: String,
+    }
+
+    /// Layout of `Mesh` before its vertices were widened
+    #[derive(Deserialize, Serialize)]
+    struct MeshV1 {
+        vertices: Vec<u16>,
+    }
+
+    struct NoLoader(ArtifactMetadata);
+    impl LoaderInfoProvider for NoLoader {
+        fn get_load_handle(&self, _asset_ref: &AssetRef) -> Option<LoadHandle> {
+            None
+        }
+        fn get_asset_id(&self, _load: LoadHandle) -> Option<AssetUuid> {
+            None
+        }
+        fn current_artifact_metadata(&self) -> Option<&ArtifactMetadata> {
+            Some(&self.0)
+        }
+    }
+
+    fn new_storage() -> GenericAssetStorage {
+        let (refop_tx, _) = unbounded();
+        let storage = GenericAssetStorage::new(
+            refop_tx,
+            IndirectionTable(Arc::new(RwLock::new(HashMap::new()))),
+        );
+        storage.add_storage::<Mesh>();
+        storage.add_storage::<Material>();
+        storage
+    }
+
+    fn load<A: Serialize + TypeUuid>(
+        storage: &GenericAssetStorage,
+        asset: &A,
+        load_handle: LoadHandle,
+        version: u32,
+    ) {
+        load_data(
+            storage,
+            AssetTypeId(A::UUID),
+            bincode::serialize(asset).unwrap(),
+            0,
+            load_handle,
+            version,
+        );
+    }
+
+    fn load_data(
+        storage: &GenericAssetStorage,
+        asset_type_id: AssetTypeId,
+        data: Vec<u8>,
+        data_version: u32,
+        load_handle: LoadHandle,
+        version: u32,
+    ) {
+        load_artifact(
+            storage,
+            ArtifactMetadata {
+                type_id: asset_type_id,
+                data_version,
+                ..Default::default()
+            },
+            data,
+            load_handle,
+            version,
+        );
+    }
+
+    fn load_artifact(
+        storage: &GenericAssetStorage,
+        artifact: ArtifactMetadata,
+        data: Vec<u8>,
+        load_handle: LoadHandle,
+        version: u32,
+    ) {
+        let (op_tx, op_rx) = unbounded();
+        let asset_type_id = artifact.type_id;
+        let loader_info = NoLoader(artifact);
+        storage
+            .update_asset(
+                &loader_info,
+                &asset_type_id,
+                data,
+                load_handle,
+                AssetLoadOp::new(op_tx, load_handle, version),
+                version,
+            )
+            .unwrap();
+        match op_rx.try_recv() {
+            Ok(HandleOp::Complete(handle, v)) => assert_eq!((load_handle, version), (handle, v)),
+            _ => panic!("load op was not completed"),
+        }
+    }
+
+    #[test]
+    fn stores_multiple_asset_types() {
+        let storage = new_storage();
+        let mesh = WeakHandle::new(LoadHandle(1));
+        let material = WeakHandle::new(LoadHandle(2));
+        load(
+            &storage,
+            &Mesh {
+                vertices: vec![1, 2, 3],
+            },
+            LoadHandle(1),
+            1,
+        );
+        load(
+            &storage,
+            &Material {
+                name: "rock".into(),
+            },
+            LoadHandle(2),
+            1,
+        );
+        assert!(storage.get::<Mesh, _>(&mesh).is_none());
+
+        storage.commit_asset_version(&AssetTypeId(Mesh::UUID), LoadHandle(1), 1);
+        storage.commit_asset_version(&AssetTypeId(Material::UUID), LoadHandle(2), 1);
+        assert_eq!(
+            vec![1, 2, 3],
+            storage.get::<Mesh, _>(&mesh).unwrap().vertices
+        );
+        assert_eq!("rock", storage.get::<Material, _>(&material).unwrap().name);
+        assert!(storage.get::<Mesh, _>(&material).is_none());
+    }
+
+    #[test]
+    fn unknown_asset_types_fail_to_load() {
+        let (refop_tx, _) = unbounded();
+        let storage = GenericAssetStorage::new(
+            refop_tx,
+            IndirectionTable(Arc::new(RwLock::new(HashMap::new()))),
+        );
+        let mesh_type = AssetTypeId(Mesh::UUID);
+        let (op_tx, _op_rx) = unbounded();
+        let loader_info = NoLoader(ArtifactMetadata {
+            type_id: mesh_type,
+            ..Default::default()
+        });
+        let result = storage.update_asset(
+            &loader_info,
+            &mesh_type,
+            bincode::serialize(&Mesh { vertices: vec![1] }).unwrap(),
+            LoadHandle(1),
+            AssetLoadOp::new(op_tx, LoadHandle(1), 1),
+            1,
+        );
+        assert!(result.is_err());
+        storage.commit_asset_version(&mesh_type, LoadHandle(1), 1);
+        storage.free(&mesh_type, LoadHandle(1), 1);
+    }
+
+    #[test]
+    fn reload_keeps_old_version_until_commit_and_free() {
+        let storage = new_storage();
+        let handle = WeakHandle::new(LoadHandle(1));
+        let mesh_type = AssetTypeId(Mesh::UUID);
+        load(&storage, &Mesh { vertices: vec![1] }, LoadHandle(1), 1);
+        storage.commit_asset_version(&mesh_type, LoadHandle(1), 1);
+        let old = storage.get::<Mesh, _>(&handle).unwrap();
+
+        load(&storage, &Mesh { vertices: vec![2] }, LoadHandle(1), 2);
+        assert_eq!(Some(1), storage.get_version::<Mesh, _>(&handle));
+        storage.commit_asset_version(&mesh_type, LoadHandle(1), 2);
+        assert_eq!(
+            Some((Arc::new(Mesh { vertices: vec![2] }), 2)),
+            storage.get_asset_with_version::<Mesh, _>(&handle)
+        );
+
+        // freeing the replaced version leaves the committed one in place
+        storage.free(&mesh_type, LoadHandle(1), 1);
+        assert_eq!(Some(2), storage.get_version::<Mesh, _>(&handle));
+        storage.free(&mesh_type, LoadHandle(1), 2);
+        assert!(storage.get::<Mesh, _>(&handle).is_none());
+        // references handed out earlier remain valid
+        assert_eq!(vec![1], old.vertices);
+    }
+
+    fn migrate_mesh_v1(data_version: u32, data: &[u8]) -> Result<Mesh, Box<dyn Error + Send>> {
+        assert_eq!(1, data_version);
+        let old: MeshV1 =
+            bincode::deserialize(data).map_err(|err| -> Box<dyn Error + Send> { err })?;
+        Ok(Mesh {
+            vertices: old.vertices.into_iter().map(u32::from).collect(),
+        })
+    }
+
+    #[test]
+    fn migrates_artifacts_with_older_data_version() {
+        let (refop_tx, _) = unbounded();
+        let storage = GenericAssetStorage::new(
+            refop_tx.clone(),
+            IndirectionTable(Arc::new(RwLock::new(HashMap::new()))),
+        );
+        storage.add_storage_with_migration::<Mesh>(2, migrate_mesh_v1);
+        check_mesh_migration(&storage);
+
+        let slab_storage = GenericAssetStorage::new(
+            refop_tx,
+            IndirectionTable(Arc::new(RwLock::new(HashMap::new()))),
+        );
+        slab_storage.add_slab_storage_with_migration::<Mesh>(2, migrate_mesh_v1);
+        check_mesh_migration(&slab_storage);
+    }
+
+    fn check_mesh_migration(storage: &GenericAssetStorage) {
+        let mesh_type = AssetTypeId(Mesh::UUID);
+
+        let v1_data = bincode::serialize(&MeshV1 {
+            vertices: vec![1, 2],
+        })
+        .unwrap();
+        load_data(storage, mesh_type, v1_data, 1, LoadHandle(1), 1);
+        storage.commit_asset_version(&mesh_type, LoadHandle(1), 1);
+        assert_eq!(
+            vec![1, 2],
+            storage
+                .get::<Mesh, _>(&WeakHandle::new(LoadHandle(1)))
+                .unwrap()
+                .vertices
+        );
+
+        // data in the current layout is deserialized without migrating
+        let v2_data = bincode::serialize(&Mesh {
+            vertices: vec![70_000],
+        })
+        .unwrap();
+        load_data(storage, mesh_type, v2_data, 2, LoadHandle(2), 1);
+        storage.commit_asset_version(&mesh_type, LoadHandle(2), 1);
+        assert_eq!(
+            vec![70_000],
+            storage
+                .get::<Mesh, _>(&WeakHandle::new(LoadHandle(2)))
+                .unwrap()
+                .vertices
+        );
+    }
+
+    #[test]
+    fn slab_storage_reuses_freed_slots() {
+        let (refop_tx, _) = unbounded();
+        let storage = GenericAssetStorage::new(
+            refop_tx,
+            IndirectionTable(Arc::new(RwLock::new(HashMap::new()))),
+        );
+        storage.add_slab_storage::<Mesh>();
+        let mesh_type = AssetTypeId(Mesh::UUID);
+        let slab_len = || {
+            storage.storage.read().unwrap()[&mesh_type]
+                .any()
+                .downcast_ref::<SlabStorage<Mesh>>()
+                .unwrap()
+                .slots
+                .len()
+        };
+
+        // at most 4 meshes are loaded at a time while 100 handles come and go
+        for handle in 1..=100u32 {
+            if handle > 4 {
+                let old_handle = LoadHandle(u64::from(handle - 4));
+                let old_version = if (handle - 4) % 2 == 0 { 2 } else { 1 };
+                storage.free(&mesh_type, old_handle, old_version);
+                assert!(storage
+                    .get::<Mesh, _>(&WeakHandle::new(old_handle))
+                    .is_none());
+            }
+            let load_handle = LoadHandle(u64::from(handle));
+            load(
+                &storage,
+                &Mesh {
+                    vertices: vec![handle],
+                },
+                load_handle,
+                1,
+            );
+            storage.commit_asset_version(&mesh_type, load_handle, 1);
+            if handle % 2 == 0 {
+                // reloads occupy the same slot as the version they replace
+                load(
+                    &storage,
+                    &Mesh {
+                        vertices: vec![handle * 10],
+                    },
+                    load_handle,
+                    2,
+                );
+                storage.commit_asset_version(&mesh_type, load_handle, 2);
+                storage.free(&mesh_type, load_handle, 1);
+            }
+            assert!(slab_len() <= 4);
+        }
+
+        for handle in 97..=100u32 {
+            let expected = if handle % 2 == 0 { handle * 10 } else { handle };
+            assert_eq!(
+                vec![expected],
+                storage
+                    .get::<Mesh, _>(&WeakHandle::new(LoadHandle(u64::from(handle))))
+                    .unwrap()
+                    .vertices
+            );
+        }
+        assert_eq!(4, slab_len());
+    }
+
+    #[test]
+    fn deserializes_with_the_artifact_serialization_format() {
+        let storage = new_storage();
+        let mesh = Mesh {
+            vertices: vec![4, 5],
+        };
+        load_artifact(
+            &storage,
+            ArtifactMetadata {
+                type_id: AssetTypeId(Mesh::UUID),
+                serialization_format: SerializationFormat::Json,
+                ..Default::default()
+            },
+            serde_json::to_vec(&mesh).unwrap(),
+            LoadHandle(1),
+            1,
+        );
+        storage.commit_asset_version(&AssetTypeId(Mesh::UUID), LoadHandle(1), 1);
+        assert_eq!(
+            Some(Arc::new(mesh)),
+            storage.get::<Mesh, _>(&WeakHandle::new(LoadHandle(1)))
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    storage::{LoadStatus, LoaderInfoProvider},
+    storage::{LoadInfo, LoadStatus, LoaderInfoProvider},
     AssetRef, AssetUuid, LoadHandle, Loader,
 };
 use crossbeam_channel::{unbounded, Receiver, Sender};
@@ -416,6 +416,9 @@ impl atelier_core::importer_context::ImporterContext for HandleSerdeContextProvi
     }
 }
 
+/// Writes the `AssetUuid` that `load` refers to in the active [`SerdeContext`], so the serialized
+/// handle stays valid across loader sessions. Human-readable formats get the hyphenated UUID
+/// string, others the 16 UUID bytes; `Deserialize` accepts both.
 fn serialize_handle<S>(load: LoadHandle, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -423,6 +426,10 @@ where
     SerdeContext::with_active(|loader, _| {
         use ser::SerializeSeq;
         let uuid: AssetUuid = loader.get_asset_id(load).unwrap_or_default();
+        if serializer.is_human_readable() {
+            let uuid = uuid::Uuid::from_bytes(uuid.0).to_hyphenated().to_string();
+            return serializer.serialize_str(&uuid);
+        }
         let mut seq = serializer.serialize_seq(Some(uuid.0.len()))?;
         for element in &uuid.0 {
             seq.serialize_element(element)?;
@@ -625,6 +632,15 @@ pub trait AssetHandle {
         loader.get_load_status(self.load_handle())
     }
 
+    /// Returns the reference count and artifact sizes of the asset, see [`LoadInfo`].
+    ///
+    /// # Parameters
+    ///
+    /// * `loader`: Loader that is loading the asset.
+    fn load_info(&self, loader: &Loader) -> Option<LoadInfo> {
+        loader.get_load_info(self.load_handle())
+    }
+
     /// Returns an immutable reference to the asset if it is committed.
     ///
     /// # Parameters
@@ -10,7 +10,20 @@ pub trait LoaderIO: Send + Sync {
     fn get_asset_metadata_with_dependencies(&mut self, request: MetadataRequest);
     fn get_asset_candidates(&mut self, requests: Vec<ResolveRequest>);
     fn get_artifacts(&mut self, requests: Vec<DataRequest>);
+    /// Provides the metadata of assets and their build dependencies. Implementations that can't
+    /// look up build dependencies drop the request, which completes it with an error.
+    fn get_asset_metadata_with_build_dependencies(&mut self, request: BuildDepsRequest) {
+        drop(request);
+    }
+    /// Finds assets by their search tags. Implementations that can't search drop the request,
+    /// which completes it with an error.
+    fn search_assets(&mut self, request: SearchRequest) {
+        drop(request);
+    }
     fn tick(&mut self, loader: &mut LoaderState);
+    /// Calls `f` with the runtime that the implementation does its IO on. The runtime is driven
+    /// by [`tick`](LoaderIO::tick), so tasks spawned on it make progress while the loader is
+    /// processed, and `f` must not be called from within a task of the runtime.
     fn with_runtime(&self, f: &mut dyn FnMut(&mut tokio::runtime::Runtime));
 }
 
@@ -126,3 +139,62 @@ impl Drop for MetadataRequest {
         }
     }
 }
+
+/// A request for the metadata of assets and of all assets they depend on to build.
+pub struct BuildDepsRequest {
+    pub(crate) tx: Sender<Result<Vec<AssetMetadata>>>,
+    pub(crate) assets: Option<Vec<AssetUuid>>,
+}
+impl BuildDepsRequest {
+    pub fn requested_assets(&self) -> &[AssetUuid] {
+        self.assets.as_ref().unwrap()
+    }
+    pub fn error<T: std::error::Error + Send + 'static>(mut self, err: T) {
+        if self.assets.take().is_some() {
+            let _ = self.tx.send(Err(Box::new(err)));
+        }
+    }
+    pub fn complete(mut self, metadata: Vec<AssetMetadata>) {
+        if self.assets.take().is_some() {
+            let _ = self.tx.send(Ok(metadata));
+        }
+    }
+}
+impl Drop for BuildDepsRequest {
+    fn drop(&mut self) {
+        if self.assets.take().is_some() {
+            let _ = self.tx.send(Err(Box::new(RequestDropError)));
+        }
+    }
+}
+
+/// A request for the assets that have a search tag with a key, and optionally a value.
+pub struct SearchRequest {
+    pub(crate) tx: Sender<Result<Vec<AssetUuid>>>,
+    pub(crate) query: Option<(String, Option<String>)>,
+}
+impl SearchRequest {
+    pub fn key(&self) -> &str {
+        &self.query.as_ref().unwrap().0
+    }
+    pub fn value(&self) -> Option<&str> {
+        self.query.as_ref().unwrap().1.as_deref()
+    }
+    pub fn error<T: std::error::Error + Send + 'static>(mut self, err: T) {
+        if self.query.take().is_some() {
+            let _ = self.tx.send(Err(Box::new(err)));
+        }
+    }
+    pub fn complete(mut self, assets: Vec<AssetUuid>) {
+        if self.query.take().is_some() {
+            let _ = self.tx.send(Ok(assets));
+        }
+    }
+}
+impl Drop for SearchRequest {
+    fn drop(&mut self) {
+        if self.query.take().is_some() {
+            let _ = self.tx.send(Err(Box::new(RequestDropError)));
+        }
+    }
+}
@@ -1,5 +1,9 @@
 #![warn(rust_2018_idioms, rust_2018_compatibility)]
 
+/// *feature:* `handle`. [`GenericAssetStorage`](crate::generic_storage::GenericAssetStorage) is a ready-made
+/// [`AssetStorage`](crate::storage::AssetStorage) that stores assets of any number of types.
+#[cfg(feature = "handle")]
+pub mod generic_storage;
 /// *feature:* `handle`. Handles provide automatic reference counting of assets, similar to [Rc](`std::rc::Rc`).
 #[cfg(feature = "handle")]
 pub mod handle;
@@ -20,7 +24,9 @@ pub use atelier_core::{AssetRef, AssetTypeId, AssetUuid};
 pub use crossbeam_channel;
 pub use loader::Loader;
 #[cfg(feature = "rpc_io")]
-pub use rpc_io::RpcIO;
+pub use rpc_io::{RpcAddress, RpcIO};
+#[cfg(feature = "tls")]
+pub use rpc_io::TlsConfig;
 pub use storage::LoadHandle;
 
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + 'static>>;
@@ -1,13 +1,15 @@
 use crate::{
     handle::{RefOp, SerdeContext},
+    io::BuildDepsRequest,
     io::DataRequest,
     io::LoaderIO,
     io::MetadataRequest,
     io::ResolveRequest,
+    io::SearchRequest,
     storage::{
         AssetLoadOp, AssetStorage, AtomicHandleAllocator, HandleAllocator, HandleOp,
         IndirectIdentifier, IndirectionResolver, IndirectionTable, LoadHandle, LoadInfo,
-        LoadStatus, LoaderInfoProvider,
+        LoadStatus, LoaderInfoProvider, UpdateResult,
     },
     Result,
 };
@@ -16,11 +18,12 @@ use crossbeam_channel::{unbounded, Receiver, Sender};
 use dashmap::DashMap;
 use log::error;
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
+    hash::{Hash, Hasher},
     path::PathBuf,
     sync::{
         atomic::{AtomicUsize, Ordering},
-        Arc,
+        Arc, RwLock,
     },
 };
 
@@ -52,6 +55,8 @@ enum LoadState {
     UnloadRequested,
     /// Asset is being unloaded by engine systems
     Unloading,
+    /// Engine systems failed to load the asset
+    Failed,
 }
 
 /// Describes the state of an indirect Handle
@@ -78,6 +83,10 @@ struct AssetVersionLoad {
     asset_type: Option<AssetTypeId>,
     auto_commit: bool,
     version: u32,
+    /// Number of times the asset storage asked to retry updating the asset
+    update_retries: u32,
+    /// Why the version is in the `Failed` state
+    error: Option<String>,
 }
 #[derive(Debug)]
 struct AssetLoad {
@@ -87,6 +96,8 @@ struct AssetLoad {
     versions: Vec<AssetVersionLoad>,
     version_counter: u32,
     pending_reload: bool,
+    /// When `Loader::prefetch` last asked for the asset, which keeps it loading without references
+    prefetched_at: Option<std::time::Instant>,
 }
 
 /// Keeps track of a pending reload
@@ -110,6 +121,14 @@ pub struct LoaderState {
     indirect_to_load: DashMap<IndirectIdentifier, LoadHandle>,
     indirect_table: IndirectionTable,
     responses: IORequestChannels,
+    /// Maximum number of asset versions whose data is requested or being loaded at the same time
+    max_data_requests: usize,
+    /// Maximum number of times an asset update is retried when the asset storage asks for it
+    max_update_retries: u32,
+    /// Derive load handles from the asset or indirect identifier instead of allocating them
+    deterministic_handles: bool,
+    /// How long a prefetched asset stays loaded without references once it has loaded
+    prefetch_lifetime: std::time::Duration,
 }
 
 #[allow(clippy::type_complexity)]
@@ -139,6 +158,7 @@ struct IORequestChannels {
 struct AssetLoadResult {
     new_state: LoadState,
     asset_type: Option<AssetTypeId>,
+    error: Option<String>,
 }
 
 impl AssetLoadResult {
@@ -146,16 +166,37 @@ impl AssetLoadResult {
         Self {
             new_state,
             asset_type: None,
+            error: None,
         }
     }
 }
 
+/// Hashes `key` into a [`LoadHandle`], with the indirect bit unset. If the handle is `taken` by
+/// another load, the next free one is returned.
+fn derive_handle<T: Hash>(key: &T, taken: impl Fn(LoadHandle) -> bool) -> LoadHandle {
+    const HANDLE_MASK: u64 = !(1 << 63);
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    let mut handle = hasher.finish() & HANDLE_MASK;
+    // 0 is never returned by the default allocator either
+    while handle == 0 || taken(LoadHandle(handle)) {
+        handle = handle.wrapping_add(1) & HANDLE_MASK;
+    }
+    LoadHandle(handle)
+}
+
 impl LoaderState {
     fn get_or_insert_indirect(&self, id: IndirectIdentifier) -> LoadHandle {
         if let Some(handle) = self.indirect_to_load.get(&id) {
             *handle
         } else {
-            let new_handle = self.handle_allocator.alloc();
+            let new_handle = if self.deterministic_handles {
+                derive_handle(&id, |handle| {
+                    self.indirect_states.contains_key(&handle.set_indirect())
+                })
+            } else {
+                self.handle_allocator.alloc()
+            };
             let new_handle = new_handle.set_indirect();
             log::trace!(
                 "Inserting indirect load for {:?} load handle {:?}",
@@ -180,7 +221,11 @@ impl LoaderState {
 
     fn get_or_insert(&self, id: AssetUuid) -> LoadHandle {
         let handle = *self.uuid_to_load.entry(id).or_insert_with(|| {
-            let new_handle = self.handle_allocator.alloc();
+            let new_handle = if self.deterministic_handles {
+                derive_handle(&id, |handle| self.load_states.contains_key(&handle))
+            } else {
+                self.handle_allocator.alloc()
+            };
 
             log::trace!(
                 "Inserting load state for {:?} load handle {:?}",
@@ -198,11 +243,14 @@ impl LoaderState {
                         metadata: None,
                         state: LoadState::None,
                         version: 1,
+                        update_retries: 0,
+                        error: None,
                     }],
                     version_counter: 1,
                     last_state_change_instant: std::time::Instant::now(),
                     refs: AtomicUsize::new(0),
                     pending_reload: false,
+                    prefetched_at: None,
                 },
             );
             new_handle
@@ -216,6 +264,13 @@ impl LoaderState {
             .map(|h| h.refs.fetch_add(num_refs, Ordering::Relaxed));
         handle
     }
+    fn prefetch(&self, id: AssetUuid) -> LoadHandle {
+        let handle = self.get_or_insert(id);
+        if let Some(mut load) = self.load_states.get_mut(&handle) {
+            load.prefetched_at = Some(std::time::Instant::now());
+        }
+        handle
+    }
     fn get_asset(&self, load: LoadHandle) -> Option<AssetTypeId> {
         let load = if load.is_indirect() {
             self.indirect_table.resolve(load)?
@@ -251,7 +306,7 @@ impl LoaderState {
     }
 
     fn add_ref_indirect(&self, id: IndirectIdentifier) -> LoadHandle {
-        let handle = self.get_or_insert_indirect(id);
+        let handle = self.get_or_insert_indirect(id.normalized());
         let state = self.indirect_states.get(&handle).unwrap();
         if let Some(uuid) = state.resolved_uuid {
             self.add_refs(uuid, 1);
@@ -270,7 +325,18 @@ impl LoaderState {
             let mut entry = self.load_states.get_mut(&key).unwrap();
             let load = entry.value_mut();
 
-            let has_refs = load.refs.load(Ordering::Relaxed) > 0;
+            if let Some(prefetched_at) = load.prefetched_at {
+                // A prefetch expires once the asset has settled and nothing referenced it in time
+                let settled = load
+                    .versions
+                    .iter()
+                    .all(|v| matches!(v.state, LoadState::Loaded | LoadState::Failed));
+                let idle_since = prefetched_at.max(load.last_state_change_instant);
+                if settled && idle_since.elapsed() >= self.prefetch_lifetime {
+                    load.prefetched_at = None;
+                }
+            }
+            let has_refs = load.refs.load(Ordering::Relaxed) > 0 || load.prefetched_at.is_some();
             if !has_refs && load.versions.is_empty() {
                 to_remove.push(key);
             } else {
@@ -279,7 +345,7 @@ impl LoaderState {
                     if load
                         .versions
                         .iter()
-                        .all(|v| matches!(v.state, LoadState::Loaded))
+                        .all(|v| matches!(v.state, LoadState::Loaded | LoadState::Failed))
                     {
                         load.version_counter += 1;
                         let new_version = load.version_counter;
@@ -291,6 +357,8 @@ impl LoaderState {
                             auto_commit: false,
                             state: LoadState::None,
                             version: new_version,
+                            update_retries: 0,
+                            error: None,
                         });
                         load.pending_reload = false;
                     }
@@ -336,7 +404,7 @@ impl LoaderState {
                             // Add ref to each of the dependent assets.
                             if let Some(artifact) = version_load.metadata.as_ref() {
                                 for dependency_asset_id in &artifact.load_deps {
-                                    if let AssetRef::Uuid(uuid) = dependency_asset_id {
+                                    if let Some(uuid) = dependency_asset_id.as_uuid() {
                                         self.add_refs(*uuid, 1);
                                     }
                                 }
@@ -420,6 +488,14 @@ impl LoaderState {
                             // Should we have confirmation from engine here?
                             LoadState::None
                         }
+                        LoadState::Failed => {
+                            // Unloading releases the dependencies of the failed version
+                            if !has_refs || newest_version > version_load.version {
+                                LoadState::UnloadRequested
+                            } else {
+                                LoadState::Failed
+                            }
+                        }
                     };
                     if version_load.state != new_state {
                         state_change = true;
@@ -526,6 +602,8 @@ impl LoaderState {
                                 metadata: Some(metadata),
                                 state: LoadState::None,
                                 version: new_version,
+                                update_retries: 0,
+                                error: None,
                             });
                         }
                     }
@@ -577,6 +655,7 @@ impl LoaderState {
                 .load_states
                 .get(&handle)
                 .expect("load did not exist when data request completed");
+            let mut retried = false;
             let load_result = match result {
                 Ok(artifact_data) => {
                     let version_load = load
@@ -585,30 +664,52 @@ impl LoaderState {
                         .find(|v| v.version == version)
                         .expect("load version did not exist when data request completed");
 
-                    let artifact_type = version_load.metadata.as_ref().unwrap().type_id;
+                    let artifact_metadata = version_load.metadata.clone().unwrap();
+                    let artifact_type = artifact_metadata.type_id;
                     let asset_id = load.asset_id;
+                    let update_retries = version_load.update_retries;
                     log::trace!("asset data request succeeded for asset {:?}", load.asset_id);
                     // We don't want to be holding a lock to the load while calling AssetStorage::update_asset in `load_data`,
                     // so we drop the load ref, and save the state transition as a return value.
                     drop(load);
-                    let update_result = storage.update_asset(
-                        self,
+                    let update_result = storage.try_update_asset(
+                        &ArtifactLoadInfo {
+                            loader: self,
+                            metadata: artifact_metadata,
+                        },
                         &artifact_type,
                         artifact_data,
                         response.1,
                         AssetLoadOp::new(self.op_tx.clone(), handle, version),
                         response.2,
                     );
-                    if let Err(storage_error) = update_result {
-                        error!(
-                            "AssetStorage implementor error when updating asset {:?}: {}",
-                            asset_id, storage_error
-                        );
-                        AssetLoadResult::from_state(LoadState::WaitingForData)
-                    } else {
-                        AssetLoadResult {
+                    match update_result {
+                        UpdateResult::Accepted => AssetLoadResult {
                             asset_type: Some(artifact_type),
                             new_state: LoadState::LoadingAsset,
+                            error: None,
+                        },
+                        UpdateResult::Retry(storage_error)
+                            if update_retries < self.max_update_retries =>
+                        {
+                            log::warn!(
+                                "AssetStorage implementor requested a retry when updating asset {:?}: {}",
+                                asset_id, storage_error
+                            );
+                            retried = true;
+                            AssetLoadResult::from_state(LoadState::WaitingForData)
+                        }
+                        UpdateResult::Retry(storage_error)
+                        | UpdateResult::Failed(storage_error) => {
+                            error!(
+                                "AssetStorage implementor error when updating asset {:?}: {}",
+                                asset_id, storage_error
+                            );
+                            AssetLoadResult {
+                                asset_type: None,
+                                new_state: LoadState::Failed,
+                                error: Some(storage_error.to_string()),
+                            }
                         }
                     }
                 }
@@ -630,12 +731,31 @@ impl LoaderState {
                 .find(|v| v.version == version)
                 .expect("load version did not exist when data request completed");
             version_load.state = load_result.new_state;
+            version_load.error = load_result.error;
             if let Some(asset_type) = load_result.asset_type {
                 version_load.asset_type = Some(asset_type);
             }
+            if retried {
+                version_load.update_retries += 1;
+            }
         }
+        let mut in_flight = self
+            .load_states
+            .iter()
+            .map(|load| {
+                load.versions
+                    .iter()
+                    .filter(|v| {
+                        matches!(v.state, LoadState::RequestingData | LoadState::LoadingAsset)
+                    })
+                    .count()
+            })
+            .sum::<usize>();
         let mut assets_to_request = Vec::new();
         for mut load in self.load_states.iter_mut() {
+            if in_flight >= self.max_data_requests {
+                break;
+            }
             let handle = *load.key();
             let load = load.value_mut();
 
@@ -645,6 +765,7 @@ impl LoaderState {
                 .find(|v| matches!(v.state, LoadState::WaitingForData))
             {
                 version_load.state = LoadState::RequestingData;
+                in_flight += 1;
                 let artifact_id = version_load.metadata.as_ref().unwrap().id;
                 assets_to_request.push(DataRequest {
                     tx: self.responses.data_tx.clone(),
@@ -680,10 +801,20 @@ impl LoaderState {
                         load_version.state = LoadState::LoadedUncommitted;
                     }
                 }
-                HandleOp::Drop(handle, version) => panic!(
-                    "load op dropped without calling complete/error, handle {:?} version {}",
-                    handle, version
-                ),
+                HandleOp::Drop(handle, version) => {
+                    // the op of an update that the storage did not accept can be dropped
+                    let rejected = self.load_states.get(&handle).map_or(false, |load| {
+                        load.versions.iter().any(|v| {
+                            v.version == version && !matches!(v.state, LoadState::LoadingAsset)
+                        })
+                    });
+                    if !rejected {
+                        panic!(
+                            "load op dropped without calling complete/error, handle {:?} version {}",
+                            handle, version
+                        );
+                    }
+                }
             }
         }
     }
@@ -812,9 +943,9 @@ impl LoaderState {
                     if let Some(new_uuid) = new_uuid {
                         let uuid_handle = self.get_or_insert(new_uuid);
                         self.add_refs(new_uuid, num_refs);
-                        self.indirect_table.0.insert(load_handle, uuid_handle);
+                        self.indirect_table.insert(load_handle, uuid_handle);
                     } else {
-                        self.indirect_table.0.remove(&load_handle);
+                        self.indirect_table.remove(load_handle);
                     }
                     state.resolved_uuid = new_uuid;
                     state.state = IndirectHandleState::Resolved;
@@ -858,7 +989,29 @@ impl LoaderInfoProvider for LoaderState {
         self.uuid_to_load.get(id.expect_uuid()).map(|l| *l)
     }
     fn get_asset_id(&self, load: LoadHandle) -> Option<AssetUuid> {
-        self.load_states.get(&load).map(|l| l.asset_id)
+        if load.is_indirect() {
+            self.indirect_states.get(&load)?.resolved_uuid
+        } else {
+            self.load_states.get(&load).map(|l| l.asset_id)
+        }
+    }
+}
+
+/// [`LoaderInfoProvider`] passed to [`AssetStorage::update_asset`], which knows the artifact being loaded.
+struct ArtifactLoadInfo<'a> {
+    loader: &'a LoaderState,
+    metadata: ArtifactMetadata,
+}
+
+impl<'a> LoaderInfoProvider for ArtifactLoadInfo<'a> {
+    fn get_load_handle(&self, id: &AssetRef) -> Option<LoadHandle> {
+        self.loader.get_load_handle(id)
+    }
+    fn get_asset_id(&self, load: LoadHandle) -> Option<AssetUuid> {
+        self.loader.get_asset_id(load)
+    }
+    fn current_artifact_metadata(&self) -> Option<&ArtifactMetadata> {
+        Some(&self.metadata)
     }
 }
 
@@ -887,7 +1040,7 @@ impl Loader {
                 pending_reloads: Vec::new(),
                 indirect_states: DashMap::new(),
                 indirect_to_load: DashMap::new(),
-                indirect_table: IndirectionTable(Arc::new(DashMap::new())),
+                indirect_table: IndirectionTable(Arc::new(RwLock::new(HashMap::new()))),
                 responses: IORequestChannels {
                     metadata_rx,
                     metadata_tx,
@@ -896,6 +1049,10 @@ impl Loader {
                     resolve_tx,
                     resolve_rx,
                 },
+                max_data_requests: usize::MAX,
+                max_update_retries: 3,
+                deterministic_handles: false,
+                prefetch_lifetime: std::time::Duration::from_secs(10),
             },
             io,
         }
@@ -920,7 +1077,73 @@ impl Loader {
     pub fn get_load(&self, id: AssetUuid) -> Option<LoadHandle> {
         self.data.uuid_to_load.get(&id).map(|l| *l)
     }
-    /// Returns the number of references to an asset.
+    /// Returns the UUID of the asset that a load handle refers to.
+    ///
+    /// Indirect handles are resolved with the loader's [`IndirectionTable`], so this returns `None`
+    /// for an indirect handle that has not been resolved yet.
+    ///
+    /// # Parameters
+    ///
+    /// * `load`: ID allocated by `Loader` to track loading of the asset.
+    pub fn handle_to_uuid(&self, load: LoadHandle) -> Option<AssetUuid> {
+        let load = if load.is_indirect() {
+            self.data.indirect_table.resolve(load)?
+        } else {
+            load
+        };
+        self.data.get_asset_id(load)
+    }
+
+    /// Returns the direct load handle of the asset with the given UUID, if the asset has been
+    /// referenced directly or through a resolved indirect handle. The inverse of
+    /// [`Loader::handle_to_uuid`], and the same as [`Loader::get_load`].
+    ///
+    /// # Parameters
+    ///
+    /// * `id`: UUID of the asset.
+    pub fn uuid_to_handle(&self, id: AssetUuid) -> Option<LoadHandle> {
+        self.get_load(id)
+    }
+
+    /// Requests the metadata of assets and of all assets they depend on to build, for tools that
+    /// inspect the build graph. None of the assets are loaded. The result is sent to the returned
+    /// channel once [`LoaderIO`] provides it, which may take calls to [`Loader::process`].
+    ///
+    /// # Parameters
+    ///
+    /// * `ids`: UUIDs of the assets.
+    pub fn request_build_dependencies(
+        &mut self,
+        ids: &[AssetUuid],
+    ) -> Receiver<Result<Vec<AssetMetadata>>> {
+        let (tx, rx) = unbounded();
+        self.io
+            .get_asset_metadata_with_build_dependencies(BuildDepsRequest {
+                tx,
+                assets: Some(ids.to_vec()),
+            });
+        rx
+    }
+
+    /// Requests the assets that have a search tag with `key`, and with `value` if it is set,
+    /// for tools such as editor search boxes. None of the assets are loaded. The result is sent to
+    /// the returned channel once [`LoaderIO`] provides it, which may take calls to
+    /// [`Loader::process`].
+    pub fn search_assets(
+        &mut self,
+        key: &str,
+        value: Option<&str>,
+    ) -> Receiver<Result<Vec<AssetUuid>>> {
+        let (tx, rx) = unbounded();
+        self.io.search_assets(SearchRequest {
+            tx,
+            query: Some((key.to_string(), value.map(str::to_string))),
+        });
+        rx
+    }
+
+    /// Returns the number of references to an asset, and the size of its artifact if its metadata
+    /// has been received.
     ///
     /// **Note:** The information is true at the time the `LoadInfo` is retrieved. The actual number
     /// of references may change.
@@ -934,9 +1157,19 @@ impl Loader {
         } else {
             load
         };
-        self.data.load_states.get(&load).map(|s| LoadInfo {
-            asset_id: s.asset_id,
-            refs: s.refs.load(Ordering::Relaxed) as u32,
+        self.data.load_states.get(&load).map(|s| {
+            let metadata = s
+                .versions
+                .iter()
+                .filter(|v| v.metadata.is_some())
+                .max_by_key(|v| v.version)
+                .and_then(|v| v.metadata.as_ref());
+            LoadInfo {
+                asset_id: s.asset_id,
+                refs: s.refs.load(Ordering::Relaxed) as u32,
+                uncompressed_size: metadata.and_then(|m| m.uncompressed_size),
+                compressed_size: metadata.and_then(|m| m.compressed_size),
+            }
         })
     }
 
@@ -968,6 +1201,12 @@ impl Loader {
                     }
                     LoadState::Loaded => LoadStatus::Loaded,
                     LoadState::UnloadRequested | LoadState::Unloading => LoadStatus::Unloading,
+                    LoadState::Failed => LoadStatus::Error(
+                        v.error
+                            .clone()
+                            .unwrap_or_else(|| "asset storage failed to update the asset".into())
+                            .into(),
+                    ),
                     _ => LoadStatus::Loading,
                 })
                 .unwrap_or(LoadStatus::NotRequested)
@@ -976,6 +1215,30 @@ impl Loader {
         }
     }
 
+    /// Returns the assets whose newest version failed to load, with their load handle and the
+    /// error reported by the [`AssetStorage`], ordered by handle. Their [`LoadStatus`] is
+    /// [`LoadStatus::Error`] until they are reloaded or unloaded.
+    pub fn failed_loads(&self) -> Vec<(LoadHandle, AssetUuid, String)> {
+        let mut failed: Vec<(LoadHandle, AssetUuid, String)> = self
+            .data
+            .load_states
+            .iter()
+            .filter_map(|entry| {
+                let version = entry.versions.iter().max_by_key(|v| v.version)?;
+                if version.state != LoadState::Failed {
+                    return None;
+                }
+                let error = version
+                    .error
+                    .clone()
+                    .unwrap_or_else(|| "asset storage failed to update the asset".into());
+                Some((*entry.key(), entry.asset_id, error))
+            })
+            .collect();
+        failed.sort_by_key(|(load, _, _)| load.0);
+        failed
+    }
+
     /// Adds a reference to an asset and returns its [`LoadHandle`].
     ///
     /// If the asset is already loaded, this returns the existing [`LoadHandle`]. If it is not
@@ -988,6 +1251,18 @@ impl Loader {
         self.data.add_refs(id, 1)
     }
 
+    /// Starts loading an asset and its load dependencies without adding a reference to it, and
+    /// returns its [`LoadHandle`]. A later [`Loader::add_ref`] then finds the asset loaded or
+    /// already on its way. If nothing references the asset within the prefetch lifetime after it
+    /// has loaded, it is unloaded again. See [`Loader::set_prefetch_lifetime`].
+    ///
+    /// # Parameters
+    ///
+    /// * `id`: UUID of the asset.
+    pub fn prefetch(&self, id: AssetUuid) -> LoadHandle {
+        self.data.prefetch(id)
+    }
+
     /// Adds a reference to an indirect id and returns its [`LoadHandle`] with [`LoadHandle::is_indirect`] set to `true`.
     ///
     /// # Parameters
@@ -997,6 +1272,45 @@ impl Loader {
         self.data.add_ref_indirect(id)
     }
 
+    /// Returns the number of references to a load handle, or `None` if the loader does not know
+    /// the handle. References held by handles from the `handle` module are counted once their
+    /// [`RefOp`](crate::handle::RefOp)s have been processed.
+    ///
+    /// For an indirect handle, this is the number of references to the indirect handle itself.
+    ///
+    /// # Parameters
+    ///
+    /// * `load`: ID allocated by `Loader` to track loading of the asset.
+    #[cfg(any(test, feature = "debug_refcounts"))]
+    pub fn debug_refcount(&self, load: LoadHandle) -> Option<usize> {
+        if load.is_indirect() {
+            self.data
+                .indirect_states
+                .get(&load)
+                .map(|state| state.refs.load(Ordering::Relaxed))
+        } else {
+            self.data
+                .load_states
+                .get(&load)
+                .map(|state| state.refs.load(Ordering::Relaxed))
+        }
+    }
+
+    /// Returns the direct load handles of all assets that have references, with their number of
+    /// references, ordered by handle.
+    #[cfg(any(test, feature = "debug_refcounts"))]
+    pub fn loaded_handles(&self) -> Vec<(LoadHandle, usize)> {
+        let mut handles: Vec<(LoadHandle, usize)> = self
+            .data
+            .load_states
+            .iter()
+            .map(|entry| (*entry.key(), entry.refs.load(Ordering::Relaxed)))
+            .filter(|(_, refs)| *refs > 0)
+            .collect();
+        handles.sort_by_key(|(load, _)| load.0);
+        handles
+    }
+
     /// Returns the [`AssetTypeId`] for the currently loaded asset of the provided load handle.
     ///
     /// # Parameters
@@ -1045,6 +1359,49 @@ impl Loader {
         Ok(())
     }
 
+    /// Limits the number of assets whose data is requested from the [`LoaderIO`] or being loaded
+    /// by the [`AssetStorage`] at the same time, which bounds memory use during bulk loads.
+    /// Further data requests wait until loads complete. Unlimited by default.
+    ///
+    /// # Parameters
+    ///
+    /// * `limit`: Maximum number of in-flight data requests, at least 1.
+    pub fn set_max_data_requests(&mut self, limit: usize) {
+        self.data.max_data_requests = limit.max(1);
+    }
+
+    /// Sets how many times the loader updates an asset again when the [`AssetStorage`] returns
+    /// [`UpdateResult::Retry`] for it. The asset fails to load after that. Defaults to 3.
+    ///
+    /// # Parameters
+    ///
+    /// * `retries`: Maximum number of retries per asset version.
+    pub fn set_max_update_retries(&mut self, retries: u32) {
+        self.data.max_update_retries = retries;
+    }
+
+    /// Derives the [`LoadHandle`] of an asset from its [`AssetUuid`], and the handle of an
+    /// indirect load from its [`IndirectIdentifier`], instead of using the [`HandleAllocator`].
+    /// Handles then stay the same across runs, so logs and recorded sessions can refer to them.
+    /// Set it before any asset is referenced. Disabled by default.
+    ///
+    /// # Parameters
+    ///
+    /// * `deterministic`: Whether handles are derived from what they load.
+    pub fn set_deterministic_handles(&mut self, deterministic: bool) {
+        self.data.deterministic_handles = deterministic;
+    }
+
+    /// Sets how long an asset loaded by [`Loader::prefetch`] stays loaded without references
+    /// after it has finished loading. Defaults to 10 seconds.
+    ///
+    /// # Parameters
+    ///
+    /// * `lifetime`: Time to wait for a reference before unloading a prefetched asset.
+    pub fn set_prefetch_lifetime(&mut self, lifetime: std::time::Duration) {
+        self.data.prefetch_lifetime = lifetime;
+    }
+
     /// Returns a reference to the loader's [`IndirectionTable`].
     ///
     /// When a user fetches an asset by LoadHandle, implementors of [`AssetStorage`]
@@ -1061,6 +1418,54 @@ impl Loader {
     pub fn invalidate_assets(&self, assets: &[AssetUuid]) {
         self.data.invalidate_assets(assets);
     }
+
+    /// *feature:* `blocking_load`. Adds a reference to an asset and calls [`Loader::process`]
+    /// until the asset is loaded, so tests don't have to drive the loader themselves. Indirect
+    /// references are resolved with [`DefaultIndirectionResolver`](crate::storage::DefaultIndirectionResolver).
+    ///
+    /// The returned handle holds the reference, release it with [`Loader::remove_ref`]. If the asset
+    /// fails to load or is not loaded within `timeout`, the reference is released and an error is
+    /// returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `id`: UUID of the asset.
+    /// * `asset_storage`: Storage for all assets of all asset types.
+    /// * `timeout`: How long to wait for the asset to be loaded.
+    #[cfg(any(test, feature = "blocking_load"))]
+    pub fn load_blocking(
+        &mut self,
+        id: AssetUuid,
+        asset_storage: &dyn AssetStorage,
+        timeout: std::time::Duration,
+    ) -> Result<LoadHandle> {
+        let handle = self.add_ref(id);
+        let deadline = std::time::Instant::now() + timeout;
+        loop {
+            if let Err(err) =
+                self.process(asset_storage, &crate::storage::DefaultIndirectionResolver)
+            {
+                self.remove_ref(handle);
+                return Err(err);
+            }
+            let err = match self.get_load_status(handle) {
+                LoadStatus::Loaded => return Ok(handle),
+                LoadStatus::Error(err) => format!("failed to load asset {:?}: {}", id, err),
+                _ if std::time::Instant::now() >= deadline => {
+                    format!("timed out waiting for asset {:?} to load", id)
+                }
+                _ => {
+                    std::thread::sleep(std::time::Duration::from_millis(1));
+                    continue;
+                }
+            };
+            self.remove_ref(handle);
+            return Err(Box::new(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                err,
+            )));
+        }
+    }
 }
 
 fn commit_asset(
@@ -1069,6 +1474,10 @@ fn commit_asset(
     version: u32,
     asset_storage: &dyn AssetStorage,
 ) {
+    let newer_version_committed = load
+        .versions
+        .iter()
+        .any(|v| v.version > version && v.state == LoadState::Loaded);
     let version_load = load
         .versions
         .iter_mut()
@@ -1078,15 +1487,27 @@ fn commit_asset(
         LoadState::LoadingAsset == version_load.state
             || LoadState::LoadedUncommitted == version_load.state
     );
+    if newer_version_committed {
+        // The storage must never see a version committed over a newer one, so the outdated
+        // version is freed instead.
+        log::debug!(
+            "discarding version {} of {:?}, a newer version is already committed",
+            version,
+            handle
+        );
+        version_load.state = LoadState::UnloadRequested;
+        return;
+    }
     let asset_type = version_load
         .asset_type
         .as_ref()
         .expect("in LoadingAsset state but asset_type is None");
     asset_storage.commit_asset_version(asset_type, handle, version_load.version);
     version_load.state = LoadState::Loaded;
+    // The committed version replaces older loaded ones. Older versions that are still loading
+    // are discarded by the `newer_version_committed` check above once they complete.
     for version_load in load.versions.iter_mut() {
-        if version_load.version != version {
-            assert!(LoadState::Loaded == version_load.state);
+        if version_load.version < version && version_load.state == LoadState::Loaded {
             version_load.state = LoadState::UnloadRequested;
         }
     }
@@ -1095,10 +1516,16 @@ fn commit_asset(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{rpc_io::RpcIO, storage::DefaultIndirectionResolver};
+    use crate::{
+        handle::{process_ref_ops, AssetHandle, Handle, SerdeContext},
+        rpc_io::RpcIO,
+        storage::DefaultIndirectionResolver,
+    };
     use atelier_core::AssetUuid;
-    use atelier_daemon::{init_logging, AssetDaemon};
-    use atelier_importer::{AsyncImporter, ImportedAsset, ImporterValue, Result as ImportResult};
+    use atelier_daemon::{init_logging, AssetDaemon, ServiceAddress};
+    use atelier_importer::{
+        AsyncImporter, ImportOp, ImportedAsset, ImporterValue, Result as ImportResult,
+    };
     use futures_core::future::BoxFuture;
     use futures_io::AsyncRead;
     use futures_util::io::AsyncReadExt;
@@ -1108,7 +1535,7 @@ mod tests {
         path::PathBuf,
         str::FromStr,
         string::FromUtf8Error,
-        sync::RwLock,
+        sync::{Mutex, RwLock},
         thread::{self, JoinHandle},
     };
     use type_uuid::TypeUuid;
@@ -1119,6 +1546,7 @@ mod tests {
         size: Option<usize>,
         commit_version: Option<u32>,
         load_version: Option<u32>,
+        load_deps: Vec<AssetRef>,
     }
     struct Storage {
         map: RwLock<HashMap<LoadHandle, LoadState>>,
@@ -1126,7 +1554,7 @@ mod tests {
     impl AssetStorage for Storage {
         fn update_asset(
             &self,
-            _loader_info: &dyn LoaderInfoProvider,
+            loader_info: &dyn LoaderInfoProvider,
             _asset_type: &AssetTypeId,
             data: Vec<u8>,
             loader_handle: LoadHandle,
@@ -1134,14 +1562,27 @@ mod tests {
             version: u32,
         ) -> Result<()> {
             println!("update asset {:?} data size {}", loader_handle, data.len());
+            // Read the artifact's own metadata the way a Deserialize impl would
+            let load_deps =
+                futures_executor::block_on(SerdeContext::with(loader_info, unbounded().0, async {
+                    SerdeContext::with_active(|loader, _| {
+                        loader
+                            .current_artifact_metadata()
+                            .expect("no artifact metadata during update_asset")
+                            .load_deps
+                            .clone()
+                    })
+                }));
             let mut map = self.map.write().unwrap();
             let state = map.entry(loader_handle).or_insert(LoadState {
                 size: None,
                 commit_version: None,
                 load_version: None,
+                load_deps: Vec::new(),
             });
 
             state.size = Some(data.len());
+            state.load_deps = load_deps;
             state.load_version = Some(version);
             load_op.complete();
             Ok(())
@@ -1166,6 +1607,156 @@ mod tests {
         }
     }
 
+    /// Holds on to load ops until the test completes them, tracking how many were in flight.
+    struct DeferredStorage {
+        pending: Mutex<Vec<AssetLoadOp>>,
+        max_in_flight: AtomicUsize,
+    }
+    impl AssetStorage for DeferredStorage {
+        fn update_asset(
+            &self,
+            _loader_info: &dyn LoaderInfoProvider,
+            _asset_type: &AssetTypeId,
+            _data: Vec<u8>,
+            _loader_handle: LoadHandle,
+            load_op: AssetLoadOp,
+            _version: u32,
+        ) -> Result<()> {
+            let mut pending = self.pending.lock().unwrap();
+            pending.push(load_op);
+            self.max_in_flight.fetch_max(pending.len(), Ordering::Relaxed);
+            Ok(())
+        }
+        fn commit_asset_version(
+            &self,
+            _asset_type: &AssetTypeId,
+            _loader_handle: LoadHandle,
+            _version: u32,
+        ) {
+        }
+        fn free(&self, _asset_type: &AssetTypeId, _loader_handle: LoadHandle, _version: u32) {}
+    }
+
+    /// Asks for a retry on the first update of each asset, like a storage that lost its device.
+    struct RetryStorage {
+        attempts: Mutex<HashMap<LoadHandle, u32>>,
+    }
+    impl AssetStorage for RetryStorage {
+        fn update_asset(
+            &self,
+            _loader_info: &dyn LoaderInfoProvider,
+            _asset_type: &AssetTypeId,
+            _data: Vec<u8>,
+            _loader_handle: LoadHandle,
+            load_op: AssetLoadOp,
+            _version: u32,
+        ) -> Result<()> {
+            load_op.complete();
+            Ok(())
+        }
+        fn try_update_asset(
+            &self,
+            loader_info: &dyn LoaderInfoProvider,
+            asset_type: &AssetTypeId,
+            data: Vec<u8>,
+            loader_handle: LoadHandle,
+            load_op: AssetLoadOp,
+            version: u32,
+        ) -> UpdateResult {
+            let mut attempts = self.attempts.lock().unwrap();
+            let attempts = attempts.entry(loader_handle).or_insert(0);
+            *attempts += 1;
+            if *attempts == 1 {
+                return UpdateResult::Retry(Box::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    "device lost",
+                )));
+            }
+            match self.update_asset(
+                loader_info,
+                asset_type,
+                data,
+                loader_handle,
+                load_op,
+                version,
+            ) {
+                Ok(()) => UpdateResult::Accepted,
+                Err(err) => UpdateResult::Failed(err),
+            }
+        }
+        fn commit_asset_version(
+            &self,
+            _asset_type: &AssetTypeId,
+            _loader_handle: LoadHandle,
+            _version: u32,
+        ) {
+        }
+        fn free(&self, _asset_type: &AssetTypeId, _loader_handle: LoadHandle, _version: u32) {}
+    }
+
+    /// Fails to update the given assets, like a storage that ran out of memory for them.
+    struct FailingStorage {
+        failing: Vec<AssetUuid>,
+    }
+    impl AssetStorage for FailingStorage {
+        fn update_asset(
+            &self,
+            loader_info: &dyn LoaderInfoProvider,
+            _asset_type: &AssetTypeId,
+            _data: Vec<u8>,
+            loader_handle: LoadHandle,
+            load_op: AssetLoadOp,
+            _version: u32,
+        ) -> Result<()> {
+            let asset_id = loader_info.get_asset_id(loader_handle).unwrap();
+            if self.failing.contains(&asset_id) {
+                return Err(Box::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    format!("out of memory for {:?}", asset_id),
+                )));
+            }
+            load_op.complete();
+            Ok(())
+        }
+        fn commit_asset_version(
+            &self,
+            _asset_type: &AssetTypeId,
+            _loader_handle: LoadHandle,
+            _version: u32,
+        ) {
+        }
+        fn free(&self, _asset_type: &AssetTypeId, _loader_handle: LoadHandle, _version: u32) {}
+    }
+
+    /// Records the versions that are committed.
+    #[derive(Default)]
+    struct RecordingStorage {
+        committed: Mutex<Vec<u32>>,
+    }
+    impl AssetStorage for RecordingStorage {
+        fn update_asset(
+            &self,
+            _loader_info: &dyn LoaderInfoProvider,
+            _asset_type: &AssetTypeId,
+            _data: Vec<u8>,
+            _loader_handle: LoadHandle,
+            load_op: AssetLoadOp,
+            _version: u32,
+        ) -> Result<()> {
+            load_op.complete();
+            Ok(())
+        }
+        fn commit_asset_version(
+            &self,
+            _asset_type: &AssetTypeId,
+            _loader_handle: LoadHandle,
+            version: u32,
+        ) {
+            self.committed.lock().unwrap().push(version);
+        }
+        fn free(&self, _asset_type: &AssetTypeId, _loader_handle: LoadHandle, _version: u32) {}
+    }
+
     /// Removes file comments (begin with `#`) and empty lines.
     #[derive(Clone, Debug, Default, Deserialize, Serialize, TypeUuid)]
     #[uuid = "346e6a3e-3278-4c53-b21c-99b4350662db"]
@@ -1213,13 +1804,14 @@ mod tests {
 
         fn import<'a>(
             &'a self,
+            op: &'a mut ImportOp,
             source: &'a mut (dyn AsyncRead + Unpin + Send + Sync),
             txt_format: &'a Self::Options,
             state: &'a mut Self::State,
         ) -> BoxFuture<'a, ImportResult<ImporterValue>> {
             Box::pin(async move {
                 if state.id.is_none() {
-                    state.id = Some(AssetUuid(*uuid::Uuid::new_v4().as_bytes()));
+                    state.id = Some(op.new_asset_uuid());
                 }
                 let mut bytes = Vec::new();
                 source.read_to_end(&mut bytes).await?;
@@ -1235,40 +1827,177 @@ mod tests {
 
                 Ok(ImporterValue {
                     assets: vec![ImportedAsset {
-                        id: state.id.expect("AssetUuid not generated"),
-                        search_tags: Vec::new(),
-                        build_deps: Vec::new(),
                         load_deps,
-                        asset_data: Box::new(parsed_asset_data),
-                        build_pipeline: None,
+                        ..ImportedAsset::new(
+                            state.id.expect("AssetUuid not generated"),
+                            Box::new(parsed_asset_data),
+                        )
                     }],
                 })
             })
         }
     }
 
-    fn wait_for_status(
-        status: LoadStatus,
-        handle: LoadHandle,
-        loader: &mut Loader,
-        storage: &Storage,
-    ) {
-        loop {
-            println!(
-                "state {:?} expecting {:?}",
-                loader.get_load_status(handle),
-                status
-            );
-            if std::mem::discriminant(&status)
-                == std::mem::discriminant(&loader.get_load_status(handle))
-            {
-                break;
-            }
-            std::thread::sleep(std::time::Duration::from_millis(10));
-            if let Err(e) = loader.process(storage, &DefaultIndirectionResolver) {
-                println!("err {:?}", e);
-            }
+    /// Produces a single asset whose load dependencies are the paths listed in the
+    /// source file. The paths are resolved to asset UUIDs by the daemon.
+    #[derive(TypeUuid)]
+    #[uuid = "1bbd0d3b-2bd5-4e0b-8a59-5b1b3a1e25a0"]
+    struct ManifestImporter;
+    impl AsyncImporter for ManifestImporter {
+        type State = TxtImporterState;
+        type Options = TxtFormat;
+
+        fn version_static() -> u32
+        where
+            Self: Sized,
+        {
+            1
+        }
+        fn version(&self) -> u32 {
+            Self::version_static()
+        }
+
+        fn import<'a>(
+            &'a self,
+            op: &'a mut ImportOp,
+            source: &'a mut (dyn AsyncRead + Unpin + Send + Sync),
+            txt_format: &'a Self::Options,
+            state: &'a mut Self::State,
+        ) -> BoxFuture<'a, ImportResult<ImporterValue>> {
+            Box::pin(async move {
+                if state.id.is_none() {
+                    state.id = Some(op.new_asset_uuid());
+                }
+                let mut bytes = Vec::new();
+                source.read_to_end(&mut bytes).await?;
+                let manifest = txt_format
+                    .from_utf8(bytes)
+                    .expect("Failed to construct manifest.");
+
+                let load_deps = manifest
+                    .lines()
+                    .map(|line| AssetRef::Path(PathBuf::from(line)))
+                    .collect::<Vec<AssetRef>>();
+
+                Ok(ImporterValue {
+                    assets: vec![ImportedAsset {
+                        load_deps,
+                        ..ImportedAsset::new(
+                            state.id.expect("AssetUuid not generated"),
+                            Box::new(manifest),
+                        )
+                    }],
+                })
+            })
+        }
+    }
+
+    /// Produces a single asset that is labeled with the contents of the source file.
+    #[derive(TypeUuid)]
+    #[uuid = "5e9d2c47-83a1-4f6b-9d0e-b2c8a4f17e35"]
+    struct LabelImporter;
+    impl AsyncImporter for LabelImporter {
+        type State = TxtImporterState;
+        type Options = TxtFormat;
+
+        fn version_static() -> u32
+        where
+            Self: Sized,
+        {
+            1
+        }
+        fn version(&self) -> u32 {
+            Self::version_static()
+        }
+
+        fn import<'a>(
+            &'a self,
+            op: &'a mut ImportOp,
+            source: &'a mut (dyn AsyncRead + Unpin + Send + Sync),
+            txt_format: &'a Self::Options,
+            state: &'a mut Self::State,
+        ) -> BoxFuture<'a, ImportResult<ImporterValue>> {
+            Box::pin(async move {
+                if state.id.is_none() {
+                    state.id = Some(op.new_asset_uuid());
+                }
+                let mut bytes = Vec::new();
+                source.read_to_end(&mut bytes).await?;
+                let label = txt_format
+                    .from_utf8(bytes)
+                    .expect("Failed to construct label.")
+                    .trim()
+                    .to_string();
+
+                Ok(ImporterValue {
+                    assets: vec![ImportedAsset {
+                        label: Some(label.clone()),
+                        ..ImportedAsset::new(
+                            state.id.expect("AssetUuid not generated"),
+                            Box::new(label),
+                        )
+                    }],
+                })
+            })
+        }
+    }
+
+    fn wait_for_status(
+        status: LoadStatus,
+        handle: LoadHandle,
+        loader: &mut Loader,
+        storage: &dyn AssetStorage,
+    ) {
+        loop {
+            println!(
+                "state {:?} expecting {:?}",
+                loader.get_load_status(handle),
+                status
+            );
+            if std::mem::discriminant(&status)
+                == std::mem::discriminant(&loader.get_load_status(handle))
+            {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            if let Err(e) = loader.process(storage, &DefaultIndirectionResolver) {
+                println!("err {:?}", e);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rpc_io_runtime_handle() {
+        let runtime = tokio::runtime::Builder::new()
+            .basic_scheduler()
+            .enable_all()
+            .build()
+            .unwrap();
+        // no daemon is needed, the loader keeps trying to connect
+        let io = RpcIO::new_with_runtime("127.0.0.1:2534".to_string(), runtime);
+        let handle = io.runtime_handle();
+        let mut loader = Loader::new(Box::new(io));
+        let storage = Storage {
+            map: RwLock::new(HashMap::new()),
+        };
+
+        let (tx, rx) = unbounded();
+        handle.spawn(async move {
+            tokio::task::yield_now().await;
+            tx.send(42).unwrap();
+        });
+        let mut result = rx.try_recv();
+        for _ in 0..500 {
+            if result.is_ok() {
+                break;
+            }
+            loader
+                .process(&storage, &DefaultIndirectionResolver)
+                .unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            result = rx.try_recv();
         }
+        assert_eq!(Ok(42), result);
     }
 
     #[test]
@@ -1289,7 +2018,7 @@ mod tests {
                     .as_bytes(),
             ),
         );
-        let storage = &mut Storage {
+        let storage = Storage {
             map: RwLock::new(HashMap::new()),
         };
         wait_for_status(LoadStatus::Loaded, handle, &mut loader, &storage);
@@ -1315,11 +2044,22 @@ mod tests {
                     .as_bytes(),
             ),
         );
-        let storage = &mut Storage {
+        let storage = Storage {
             map: RwLock::new(HashMap::new()),
         };
         wait_for_status(LoadStatus::Loaded, handle, &mut loader, &storage);
 
+        // The storage saw the asset's own load dependencies while loading it
+        let expected_deps: Vec<AssetRef> = asset_tree()
+            .into_iter()
+            .filter(|(_, file_name)| *file_name == "asset_b.txt" || *file_name == "asset_d.txt")
+            .map(|(asset_uuid, _)| AssetRef::Uuid(asset_uuid))
+            .collect();
+        assert_eq!(
+            expected_deps,
+            storage.map.read().unwrap().get(&handle).unwrap().load_deps
+        );
+
         // Check that dependent assets are loaded
         let asset_handles = asset_tree()
             .iter()
@@ -1361,6 +2101,664 @@ mod tests {
             });
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_connect_unix_socket() {
+        let _ = init_logging(); // Another test may have initialized logging, so we ignore errors.
+
+        // Start daemon in a separate thread
+        let socket_dir = tempfile::tempdir().unwrap();
+        let socket_path = socket_dir.path().join("daemon.sock");
+        let _atelier_daemon = spawn_daemon_at(ServiceAddress::Unix(socket_path.clone()));
+
+        let mut loader = Loader::new(Box::new(
+            RpcIO::new(crate::RpcAddress::Unix(socket_path)).unwrap(),
+        ));
+        let handle = loader.add_ref(
+            // asset uuid of "tests/assets/asset.txt"
+            AssetUuid(
+                *uuid::Uuid::parse_str("60352042-616f-460e-abd2-546195c060fe")
+                    .unwrap()
+                    .as_bytes(),
+            ),
+        );
+        let storage = Storage {
+            map: RwLock::new(HashMap::new()),
+        };
+        wait_for_status(LoadStatus::Loaded, handle, &mut loader, &storage);
+        loader.remove_ref(handle);
+        wait_for_status(LoadStatus::NotRequested, handle, &mut loader, &storage);
+    }
+
+    #[cfg(feature = "tls")]
+    #[test]
+    fn test_connect_tls() {
+        let _ = init_logging(); // Another test may have initialized logging, so we ignore errors.
+
+        // Start daemon in a separate thread
+        let daemon_port = 2535;
+        let daemon_address = format!("127.0.0.1:{}", daemon_port);
+        let tls_path = PathBuf::from_iter(&[env!("CARGO_MANIFEST_DIR"), "tests", "tls"]);
+        let daemon_tls = atelier_daemon::TlsConfig {
+            cert_path: tls_path.join("daemon.pem"),
+            key_path: tls_path.join("daemon.key.pem"),
+            client_ca_path: Some(tls_path.join("ca.pem")),
+        };
+        let socket_address = daemon_address.parse::<std::net::SocketAddr>().unwrap();
+        let _atelier_daemon = spawn_configured_daemon(move |daemon| {
+            daemon.with_address(socket_address).with_tls(daemon_tls)
+        });
+
+        let loader_tls = crate::TlsConfig {
+            ca_path: tls_path.join("ca.pem"),
+            domain: "localhost".to_string(),
+            client_cert: Some((tls_path.join("loader.pem"), tls_path.join("loader.key.pem"))),
+        };
+        let rpc_io = RpcIO::new(daemon_address)
+            .unwrap()
+            .with_tls(&loader_tls)
+            .unwrap();
+        let mut loader = Loader::new(Box::new(rpc_io));
+        let handle = loader.add_ref(
+            // asset uuid of "tests/assets/asset.txt"
+            AssetUuid(
+                *uuid::Uuid::parse_str("60352042-616f-460e-abd2-546195c060fe")
+                    .unwrap()
+                    .as_bytes(),
+            ),
+        );
+        let storage = Storage {
+            map: RwLock::new(HashMap::new()),
+        };
+        wait_for_status(LoadStatus::Loaded, handle, &mut loader, &storage);
+        loader.remove_ref(handle);
+        wait_for_status(LoadStatus::NotRequested, handle, &mut loader, &storage);
+    }
+
+    #[test]
+    fn test_load_info_reports_artifact_sizes() {
+        let _ = init_logging(); // Another test may have initialized logging, so we ignore errors.
+
+        // Start daemon in a separate thread
+        let daemon_port = 2515;
+        let daemon_address = format!("127.0.0.1:{}", daemon_port);
+        let _atelier_daemon = spawn_daemon(&daemon_address);
+
+        let mut loader = Loader::new(Box::new(RpcIO::new(daemon_address).unwrap()));
+        let handle = loader.add_ref(
+            // asset uuid of "tests/assets/asset_a.txt"
+            AssetUuid(
+                *uuid::Uuid::parse_str("a5ce4da0-675e-4460-be02-c8b145c2ee49")
+                    .unwrap()
+                    .as_bytes(),
+            ),
+        );
+        let storage = Storage {
+            map: RwLock::new(HashMap::new()),
+        };
+        wait_for_status(LoadStatus::Loaded, handle, &mut loader, &storage);
+
+        // Every asset in the batch reports the size of the data handed to the storage
+        let mut total_bytes = 0;
+        for (asset_uuid, file_name) in asset_tree() {
+            let load_handle = loader
+                .get_load(asset_uuid)
+                .unwrap_or_else(|| panic!("Expected `{}` to be loaded.", file_name));
+            let info = loader.get_load_info(load_handle).unwrap();
+            let stored_size = storage
+                .map
+                .read()
+                .unwrap()
+                .get(&load_handle)
+                .and_then(|state| state.size)
+                .unwrap() as u64;
+            assert_eq!(Some(stored_size), info.uncompressed_size, "{}", file_name);
+            assert_eq!(Some(stored_size), info.compressed_size, "{}", file_name);
+            total_bytes += info.uncompressed_size.unwrap();
+        }
+        assert!(total_bytes > 0);
+
+        loader.remove_ref(handle);
+        wait_for_status(LoadStatus::NotRequested, handle, &mut loader, &storage);
+    }
+
+    #[test]
+    fn test_max_data_requests() {
+        let _ = init_logging(); // Another test may have initialized logging, so we ignore errors.
+
+        // Start daemon in a separate thread
+        let daemon_port = 2520;
+        let daemon_address = format!("127.0.0.1:{}", daemon_port);
+        let _atelier_daemon = spawn_daemon(&daemon_address);
+
+        let mut loader = Loader::new(Box::new(RpcIO::new(daemon_address).unwrap()));
+        loader.set_max_data_requests(1);
+        let handle = loader.add_ref(
+            // asset uuid of "tests/assets/asset_a.txt"
+            AssetUuid(
+                *uuid::Uuid::parse_str("a5ce4da0-675e-4460-be02-c8b145c2ee49")
+                    .unwrap()
+                    .as_bytes(),
+            ),
+        );
+        let storage = DeferredStorage {
+            pending: Mutex::new(Vec::new()),
+            max_in_flight: AtomicUsize::new(0),
+        };
+        while !matches!(loader.get_load_status(handle), LoadStatus::Loaded) {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            if let Err(e) = loader.process(&storage, &DefaultIndirectionResolver) {
+                println!("err {:?}", e);
+            }
+            for load_op in storage.pending.lock().unwrap().drain(..) {
+                load_op.complete();
+            }
+        }
+
+        // asset_a and all of its dependencies were loaded, one at a time
+        for (asset_uuid, file_name) in asset_tree() {
+            let load_handle = loader
+                .get_load(asset_uuid)
+                .unwrap_or_else(|| panic!("Expected `{}` to be loaded.", file_name));
+            assert!(matches!(
+                loader.get_load_status(load_handle),
+                LoadStatus::Loaded
+            ));
+        }
+        assert_eq!(1, storage.max_in_flight.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn test_update_retry() {
+        let _ = init_logging(); // Another test may have initialized logging, so we ignore errors.
+
+        // Start daemon in a separate thread
+        let daemon_port = 2532;
+        let daemon_address = format!("127.0.0.1:{}", daemon_port);
+        let _atelier_daemon = spawn_daemon(&daemon_address);
+
+        let mut loader = Loader::new(Box::new(RpcIO::new(daemon_address).unwrap()));
+        let handle = loader.add_ref(
+            // asset uuid of "tests/assets/asset_a.txt"
+            AssetUuid(
+                *uuid::Uuid::parse_str("a5ce4da0-675e-4460-be02-c8b145c2ee49")
+                    .unwrap()
+                    .as_bytes(),
+            ),
+        );
+        let storage = RetryStorage {
+            attempts: Mutex::new(HashMap::new()),
+        };
+        wait_for_status(LoadStatus::Loaded, handle, &mut loader, &storage);
+
+        // every asset was loaded on the second attempt
+        for (asset_uuid, file_name) in asset_tree() {
+            let load_handle = loader
+                .get_load(asset_uuid)
+                .unwrap_or_else(|| panic!("Expected `{}` to be loaded.", file_name));
+            assert_eq!(Some(&2), storage.attempts.lock().unwrap().get(&load_handle));
+        }
+    }
+
+    #[test]
+    fn test_failed_loads() {
+        let _ = init_logging(); // Another test may have initialized logging, so we ignore errors.
+
+        // Start daemon in a separate thread
+        let daemon_port = 2537;
+        let daemon_address = format!("127.0.0.1:{}", daemon_port);
+        let _atelier_daemon = spawn_daemon(&daemon_address);
+
+        let mut loader = Loader::new(Box::new(RpcIO::new(daemon_address).unwrap()));
+        let tree = asset_tree();
+        let (asset_a, _) = tree[0];
+        let (asset_b, _) = tree[1];
+        let (asset_c, _) = tree[2];
+        let storage = FailingStorage {
+            failing: vec![asset_b, asset_c],
+        };
+        // the asset stops waiting for its dependencies once they failed
+        let handle = loader.add_ref(asset_a);
+        wait_for_status(LoadStatus::Loaded, handle, &mut loader, &storage);
+
+        let mut expected = vec![
+            (loader.get_load(asset_b).unwrap(), asset_b),
+            (loader.get_load(asset_c).unwrap(), asset_c),
+        ];
+        expected.sort_by_key(|(load, _)| load.0);
+        let failed = loader.failed_loads();
+        assert_eq!(2, failed.len());
+        for ((load, asset_id, error), (expected_load, expected_id)) in
+            failed.iter().zip(expected.iter())
+        {
+            assert_eq!(expected_load, load);
+            assert_eq!(expected_id, asset_id);
+            assert_eq!(&format!("out of memory for {:?}", asset_id), error);
+            match loader.get_load_status(*load) {
+                LoadStatus::Error(err) => assert_eq!(error, &err.to_string()),
+                _ => panic!("Expected a failed load to have the `Error` status."),
+            }
+        }
+    }
+
+    #[test]
+    fn test_commit_out_of_order_versions() {
+        // the test module has a `LoadState` of its own
+        let version_load = |version| AssetVersionLoad {
+            state: super::LoadState::LoadingAsset,
+            metadata: None,
+            asset_type: Some(AssetTypeId([1; 16])),
+            auto_commit: true,
+            version,
+            update_retries: 0,
+            error: None,
+        };
+        let mut load = AssetLoad {
+            asset_id: AssetUuid([2; 16]),
+            last_state_change_instant: std::time::Instant::now(),
+            refs: AtomicUsize::new(1),
+            versions: vec![version_load(1), version_load(2), version_load(3)],
+            version_counter: 3,
+            pending_reload: false,
+            prefetched_at: None,
+        };
+        let handle = LoadHandle(1);
+        let storage = RecordingStorage::default();
+
+        // version 2 completes first, then the newest one, then the oldest one
+        commit_asset(handle, &mut load, 2, &storage);
+        commit_asset(handle, &mut load, 3, &storage);
+        commit_asset(handle, &mut load, 1, &storage);
+
+        assert_eq!(vec![2, 3], *storage.committed.lock().unwrap());
+        let states: Vec<(u32, super::LoadState)> =
+            load.versions.iter().map(|v| (v.version, v.state)).collect();
+        assert_eq!(
+            vec![
+                (1, super::LoadState::UnloadRequested),
+                (2, super::LoadState::UnloadRequested),
+                (3, super::LoadState::Loaded),
+            ],
+            states
+        );
+    }
+
+    #[test]
+    fn test_deterministic_handles() {
+        let assets = [
+            "a5ce4da0-675e-4460-be02-c8b145c2ee49",
+            "039dc5f8-d5a2-4c1f-8b3e-8d0c9f2a1b6e",
+            "60352042-616f-460e-abd2-546195c060fe",
+        ]
+        .iter()
+        .map(|id| AssetUuid(*uuid::Uuid::parse_str(id).unwrap().as_bytes()))
+        .collect::<Vec<_>>();
+        let path = IndirectIdentifier::Path("asset_a.txt".to_string());
+        let session = |assets: &[AssetUuid]| {
+            // the loader never connects, handles are assigned when a reference is added
+            let mut loader = Loader::new(Box::new(RpcIO::default()));
+            loader.set_deterministic_handles(true);
+            let mut handles = assets
+                .iter()
+                .map(|id| (*id, loader.add_ref(*id)))
+                .collect::<Vec<_>>();
+            handles.sort_by_key(|(id, _)| *id);
+            (handles, loader.add_ref_indirect(path.clone()))
+        };
+
+        let (first_handles, first_indirect) = session(&assets);
+        let reversed = assets.iter().rev().copied().collect::<Vec<_>>();
+        let (second_handles, second_indirect) = session(&reversed);
+
+        // handles don't depend on the order in which assets were referenced
+        assert_eq!(first_handles, second_handles);
+        assert_eq!(first_indirect, second_indirect);
+        assert!(first_indirect.is_indirect());
+        assert!(first_handles
+            .iter()
+            .all(|(_, handle)| !handle.is_indirect()));
+    }
+
+    #[test]
+    fn test_handle_serde_round_trip() {
+        #[derive(Serialize, Deserialize)]
+        struct Material {
+            texture: Handle<String>,
+        }
+
+        let texture_uuid = "2e5c2b9e-5b4f-4d0e-9c41-8f6a7d3b1c20";
+        let texture = AssetUuid(*Uuid::parse_str(texture_uuid).unwrap().as_bytes());
+        let loader = Loader::new(Box::new(RpcIO::default()));
+        let load = loader.add_ref(texture);
+        let (tx, _rx) = unbounded();
+        let json = format!("{{\"texture\":\"{}\"}}", texture_uuid);
+
+        let (reserialized, bytes, from_bytes) = loader.with_serde_context(&tx, || {
+            let material: Material = serde_json::from_str(&json).unwrap();
+            let bytes = bincode::serialize(&material).unwrap();
+            let from_bytes: Material = bincode::deserialize(&bytes).unwrap();
+            (
+                serde_json::to_string(&material).unwrap(),
+                bytes,
+                from_bytes.texture.load_handle(),
+            )
+        });
+
+        assert_eq!(json, reserialized);
+        assert!(bytes.ends_with(&texture.0));
+        assert_eq!(load, from_bytes);
+    }
+
+    #[test]
+    fn test_load_blocking() {
+        let _ = init_logging(); // Another test may have initialized logging, so we ignore errors.
+
+        // Start daemon in a separate thread
+        let daemon_port = 2533;
+        let daemon_address = format!("127.0.0.1:{}", daemon_port);
+        let _atelier_daemon = spawn_daemon(&daemon_address);
+
+        let mut loader = Loader::new(Box::new(RpcIO::new(daemon_address).unwrap()));
+        let storage = Storage {
+            map: RwLock::new(HashMap::new()),
+        };
+        let handle = loader
+            .load_blocking(
+                // asset uuid of "tests/assets/asset_a.txt"
+                AssetUuid(
+                    *uuid::Uuid::parse_str("a5ce4da0-675e-4460-be02-c8b145c2ee49")
+                        .unwrap()
+                        .as_bytes(),
+                ),
+                &storage,
+                std::time::Duration::from_secs(30),
+            )
+            .expect("asset_a.txt failed to load");
+        assert_eq!(
+            std::mem::discriminant(&LoadStatus::Loaded),
+            std::mem::discriminant(&loader.get_load_status(handle))
+        );
+        assert!(storage.map.read().unwrap().contains_key(&handle));
+
+        // an asset that doesn't exist is never loaded
+        let result = loader.load_blocking(
+            AssetUuid([0xff; 16]),
+            &storage,
+            std::time::Duration::from_millis(100),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_prefetch() {
+        let _ = init_logging(); // Another test may have initialized logging, so we ignore errors.
+
+        // Start daemon in a separate thread
+        let daemon_port = 2539;
+        let daemon_address = format!("127.0.0.1:{}", daemon_port);
+        let _atelier_daemon = spawn_daemon(&daemon_address);
+
+        let mut loader = Loader::new(Box::new(RpcIO::new(daemon_address).unwrap()));
+        loader.set_prefetch_lifetime(std::time::Duration::from_millis(200));
+        let storage = Storage {
+            map: RwLock::new(HashMap::new()),
+        };
+        // asset uuid of "tests/assets/asset_a.txt"
+        let asset_a = AssetUuid(
+            *uuid::Uuid::parse_str("a5ce4da0-675e-4460-be02-c8b145c2ee49")
+                .unwrap()
+                .as_bytes(),
+        );
+
+        // prefetching loads the asset and its dependencies without referencing the asset
+        let handle = loader.prefetch(asset_a);
+        wait_for_status(LoadStatus::Loaded, handle, &mut loader, &storage);
+        assert_eq!(Some(0), loader.debug_refcount(handle));
+        for (asset_uuid, file_name) in asset_tree() {
+            let dependency = loader.get_load(asset_uuid).unwrap();
+            assert_eq!(
+                std::mem::discriminant(&LoadStatus::Loaded),
+                std::mem::discriminant(&loader.get_load_status(dependency)),
+                "Expected `{}` to be loaded.",
+                file_name
+            );
+        }
+
+        // a real load finds the asset loaded without processing
+        assert_eq!(handle, loader.add_ref(asset_a));
+        assert_eq!(
+            std::mem::discriminant(&LoadStatus::Loaded),
+            std::mem::discriminant(&loader.get_load_status(handle))
+        );
+        loader.remove_ref(handle);
+        wait_for_status(LoadStatus::NotRequested, handle, &mut loader, &storage);
+
+        // a prefetched asset that is never referenced is unloaded again
+        loader.prefetch(asset_a);
+        wait_for_status(LoadStatus::Loaded, handle, &mut loader, &storage);
+        wait_for_status(LoadStatus::NotRequested, handle, &mut loader, &storage);
+        assert!(!storage.map.read().unwrap().contains_key(&handle));
+    }
+
+    #[test]
+    fn test_rpc_message_limits() {
+        let _ = init_logging(); // Another test may have initialized logging, so we ignore errors.
+
+        // Start daemon in a separate thread
+        let daemon_port = 2538;
+        let daemon_address = format!("127.0.0.1:{}", daemon_port);
+        let service_address: std::net::SocketAddr = daemon_address.parse().unwrap();
+        let _atelier_daemon = spawn_configured_daemon(move |daemon| {
+            daemon
+                .with_service_address(service_address.into())
+                .with_rpc_message_limits(1 << 20, 64)
+        });
+        // asset uuid of "tests/assets/asset_a.txt"
+        let asset_a = AssetUuid(
+            *uuid::Uuid::parse_str("a5ce4da0-675e-4460-be02-c8b145c2ee49")
+                .unwrap()
+                .as_bytes(),
+        );
+        let storage = Storage {
+            map: RwLock::new(HashMap::new()),
+        };
+
+        // the responses with the asset's metadata don't fit in 32 words
+        let rpc_io = RpcIO::new(daemon_address.clone())
+            .unwrap()
+            .with_message_limits(32, 64);
+        let mut loader = Loader::new(Box::new(rpc_io));
+        let result = loader.load_blocking(asset_a, &storage, std::time::Duration::from_secs(2));
+        assert!(result.is_err());
+
+        let rpc_io = RpcIO::new(daemon_address)
+            .unwrap()
+            .with_message_limits(1 << 20, 64);
+        let mut loader = Loader::new(Box::new(rpc_io));
+        let handle = loader
+            .load_blocking(asset_a, &storage, std::time::Duration::from_secs(30))
+            .expect("asset_a.txt failed to load");
+        assert!(storage.map.read().unwrap().contains_key(&handle));
+    }
+
+    #[test]
+    fn test_load_with_path_dependencies() {
+        let _ = init_logging(); // Another test may have initialized logging, so we ignore errors.
+
+        // Start daemon in a separate thread
+        let daemon_port = 2510;
+        let daemon_address = format!("127.0.0.1:{}", daemon_port);
+        let _atelier_daemon = spawn_daemon(&daemon_address);
+
+        let mut loader = Loader::new(Box::new(RpcIO::new(daemon_address).unwrap()));
+        let handle = loader.add_ref(
+            // asset uuid of "tests/assets/scene.manifest"
+            AssetUuid(
+                *uuid::Uuid::parse_str("7b8f1c6e-5f0d-4b7e-9a1f-3c2d4e5f6a7b")
+                    .unwrap()
+                    .as_bytes(),
+            ),
+        );
+        let storage = Storage {
+            map: RwLock::new(HashMap::new()),
+        };
+        wait_for_status(LoadStatus::Loaded, handle, &mut loader, &storage);
+
+        // The paths listed in the manifest must have been resolved to the UUIDs of the
+        // referenced assets, including their own dependencies.
+        let asset_handles = asset_tree()
+            .into_iter()
+            .filter(|(_, file_name)| *file_name != "asset_a.txt")
+            .map(|(asset_uuid, file_name)| {
+                let asset_load_handle = loader
+                    .get_load(asset_uuid)
+                    .unwrap_or_else(|| panic!("Expected `{}` to be loaded.", file_name));
+                assert_eq!(
+                    std::mem::discriminant(&LoadStatus::Loaded),
+                    std::mem::discriminant(&loader.get_load_status(asset_load_handle)),
+                    "Expected `{}` to be loaded.",
+                    file_name
+                );
+                asset_load_handle
+            })
+            .collect::<Vec<LoadHandle>>();
+
+        loader.remove_ref(handle);
+        wait_for_status(LoadStatus::NotRequested, handle, &mut loader, &storage);
+        for asset_load_handle in asset_handles {
+            wait_for_status(
+                LoadStatus::NotRequested,
+                asset_load_handle,
+                &mut loader,
+                &storage,
+            );
+        }
+    }
+
+    #[test]
+    fn test_indirect_paths_are_normalized() {
+        let _ = init_logging(); // Another test may have initialized logging, so we ignore errors.
+
+        // Start daemon in a separate thread
+        let daemon_port = 2525;
+        let daemon_address = format!("127.0.0.1:{}", daemon_port);
+        let _atelier_daemon = spawn_daemon(&daemon_address);
+
+        let mut loader = Loader::new(Box::new(RpcIO::new(daemon_address).unwrap()));
+        let handle = loader.add_ref_indirect(IndirectIdentifier::Path(
+            "./subdir//../asset.txt".to_string(),
+        ));
+        assert_eq!(
+            handle,
+            loader.add_ref_indirect(IndirectIdentifier::Path("asset.txt".to_string()))
+        );
+        let storage = Storage {
+            map: RwLock::new(HashMap::new()),
+        };
+        wait_for_status(LoadStatus::Loaded, handle, &mut loader, &storage);
+
+        // asset uuid of "tests/assets/asset.txt"
+        let asset_uuid = AssetUuid(
+            *uuid::Uuid::parse_str("60352042-616f-460e-abd2-546195c060fe")
+                .unwrap()
+                .as_bytes(),
+        );
+        assert_eq!(
+            loader.get_load(asset_uuid),
+            loader.indirection_table().resolve(handle)
+        );
+    }
+
+    #[test]
+    fn test_handle_uuid_lookup() {
+        let _ = init_logging(); // Another test may have initialized logging, so we ignore errors.
+
+        // Start daemon in a separate thread
+        let daemon_port = 2530;
+        let daemon_address = format!("127.0.0.1:{}", daemon_port);
+        let _atelier_daemon = spawn_daemon(&daemon_address);
+
+        let mut loader = Loader::new(Box::new(RpcIO::new(daemon_address).unwrap()));
+        // asset uuid of "tests/assets/asset.txt"
+        let asset_uuid = AssetUuid(
+            *uuid::Uuid::parse_str("60352042-616f-460e-abd2-546195c060fe")
+                .unwrap()
+                .as_bytes(),
+        );
+        let indirect_handle =
+            loader.add_ref_indirect(IndirectIdentifier::Path("asset.txt".to_string()));
+        assert_eq!(None, loader.handle_to_uuid(indirect_handle));
+
+        let handle = loader.add_ref(asset_uuid);
+        let storage = Storage {
+            map: RwLock::new(HashMap::new()),
+        };
+        wait_for_status(LoadStatus::Loaded, handle, &mut loader, &storage);
+        wait_for_status(LoadStatus::Loaded, indirect_handle, &mut loader, &storage);
+
+        assert_eq!(Some(asset_uuid), loader.handle_to_uuid(handle));
+        assert_eq!(Some(handle), loader.uuid_to_handle(asset_uuid));
+        assert_eq!(Some(asset_uuid), loader.handle_to_uuid(indirect_handle));
+        assert_eq!(None, loader.uuid_to_handle(AssetUuid([0; 16])));
+    }
+
+    #[test]
+    fn test_load_by_label() {
+        let _ = init_logging(); // Another test may have initialized logging, so we ignore errors.
+
+        // Start daemon in a separate thread
+        let daemon_port = 2536;
+        let daemon_address = format!("127.0.0.1:{}", daemon_port);
+        let _atelier_daemon = spawn_daemon(&daemon_address);
+
+        let mut loader = Loader::new(Box::new(RpcIO::new(daemon_address).unwrap()));
+        let handle = loader.add_ref_indirect(IndirectIdentifier::Label("player_mesh".to_string()));
+        let storage = Storage {
+            map: RwLock::new(HashMap::new()),
+        };
+        wait_for_status(LoadStatus::Loaded, handle, &mut loader, &storage);
+
+        // asset uuid of "tests/assets/player.label"
+        let asset_uuid = AssetUuid(
+            *uuid::Uuid::parse_str("9a4e6c1d-2b7f-4e85-a3d0-6f1b8c2e7d49")
+                .unwrap()
+                .as_bytes(),
+        );
+        assert_eq!(Some(asset_uuid), loader.handle_to_uuid(handle));
+        assert_eq!(
+            loader.get_load(asset_uuid),
+            loader.indirection_table().resolve(handle)
+        );
+    }
+
+    #[test]
+    fn test_debug_refcounts() {
+        // the loader does not connect until it is processed
+        let loader = Loader::new(Box::new(RpcIO::new("127.0.0.1:2531".to_string()).unwrap()));
+        let (tx, rx) = crossbeam_channel::unbounded();
+        let first = AssetUuid([1; 16]);
+        let second = AssetUuid([2; 16]);
+
+        let handle: Handle<()> = Handle::new(tx.clone(), loader.add_ref(first));
+        let other: Handle<()> = Handle::new(tx.clone(), loader.add_ref(second));
+        let clone = handle.clone();
+        process_ref_ops(&loader, &rx);
+        assert_eq!(Some(2), loader.debug_refcount(handle.load_handle()));
+        assert_eq!(Some(1), loader.debug_refcount(other.load_handle()));
+        assert_eq!(
+            vec![(handle.load_handle(), 2), (other.load_handle(), 1)],
+            loader.loaded_handles()
+        );
+
+        let load = handle.load_handle();
+        drop(handle);
+        drop(clone);
+        process_ref_ops(&loader, &rx);
+        assert_eq!(Some(0), loader.debug_refcount(load));
+        assert_eq!(vec![(other.load_handle(), 1)], loader.loaded_handles());
+        assert_eq!(None, loader.debug_refcount(LoadHandle(u64::MAX >> 1)));
+    }
+
     fn asset_tree() -> Vec<(AssetUuid, &'static str)> {
         [
             ("a5ce4da0-675e-4460-be02-c8b145c2ee49", "asset_a.txt"),
@@ -1380,18 +2778,29 @@ mod tests {
     }
 
     fn spawn_daemon(daemon_address: &str) -> JoinHandle<()> {
-        let daemon_address = daemon_address
+        let daemon_address: std::net::SocketAddr = daemon_address
             .parse()
             .expect("Failed to parse string as `SocketAddr`.");
+        spawn_daemon_at(daemon_address.into())
+    }
+
+    fn spawn_daemon_at(daemon_address: ServiceAddress) -> JoinHandle<()> {
+        spawn_configured_daemon(move |daemon| daemon.with_service_address(daemon_address))
+    }
+
+    fn spawn_configured_daemon(
+        configure: impl FnOnce(AssetDaemon) -> AssetDaemon + Send + 'static,
+    ) -> JoinHandle<()> {
         thread::Builder::new()
             .name("atelier-daemon".to_string())
             .spawn(move || {
                 let tests_path = PathBuf::from_iter(&[env!("CARGO_MANIFEST_DIR"), "tests"]);
 
-                AssetDaemon::default()
+                configure(AssetDaemon::default())
                     .with_db_path(tests_path.join("assets_db"))
-                    .with_address(daemon_address)
                     .with_importer("txt", TxtImporter)
+                    .with_importer("manifest", ManifestImporter)
+                    .with_importer("label", LabelImporter)
                     .with_asset_dirs(vec![tests_path.join("assets")])
                     .run();
             })
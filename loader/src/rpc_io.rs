@@ -7,10 +7,13 @@ use futures_channel::oneshot;
 use futures_util::AsyncReadExt;
 use std::sync::Mutex;
 use std::{error::Error, path::PathBuf};
-use tokio::runtime::{Builder, Runtime};
+use tokio::runtime::{Builder, Handle, Runtime};
 
-use crate::io::{DataRequest, LoaderIO, MetadataRequest, ResolveRequest};
+use crate::io::{
+    BuildDepsRequest, DataRequest, LoaderIO, MetadataRequest, ResolveRequest, SearchRequest,
+};
 use crate::loader::LoaderState;
+use crate::storage::IndirectIdentifier;
 
 type Promise<T> = capnp::capability::Promise<T, capnp::Error>;
 
@@ -48,10 +51,15 @@ struct QueuedRequests {
     data_requests: Vec<DataRequest>,
     metadata_requests: Vec<MetadataRequest>,
     resolve_requests: Vec<ResolveRequest>,
+    build_deps_requests: Vec<BuildDepsRequest>,
+    search_requests: Vec<SearchRequest>,
 }
 
 pub struct RpcIO {
-    connect_string: String,
+    address: RpcAddress,
+    tls: ClientTls,
+    /// Limits for the messages read from the daemon
+    reader_options: ReaderOptions,
     runtime: Mutex<RpcRuntime>,
     requests: QueuedRequests,
 }
@@ -59,6 +67,50 @@ pub fn default_connect_string() -> &'static str {
     "127.0.0.1:9999"
 }
 
+/// Where [`RpcIO`] connects to the daemon.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RpcAddress {
+    /// A host and port, such as [`default_connect_string`].
+    Tcp(String),
+    /// *unix only.* The path of the Unix domain socket that the daemon listens on.
+    #[cfg(unix)]
+    Unix(PathBuf),
+}
+
+impl From<String> for RpcAddress {
+    fn from(connect_string: String) -> Self {
+        RpcAddress::Tcp(connect_string)
+    }
+}
+
+impl From<&str> for RpcAddress {
+    fn from(connect_string: &str) -> Self {
+        RpcAddress::Tcp(connect_string.to_string())
+    }
+}
+
+/// *feature:* `tls`. How [`RpcIO`] verifies a daemon that encrypts its connections with TLS, and
+/// authenticates to it. All files are PEM encoded.
+#[cfg(feature = "tls")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TlsConfig {
+    /// CA certificates that sign the certificate of the daemon.
+    pub ca_path: PathBuf,
+    /// The domain name that the daemon's certificate is issued for, such as `localhost`.
+    pub domain: String,
+    /// The certificate chain and private key of the loader, for daemons that only accept
+    /// loaders with a certificate signed by their CA. The key is in PKCS #8 or RSA format.
+    pub client_cert: Option<(PathBuf, PathBuf)>,
+}
+
+/// The TLS settings of a connection, which is unencrypted unless the `tls` feature is enabled
+/// and [`RpcIO::with_tls`] was called.
+#[derive(Clone, Default)]
+struct ClientTls {
+    #[cfg(feature = "tls")]
+    connector: Option<(tokio_rustls::TlsConnector, tokio_rustls::webpki::DNSName)>,
+}
+
 impl Default for RpcIO {
     fn default() -> RpcIO {
         RpcIO::new(default_connect_string().to_string()).unwrap()
@@ -66,17 +118,103 @@ impl Default for RpcIO {
 }
 
 impl RpcIO {
-    pub fn new(connect_string: String) -> std::io::Result<RpcIO> {
-        Ok(RpcIO {
-            connect_string,
+    /// Creates an `RpcIO` that connects to the daemon at `address`, which is either an
+    /// [`RpcAddress`] or a TCP connect string.
+    pub fn new(address: impl Into<RpcAddress>) -> std::io::Result<RpcIO> {
+        let runtime = Builder::new().basic_scheduler().enable_all().build()?;
+        Ok(RpcIO::new_with_runtime(address, runtime))
+    }
+
+    /// Creates an `RpcIO` that runs on `runtime`, so the engine decides how its threads are set up.
+    /// The runtime needs the IO and time drivers to be enabled.
+    pub fn new_with_runtime(address: impl Into<RpcAddress>, runtime: Runtime) -> RpcIO {
+        RpcIO {
+            address: address.into(),
+            tls: ClientTls::default(),
+            reader_options: *ReaderOptions::new()
+                .nesting_limit(64)
+                .traversal_limit_in_words(64 * 1024 * 1024),
             runtime: Mutex::new(RpcRuntime {
-                runtime: Builder::new().basic_scheduler().enable_all().build()?,
+                runtime,
                 local: tokio::task::LocalSet::new(),
                 connection: InternalConnectionState::None,
             }),
             requests: Default::default(),
-        })
+        }
+    }
+
+    /// Returns a handle to the runtime, see [`LoaderIO::with_runtime`]. Tasks spawned on a basic
+    /// scheduler runtime only make progress while [`Loader::process`](crate::Loader::process) is
+    /// called.
+    pub fn runtime_handle(&self) -> Handle {
+        self.runtime.lock().unwrap().runtime.handle().clone()
     }
+
+    /// Sets the limits of the RPC messages read from the daemon: the number of 8 byte words that
+    /// may be traversed in a message, and how deeply structs and lists may nest. Responses that
+    /// exceed them fail, so raise them when the metadata or artifacts of assets are larger. The
+    /// defaults are 64Mi words (512MiB) and 64.
+    pub fn with_message_limits(
+        mut self,
+        traversal_limit_in_words: u64,
+        nesting_limit: i32,
+    ) -> Self {
+        self.reader_options = *ReaderOptions::new()
+            .nesting_limit(nesting_limit)
+            .traversal_limit_in_words(traversal_limit_in_words);
+        self
+    }
+
+    /// *feature:* `tls`. Connects to the daemon over TLS, for daemons started with
+    /// `AssetDaemon::with_tls`. Returns an error if the certificates in `config` can't be loaded.
+    #[cfg(feature = "tls")]
+    pub fn with_tls(mut self, config: &TlsConfig) -> std::io::Result<Self> {
+        use tokio_rustls::{
+            rustls::{internal::pemfile, ClientConfig},
+            webpki::DNSNameRef,
+        };
+        let mut client_config = ClientConfig::new();
+        for cert in read_pem(&config.ca_path, pemfile::certs)? {
+            client_config
+                .root_store
+                .add(&cert)
+                .map_err(|err| invalid_data(format!("{}: {}", config.ca_path.display(), err)))?;
+        }
+        if let Some((cert_path, key_path)) = &config.client_cert {
+            let certs = read_pem(cert_path, pemfile::certs)?;
+            let mut keys = read_pem(key_path, pemfile::pkcs8_private_keys)?;
+            if keys.is_empty() {
+                keys = read_pem(key_path, pemfile::rsa_private_keys)?;
+            }
+            let key = keys.pop().ok_or_else(|| {
+                invalid_data(format!("{} contains no private key", key_path.display()))
+            })?;
+            client_config
+                .set_single_client_cert(certs, key)
+                .map_err(|err| invalid_data(format!("invalid TLS certificate: {}", err)))?;
+        }
+        let domain = DNSNameRef::try_from_ascii_str(&config.domain)
+            .map_err(|_| invalid_data(format!("{} is not a valid domain name", config.domain)))?;
+        self.tls.connector = Some((
+            tokio_rustls::TlsConnector::from(std::sync::Arc::new(client_config)),
+            domain.to_owned(),
+        ));
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "tls")]
+fn read_pem<T>(
+    path: &std::path::Path,
+    read: fn(&mut dyn std::io::BufRead) -> Result<Vec<T>, ()>,
+) -> std::io::Result<Vec<T>> {
+    let mut reader = std::io::BufReader::new(std::fs::File::open(path)?);
+    read(&mut reader).map_err(|_| invalid_data(format!("{} is not valid PEM", path.display())))
+}
+
+#[cfg(feature = "tls")]
+fn invalid_data(message: String) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message)
 }
 impl RpcRuntime {
     fn check_asset_changes(&mut self, loader: &LoaderState) {
@@ -109,43 +247,44 @@ impl RpcRuntime {
             };
     }
 
-    fn connect(&mut self, connect_string: &str) {
+    fn connect(&mut self, address: &RpcAddress, tls: &ClientTls, reader_options: ReaderOptions) {
         match self.connection {
             InternalConnectionState::Connected(_) | InternalConnectionState::Connecting(_) => {
                 panic!("Trying to connect while already connected or connecting")
             }
             _ => {}
         };
-        use std::net::ToSocketAddrs;
-        let addr = connect_string.to_socket_addrs().unwrap().next().unwrap();
+        let address = address.clone();
+        let tls = tls.clone();
         let (conn_tx, conn_rx) = oneshot::channel();
         self.local.spawn_local(async move {
             let result = async move {
-                let stream = ::tokio::net::TcpStream::connect(&addr)
-                    .await
-                    .map_err(|e| -> Box<dyn Error> { Box::new(e) })?;
-                stream.set_nodelay(true)?;
-                use tokio_util::compat::*;
-                let (reader, writer) = stream.compat().split();
-                let rpc_network = Box::new(twoparty::VatNetwork::new(
-                    reader,
-                    writer,
-                    rpc_twoparty_capnp::Side::Client,
-                    *ReaderOptions::new()
-                        .nesting_limit(64)
-                        .traversal_limit_in_words(64 * 1024 * 1024),
-                ));
+                let rpc_network = match address {
+                    RpcAddress::Tcp(connect_string) => {
+                        use std::net::ToSocketAddrs;
+                        let addr = connect_string.to_socket_addrs()?.next().ok_or_else(|| {
+                            format!("{} does not resolve to an address", connect_string)
+                        })?;
+                        let stream = ::tokio::net::TcpStream::connect(&addr).await?;
+                        stream.set_nodelay(true)?;
+                        tls.network(stream, reader_options).await?
+                    }
+                    #[cfg(unix)]
+                    RpcAddress::Unix(path) => {
+                        tls.network(
+                            ::tokio::net::UnixStream::connect(&path).await?,
+                            reader_options,
+                        )
+                        .await?
+                    }
+                };
 
                 let mut rpc_system = RpcSystem::new(rpc_network, None);
                 let hub: asset_hub::Client = rpc_system.bootstrap(rpc_twoparty_capnp::Side::Server);
                 let _disconnector = rpc_system.get_disconnector();
                 tokio::task::spawn_local(rpc_system);
                 let request = hub.get_snapshot_request();
-                let response = request
-                    .send()
-                    .promise
-                    .await
-                    .map_err(|e| -> Box<dyn Error> { Box::new(e) })?;
+                let response = request.send().promise.await?;
 
                 let snapshot = response.get()?.get_snapshot()?;
                 let (snapshot_tx, snapshot_rx) = unbounded();
@@ -155,17 +294,12 @@ impl RpcRuntime {
                 });
                 let mut request = hub.register_listener_request();
                 request.get().set_listener(listener);
-                let rpc_conn = request
-                    .send()
-                    .promise
-                    .await
-                    .map(|_| RpcConnection {
-                        _asset_hub: hub,
-                        snapshot,
-                        snapshot_rx,
-                    })
-                    .map_err(|e| -> Box<dyn Error> { Box::new(e) })?;
-                Ok(rpc_conn)
+                let rpc_conn = request.send().promise.await.map(|_| RpcConnection {
+                    _asset_hub: hub,
+                    snapshot,
+                    snapshot_rx,
+                })?;
+                Ok::<_, Box<dyn Error>>(rpc_conn)
             }
             .await;
             let _ = conn_tx.send(result);
@@ -174,6 +308,44 @@ impl RpcRuntime {
     }
 }
 
+impl ClientTls {
+    /// Starts the TLS session on `stream` if TLS is configured.
+    async fn network<S>(
+        &self,
+        stream: S,
+        reader_options: ReaderOptions,
+    ) -> std::io::Result<Box<dyn capnp_rpc::VatNetwork<rpc_twoparty_capnp::Side>>>
+    where
+        S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin + 'static,
+    {
+        #[cfg(feature = "tls")]
+        {
+            if let Some((connector, domain)) = &self.connector {
+                let stream = connector.connect(domain.as_ref(), stream).await?;
+                return Ok(client_network(stream, reader_options));
+            }
+        }
+        Ok(client_network(stream, reader_options))
+    }
+}
+
+fn client_network<S>(
+    stream: S,
+    reader_options: ReaderOptions,
+) -> Box<dyn capnp_rpc::VatNetwork<rpc_twoparty_capnp::Side>>
+where
+    S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin + 'static,
+{
+    use tokio_util::compat::*;
+    let (reader, writer) = stream.compat().split();
+    Box::new(twoparty::VatNetwork::new(
+        reader,
+        writer,
+        rpc_twoparty_capnp::Side::Client,
+        reader_options,
+    ))
+}
+
 async fn do_metadata_request(
     asset: &MetadataRequest,
     snapshot: &asset_hub::snapshot::Client,
@@ -196,6 +368,45 @@ async fn do_metadata_request(
     Ok(artifacts)
 }
 
+async fn do_build_deps_request(
+    request: &BuildDepsRequest,
+    snapshot: &asset_hub::snapshot::Client,
+) -> Result<Vec<AssetMetadata>, capnp::Error> {
+    let mut rpc_request = snapshot.get_asset_metadata_with_build_dependencies_request();
+    let mut assets = rpc_request
+        .get()
+        .init_assets(request.requested_assets().len() as u32);
+    for (idx, asset) in request.requested_assets().iter().enumerate() {
+        assets.reborrow().get(idx as u32).set_id(&asset.0);
+    }
+    let response = rpc_request.send().promise.await?;
+    let reader = response.get()?;
+    let metadata = reader
+        .get_assets()?
+        .into_iter()
+        .map(|a| parse_db_metadata(&a))
+        .collect::<Vec<_>>();
+    Ok(metadata)
+}
+
+async fn do_search_request(
+    request: &SearchRequest,
+    snapshot: &asset_hub::snapshot::Client,
+) -> Result<Vec<AssetUuid>, capnp::Error> {
+    let mut rpc_request = snapshot.search_assets_request();
+    rpc_request.get().set_key(request.key().as_bytes());
+    if let Some(value) = request.value() {
+        rpc_request.get().set_value(value.as_bytes());
+    }
+    let response = rpc_request.send().promise.await?;
+    let reader = response.get()?;
+    let mut assets = Vec::new();
+    for id in reader.get_assets()? {
+        assets.push(AssetUuid(utils::make_array(id.get_id()?)));
+    }
+    Ok(assets)
+}
+
 async fn do_import_artifact_request(
     asset: &DataRequest,
     snapshot: &asset_hub::snapshot::Client,
@@ -213,7 +424,12 @@ async fn do_resolve_request(
     resolve: &ResolveRequest,
     snapshot: &asset_hub::snapshot::Client,
 ) -> Result<Vec<(PathBuf, Vec<AssetMetadata>)>, capnp::Error> {
-    let path = resolve.identifier().path();
+    let path = match resolve.identifier() {
+        IndirectIdentifier::Label(label) => return do_resolve_label(label, snapshot).await,
+        identifier => identifier
+            .path()
+            .expect("identifier without a label has a path"),
+    };
     // get asset IDs at path
     let mut request = snapshot.get_assets_for_paths_request();
     let mut paths = request.get().init_paths(1);
@@ -241,6 +457,37 @@ async fn do_resolve_request(
     Ok(results)
 }
 
+/// Returns the asset that has `label` as the only candidate, at the path of its source file.
+async fn do_resolve_label(
+    label: &str,
+    snapshot: &asset_hub::snapshot::Client,
+) -> Result<Vec<(PathBuf, Vec<AssetMetadata>)>, capnp::Error> {
+    let mut request = snapshot.get_asset_for_label_request();
+    request.get().set_label(label.as_bytes());
+    let response = request.send().promise.await?;
+    let asset_ids = response.get()?.get_assets()?;
+    if asset_ids.len() == 0 {
+        return Ok(Vec::new());
+    }
+    let mut request = snapshot.get_path_for_assets_request();
+    request.get().set_assets(asset_ids)?;
+    let response = request.send().promise.await?;
+    let path = match response.get()?.get_paths()?.iter().next() {
+        Some(asset_path) => PathBuf::from(std::str::from_utf8(asset_path.get_path()?)?),
+        None => return Ok(Vec::new()),
+    };
+    let mut request = snapshot.get_asset_metadata_request();
+    request.get().set_assets(asset_ids)?;
+    let response = request.send().promise.await?;
+    let metadata = response
+        .get()?
+        .get_assets()?
+        .into_iter()
+        .map(|a| parse_db_metadata(&a))
+        .collect();
+    Ok(vec![(path, metadata)])
+}
+
 fn process_requests(runtime: &mut RpcRuntime, requests: &mut QueuedRequests) {
     if let InternalConnectionState::Connected(connection) = &runtime.connection {
         let len = requests.data_requests.len();
@@ -287,6 +534,36 @@ fn process_requests(runtime: &mut RpcRuntime, requests: &mut QueuedRequests) {
                 }
             });
         }
+
+        let len = requests.build_deps_requests.len();
+        for m in requests.build_deps_requests.drain(0..len) {
+            let snapshot = connection.snapshot.clone();
+            runtime.local.spawn_local(async move {
+                match do_build_deps_request(&m, &snapshot).await {
+                    Ok(data) => {
+                        m.complete(data);
+                    }
+                    Err(e) => {
+                        m.error(e);
+                    }
+                }
+            });
+        }
+
+        let len = requests.search_requests.len();
+        for m in requests.search_requests.drain(0..len) {
+            let snapshot = connection.snapshot.clone();
+            runtime.local.spawn_local(async move {
+                match do_search_request(&m, &snapshot).await {
+                    Ok(assets) => {
+                        m.complete(assets);
+                    }
+                    Err(e) => {
+                        m.error(e);
+                    }
+                }
+            });
+        }
     }
 }
 
@@ -309,15 +586,27 @@ impl LoaderIO for RpcIO {
         process_requests(&mut runtime, &mut self.requests);
     }
 
+    fn get_asset_metadata_with_build_dependencies(&mut self, request: BuildDepsRequest) {
+        self.requests.build_deps_requests.push(request);
+        let mut runtime = self.runtime.lock().unwrap();
+        process_requests(&mut runtime, &mut self.requests);
+    }
+
+    fn search_assets(&mut self, request: SearchRequest) {
+        self.requests.search_requests.push(request);
+        let mut runtime = self.runtime.lock().unwrap();
+        process_requests(&mut runtime, &mut self.requests);
+    }
+
     fn tick(&mut self, loader: &mut LoaderState) {
         let mut runtime = self.runtime.lock().unwrap();
         match &runtime.connection {
             InternalConnectionState::Error(err) => {
                 log::error!("Error connecting RpcIO: {}", err);
-                runtime.connect(&self.connect_string);
+                runtime.connect(&self.address, &self.tls, self.reader_options);
             }
             InternalConnectionState::None => {
-                runtime.connect(&self.connect_string);
+                runtime.connect(&self.address, &self.tls, self.reader_options);
             }
             _ => {}
         };
@@ -426,4 +715,18 @@ impl asset_hub::listener::Server for ListenerImpl {
         self.snapshot_change = Some(params.get_latest_change());
         Promise::ok(())
     }
+
+    fn import_error(
+        &mut self,
+        params: asset_hub::listener::ImportErrorParams,
+        _results: asset_hub::listener::ImportErrorResults,
+    ) -> Promise<()> {
+        let params = pry!(params.get());
+        log::error!(
+            "failed to import {}: {}",
+            String::from_utf8_lossy(pry!(params.get_path())),
+            pry!(params.get_message())
+        );
+        Promise::ok(())
+    }
 }
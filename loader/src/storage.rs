@@ -1,12 +1,12 @@
-use atelier_core::{AssetMetadata, AssetRef, AssetTypeId, AssetUuid};
+use atelier_core::{ArtifactMetadata, AssetMetadata, AssetRef, AssetTypeId, AssetUuid};
 use crossbeam_channel::Sender;
-use dashmap::DashMap;
 use std::{
+    collections::HashMap,
     error::Error,
-    path::PathBuf,
+    path::{Path, PathBuf},
     sync::{
         atomic::{AtomicU64, Ordering},
-        Arc,
+        Arc, RwLock,
     },
 };
 
@@ -89,6 +89,19 @@ impl Drop for AssetLoadOp {
     }
 }
 
+/// Outcome of [`AssetStorage::try_update_asset`].
+#[derive(Debug)]
+pub enum UpdateResult {
+    /// The storage accepted the data and signals completion through the [`AssetLoadOp`].
+    Accepted,
+    /// The update failed for a transient reason, such as a lost GPU device. The loader requests
+    /// the data and updates the asset again, up to
+    /// [`Loader::set_max_update_retries`](crate::loader::Loader::set_max_update_retries) times.
+    Retry(Box<dyn Error + Send + 'static>),
+    /// The update failed and the asset is not loaded.
+    Failed(Box<dyn Error + Send + 'static>),
+}
+
 /// Storage for all assets of all asset types.
 ///
 /// Consumers are expected to provide the implementation for this, as this is the bridge between
@@ -101,7 +114,7 @@ pub trait AssetStorage {
     ///
     /// # Parameters
     ///
-    /// * `loader`: Loader implementation calling this function.
+    /// * `loader_info`: Loader information, including the metadata of the artifact being loaded.
     /// * `asset_type_id`: UUID of the asset type.
     /// * `data`: The updated asset byte data.
     /// * `load_handle`: ID allocated by [`Loader`](crate::loader::Loader) to track loading of a particular asset.
@@ -117,8 +130,40 @@ pub trait AssetStorage {
         version: u32,
     ) -> Result<(), Box<dyn Error + Send + 'static>>;
 
+    /// Updates the backing data of an asset like [`update_asset`](AssetStorage::update_asset),
+    /// and lets the storage ask for the update to be retried. The loader calls this method.
+    ///
+    /// The `load_op` of an update that is not [`UpdateResult::Accepted`] can be dropped. The
+    /// default implementation calls `update_asset` and reports errors as [`UpdateResult::Failed`].
+    fn try_update_asset(
+        &self,
+        loader_info: &dyn LoaderInfoProvider,
+        asset_type_id: &AssetTypeId,
+        data: Vec<u8>,
+        load_handle: LoadHandle,
+        load_op: AssetLoadOp,
+        version: u32,
+    ) -> UpdateResult {
+        match self.update_asset(
+            loader_info,
+            asset_type_id,
+            data,
+            load_handle,
+            load_op,
+            version,
+        ) {
+            Ok(()) => UpdateResult::Accepted,
+            Err(err) => UpdateResult::Failed(err),
+        }
+    }
+
     /// Commits the specified asset version as loaded and ready to use.
     ///
+    /// For a given `load_handle`, the loader only commits versions newer than the last committed
+    /// one. When an older version finishes loading after a newer one was committed, as can happen
+    /// during hot reloading, it is never committed but freed with [`AssetStorage::free`], so the
+    /// storage does not have to compare versions itself.
+    ///
     /// # Parameters
     ///
     /// * `asset_type_id`: UUID of the asset type.
@@ -163,6 +208,11 @@ pub struct LoadInfo {
     pub asset_id: AssetUuid,
     /// Number of references to the asset.
     pub refs: u32,
+    /// Size of the asset's artifact when serialized and uncompressed, once its metadata has been
+    /// received. Useful to sum the expected bytes of pending loads for progress reporting.
+    pub uncompressed_size: Option<u64>,
+    /// Size of the asset's artifact when compressed, once its metadata has been received.
+    pub compressed_size: Option<u64>,
 }
 
 /// Provides information about mappings between `AssetUuid` and `LoadHandle`.
@@ -183,6 +233,13 @@ pub trait LoaderInfoProvider: Send + Sync {
     ///
     /// * `load_handle`: ID allocated by [`Loader`](crate::loader::Loader) to track loading of the asset.
     fn get_asset_id(&self, load: LoadHandle) -> Option<AssetUuid>;
+
+    /// Returns the metadata of the artifact being loaded, when called on the `loader_info` passed
+    /// to [`AssetStorage::update_asset`]. Deserialization code can reach it through
+    /// `SerdeContext::with_active`, for example to read the asset's own `load_deps`.
+    fn current_artifact_metadata(&self) -> Option<&ArtifactMetadata> {
+        None
+    }
 }
 
 /// Allocates LoadHandles for [`Loader`](crate::loader::Loader) implementations.
@@ -228,20 +285,43 @@ pub enum IndirectIdentifier {
     PathWithTagAndType(String, String, AssetTypeId),
     PathWithType(String, AssetTypeId),
     Path(String),
+    /// The label that an importer gave the asset, see `ImportedAsset::label`.
+    Label(String),
 }
 impl IndirectIdentifier {
-    pub fn path(&self) -> &str {
+    /// Returns the source path of the identifier, or `None` for a label.
+    pub fn path(&self) -> Option<&str> {
         match self {
-            IndirectIdentifier::PathWithTagAndType(path, _, _) => path.as_str(),
-            IndirectIdentifier::PathWithType(path, _) => path.as_str(),
-            IndirectIdentifier::Path(path) => path.as_str(),
+            IndirectIdentifier::PathWithTagAndType(path, _, _) => Some(path.as_str()),
+            IndirectIdentifier::PathWithType(path, _) => Some(path.as_str()),
+            IndirectIdentifier::Path(path) => Some(path.as_str()),
+            IndirectIdentifier::Label(_) => None,
         }
     }
     pub fn type_id(&self) -> Option<&AssetTypeId> {
         match self {
             IndirectIdentifier::PathWithTagAndType(_, _, ty) => Some(ty),
             IndirectIdentifier::PathWithType(_, ty) => Some(ty),
-            IndirectIdentifier::Path(_) => None,
+            IndirectIdentifier::Path(_) | IndirectIdentifier::Label(_) => None,
+        }
+    }
+    /// Returns the identifier with its path normalized like the daemon normalizes source paths,
+    /// so that identifiers for the same path are equal.
+    pub(crate) fn normalized(self) -> Self {
+        let normalize = |path: String| {
+            atelier_core::utils::normalize_path(Path::new(&path))
+                .to_string_lossy()
+                .into_owned()
+        };
+        match self {
+            IndirectIdentifier::PathWithTagAndType(path, tag, ty) => {
+                IndirectIdentifier::PathWithTagAndType(normalize(path), tag, ty)
+            }
+            IndirectIdentifier::PathWithType(path, ty) => {
+                IndirectIdentifier::PathWithType(normalize(path), ty)
+            }
+            IndirectIdentifier::Path(path) => IndirectIdentifier::Path(normalize(path)),
+            IndirectIdentifier::Label(label) => IndirectIdentifier::Label(label),
         }
     }
 }
@@ -279,9 +359,72 @@ impl IndirectionResolver for DefaultIndirectionResolver {
 
 /// Resolves indirect [`LoadHandle`]s. See [`LoadHandle::is_indirect`] for details.
 #[derive(Clone)]
-pub struct IndirectionTable(pub(crate) Arc<DashMap<LoadHandle, LoadHandle>>);
+pub struct IndirectionTable(pub(crate) Arc<RwLock<HashMap<LoadHandle, LoadHandle>>>);
 impl IndirectionTable {
     pub fn resolve(&self, indirect_handle: LoadHandle) -> Option<LoadHandle> {
-        self.0.get(&indirect_handle).map(|l| *l)
+        self.0.read().unwrap().get(&indirect_handle).copied()
+    }
+
+    /// Points each indirect handle at a new direct handle. All reassignments become visible to
+    /// [`IndirectionTable::resolve`] at once, so no resolve observes a partially applied batch.
+    ///
+    /// The table does not hold references to the direct handles; the caller must keep the new
+    /// targets loaded for as long as they may be resolved.
+    pub fn reassign_batch(&self, reassignments: impl Iterator<Item = (LoadHandle, LoadHandle)>) {
+        let reassignments: Vec<_> = reassignments.collect();
+        let mut table = self.0.write().unwrap();
+        for (indirect_handle, direct_handle) in reassignments {
+            debug_assert!(indirect_handle.is_indirect());
+            table.insert(indirect_handle, direct_handle);
+        }
+    }
+
+    pub(crate) fn insert(&self, indirect_handle: LoadHandle, direct_handle: LoadHandle) {
+        self.0
+            .write()
+            .unwrap()
+            .insert(indirect_handle, direct_handle);
+    }
+
+    pub(crate) fn remove(&self, indirect_handle: LoadHandle) {
+        self.0.write().unwrap().remove(&indirect_handle);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reassign_batch_is_never_partially_visible() {
+        let table = IndirectionTable(Arc::new(RwLock::new(HashMap::new())));
+        let indirect: Vec<_> = (0..16).map(|i| LoadHandle(i).set_indirect()).collect();
+        let generation = |gen: u64| {
+            indirect
+                .iter()
+                .map(move |h| (*h, LoadHandle(gen)))
+                .collect::<Vec<_>>()
+        };
+        table.reassign_batch(generation(0).into_iter());
+
+        let reader = {
+            let table = table.clone();
+            let indirect = indirect.clone();
+            std::thread::spawn(move || {
+                for _ in 0..10_000 {
+                    let snapshot = table.0.read().unwrap();
+                    let first = snapshot[&indirect[0]];
+                    assert!(indirect.iter().all(|h| snapshot[h] == first));
+                }
+            })
+        };
+        for gen in 1..1_000 {
+            table.reassign_batch(generation(gen).into_iter());
+        }
+        reader.join().unwrap();
+
+        for h in &indirect {
+            assert_eq!(Some(LoadHandle(999)), table.resolve(*h));
+        }
     }
 }
@@ -35,6 +35,24 @@ impl From<data_capnp::CompressionType> for atelier_core::CompressionType {
     }
 }
 
+impl From<atelier_core::SerializationFormat> for data_capnp::SerializationFormat {
+    fn from(f: atelier_core::SerializationFormat) -> Self {
+        match f {
+            atelier_core::SerializationFormat::Bincode => Self::Bincode,
+            atelier_core::SerializationFormat::Json => Self::Json,
+        }
+    }
+}
+
+impl From<data_capnp::SerializationFormat> for atelier_core::SerializationFormat {
+    fn from(f: data_capnp::SerializationFormat) -> Self {
+        match f {
+            data_capnp::SerializationFormat::Bincode => Self::Bincode,
+            data_capnp::SerializationFormat::Json => Self::Json,
+        }
+    }
+}
+
 pub use crate::{data_capnp as data, service_capnp as service};
 
 fn set_assetref_list(
@@ -71,6 +89,16 @@ pub fn parse_db_asset_ref(asset_ref: &data::asset_ref::Reader<'_>) -> AssetRef {
     }
 }
 
+/// Returns the `ArtifactId` for an artifact hash stored in the schema, or `None` if the hash is
+/// shorter than 8 bytes. The hash is either 8 bytes for 64-bit hashes or 16 bytes for 128-bit
+/// hashes, where the first 8 bytes are equal to the `ArtifactId`.
+pub fn parse_artifact_id(hash: &[u8]) -> Option<ArtifactId> {
+    if hash.len() < 8 {
+        return None;
+    }
+    Some(ArtifactId(u64::from_le_bytes(make_array(&hash[0..8]))))
+}
+
 pub fn parse_artifact_metadata(artifact: &data::artifact_metadata::Reader<'_>) -> ArtifactMetadata {
     let asset_id = make_array(
         artifact
@@ -91,11 +119,16 @@ pub fn parse_artifact_metadata(artifact: &data::artifact_metadata::Reader<'_>) -
     } else {
         Some(uncompressed_size)
     };
+    let hash = artifact.get_hash().expect("capnp: failed to read hash");
+    let wide_id = if hash.len() == 16 {
+        Some(u128::from_le_bytes(make_array(hash)))
+    } else {
+        None
+    };
     ArtifactMetadata {
         asset_id,
-        id: ArtifactId(u64::from_le_bytes(make_array(
-            artifact.get_hash().expect("capnp: failed to read hash"),
-        ))),
+        id: parse_artifact_id(hash).expect("capnp: artifact hash is shorter than 8 bytes"),
+        wide_id,
         load_deps: artifact
             .get_load_deps()
             .expect("capnp: failed to read load deps")
@@ -119,6 +152,12 @@ pub fn parse_artifact_metadata(artifact: &data::artifact_metadata::Reader<'_>) -
             .into(),
         compressed_size,
         uncompressed_size,
+        content_hash: artifact.get_content_hash(),
+        data_version: artifact.get_data_version(),
+        serialization_format: artifact
+            .get_serialization_format()
+            .expect("capnp: failed to read serialization format")
+            .into(),
     }
 }
 
@@ -172,11 +211,19 @@ pub fn parse_db_metadata(metadata: &data::asset_metadata::Reader<'_>) -> AssetMe
     } else {
         None
     };
+    let label = std::str::from_utf8(metadata.get_label().expect("capnp: failed to read label"))
+        .expect("failed to read label as utf8");
+    let label = if !label.is_empty() {
+        Some(label.to_owned())
+    } else {
+        None
+    };
     AssetMetadata {
         id: asset_id,
         search_tags,
         build_pipeline,
         artifact: artifact_metadata,
+        label,
     }
 }
 pub fn build_artifact_metadata(
@@ -190,7 +237,7 @@ pub fn build_artifact_metadata(
         .set_id(&artifact_metadata.asset_id.0);
     artifact
         .reborrow()
-        .set_hash(&artifact_metadata.id.0.to_le_bytes());
+        .set_hash(&artifact_metadata.hash_bytes());
     set_assetref_list(
         &artifact_metadata.load_deps,
         &mut artifact
@@ -215,6 +262,15 @@ pub fn build_artifact_metadata(
     artifact
         .reborrow()
         .set_type_id(&artifact_metadata.type_id.0);
+    artifact
+        .reborrow()
+        .set_content_hash(artifact_metadata.content_hash);
+    artifact
+        .reborrow()
+        .set_data_version(artifact_metadata.data_version);
+    artifact
+        .reborrow()
+        .set_serialization_format(artifact_metadata.serialization_format.into());
 }
 
 pub fn build_asset_metadata(
@@ -248,6 +304,9 @@ pub fn build_asset_metadata(
         m.reborrow().init_latest_artifact().set_none(());
     }
     m.reborrow().set_source(source);
+    if let Some(label) = &metadata.label {
+        m.reborrow().set_label(label.as_bytes());
+    }
 }
 
 pub fn build_asset_metadata_message<K>(
@@ -2874,6 +2874,21 @@ pub mod artifact_metadata {
         pub fn has_type_id(&self) -> bool {
             !self.reader.get_pointer_field(4).is_null()
         }
+        #[inline]
+        pub fn get_content_hash(self) -> u64 {
+            self.reader.get_data_field::<u64>(3)
+        }
+        #[inline]
+        pub fn get_data_version(self) -> u32 {
+            self.reader.get_data_field::<u32>(1)
+        }
+        #[inline]
+        pub fn get_serialization_format(
+            self,
+        ) -> ::core::result::Result<crate::data_capnp::SerializationFormat, ::capnp::NotInSchema>
+        {
+            ::capnp::traits::FromU16::from_u16(self.reader.get_data_field::<u16>(1))
+        }
     }
 
     pub struct Builder<'a> {
@@ -3101,6 +3116,33 @@ pub mod artifact_metadata {
         pub fn has_type_id(&self) -> bool {
             !self.builder.get_pointer_field(4).is_null()
         }
+        #[inline]
+        pub fn get_content_hash(self) -> u64 {
+            self.builder.get_data_field::<u64>(3)
+        }
+        #[inline]
+        pub fn set_content_hash(&mut self, value: u64) {
+            self.builder.set_data_field::<u64>(3, value);
+        }
+        #[inline]
+        pub fn get_data_version(self) -> u32 {
+            self.builder.get_data_field::<u32>(1)
+        }
+        #[inline]
+        pub fn set_data_version(&mut self, value: u32) {
+            self.builder.set_data_field::<u32>(1, value);
+        }
+        #[inline]
+        pub fn get_serialization_format(
+            self,
+        ) -> ::core::result::Result<crate::data_capnp::SerializationFormat, ::capnp::NotInSchema>
+        {
+            ::capnp::traits::FromU16::from_u16(self.builder.get_data_field::<u16>(1))
+        }
+        #[inline]
+        pub fn set_serialization_format(&mut self, value: crate::data_capnp::SerializationFormat) {
+            self.builder.set_data_field::<u16>(1, value as u16)
+        }
     }
 
     pub struct Pipeline {
@@ -3121,7 +3163,7 @@ pub mod artifact_metadata {
     mod _private {
         use capnp::private::layout;
         pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
-            data: 3,
+            data: 4,
             pointers: 5,
         };
         pub const TYPE_ID: u64 = 0xf0ac_1e9d_bec2_4dbf;
@@ -3241,6 +3283,16 @@ pub mod asset_metadata {
         ) -> ::core::result::Result<crate::data_capnp::AssetSource, ::capnp::NotInSchema> {
             ::capnp::traits::FromU16::from_u16(self.reader.get_data_field::<u16>(1))
         }
+        #[inline]
+        pub fn get_label(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+            ::capnp::traits::FromPointerReader::get_from_pointer(
+                &self.reader.get_pointer_field(5),
+                ::core::option::Option::None,
+            )
+        }
+        pub fn has_label(&self) -> bool {
+            !self.reader.get_pointer_field(5).is_null()
+        }
         pub fn has_error(&self) -> bool {
             if self.reader.get_data_field::<u16>(2) != 0 {
                 return false;
@@ -3442,6 +3494,24 @@ pub mod asset_metadata {
             self.builder.set_data_field::<u16>(1, value as u16)
         }
         #[inline]
+        pub fn get_label(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                self.builder.get_pointer_field(5),
+                ::core::option::Option::None,
+            )
+        }
+        #[inline]
+        pub fn set_label(&mut self, value: ::capnp::data::Reader) {
+            self.builder.get_pointer_field(5).set_data(value);
+        }
+        #[inline]
+        pub fn init_label(self, size: u32) -> ::capnp::data::Builder<'a> {
+            self.builder.get_pointer_field(5).init_data(size)
+        }
+        pub fn has_label(&self) -> bool {
+            !self.builder.get_pointer_field(5).is_null()
+        }
+        #[inline]
         pub fn set_error<'b>(
             &mut self,
             value: crate::data_capnp::error::Reader<'b>,
@@ -3510,7 +3580,7 @@ pub mod asset_metadata {
         use capnp::private::layout;
         pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
             data: 1,
-            pointers: 5,
+            pointers: 6,
         };
         pub const TYPE_ID: u64 = 0xd390_2cad_76fc_fde0;
     }
@@ -5099,6 +5169,35 @@ impl ::capnp::traits::HasTypeId for CompressionType {
     }
 }
 
+#[repr(u16)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum SerializationFormat {
+    Bincode = 0,
+    Json = 1,
+}
+impl ::capnp::traits::FromU16 for SerializationFormat {
+    #[inline]
+    fn from_u16(value: u16) -> ::core::result::Result<SerializationFormat, ::capnp::NotInSchema> {
+        match value {
+            0 => ::core::result::Result::Ok(SerializationFormat::Bincode),
+            1 => ::core::result::Result::Ok(SerializationFormat::Json),
+            n => ::core::result::Result::Err(::capnp::NotInSchema(n)),
+        }
+    }
+}
+impl ::capnp::traits::ToU16 for SerializationFormat {
+    #[inline]
+    fn to_u16(self) -> u16 {
+        self as u16
+    }
+}
+impl ::capnp::traits::HasTypeId for SerializationFormat {
+    #[inline]
+    fn type_id() -> u64 {
+        0xe3b6_5d4a_9c21_f870u64
+    }
+}
+
 pub mod daemon_info {
     #[derive(Copy, Clone)]
     pub struct Owned;
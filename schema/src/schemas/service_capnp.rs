@@ -674,196 +674,1659 @@ pub mod asset_data {
     }
 }
 
-pub mod asset_hub {
-    #![allow(unused_variables)]
-    pub type RegisterListenerParams = ::capnp::capability::Params<
-        crate::service_capnp::asset_hub::register_listener_params::Owned,
-    >;
-    pub type RegisterListenerResults = ::capnp::capability::Results<
-        crate::service_capnp::asset_hub::register_listener_results::Owned,
-    >;
-    pub type GetSnapshotParams =
-        ::capnp::capability::Params<crate::service_capnp::asset_hub::get_snapshot_params::Owned>;
-    pub type GetSnapshotResults =
-        ::capnp::capability::Results<crate::service_capnp::asset_hub::get_snapshot_results::Owned>;
+pub mod asset_change {
+    pub use self::Which::{Added, Changed, Removed};
 
-    pub struct Client {
-        pub client: ::capnp::capability::Client,
-    }
-    impl ::capnp::capability::FromClientHook for Client {
-        fn new(hook: Box<dyn (::capnp::private::capability::ClientHook)>) -> Client {
-            Client {
-                client: ::capnp::capability::Client::new(hook),
-            }
-        }
-    }
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
-        type Reader = Client;
-        type Builder = Client;
+        type Reader = Reader<'a>;
+        type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+        type Reader = Reader<'a>;
+        type Builder = Builder<'a>;
     }
     impl ::capnp::traits::Pipelined for Owned {
-        type Pipeline = Client;
+        type Pipeline = Pipeline;
     }
-    impl<'a> ::capnp::traits::FromPointerReader<'a> for Client {
-        fn get_from_pointer(
-            reader: &::capnp::private::layout::PointerReader<'a>,
-            _default: ::core::option::Option<&'a [capnp::Word]>,
-        ) -> ::capnp::Result<Client> {
-            ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(
-                reader.get_capability()?,
-            ))
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+        reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+        #[inline]
+        fn type_id() -> u64 {
+            _private::TYPE_ID
         }
     }
-    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Client {
-        fn init_pointer(
-            _builder: ::capnp::private::layout::PointerBuilder<'a>,
-            _size: u32,
-        ) -> Client {
-            unimplemented!()
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+        fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+            Reader { reader }
         }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
         fn get_from_pointer(
-            builder: ::capnp::private::layout::PointerBuilder<'a>,
-            _default: ::core::option::Option<&'a [capnp::Word]>,
-        ) -> ::capnp::Result<Client> {
-            ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(
-                builder.get_capability()?,
+            reader: &::capnp::private::layout::PointerReader<'a>,
+            default: ::core::option::Option<&'a [capnp::Word]>,
+        ) -> ::capnp::Result<Reader<'a>> {
+            ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                reader.get_struct(default)?,
             ))
         }
     }
 
-    impl ::capnp::traits::SetPointerBuilder<Client> for Client {
-        fn set_pointer_builder(
-            pointer: ::capnp::private::layout::PointerBuilder,
-            from: Client,
-            _canonicalize: bool,
-        ) -> ::capnp::Result<()> {
-            pointer.set_capability(from.client.hook);
-            ::core::result::Result::Ok(())
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+        fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+            self.reader
         }
     }
-    impl ::capnp::traits::HasTypeId for Client {
-        #[inline]
-        fn type_id() -> u64 {
-            _private::TYPE_ID
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+        fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+            self.reader
+                .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
         }
     }
-    impl Clone for Client {
-        fn clone(&self) -> Client {
-            Client {
-                client: ::capnp::capability::Client::new(self.client.hook.add_ref()),
-            }
+
+    impl<'a> Reader<'a> {
+        pub fn reborrow(&self) -> Reader {
+            Reader { ..*self }
         }
-    }
-    impl Client {
-        pub fn register_listener_request(
-            &self,
-        ) -> ::capnp::capability::Request<
-            crate::service_capnp::asset_hub::register_listener_params::Owned,
-            crate::service_capnp::asset_hub::register_listener_results::Owned,
-        > {
-            self.client.new_call(_private::TYPE_ID, 0, None)
+
+        pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+            self.reader.total_size()
         }
-        pub fn get_snapshot_request(
-            &self,
-        ) -> ::capnp::capability::Request<
-            crate::service_capnp::asset_hub::get_snapshot_params::Owned,
-            crate::service_capnp::asset_hub::get_snapshot_results::Owned,
-        > {
-            self.client.new_call(_private::TYPE_ID, 1, None)
+        #[inline]
+        pub fn get_id(self) -> ::capnp::Result<crate::data_capnp::asset_uuid::Reader<'a>> {
+            ::capnp::traits::FromPointerReader::get_from_pointer(
+                &self.reader.get_pointer_field(0),
+                ::core::option::Option::None,
+            )
         }
-    }
-    pub trait Server {
-        fn register_listener(
-            &mut self,
-            _: RegisterListenerParams,
-            _: RegisterListenerResults,
-        ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-            ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                "method not implemented".to_string(),
-            ))
+        pub fn has_id(&self) -> bool {
+            !self.reader.get_pointer_field(0).is_null()
         }
-        fn get_snapshot(
-            &mut self,
-            _: GetSnapshotParams,
-            _: GetSnapshotResults,
-        ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-            ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                "method not implemented".to_string(),
-            ))
+        pub fn has_added(&self) -> bool {
+            if self.reader.get_data_field::<u16>(0) != 0 {
+                return false;
+            }
+            !self.reader.get_pointer_field(1).is_null()
+        }
+        #[inline]
+        pub fn which(self) -> ::core::result::Result<WhichReader<'a>, ::capnp::NotInSchema> {
+            match self.reader.get_data_field::<u16>(0) {
+                0 => ::core::result::Result::Ok(Added(
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(1),
+                        ::core::option::Option::None,
+                    ),
+                )),
+                1 => ::core::result::Result::Ok(Changed(())),
+                2 => ::core::result::Result::Ok(Removed(())),
+                x => ::core::result::Result::Err(::capnp::NotInSchema(x)),
+            }
         }
     }
-    pub struct ServerDispatch<_T> {
-        pub server: _T,
+
+    pub struct Builder<'a> {
+        builder: ::capnp::private::layout::StructBuilder<'a>,
     }
-    impl<_S: Server + 'static> ::capnp::capability::FromServer<_S> for Client {
-        type Dispatch = ServerDispatch<_S>;
-        fn from_server(s: _S) -> ServerDispatch<_S> {
-            ServerDispatch { server: s }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+        #[inline]
+        fn struct_size() -> ::capnp::private::layout::StructSize {
+            _private::STRUCT_SIZE
         }
     }
-    impl<_T: Server> ::core::ops::Deref for ServerDispatch<_T> {
-        type Target = _T;
-        fn deref(&self) -> &_T {
-            &self.server
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+        #[inline]
+        fn type_id() -> u64 {
+            _private::TYPE_ID
         }
     }
-    impl<_T: Server> ::core::ops::DerefMut for ServerDispatch<_T> {
-        fn deref_mut(&mut self) -> &mut _T {
-            &mut self.server
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+        fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+            Builder { builder }
         }
     }
-    impl<_T: Server> ::capnp::capability::Server for ServerDispatch<_T> {
-        fn dispatch_call(
-            &mut self,
-            interface_id: u64,
-            method_id: u16,
-            params: ::capnp::capability::Params<::capnp::any_pointer::Owned>,
-            results: ::capnp::capability::Results<::capnp::any_pointer::Owned>,
-        ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-            match interface_id {
-                _private::TYPE_ID => ServerDispatch::<_T>::dispatch_call_internal(
-                    &mut self.server,
-                    method_id,
-                    params,
-                    results,
-                ),
-                _ => ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                    "Method not implemented.".to_string(),
-                )),
-            }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+        fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+            self.builder
+                .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
         }
     }
-    impl<_T: Server> ServerDispatch<_T> {
-        pub fn dispatch_call_internal(
-            server: &mut _T,
-            method_id: u16,
-            params: ::capnp::capability::Params<::capnp::any_pointer::Owned>,
-            results: ::capnp::capability::Results<::capnp::any_pointer::Owned>,
-        ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-            match method_id {
-                0 => server.register_listener(
-                    ::capnp::private::capability::internal_get_typed_params(params),
-                    ::capnp::private::capability::internal_get_typed_results(results),
-                ),
-                1 => server.get_snapshot(
-                    ::capnp::private::capability::internal_get_typed_params(params),
-                    ::capnp::private::capability::internal_get_typed_results(results),
-                ),
-                _ => ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                    "Method not implemented.".to_string(),
-                )),
-            }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+        fn init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> Builder<'a> {
+            ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+        }
+        fn get_from_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            default: ::core::option::Option<&'a [capnp::Word]>,
+        ) -> ::capnp::Result<Builder<'a>> {
+            ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                builder.get_struct(_private::STRUCT_SIZE, default)?,
+            ))
         }
     }
-    pub mod _private {
-        pub const TYPE_ID: u64 = 0xe06a_89bf_80a1_1b12;
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+        fn set_pointer_builder<'b>(
+            pointer: ::capnp::private::layout::PointerBuilder<'b>,
+            value: Reader<'a>,
+            canonicalize: bool,
+        ) -> ::capnp::Result<()> {
+            pointer.set_struct(&value.reader, canonicalize)
+        }
     }
 
-    pub mod snapshot {
-        #![allow(unused_variables)]
-        pub type GetAssetMetadataParams = ::capnp::capability::Params<
+    impl<'a> Builder<'a> {
+        pub fn into_reader(self) -> Reader<'a> {
+            ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+        }
+        pub fn reborrow(&mut self) -> Builder {
+            Builder { ..*self }
+        }
+        pub fn reborrow_as_reader(&self) -> Reader {
+            ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+        }
+
+        pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+            self.builder.into_reader().total_size()
+        }
+        #[inline]
+        pub fn get_id(self) -> ::capnp::Result<crate::data_capnp::asset_uuid::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                self.builder.get_pointer_field(0),
+                ::core::option::Option::None,
+            )
+        }
+        #[inline]
+        pub fn set_id<'b>(
+            &mut self,
+            value: crate::data_capnp::asset_uuid::Reader<'b>,
+        ) -> ::capnp::Result<()> {
+            ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                self.builder.get_pointer_field(0),
+                value,
+                false,
+            )
+        }
+        #[inline]
+        pub fn init_id(self) -> crate::data_capnp::asset_uuid::Builder<'a> {
+            ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+        }
+        pub fn has_id(&self) -> bool {
+            !self.builder.get_pointer_field(0).is_null()
+        }
+        #[inline]
+        pub fn set_added(&mut self, value: ::capnp::data::Reader) {
+            self.builder.set_data_field::<u16>(0, 0);
+            self.builder.get_pointer_field(1).set_data(value);
+        }
+        #[inline]
+        pub fn init_added(self, size: u32) -> ::capnp::data::Builder<'a> {
+            self.builder.set_data_field::<u16>(0, 0);
+            self.builder.get_pointer_field(1).init_data(size)
+        }
+        pub fn has_added(&self) -> bool {
+            if self.builder.get_data_field::<u16>(0) != 0 {
+                return false;
+            }
+            !self.builder.get_pointer_field(1).is_null()
+        }
+        #[inline]
+        pub fn set_changed(&mut self, _value: ()) {
+            self.builder.set_data_field::<u16>(0, 1);
+        }
+        #[inline]
+        pub fn set_removed(&mut self, _value: ()) {
+            self.builder.set_data_field::<u16>(0, 2);
+        }
+        #[inline]
+        pub fn which(self) -> ::core::result::Result<WhichBuilder<'a>, ::capnp::NotInSchema> {
+            match self.builder.get_data_field::<u16>(0) {
+                0 => ::core::result::Result::Ok(Added(
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(1),
+                        ::core::option::Option::None,
+                    ),
+                )),
+                1 => ::core::result::Result::Ok(Changed(())),
+                2 => ::core::result::Result::Ok(Removed(())),
+                x => ::core::result::Result::Err(::capnp::NotInSchema(x)),
+            }
+        }
+    }
+
+    pub struct Pipeline {
+        _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+        fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+            Pipeline {
+                _typeless: typeless,
+            }
+        }
+    }
+    impl Pipeline {
+        pub fn get_id(&self) -> crate::data_capnp::asset_uuid::Pipeline {
+            ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+        }
+    }
+    mod _private {
+        use capnp::private::layout;
+        pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+            data: 1,
+            pointers: 2,
+        };
+        pub const TYPE_ID: u64 = 0xb3e1_6c0d_58a4_f27e;
+    }
+    pub enum Which<A0> {
+        Added(A0),
+        Changed(()),
+        Removed(()),
+    }
+    pub type WhichReader<'a> = Which<::capnp::Result<::capnp::data::Reader<'a>>>;
+    pub type WhichBuilder<'a> = Which<::capnp::Result<::capnp::data::Builder<'a>>>;
+}
+
+pub mod importer_info {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+        type Reader = Reader<'a>;
+        type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+        type Reader = Reader<'a>;
+        type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+        type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+        reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+        #[inline]
+        fn type_id() -> u64 {
+            _private::TYPE_ID
+        }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+        fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+            Reader { reader }
+        }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+        fn get_from_pointer(
+            reader: &::capnp::private::layout::PointerReader<'a>,
+            default: ::core::option::Option<&'a [capnp::Word]>,
+        ) -> ::capnp::Result<Reader<'a>> {
+            ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                reader.get_struct(default)?,
+            ))
+        }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+        fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+            self.reader
+        }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+        fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+            self.reader
+                .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+        }
+    }
+
+    impl<'a> Reader<'a> {
+        pub fn reborrow(&self) -> Reader {
+            Reader { ..*self }
+        }
+
+        pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+            self.reader.total_size()
+        }
+        #[inline]
+        pub fn get_extension(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+            ::capnp::traits::FromPointerReader::get_from_pointer(
+                &self.reader.get_pointer_field(0),
+                ::core::option::Option::None,
+            )
+        }
+        pub fn has_extension(&self) -> bool {
+            !self.reader.get_pointer_field(0).is_null()
+        }
+        #[inline]
+        pub fn get_importer_type(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+            ::capnp::traits::FromPointerReader::get_from_pointer(
+                &self.reader.get_pointer_field(1),
+                ::core::option::Option::None,
+            )
+        }
+        pub fn has_importer_type(&self) -> bool {
+            !self.reader.get_pointer_field(1).is_null()
+        }
+        #[inline]
+        pub fn get_version(self) -> u32 {
+            self.reader.get_data_field::<u32>(0)
+        }
+        #[inline]
+        pub fn get_options_type(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+            ::capnp::traits::FromPointerReader::get_from_pointer(
+                &self.reader.get_pointer_field(2),
+                ::core::option::Option::None,
+            )
+        }
+        pub fn has_options_type(&self) -> bool {
+            !self.reader.get_pointer_field(2).is_null()
+        }
+    }
+
+    pub struct Builder<'a> {
+        builder: ::capnp::private::layout::StructBuilder<'a>,
+    }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+        #[inline]
+        fn struct_size() -> ::capnp::private::layout::StructSize {
+            _private::STRUCT_SIZE
+        }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+        #[inline]
+        fn type_id() -> u64 {
+            _private::TYPE_ID
+        }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+        fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+            Builder { builder }
+        }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+        fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+            self.builder
+                .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+        }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+        fn init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> Builder<'a> {
+            ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+        }
+        fn get_from_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            default: ::core::option::Option<&'a [capnp::Word]>,
+        ) -> ::capnp::Result<Builder<'a>> {
+            ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                builder.get_struct(_private::STRUCT_SIZE, default)?,
+            ))
+        }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+        fn set_pointer_builder<'b>(
+            pointer: ::capnp::private::layout::PointerBuilder<'b>,
+            value: Reader<'a>,
+            canonicalize: bool,
+        ) -> ::capnp::Result<()> {
+            pointer.set_struct(&value.reader, canonicalize)
+        }
+    }
+
+    impl<'a> Builder<'a> {
+        pub fn into_reader(self) -> Reader<'a> {
+            ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+        }
+        pub fn reborrow(&mut self) -> Builder {
+            Builder { ..*self }
+        }
+        pub fn reborrow_as_reader(&self) -> Reader {
+            ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+        }
+
+        pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+            self.builder.into_reader().total_size()
+        }
+        #[inline]
+        pub fn get_extension(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                self.builder.get_pointer_field(0),
+                ::core::option::Option::None,
+            )
+        }
+        #[inline]
+        pub fn set_extension(&mut self, value: ::capnp::text::Reader) {
+            self.builder.get_pointer_field(0).set_text(value);
+        }
+        #[inline]
+        pub fn init_extension(self, size: u32) -> ::capnp::text::Builder<'a> {
+            self.builder.get_pointer_field(0).init_text(size)
+        }
+        pub fn has_extension(&self) -> bool {
+            !self.builder.get_pointer_field(0).is_null()
+        }
+        #[inline]
+        pub fn get_importer_type(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                self.builder.get_pointer_field(1),
+                ::core::option::Option::None,
+            )
+        }
+        #[inline]
+        pub fn set_importer_type(&mut self, value: ::capnp::data::Reader) {
+            self.builder.get_pointer_field(1).set_data(value);
+        }
+        #[inline]
+        pub fn init_importer_type(self, size: u32) -> ::capnp::data::Builder<'a> {
+            self.builder.get_pointer_field(1).init_data(size)
+        }
+        pub fn has_importer_type(&self) -> bool {
+            !self.builder.get_pointer_field(1).is_null()
+        }
+        #[inline]
+        pub fn get_version(self) -> u32 {
+            self.builder.get_data_field::<u32>(0)
+        }
+        #[inline]
+        pub fn set_version(&mut self, value: u32) {
+            self.builder.set_data_field::<u32>(0, value);
+        }
+        #[inline]
+        pub fn get_options_type(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                self.builder.get_pointer_field(2),
+                ::core::option::Option::None,
+            )
+        }
+        #[inline]
+        pub fn set_options_type(&mut self, value: ::capnp::data::Reader) {
+            self.builder.get_pointer_field(2).set_data(value);
+        }
+        #[inline]
+        pub fn init_options_type(self, size: u32) -> ::capnp::data::Builder<'a> {
+            self.builder.get_pointer_field(2).init_data(size)
+        }
+        pub fn has_options_type(&self) -> bool {
+            !self.builder.get_pointer_field(2).is_null()
+        }
+    }
+
+    pub struct Pipeline {
+        _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+        fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+            Pipeline {
+                _typeless: typeless,
+            }
+        }
+    }
+    impl Pipeline {}
+    mod _private {
+        use capnp::private::layout;
+        pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+            data: 1,
+            pointers: 3,
+        };
+        pub const TYPE_ID: u64 = 0x9bcb_0faf_46fc_06dd;
+    }
+}
+
+pub mod asset_metadata_version {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+        type Reader = Reader<'a>;
+        type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+        type Reader = Reader<'a>;
+        type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+        type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+        reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+        #[inline]
+        fn type_id() -> u64 {
+            _private::TYPE_ID
+        }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+        fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+            Reader { reader }
+        }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+        fn get_from_pointer(
+            reader: &::capnp::private::layout::PointerReader<'a>,
+            default: ::core::option::Option<&'a [capnp::Word]>,
+        ) -> ::capnp::Result<Reader<'a>> {
+            ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                reader.get_struct(default)?,
+            ))
+        }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+        fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+            self.reader
+        }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+        fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+            self.reader
+                .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+        }
+    }
+
+    impl<'a> Reader<'a> {
+        pub fn reborrow(&self) -> Reader {
+            Reader { ..*self }
+        }
+
+        pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+            self.reader.total_size()
+        }
+        #[inline]
+        pub fn get_search_tags(self) -> u64 {
+            self.reader.get_data_field::<u64>(0)
+        }
+        #[inline]
+        pub fn get_build_pipeline(self) -> u64 {
+            self.reader.get_data_field::<u64>(1)
+        }
+        #[inline]
+        pub fn get_artifact(self) -> u64 {
+            self.reader.get_data_field::<u64>(2)
+        }
+        #[inline]
+        pub fn get_label(self) -> u64 {
+            self.reader.get_data_field::<u64>(3)
+        }
+    }
+
+    pub struct Builder<'a> {
+        builder: ::capnp::private::layout::StructBuilder<'a>,
+    }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+        #[inline]
+        fn struct_size() -> ::capnp::private::layout::StructSize {
+            _private::STRUCT_SIZE
+        }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+        #[inline]
+        fn type_id() -> u64 {
+            _private::TYPE_ID
+        }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+        fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+            Builder { builder }
+        }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+        fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+            self.builder
+                .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+        }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+        fn init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> Builder<'a> {
+            ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+        }
+        fn get_from_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            default: ::core::option::Option<&'a [capnp::Word]>,
+        ) -> ::capnp::Result<Builder<'a>> {
+            ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                builder.get_struct(_private::STRUCT_SIZE, default)?,
+            ))
+        }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+        fn set_pointer_builder<'b>(
+            pointer: ::capnp::private::layout::PointerBuilder<'b>,
+            value: Reader<'a>,
+            canonicalize: bool,
+        ) -> ::capnp::Result<()> {
+            pointer.set_struct(&value.reader, canonicalize)
+        }
+    }
+
+    impl<'a> Builder<'a> {
+        pub fn into_reader(self) -> Reader<'a> {
+            ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+        }
+        pub fn reborrow(&mut self) -> Builder {
+            Builder { ..*self }
+        }
+        pub fn reborrow_as_reader(&self) -> Reader {
+            ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+        }
+
+        pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+            self.builder.into_reader().total_size()
+        }
+        #[inline]
+        pub fn get_search_tags(self) -> u64 {
+            self.builder.get_data_field::<u64>(0)
+        }
+        #[inline]
+        pub fn set_search_tags(&mut self, value: u64) {
+            self.builder.set_data_field::<u64>(0, value);
+        }
+        #[inline]
+        pub fn get_build_pipeline(self) -> u64 {
+            self.builder.get_data_field::<u64>(1)
+        }
+        #[inline]
+        pub fn set_build_pipeline(&mut self, value: u64) {
+            self.builder.set_data_field::<u64>(1, value);
+        }
+        #[inline]
+        pub fn get_artifact(self) -> u64 {
+            self.builder.get_data_field::<u64>(2)
+        }
+        #[inline]
+        pub fn set_artifact(&mut self, value: u64) {
+            self.builder.set_data_field::<u64>(2, value);
+        }
+        #[inline]
+        pub fn get_label(self) -> u64 {
+            self.builder.get_data_field::<u64>(3)
+        }
+        #[inline]
+        pub fn set_label(&mut self, value: u64) {
+            self.builder.set_data_field::<u64>(3, value);
+        }
+    }
+
+    pub struct Pipeline {
+        _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+        fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+            Pipeline {
+                _typeless: typeless,
+            }
+        }
+    }
+    impl Pipeline {}
+    mod _private {
+        use capnp::private::layout;
+        pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+            data: 4,
+            pointers: 0,
+        };
+        pub const TYPE_ID: u64 = 0xdd51_6bad_18d2_d4ac;
+    }
+}
+pub mod known_asset_metadata {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+        type Reader = Reader<'a>;
+        type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+        type Reader = Reader<'a>;
+        type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+        type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+        reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+        #[inline]
+        fn type_id() -> u64 {
+            _private::TYPE_ID
+        }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+        fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+            Reader { reader }
+        }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+        fn get_from_pointer(
+            reader: &::capnp::private::layout::PointerReader<'a>,
+            default: ::core::option::Option<&'a [capnp::Word]>,
+        ) -> ::capnp::Result<Reader<'a>> {
+            ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                reader.get_struct(default)?,
+            ))
+        }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+        fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+            self.reader
+        }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+        fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+            self.reader
+                .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+        }
+    }
+
+    impl<'a> Reader<'a> {
+        pub fn reborrow(&self) -> Reader {
+            Reader { ..*self }
+        }
+
+        pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+            self.reader.total_size()
+        }
+        #[inline]
+        pub fn get_id(self) -> ::capnp::Result<crate::data_capnp::asset_uuid::Reader<'a>> {
+            ::capnp::traits::FromPointerReader::get_from_pointer(
+                &self.reader.get_pointer_field(0),
+                ::core::option::Option::None,
+            )
+        }
+        pub fn has_id(&self) -> bool {
+            !self.reader.get_pointer_field(0).is_null()
+        }
+        #[inline]
+        pub fn get_version(
+            self,
+        ) -> ::capnp::Result<crate::service_capnp::asset_metadata_version::Reader<'a>> {
+            ::capnp::traits::FromPointerReader::get_from_pointer(
+                &self.reader.get_pointer_field(1),
+                ::core::option::Option::None,
+            )
+        }
+        pub fn has_version(&self) -> bool {
+            !self.reader.get_pointer_field(1).is_null()
+        }
+    }
+
+    pub struct Builder<'a> {
+        builder: ::capnp::private::layout::StructBuilder<'a>,
+    }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+        #[inline]
+        fn struct_size() -> ::capnp::private::layout::StructSize {
+            _private::STRUCT_SIZE
+        }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+        #[inline]
+        fn type_id() -> u64 {
+            _private::TYPE_ID
+        }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+        fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+            Builder { builder }
+        }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+        fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+            self.builder
+                .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+        }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+        fn init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> Builder<'a> {
+            ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+        }
+        fn get_from_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            default: ::core::option::Option<&'a [capnp::Word]>,
+        ) -> ::capnp::Result<Builder<'a>> {
+            ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                builder.get_struct(_private::STRUCT_SIZE, default)?,
+            ))
+        }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+        fn set_pointer_builder<'b>(
+            pointer: ::capnp::private::layout::PointerBuilder<'b>,
+            value: Reader<'a>,
+            canonicalize: bool,
+        ) -> ::capnp::Result<()> {
+            pointer.set_struct(&value.reader, canonicalize)
+        }
+    }
+
+    impl<'a> Builder<'a> {
+        pub fn into_reader(self) -> Reader<'a> {
+            ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+        }
+        pub fn reborrow(&mut self) -> Builder {
+            Builder { ..*self }
+        }
+        pub fn reborrow_as_reader(&self) -> Reader {
+            ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+        }
+
+        pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+            self.builder.into_reader().total_size()
+        }
+        #[inline]
+        pub fn get_id(self) -> ::capnp::Result<crate::data_capnp::asset_uuid::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                self.builder.get_pointer_field(0),
+                ::core::option::Option::None,
+            )
+        }
+        #[inline]
+        pub fn set_id<'b>(
+            &mut self,
+            value: crate::data_capnp::asset_uuid::Reader<'b>,
+        ) -> ::capnp::Result<()> {
+            ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                self.builder.get_pointer_field(0),
+                value,
+                false,
+            )
+        }
+        #[inline]
+        pub fn init_id(self) -> crate::data_capnp::asset_uuid::Builder<'a> {
+            ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+        }
+        pub fn has_id(&self) -> bool {
+            !self.builder.get_pointer_field(0).is_null()
+        }
+        #[inline]
+        pub fn get_version(
+            self,
+        ) -> ::capnp::Result<crate::service_capnp::asset_metadata_version::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                self.builder.get_pointer_field(1),
+                ::core::option::Option::None,
+            )
+        }
+        #[inline]
+        pub fn set_version<'b>(
+            &mut self,
+            value: crate::service_capnp::asset_metadata_version::Reader<'b>,
+        ) -> ::capnp::Result<()> {
+            ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                self.builder.get_pointer_field(1),
+                value,
+                false,
+            )
+        }
+        #[inline]
+        pub fn init_version(self) -> crate::service_capnp::asset_metadata_version::Builder<'a> {
+            ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), 0)
+        }
+        pub fn has_version(&self) -> bool {
+            !self.builder.get_pointer_field(1).is_null()
+        }
+    }
+
+    pub struct Pipeline {
+        _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+        fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+            Pipeline {
+                _typeless: typeless,
+            }
+        }
+    }
+    impl Pipeline {
+        pub fn get_id(&self) -> crate::data_capnp::asset_uuid::Pipeline {
+            ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+        }
+        pub fn get_version(&self) -> crate::service_capnp::asset_metadata_version::Pipeline {
+            ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(1))
+        }
+    }
+    mod _private {
+        use capnp::private::layout;
+        pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+            data: 0,
+            pointers: 2,
+        };
+        pub const TYPE_ID: u64 = 0xf24d_33ac_9cab_f66c;
+    }
+}
+pub mod asset_metadata_change {
+    pub use self::Which::{Changed, Removed};
+
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+        type Reader = Reader<'a>;
+        type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+        type Reader = Reader<'a>;
+        type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+        type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+        reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+        #[inline]
+        fn type_id() -> u64 {
+            _private::TYPE_ID
+        }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+        fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+            Reader { reader }
+        }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+        fn get_from_pointer(
+            reader: &::capnp::private::layout::PointerReader<'a>,
+            default: ::core::option::Option<&'a [capnp::Word]>,
+        ) -> ::capnp::Result<Reader<'a>> {
+            ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                reader.get_struct(default)?,
+            ))
+        }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+        fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+            self.reader
+        }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+        fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+            self.reader
+                .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+        }
+    }
+
+    impl<'a> Reader<'a> {
+        pub fn reborrow(&self) -> Reader {
+            Reader { ..*self }
+        }
+
+        pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+            self.reader.total_size()
+        }
+        #[inline]
+        pub fn get_id(self) -> ::capnp::Result<crate::data_capnp::asset_uuid::Reader<'a>> {
+            ::capnp::traits::FromPointerReader::get_from_pointer(
+                &self.reader.get_pointer_field(0),
+                ::core::option::Option::None,
+            )
+        }
+        pub fn has_id(&self) -> bool {
+            !self.reader.get_pointer_field(0).is_null()
+        }
+        #[inline]
+        pub fn which(self) -> ::core::result::Result<WhichReader, ::capnp::NotInSchema> {
+            match self.reader.get_data_field::<u16>(0) {
+                0 => ::core::result::Result::Ok(Removed(())),
+                1 => ::core::result::Result::Ok(Changed(())),
+                x => ::core::result::Result::Err(::capnp::NotInSchema(x)),
+            }
+        }
+        #[inline]
+        pub fn get_version(
+            self,
+        ) -> ::capnp::Result<crate::service_capnp::asset_metadata_version::Reader<'a>> {
+            ::capnp::traits::FromPointerReader::get_from_pointer(
+                &self.reader.get_pointer_field(1),
+                ::core::option::Option::None,
+            )
+        }
+        pub fn has_version(&self) -> bool {
+            !self.reader.get_pointer_field(1).is_null()
+        }
+        #[inline]
+        pub fn get_search_tags_changed(self) -> bool {
+            self.reader.get_bool_field(16)
+        }
+        #[inline]
+        pub fn get_search_tags(
+            self,
+        ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::data_capnp::key_value::Owned>>
+        {
+            ::capnp::traits::FromPointerReader::get_from_pointer(
+                &self.reader.get_pointer_field(2),
+                ::core::option::Option::None,
+            )
+        }
+        pub fn has_search_tags(&self) -> bool {
+            !self.reader.get_pointer_field(2).is_null()
+        }
+        #[inline]
+        pub fn get_build_pipeline_changed(self) -> bool {
+            self.reader.get_bool_field(17)
+        }
+        #[inline]
+        pub fn get_build_pipeline(
+            self,
+        ) -> ::capnp::Result<crate::data_capnp::asset_uuid::Reader<'a>> {
+            ::capnp::traits::FromPointerReader::get_from_pointer(
+                &self.reader.get_pointer_field(3),
+                ::core::option::Option::None,
+            )
+        }
+        pub fn has_build_pipeline(&self) -> bool {
+            !self.reader.get_pointer_field(3).is_null()
+        }
+        #[inline]
+        pub fn get_artifact_changed(self) -> bool {
+            self.reader.get_bool_field(18)
+        }
+        #[inline]
+        pub fn get_artifact(
+            self,
+        ) -> ::capnp::Result<crate::data_capnp::artifact_metadata::Reader<'a>> {
+            ::capnp::traits::FromPointerReader::get_from_pointer(
+                &self.reader.get_pointer_field(4),
+                ::core::option::Option::None,
+            )
+        }
+        pub fn has_artifact(&self) -> bool {
+            !self.reader.get_pointer_field(4).is_null()
+        }
+        #[inline]
+        pub fn get_label_changed(self) -> bool {
+            self.reader.get_bool_field(19)
+        }
+        #[inline]
+        pub fn get_label(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+            ::capnp::traits::FromPointerReader::get_from_pointer(
+                &self.reader.get_pointer_field(5),
+                ::core::option::Option::None,
+            )
+        }
+        pub fn has_label(&self) -> bool {
+            !self.reader.get_pointer_field(5).is_null()
+        }
+    }
+
+    pub struct Builder<'a> {
+        builder: ::capnp::private::layout::StructBuilder<'a>,
+    }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+        #[inline]
+        fn struct_size() -> ::capnp::private::layout::StructSize {
+            _private::STRUCT_SIZE
+        }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+        #[inline]
+        fn type_id() -> u64 {
+            _private::TYPE_ID
+        }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+        fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+            Builder { builder }
+        }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+        fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+            self.builder
+                .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+        }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+        fn init_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> Builder<'a> {
+            ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+        }
+        fn get_from_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            default: ::core::option::Option<&'a [capnp::Word]>,
+        ) -> ::capnp::Result<Builder<'a>> {
+            ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                builder.get_struct(_private::STRUCT_SIZE, default)?,
+            ))
+        }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+        fn set_pointer_builder<'b>(
+            pointer: ::capnp::private::layout::PointerBuilder<'b>,
+            value: Reader<'a>,
+            canonicalize: bool,
+        ) -> ::capnp::Result<()> {
+            pointer.set_struct(&value.reader, canonicalize)
+        }
+    }
+
+    impl<'a> Builder<'a> {
+        pub fn into_reader(self) -> Reader<'a> {
+            ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+        }
+        pub fn reborrow(&mut self) -> Builder {
+            Builder { ..*self }
+        }
+        pub fn reborrow_as_reader(&self) -> Reader {
+            ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+        }
+
+        pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+            self.builder.into_reader().total_size()
+        }
+        #[inline]
+        pub fn get_id(self) -> ::capnp::Result<crate::data_capnp::asset_uuid::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                self.builder.get_pointer_field(0),
+                ::core::option::Option::None,
+            )
+        }
+        #[inline]
+        pub fn set_id<'b>(
+            &mut self,
+            value: crate::data_capnp::asset_uuid::Reader<'b>,
+        ) -> ::capnp::Result<()> {
+            ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                self.builder.get_pointer_field(0),
+                value,
+                false,
+            )
+        }
+        #[inline]
+        pub fn init_id(self) -> crate::data_capnp::asset_uuid::Builder<'a> {
+            ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+        }
+        pub fn has_id(&self) -> bool {
+            !self.builder.get_pointer_field(0).is_null()
+        }
+        #[inline]
+        pub fn set_removed(&mut self, _value: ()) {
+            self.builder.set_data_field::<u16>(0, 0);
+        }
+        #[inline]
+        pub fn set_changed(&mut self, _value: ()) {
+            self.builder.set_data_field::<u16>(0, 1);
+        }
+        #[inline]
+        pub fn which(self) -> ::core::result::Result<WhichBuilder, ::capnp::NotInSchema> {
+            match self.builder.get_data_field::<u16>(0) {
+                0 => ::core::result::Result::Ok(Removed(())),
+                1 => ::core::result::Result::Ok(Changed(())),
+                x => ::core::result::Result::Err(::capnp::NotInSchema(x)),
+            }
+        }
+        #[inline]
+        pub fn get_version(
+            self,
+        ) -> ::capnp::Result<crate::service_capnp::asset_metadata_version::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                self.builder.get_pointer_field(1),
+                ::core::option::Option::None,
+            )
+        }
+        #[inline]
+        pub fn set_version<'b>(
+            &mut self,
+            value: crate::service_capnp::asset_metadata_version::Reader<'b>,
+        ) -> ::capnp::Result<()> {
+            ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                self.builder.get_pointer_field(1),
+                value,
+                false,
+            )
+        }
+        #[inline]
+        pub fn init_version(self) -> crate::service_capnp::asset_metadata_version::Builder<'a> {
+            ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), 0)
+        }
+        pub fn has_version(&self) -> bool {
+            !self.builder.get_pointer_field(1).is_null()
+        }
+        #[inline]
+        pub fn get_search_tags_changed(self) -> bool {
+            self.builder.get_bool_field(16)
+        }
+        #[inline]
+        pub fn set_search_tags_changed(&mut self, value: bool) {
+            self.builder.set_bool_field(16, value);
+        }
+        #[inline]
+        pub fn get_search_tags(
+            self,
+        ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::data_capnp::key_value::Owned>>
+        {
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                self.builder.get_pointer_field(2),
+                ::core::option::Option::None,
+            )
+        }
+        #[inline]
+        pub fn set_search_tags(
+            &mut self,
+            value: ::capnp::struct_list::Reader<'a, crate::data_capnp::key_value::Owned>,
+        ) -> ::capnp::Result<()> {
+            ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                self.builder.get_pointer_field(2),
+                value,
+                false,
+            )
+        }
+        #[inline]
+        pub fn init_search_tags(
+            self,
+            size: u32,
+        ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::key_value::Owned> {
+            ::capnp::traits::FromPointerBuilder::init_pointer(
+                self.builder.get_pointer_field(2),
+                size,
+            )
+        }
+        pub fn has_search_tags(&self) -> bool {
+            !self.builder.get_pointer_field(2).is_null()
+        }
+        #[inline]
+        pub fn get_build_pipeline_changed(self) -> bool {
+            self.builder.get_bool_field(17)
+        }
+        #[inline]
+        pub fn set_build_pipeline_changed(&mut self, value: bool) {
+            self.builder.set_bool_field(17, value);
+        }
+        #[inline]
+        pub fn get_build_pipeline(
+            self,
+        ) -> ::capnp::Result<crate::data_capnp::asset_uuid::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                self.builder.get_pointer_field(3),
+                ::core::option::Option::None,
+            )
+        }
+        #[inline]
+        pub fn set_build_pipeline<'b>(
+            &mut self,
+            value: crate::data_capnp::asset_uuid::Reader<'b>,
+        ) -> ::capnp::Result<()> {
+            ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                self.builder.get_pointer_field(3),
+                value,
+                false,
+            )
+        }
+        #[inline]
+        pub fn init_build_pipeline(self) -> crate::data_capnp::asset_uuid::Builder<'a> {
+            ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), 0)
+        }
+        pub fn has_build_pipeline(&self) -> bool {
+            !self.builder.get_pointer_field(3).is_null()
+        }
+        #[inline]
+        pub fn get_artifact_changed(self) -> bool {
+            self.builder.get_bool_field(18)
+        }
+        #[inline]
+        pub fn set_artifact_changed(&mut self, value: bool) {
+            self.builder.set_bool_field(18, value);
+        }
+        #[inline]
+        pub fn get_artifact(
+            self,
+        ) -> ::capnp::Result<crate::data_capnp::artifact_metadata::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                self.builder.get_pointer_field(4),
+                ::core::option::Option::None,
+            )
+        }
+        #[inline]
+        pub fn set_artifact<'b>(
+            &mut self,
+            value: crate::data_capnp::artifact_metadata::Reader<'b>,
+        ) -> ::capnp::Result<()> {
+            ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                self.builder.get_pointer_field(4),
+                value,
+                false,
+            )
+        }
+        #[inline]
+        pub fn init_artifact(self) -> crate::data_capnp::artifact_metadata::Builder<'a> {
+            ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(4), 0)
+        }
+        pub fn has_artifact(&self) -> bool {
+            !self.builder.get_pointer_field(4).is_null()
+        }
+        #[inline]
+        pub fn get_label_changed(self) -> bool {
+            self.builder.get_bool_field(19)
+        }
+        #[inline]
+        pub fn set_label_changed(&mut self, value: bool) {
+            self.builder.set_bool_field(19, value);
+        }
+        #[inline]
+        pub fn get_label(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                self.builder.get_pointer_field(5),
+                ::core::option::Option::None,
+            )
+        }
+        #[inline]
+        pub fn set_label(&mut self, value: ::capnp::data::Reader) {
+            self.builder.get_pointer_field(5).set_data(value);
+        }
+        #[inline]
+        pub fn init_label(self, size: u32) -> ::capnp::data::Builder<'a> {
+            self.builder.get_pointer_field(5).init_data(size)
+        }
+        pub fn has_label(&self) -> bool {
+            !self.builder.get_pointer_field(5).is_null()
+        }
+    }
+
+    pub struct Pipeline {
+        _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+        fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+            Pipeline {
+                _typeless: typeless,
+            }
+        }
+    }
+    impl Pipeline {
+        pub fn get_id(&self) -> crate::data_capnp::asset_uuid::Pipeline {
+            ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+        }
+        pub fn get_version(&self) -> crate::service_capnp::asset_metadata_version::Pipeline {
+            ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(1))
+        }
+        pub fn get_build_pipeline(&self) -> crate::data_capnp::asset_uuid::Pipeline {
+            ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(3))
+        }
+        pub fn get_artifact(&self) -> crate::data_capnp::artifact_metadata::Pipeline {
+            ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(4))
+        }
+    }
+    mod _private {
+        use capnp::private::layout;
+        pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+            data: 1,
+            pointers: 6,
+        };
+        pub const TYPE_ID: u64 = 0x8b6f_9226_d064_d50a;
+    }
+    pub enum Which {
+        Removed(()),
+        Changed(()),
+    }
+    pub type WhichReader = Which;
+    pub type WhichBuilder = Which;
+}
+pub mod asset_hub {
+    #![allow(unused_variables)]
+    pub type RegisterListenerParams = ::capnp::capability::Params<
+        crate::service_capnp::asset_hub::register_listener_params::Owned,
+    >;
+    pub type RegisterListenerResults = ::capnp::capability::Results<
+        crate::service_capnp::asset_hub::register_listener_results::Owned,
+    >;
+    pub type GetSnapshotParams =
+        ::capnp::capability::Params<crate::service_capnp::asset_hub::get_snapshot_params::Owned>;
+    pub type GetSnapshotResults =
+        ::capnp::capability::Results<crate::service_capnp::asset_hub::get_snapshot_results::Owned>;
+    pub type GetImportersParams =
+        ::capnp::capability::Params<crate::service_capnp::asset_hub::get_importers_params::Owned>;
+    pub type GetImportersResults =
+        ::capnp::capability::Results<crate::service_capnp::asset_hub::get_importers_results::Owned>;
+
+    pub struct Client {
+        pub client: ::capnp::capability::Client,
+    }
+    impl ::capnp::capability::FromClientHook for Client {
+        fn new(hook: Box<dyn (::capnp::private::capability::ClientHook)>) -> Client {
+            Client {
+                client: ::capnp::capability::Client::new(hook),
+            }
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+        type Reader = Client;
+        type Builder = Client;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+        type Pipeline = Client;
+    }
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Client {
+        fn get_from_pointer(
+            reader: &::capnp::private::layout::PointerReader<'a>,
+            _default: ::core::option::Option<&'a [capnp::Word]>,
+        ) -> ::capnp::Result<Client> {
+            ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(
+                reader.get_capability()?,
+            ))
+        }
+    }
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Client {
+        fn init_pointer(
+            _builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> Client {
+            unimplemented!()
+        }
+        fn get_from_pointer(
+            builder: ::capnp::private::layout::PointerBuilder<'a>,
+            _default: ::core::option::Option<&'a [capnp::Word]>,
+        ) -> ::capnp::Result<Client> {
+            ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(
+                builder.get_capability()?,
+            ))
+        }
+    }
+
+    impl ::capnp::traits::SetPointerBuilder<Client> for Client {
+        fn set_pointer_builder(
+            pointer: ::capnp::private::layout::PointerBuilder,
+            from: Client,
+            _canonicalize: bool,
+        ) -> ::capnp::Result<()> {
+            pointer.set_capability(from.client.hook);
+            ::core::result::Result::Ok(())
+        }
+    }
+    impl ::capnp::traits::HasTypeId for Client {
+        #[inline]
+        fn type_id() -> u64 {
+            _private::TYPE_ID
+        }
+    }
+    impl Clone for Client {
+        fn clone(&self) -> Client {
+            Client {
+                client: ::capnp::capability::Client::new(self.client.hook.add_ref()),
+            }
+        }
+    }
+    impl Client {
+        pub fn register_listener_request(
+            &self,
+        ) -> ::capnp::capability::Request<
+            crate::service_capnp::asset_hub::register_listener_params::Owned,
+            crate::service_capnp::asset_hub::register_listener_results::Owned,
+        > {
+            self.client.new_call(_private::TYPE_ID, 0, None)
+        }
+        pub fn get_snapshot_request(
+            &self,
+        ) -> ::capnp::capability::Request<
+            crate::service_capnp::asset_hub::get_snapshot_params::Owned,
+            crate::service_capnp::asset_hub::get_snapshot_results::Owned,
+        > {
+            self.client.new_call(_private::TYPE_ID, 1, None)
+        }
+        pub fn get_importers_request(
+            &self,
+        ) -> ::capnp::capability::Request<
+            crate::service_capnp::asset_hub::get_importers_params::Owned,
+            crate::service_capnp::asset_hub::get_importers_results::Owned,
+        > {
+            self.client.new_call(_private::TYPE_ID, 2, None)
+        }
+    }
+    pub trait Server {
+        fn register_listener(
+            &mut self,
+            _: RegisterListenerParams,
+            _: RegisterListenerResults,
+        ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+            ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                "method not implemented".to_string(),
+            ))
+        }
+        fn get_snapshot(
+            &mut self,
+            _: GetSnapshotParams,
+            _: GetSnapshotResults,
+        ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+            ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                "method not implemented".to_string(),
+            ))
+        }
+        fn get_importers(
+            &mut self,
+            _: GetImportersParams,
+            _: GetImportersResults,
+        ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+            ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                "method not implemented".to_string(),
+            ))
+        }
+    }
+    pub struct ServerDispatch<_T> {
+        pub server: _T,
+    }
+    impl<_S: Server + 'static> ::capnp::capability::FromServer<_S> for Client {
+        type Dispatch = ServerDispatch<_S>;
+        fn from_server(s: _S) -> ServerDispatch<_S> {
+            ServerDispatch { server: s }
+        }
+    }
+    impl<_T: Server> ::core::ops::Deref for ServerDispatch<_T> {
+        type Target = _T;
+        fn deref(&self) -> &_T {
+            &self.server
+        }
+    }
+    impl<_T: Server> ::core::ops::DerefMut for ServerDispatch<_T> {
+        fn deref_mut(&mut self) -> &mut _T {
+            &mut self.server
+        }
+    }
+    impl<_T: Server> ::capnp::capability::Server for ServerDispatch<_T> {
+        fn dispatch_call(
+            &mut self,
+            interface_id: u64,
+            method_id: u16,
+            params: ::capnp::capability::Params<::capnp::any_pointer::Owned>,
+            results: ::capnp::capability::Results<::capnp::any_pointer::Owned>,
+        ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+            match interface_id {
+                _private::TYPE_ID => ServerDispatch::<_T>::dispatch_call_internal(
+                    &mut self.server,
+                    method_id,
+                    params,
+                    results,
+                ),
+                _ => ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "Method not implemented.".to_string(),
+                )),
+            }
+        }
+    }
+    impl<_T: Server> ServerDispatch<_T> {
+        pub fn dispatch_call_internal(
+            server: &mut _T,
+            method_id: u16,
+            params: ::capnp::capability::Params<::capnp::any_pointer::Owned>,
+            results: ::capnp::capability::Results<::capnp::any_pointer::Owned>,
+        ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+            match method_id {
+                0 => server.register_listener(
+                    ::capnp::private::capability::internal_get_typed_params(params),
+                    ::capnp::private::capability::internal_get_typed_results(results),
+                ),
+                1 => server.get_snapshot(
+                    ::capnp::private::capability::internal_get_typed_params(params),
+                    ::capnp::private::capability::internal_get_typed_results(results),
+                ),
+                2 => server.get_importers(
+                    ::capnp::private::capability::internal_get_typed_params(params),
+                    ::capnp::private::capability::internal_get_typed_results(results),
+                ),
+                _ => ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "Method not implemented.".to_string(),
+                )),
+            }
+        }
+    }
+    pub mod _private {
+        pub const TYPE_ID: u64 = 0xe06a_89bf_80a1_1b12;
+    }
+
+    pub mod snapshot {
+        #![allow(unused_variables)]
+        pub type GetAssetMetadataParams = ::capnp::capability::Params<
             crate::service_capnp::asset_hub::snapshot::get_asset_metadata_params::Owned,
         >;
         pub type GetAssetMetadataResults = ::capnp::capability::Results<
@@ -931,387 +2394,3992 @@ pub mod asset_hub {
         pub type DeleteFileResults = ::capnp::capability::Results<
             crate::service_capnp::asset_hub::snapshot::delete_file_results::Owned,
         >;
+        pub type ForceReimportParams = ::capnp::capability::Params<
+            crate::service_capnp::asset_hub::snapshot::force_reimport_params::Owned,
+        >;
+        pub type ForceReimportResults = ::capnp::capability::Results<
+            crate::service_capnp::asset_hub::snapshot::force_reimport_results::Owned,
+        >;
+        pub type GetAssetMetadataWithBuildDependenciesParams<> = ::capnp::capability::Params<crate::service_capnp::asset_hub::snapshot::get_asset_metadata_with_build_dependencies_params::Owned>;
+        pub type GetAssetMetadataWithBuildDependenciesResults<> = ::capnp::capability::Results<crate::service_capnp::asset_hub::snapshot::get_asset_metadata_with_build_dependencies_results::Owned>;
+        pub type SearchAssetsParams = ::capnp::capability::Params<
+            crate::service_capnp::asset_hub::snapshot::search_assets_params::Owned,
+        >;
+        pub type SearchAssetsResults = ::capnp::capability::Results<
+            crate::service_capnp::asset_hub::snapshot::search_assets_results::Owned,
+        >;
+        pub type GetAssetForLabelParams = ::capnp::capability::Params<
+            crate::service_capnp::asset_hub::snapshot::get_asset_for_label_params::Owned,
+        >;
+        pub type GetAssetForLabelResults = ::capnp::capability::Results<
+            crate::service_capnp::asset_hub::snapshot::get_asset_for_label_results::Owned,
+        >;
+        pub type RebuildAllArtifactsParams = ::capnp::capability::Params<
+            crate::service_capnp::asset_hub::snapshot::rebuild_all_artifacts_params::Owned,
+        >;
+        pub type RebuildAllArtifactsResults = ::capnp::capability::Results<
+            crate::service_capnp::asset_hub::snapshot::rebuild_all_artifacts_results::Owned,
+        >;
+        pub type GetAssetMetadataChangesParams = ::capnp::capability::Params<
+            crate::service_capnp::asset_hub::snapshot::get_asset_metadata_changes_params::Owned,
+        >;
+        pub type GetAssetMetadataChangesResults = ::capnp::capability::Results<
+            crate::service_capnp::asset_hub::snapshot::get_asset_metadata_changes_results::Owned,
+        >;
+
+        pub struct Client {
+            pub client: ::capnp::capability::Client,
+        }
+        impl ::capnp::capability::FromClientHook for Client {
+            fn new(hook: Box<dyn (::capnp::private::capability::ClientHook)>) -> Client {
+                Client {
+                    client: ::capnp::capability::Client::new(hook),
+                }
+            }
+        }
+        #[derive(Copy, Clone)]
+        pub struct Owned;
+        impl<'a> ::capnp::traits::Owned<'a> for Owned {
+            type Reader = Client;
+            type Builder = Client;
+        }
+        impl ::capnp::traits::Pipelined for Owned {
+            type Pipeline = Client;
+        }
+        impl<'a> ::capnp::traits::FromPointerReader<'a> for Client {
+            fn get_from_pointer(
+                reader: &::capnp::private::layout::PointerReader<'a>,
+                _default: ::core::option::Option<&'a [capnp::Word]>,
+            ) -> ::capnp::Result<Client> {
+                ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(
+                    reader.get_capability()?,
+                ))
+            }
+        }
+        impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Client {
+            fn init_pointer(
+                _builder: ::capnp::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> Client {
+                unimplemented!()
+            }
+            fn get_from_pointer(
+                builder: ::capnp::private::layout::PointerBuilder<'a>,
+                _default: ::core::option::Option<&'a [capnp::Word]>,
+            ) -> ::capnp::Result<Client> {
+                ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(
+                    builder.get_capability()?,
+                ))
+            }
+        }
+
+        impl ::capnp::traits::SetPointerBuilder<Client> for Client {
+            fn set_pointer_builder(
+                pointer: ::capnp::private::layout::PointerBuilder,
+                from: Client,
+                _canonicalize: bool,
+            ) -> ::capnp::Result<()> {
+                pointer.set_capability(from.client.hook);
+                ::core::result::Result::Ok(())
+            }
+        }
+        impl ::capnp::traits::HasTypeId for Client {
+            #[inline]
+            fn type_id() -> u64 {
+                _private::TYPE_ID
+            }
+        }
+        impl Clone for Client {
+            fn clone(&self) -> Client {
+                Client {
+                    client: ::capnp::capability::Client::new(self.client.hook.add_ref()),
+                }
+            }
+        }
+        impl Client {
+            pub fn get_asset_metadata_request(
+                &self,
+            ) -> ::capnp::capability::Request<
+                crate::service_capnp::asset_hub::snapshot::get_asset_metadata_params::Owned,
+                crate::service_capnp::asset_hub::snapshot::get_asset_metadata_results::Owned,
+            > {
+                self.client.new_call(_private::TYPE_ID, 0, None)
+            }
+pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capability::Request<crate::service_capnp::asset_hub::snapshot::get_asset_metadata_with_dependencies_params::Owned,crate::service_capnp::asset_hub::snapshot::get_asset_metadata_with_dependencies_results::Owned>{
+                self.client.new_call(_private::TYPE_ID, 1, None)
+            }
+            pub fn get_all_asset_metadata_request(
+                &self,
+            ) -> ::capnp::capability::Request<
+                crate::service_capnp::asset_hub::snapshot::get_all_asset_metadata_params::Owned,
+                crate::service_capnp::asset_hub::snapshot::get_all_asset_metadata_results::Owned,
+            > {
+                self.client.new_call(_private::TYPE_ID, 2, None)
+            }
+            pub fn get_latest_asset_change_request(
+                &self,
+            ) -> ::capnp::capability::Request<
+                crate::service_capnp::asset_hub::snapshot::get_latest_asset_change_params::Owned,
+                crate::service_capnp::asset_hub::snapshot::get_latest_asset_change_results::Owned,
+            > {
+                self.client.new_call(_private::TYPE_ID, 3, None)
+            }
+            pub fn get_asset_changes_request(
+                &self,
+            ) -> ::capnp::capability::Request<
+                crate::service_capnp::asset_hub::snapshot::get_asset_changes_params::Owned,
+                crate::service_capnp::asset_hub::snapshot::get_asset_changes_results::Owned,
+            > {
+                self.client.new_call(_private::TYPE_ID, 4, None)
+            }
+            pub fn get_import_artifacts_request(
+                &self,
+            ) -> ::capnp::capability::Request<
+                crate::service_capnp::asset_hub::snapshot::get_import_artifacts_params::Owned,
+                crate::service_capnp::asset_hub::snapshot::get_import_artifacts_results::Owned,
+            > {
+                self.client.new_call(_private::TYPE_ID, 5, None)
+            }
+            pub fn update_asset_request(
+                &self,
+            ) -> ::capnp::capability::Request<
+                crate::service_capnp::asset_hub::snapshot::update_asset_params::Owned,
+                crate::service_capnp::asset_hub::snapshot::update_asset_results::Owned,
+            > {
+                self.client.new_call(_private::TYPE_ID, 6, None)
+            }
+            pub fn patch_asset_request(
+                &self,
+            ) -> ::capnp::capability::Request<
+                crate::service_capnp::asset_hub::snapshot::patch_asset_params::Owned,
+                crate::service_capnp::asset_hub::snapshot::patch_asset_results::Owned,
+            > {
+                self.client.new_call(_private::TYPE_ID, 7, None)
+            }
+            pub fn get_path_for_assets_request(
+                &self,
+            ) -> ::capnp::capability::Request<
+                crate::service_capnp::asset_hub::snapshot::get_path_for_assets_params::Owned,
+                crate::service_capnp::asset_hub::snapshot::get_path_for_assets_results::Owned,
+            > {
+                self.client.new_call(_private::TYPE_ID, 8, None)
+            }
+            pub fn get_assets_for_paths_request(
+                &self,
+            ) -> ::capnp::capability::Request<
+                crate::service_capnp::asset_hub::snapshot::get_assets_for_paths_params::Owned,
+                crate::service_capnp::asset_hub::snapshot::get_assets_for_paths_results::Owned,
+            > {
+                self.client.new_call(_private::TYPE_ID, 9, None)
+            }
+            pub fn create_file_request(
+                &self,
+            ) -> ::capnp::capability::Request<
+                crate::service_capnp::asset_hub::snapshot::create_file_params::Owned,
+                crate::service_capnp::asset_hub::snapshot::create_file_results::Owned,
+            > {
+                self.client.new_call(_private::TYPE_ID, 10, None)
+            }
+            pub fn delete_file_request(
+                &self,
+            ) -> ::capnp::capability::Request<
+                crate::service_capnp::asset_hub::snapshot::delete_file_params::Owned,
+                crate::service_capnp::asset_hub::snapshot::delete_file_results::Owned,
+            > {
+                self.client.new_call(_private::TYPE_ID, 11, None)
+            }
+            pub fn force_reimport_request(
+                &self,
+            ) -> ::capnp::capability::Request<
+                crate::service_capnp::asset_hub::snapshot::force_reimport_params::Owned,
+                crate::service_capnp::asset_hub::snapshot::force_reimport_results::Owned,
+            > {
+                self.client.new_call(_private::TYPE_ID, 12, None)
+            }
+pub fn get_asset_metadata_with_build_dependencies_request(&self) -> ::capnp::capability::Request<crate::service_capnp::asset_hub::snapshot::get_asset_metadata_with_build_dependencies_params::Owned,crate::service_capnp::asset_hub::snapshot::get_asset_metadata_with_build_dependencies_results::Owned>{
+                self.client.new_call(_private::TYPE_ID, 13, None)
+            }
+            pub fn search_assets_request(
+                &self,
+            ) -> ::capnp::capability::Request<
+                crate::service_capnp::asset_hub::snapshot::search_assets_params::Owned,
+                crate::service_capnp::asset_hub::snapshot::search_assets_results::Owned,
+            > {
+                self.client.new_call(_private::TYPE_ID, 14, None)
+            }
+            pub fn get_asset_for_label_request(
+                &self,
+            ) -> ::capnp::capability::Request<
+                crate::service_capnp::asset_hub::snapshot::get_asset_for_label_params::Owned,
+                crate::service_capnp::asset_hub::snapshot::get_asset_for_label_results::Owned,
+            > {
+                self.client.new_call(_private::TYPE_ID, 15, None)
+            }
+            pub fn rebuild_all_artifacts_request(
+                &self,
+            ) -> ::capnp::capability::Request<
+                crate::service_capnp::asset_hub::snapshot::rebuild_all_artifacts_params::Owned,
+                crate::service_capnp::asset_hub::snapshot::rebuild_all_artifacts_results::Owned,
+            > {
+                self.client.new_call(_private::TYPE_ID, 16, None)
+            }
+            pub fn get_asset_metadata_changes_request(
+                &self,
+            ) -> ::capnp::capability::Request<
+                crate::service_capnp::asset_hub::snapshot::get_asset_metadata_changes_params::Owned,
+                crate::service_capnp::asset_hub::snapshot::get_asset_metadata_changes_results::Owned,
+            >{
+                self.client.new_call(_private::TYPE_ID, 17, None)
+            }
+        }
+        pub trait Server {
+            fn get_asset_metadata(
+                &mut self,
+                _: GetAssetMetadataParams,
+                _: GetAssetMetadataResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+            fn get_asset_metadata_with_dependencies(
+                &mut self,
+                _: GetAssetMetadataWithDependenciesParams,
+                _: GetAssetMetadataWithDependenciesResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+            fn get_all_asset_metadata(
+                &mut self,
+                _: GetAllAssetMetadataParams,
+                _: GetAllAssetMetadataResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+            fn get_latest_asset_change(
+                &mut self,
+                _: GetLatestAssetChangeParams,
+                _: GetLatestAssetChangeResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+            fn get_asset_changes(
+                &mut self,
+                _: GetAssetChangesParams,
+                _: GetAssetChangesResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+            fn get_import_artifacts(
+                &mut self,
+                _: GetImportArtifactsParams,
+                _: GetImportArtifactsResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+            fn update_asset(
+                &mut self,
+                _: UpdateAssetParams,
+                _: UpdateAssetResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+            fn patch_asset(
+                &mut self,
+                _: PatchAssetParams,
+                _: PatchAssetResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+            fn get_path_for_assets(
+                &mut self,
+                _: GetPathForAssetsParams,
+                _: GetPathForAssetsResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+            fn get_assets_for_paths(
+                &mut self,
+                _: GetAssetsForPathsParams,
+                _: GetAssetsForPathsResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+            fn create_file(
+                &mut self,
+                _: CreateFileParams,
+                _: CreateFileResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+            fn delete_file(
+                &mut self,
+                _: DeleteFileParams,
+                _: DeleteFileResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+            fn force_reimport(
+                &mut self,
+                _: ForceReimportParams,
+                _: ForceReimportResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+            fn get_asset_metadata_with_build_dependencies(
+                &mut self,
+                _: GetAssetMetadataWithBuildDependenciesParams,
+                _: GetAssetMetadataWithBuildDependenciesResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+            fn search_assets(
+                &mut self,
+                _: SearchAssetsParams,
+                _: SearchAssetsResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+            fn get_asset_for_label(
+                &mut self,
+                _: GetAssetForLabelParams,
+                _: GetAssetForLabelResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+            fn rebuild_all_artifacts(
+                &mut self,
+                _: RebuildAllArtifactsParams,
+                _: RebuildAllArtifactsResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+            fn get_asset_metadata_changes(
+                &mut self,
+                _: GetAssetMetadataChangesParams,
+                _: GetAssetMetadataChangesResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+        }
+        pub struct ServerDispatch<_T> {
+            pub server: _T,
+        }
+        impl<_S: Server + 'static> ::capnp::capability::FromServer<_S> for Client {
+            type Dispatch = ServerDispatch<_S>;
+            fn from_server(s: _S) -> ServerDispatch<_S> {
+                ServerDispatch { server: s }
+            }
+        }
+        impl<_T: Server> ::core::ops::Deref for ServerDispatch<_T> {
+            type Target = _T;
+            fn deref(&self) -> &_T {
+                &self.server
+            }
+        }
+        impl<_T: Server> ::core::ops::DerefMut for ServerDispatch<_T> {
+            fn deref_mut(&mut self) -> &mut _T {
+                &mut self.server
+            }
+        }
+        impl<_T: Server> ::capnp::capability::Server for ServerDispatch<_T> {
+            fn dispatch_call(
+                &mut self,
+                interface_id: u64,
+                method_id: u16,
+                params: ::capnp::capability::Params<::capnp::any_pointer::Owned>,
+                results: ::capnp::capability::Results<::capnp::any_pointer::Owned>,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                match interface_id {
+                    _private::TYPE_ID => ServerDispatch::<_T>::dispatch_call_internal(
+                        &mut self.server,
+                        method_id,
+                        params,
+                        results,
+                    ),
+                    _ => ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                        "Method not implemented.".to_string(),
+                    )),
+                }
+            }
+        }
+        impl<_T: Server> ServerDispatch<_T> {
+            pub fn dispatch_call_internal(
+                server: &mut _T,
+                method_id: u16,
+                params: ::capnp::capability::Params<::capnp::any_pointer::Owned>,
+                results: ::capnp::capability::Results<::capnp::any_pointer::Owned>,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                match method_id {
+                    0 => server.get_asset_metadata(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    1 => server.get_asset_metadata_with_dependencies(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    2 => server.get_all_asset_metadata(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    3 => server.get_latest_asset_change(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    4 => server.get_asset_changes(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    5 => server.get_import_artifacts(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    6 => server.update_asset(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    7 => server.patch_asset(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    8 => server.get_path_for_assets(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    9 => server.get_assets_for_paths(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    10 => server.create_file(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    11 => server.delete_file(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    12 => server.force_reimport(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    13 => server.get_asset_metadata_with_build_dependencies(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    14 => server.search_assets(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    15 => server.get_asset_for_label(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    16 => server.rebuild_all_artifacts(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    17 => server.get_asset_metadata_changes(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    _ => ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                        "Method not implemented.".to_string(),
+                    )),
+                }
+            }
+        }
+        pub mod _private {
+            pub const TYPE_ID: u64 = 0xda70_22fe_c2b7_2fd6;
+        }
+
+        pub mod get_asset_metadata_params {
+            #[derive(Copy, Clone)]
+            pub struct Owned;
+            impl<'a> ::capnp::traits::Owned<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl ::capnp::traits::Pipelined for Owned {
+                type Pipeline = Pipeline;
+            }
+
+            #[derive(Clone, Copy)]
+            pub struct Reader<'a> {
+                reader: ::capnp::private::layout::StructReader<'a>,
+            }
+
+            impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+                fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+                    Reader { reader }
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+                fn get_from_pointer(
+                    reader: &::capnp::private::layout::PointerReader<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Reader<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                        reader.get_struct(default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+                fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+                    self.reader
+                }
+            }
+
+            impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+                fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+                    self.reader
+                        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+                }
+            }
+
+            impl<'a> Reader<'a> {
+                pub fn reborrow(&self) -> Reader {
+                    Reader { ..*self }
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.reader.total_size()
+                }
+                #[inline]
+                pub fn get_assets(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
+                > {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_assets(&self) -> bool {
+                    !self.reader.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Builder<'a> {
+                builder: ::capnp::private::layout::StructBuilder<'a>,
+            }
+            impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+                #[inline]
+                fn struct_size() -> ::capnp::private::layout::StructSize {
+                    _private::STRUCT_SIZE
+                }
+            }
+            impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+                fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+                    Builder { builder }
+                }
+            }
+
+            impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+                fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+                    self.builder
+                        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+                fn init_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> Builder<'a> {
+                    ::capnp::traits::FromStructBuilder::new(
+                        builder.init_struct(_private::STRUCT_SIZE),
+                    )
+                }
+                fn get_from_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Builder<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                        builder.get_struct(_private::STRUCT_SIZE, default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+                fn set_pointer_builder<'b>(
+                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                    value: Reader<'a>,
+                    canonicalize: bool,
+                ) -> ::capnp::Result<()> {
+                    pointer.set_struct(&value.reader, canonicalize)
+                }
+            }
+
+            impl<'a> Builder<'a> {
+                pub fn into_reader(self) -> Reader<'a> {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+                pub fn reborrow(&mut self) -> Builder {
+                    Builder { ..*self }
+                }
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+                #[inline]
+                pub fn get_assets(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>,
+                > {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_assets(
+                    &mut self,
+                    value: ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
+                ) -> ::capnp::Result<()> {
+                    ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                        self.builder.get_pointer_field(0),
+                        value,
+                        false,
+                    )
+                }
+                #[inline]
+                pub fn init_assets(
+                    self,
+                    size: u32,
+                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>
+                {
+                    ::capnp::traits::FromPointerBuilder::init_pointer(
+                        self.builder.get_pointer_field(0),
+                        size,
+                    )
+                }
+                pub fn has_assets(&self) -> bool {
+                    !self.builder.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
+            }
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
+                    }
+                }
+            }
+            impl Pipeline {}
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 0,
+                    pointers: 1,
+                };
+                pub const TYPE_ID: u64 = 0x9950_490a_65ff_9465;
+            }
+        }
+
+        pub mod get_asset_metadata_results {
+            #[derive(Copy, Clone)]
+            pub struct Owned;
+            impl<'a> ::capnp::traits::Owned<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl ::capnp::traits::Pipelined for Owned {
+                type Pipeline = Pipeline;
+            }
+
+            #[derive(Clone, Copy)]
+            pub struct Reader<'a> {
+                reader: ::capnp::private::layout::StructReader<'a>,
+            }
+
+            impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+                fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+                    Reader { reader }
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+                fn get_from_pointer(
+                    reader: &::capnp::private::layout::PointerReader<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Reader<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                        reader.get_struct(default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+                fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+                    self.reader
+                }
+            }
+
+            impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+                fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+                    self.reader
+                        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+                }
+            }
+
+            impl<'a> Reader<'a> {
+                pub fn reborrow(&self) -> Reader {
+                    Reader { ..*self }
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.reader.total_size()
+                }
+                #[inline]
+                pub fn get_assets(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_metadata::Owned>,
+                > {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_assets(&self) -> bool {
+                    !self.reader.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Builder<'a> {
+                builder: ::capnp::private::layout::StructBuilder<'a>,
+            }
+            impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+                #[inline]
+                fn struct_size() -> ::capnp::private::layout::StructSize {
+                    _private::STRUCT_SIZE
+                }
+            }
+            impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+                fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+                    Builder { builder }
+                }
+            }
+
+            impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+                fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+                    self.builder
+                        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+                fn init_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> Builder<'a> {
+                    ::capnp::traits::FromStructBuilder::new(
+                        builder.init_struct(_private::STRUCT_SIZE),
+                    )
+                }
+                fn get_from_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Builder<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                        builder.get_struct(_private::STRUCT_SIZE, default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+                fn set_pointer_builder<'b>(
+                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                    value: Reader<'a>,
+                    canonicalize: bool,
+                ) -> ::capnp::Result<()> {
+                    pointer.set_struct(&value.reader, canonicalize)
+                }
+            }
+
+            impl<'a> Builder<'a> {
+                pub fn into_reader(self) -> Reader<'a> {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+                pub fn reborrow(&mut self) -> Builder {
+                    Builder { ..*self }
+                }
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+                #[inline]
+                pub fn get_assets(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_metadata::Owned>,
+                > {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_assets(
+                    &mut self,
+                    value: ::capnp::struct_list::Reader<
+                        'a,
+                        crate::data_capnp::asset_metadata::Owned,
+                    >,
+                ) -> ::capnp::Result<()> {
+                    ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                        self.builder.get_pointer_field(0),
+                        value,
+                        false,
+                    )
+                }
+                #[inline]
+                pub fn init_assets(
+                    self,
+                    size: u32,
+                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_metadata::Owned>
+                {
+                    ::capnp::traits::FromPointerBuilder::init_pointer(
+                        self.builder.get_pointer_field(0),
+                        size,
+                    )
+                }
+                pub fn has_assets(&self) -> bool {
+                    !self.builder.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
+            }
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
+                    }
+                }
+            }
+            impl Pipeline {}
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 0,
+                    pointers: 1,
+                };
+                pub const TYPE_ID: u64 = 0xa407_3b0d_081c_18e1;
+            }
+        }
+
+        pub mod get_asset_metadata_with_dependencies_params {
+            #[derive(Copy, Clone)]
+            pub struct Owned;
+            impl<'a> ::capnp::traits::Owned<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl ::capnp::traits::Pipelined for Owned {
+                type Pipeline = Pipeline;
+            }
+
+            #[derive(Clone, Copy)]
+            pub struct Reader<'a> {
+                reader: ::capnp::private::layout::StructReader<'a>,
+            }
+
+            impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+                fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+                    Reader { reader }
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+                fn get_from_pointer(
+                    reader: &::capnp::private::layout::PointerReader<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Reader<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                        reader.get_struct(default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+                fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+                    self.reader
+                }
+            }
+
+            impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+                fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+                    self.reader
+                        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+                }
+            }
+
+            impl<'a> Reader<'a> {
+                pub fn reborrow(&self) -> Reader {
+                    Reader { ..*self }
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.reader.total_size()
+                }
+                #[inline]
+                pub fn get_assets(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
+                > {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_assets(&self) -> bool {
+                    !self.reader.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Builder<'a> {
+                builder: ::capnp::private::layout::StructBuilder<'a>,
+            }
+            impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+                #[inline]
+                fn struct_size() -> ::capnp::private::layout::StructSize {
+                    _private::STRUCT_SIZE
+                }
+            }
+            impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+                fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+                    Builder { builder }
+                }
+            }
+
+            impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+                fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+                    self.builder
+                        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+                fn init_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> Builder<'a> {
+                    ::capnp::traits::FromStructBuilder::new(
+                        builder.init_struct(_private::STRUCT_SIZE),
+                    )
+                }
+                fn get_from_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Builder<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                        builder.get_struct(_private::STRUCT_SIZE, default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+                fn set_pointer_builder<'b>(
+                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                    value: Reader<'a>,
+                    canonicalize: bool,
+                ) -> ::capnp::Result<()> {
+                    pointer.set_struct(&value.reader, canonicalize)
+                }
+            }
+
+            impl<'a> Builder<'a> {
+                pub fn into_reader(self) -> Reader<'a> {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+                pub fn reborrow(&mut self) -> Builder {
+                    Builder { ..*self }
+                }
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+                #[inline]
+                pub fn get_assets(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>,
+                > {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_assets(
+                    &mut self,
+                    value: ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
+                ) -> ::capnp::Result<()> {
+                    ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                        self.builder.get_pointer_field(0),
+                        value,
+                        false,
+                    )
+                }
+                #[inline]
+                pub fn init_assets(
+                    self,
+                    size: u32,
+                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>
+                {
+                    ::capnp::traits::FromPointerBuilder::init_pointer(
+                        self.builder.get_pointer_field(0),
+                        size,
+                    )
+                }
+                pub fn has_assets(&self) -> bool {
+                    !self.builder.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
+            }
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
+                    }
+                }
+            }
+            impl Pipeline {}
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 0,
+                    pointers: 1,
+                };
+                pub const TYPE_ID: u64 = 0xd145_75a0_93f7_4505;
+            }
+        }
+
+        pub mod get_asset_metadata_with_dependencies_results {
+            #[derive(Copy, Clone)]
+            pub struct Owned;
+            impl<'a> ::capnp::traits::Owned<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl ::capnp::traits::Pipelined for Owned {
+                type Pipeline = Pipeline;
+            }
+
+            #[derive(Clone, Copy)]
+            pub struct Reader<'a> {
+                reader: ::capnp::private::layout::StructReader<'a>,
+            }
+
+            impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+                fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+                    Reader { reader }
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+                fn get_from_pointer(
+                    reader: &::capnp::private::layout::PointerReader<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Reader<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                        reader.get_struct(default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+                fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+                    self.reader
+                }
+            }
+
+            impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+                fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+                    self.reader
+                        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+                }
+            }
+
+            impl<'a> Reader<'a> {
+                pub fn reborrow(&self) -> Reader {
+                    Reader { ..*self }
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.reader.total_size()
+                }
+                #[inline]
+                pub fn get_assets(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_metadata::Owned>,
+                > {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_assets(&self) -> bool {
+                    !self.reader.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Builder<'a> {
+                builder: ::capnp::private::layout::StructBuilder<'a>,
+            }
+            impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+                #[inline]
+                fn struct_size() -> ::capnp::private::layout::StructSize {
+                    _private::STRUCT_SIZE
+                }
+            }
+            impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+                fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+                    Builder { builder }
+                }
+            }
+
+            impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+                fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+                    self.builder
+                        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+                fn init_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> Builder<'a> {
+                    ::capnp::traits::FromStructBuilder::new(
+                        builder.init_struct(_private::STRUCT_SIZE),
+                    )
+                }
+                fn get_from_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Builder<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                        builder.get_struct(_private::STRUCT_SIZE, default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+                fn set_pointer_builder<'b>(
+                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                    value: Reader<'a>,
+                    canonicalize: bool,
+                ) -> ::capnp::Result<()> {
+                    pointer.set_struct(&value.reader, canonicalize)
+                }
+            }
+
+            impl<'a> Builder<'a> {
+                pub fn into_reader(self) -> Reader<'a> {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+                pub fn reborrow(&mut self) -> Builder {
+                    Builder { ..*self }
+                }
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+                #[inline]
+                pub fn get_assets(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_metadata::Owned>,
+                > {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_assets(
+                    &mut self,
+                    value: ::capnp::struct_list::Reader<
+                        'a,
+                        crate::data_capnp::asset_metadata::Owned,
+                    >,
+                ) -> ::capnp::Result<()> {
+                    ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                        self.builder.get_pointer_field(0),
+                        value,
+                        false,
+                    )
+                }
+                #[inline]
+                pub fn init_assets(
+                    self,
+                    size: u32,
+                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_metadata::Owned>
+                {
+                    ::capnp::traits::FromPointerBuilder::init_pointer(
+                        self.builder.get_pointer_field(0),
+                        size,
+                    )
+                }
+                pub fn has_assets(&self) -> bool {
+                    !self.builder.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
+            }
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
+                    }
+                }
+            }
+            impl Pipeline {}
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 0,
+                    pointers: 1,
+                };
+                pub const TYPE_ID: u64 = 0xf778_60ac_c787_329d;
+            }
+        }
+
+        pub mod get_all_asset_metadata_params {
+            #[derive(Copy, Clone)]
+            pub struct Owned;
+            impl<'a> ::capnp::traits::Owned<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl ::capnp::traits::Pipelined for Owned {
+                type Pipeline = Pipeline;
+            }
+
+            #[derive(Clone, Copy)]
+            pub struct Reader<'a> {
+                reader: ::capnp::private::layout::StructReader<'a>,
+            }
+
+            impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+                fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+                    Reader { reader }
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+                fn get_from_pointer(
+                    reader: &::capnp::private::layout::PointerReader<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Reader<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                        reader.get_struct(default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+                fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+                    self.reader
+                }
+            }
+
+            impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+                fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+                    self.reader
+                        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+                }
+            }
+
+            impl<'a> Reader<'a> {
+                pub fn reborrow(&self) -> Reader {
+                    Reader { ..*self }
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.reader.total_size()
+                }
+            }
+
+            pub struct Builder<'a> {
+                builder: ::capnp::private::layout::StructBuilder<'a>,
+            }
+            impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+                #[inline]
+                fn struct_size() -> ::capnp::private::layout::StructSize {
+                    _private::STRUCT_SIZE
+                }
+            }
+            impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+                fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+                    Builder { builder }
+                }
+            }
+
+            impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+                fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+                    self.builder
+                        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+                fn init_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> Builder<'a> {
+                    ::capnp::traits::FromStructBuilder::new(
+                        builder.init_struct(_private::STRUCT_SIZE),
+                    )
+                }
+                fn get_from_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Builder<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                        builder.get_struct(_private::STRUCT_SIZE, default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+                fn set_pointer_builder<'b>(
+                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                    value: Reader<'a>,
+                    canonicalize: bool,
+                ) -> ::capnp::Result<()> {
+                    pointer.set_struct(&value.reader, canonicalize)
+                }
+            }
+
+            impl<'a> Builder<'a> {
+                pub fn into_reader(self) -> Reader<'a> {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+                pub fn reborrow(&mut self) -> Builder {
+                    Builder { ..*self }
+                }
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+            }
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
+            }
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
+                    }
+                }
+            }
+            impl Pipeline {}
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 0,
+                    pointers: 0,
+                };
+                pub const TYPE_ID: u64 = 0xe039_b049_3c2d_4287;
+            }
+        }
+
+        pub mod get_all_asset_metadata_results {
+            #[derive(Copy, Clone)]
+            pub struct Owned;
+            impl<'a> ::capnp::traits::Owned<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl ::capnp::traits::Pipelined for Owned {
+                type Pipeline = Pipeline;
+            }
+
+            #[derive(Clone, Copy)]
+            pub struct Reader<'a> {
+                reader: ::capnp::private::layout::StructReader<'a>,
+            }
+
+            impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+                fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+                    Reader { reader }
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+                fn get_from_pointer(
+                    reader: &::capnp::private::layout::PointerReader<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Reader<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                        reader.get_struct(default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+                fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+                    self.reader
+                }
+            }
+
+            impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+                fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+                    self.reader
+                        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+                }
+            }
+
+            impl<'a> Reader<'a> {
+                pub fn reborrow(&self) -> Reader {
+                    Reader { ..*self }
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.reader.total_size()
+                }
+                #[inline]
+                pub fn get_assets(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_metadata::Owned>,
+                > {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_assets(&self) -> bool {
+                    !self.reader.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Builder<'a> {
+                builder: ::capnp::private::layout::StructBuilder<'a>,
+            }
+            impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+                #[inline]
+                fn struct_size() -> ::capnp::private::layout::StructSize {
+                    _private::STRUCT_SIZE
+                }
+            }
+            impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+                fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+                    Builder { builder }
+                }
+            }
+
+            impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+                fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+                    self.builder
+                        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+                fn init_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> Builder<'a> {
+                    ::capnp::traits::FromStructBuilder::new(
+                        builder.init_struct(_private::STRUCT_SIZE),
+                    )
+                }
+                fn get_from_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Builder<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                        builder.get_struct(_private::STRUCT_SIZE, default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+                fn set_pointer_builder<'b>(
+                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                    value: Reader<'a>,
+                    canonicalize: bool,
+                ) -> ::capnp::Result<()> {
+                    pointer.set_struct(&value.reader, canonicalize)
+                }
+            }
+
+            impl<'a> Builder<'a> {
+                pub fn into_reader(self) -> Reader<'a> {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+                pub fn reborrow(&mut self) -> Builder {
+                    Builder { ..*self }
+                }
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+                #[inline]
+                pub fn get_assets(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_metadata::Owned>,
+                > {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_assets(
+                    &mut self,
+                    value: ::capnp::struct_list::Reader<
+                        'a,
+                        crate::data_capnp::asset_metadata::Owned,
+                    >,
+                ) -> ::capnp::Result<()> {
+                    ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                        self.builder.get_pointer_field(0),
+                        value,
+                        false,
+                    )
+                }
+                #[inline]
+                pub fn init_assets(
+                    self,
+                    size: u32,
+                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_metadata::Owned>
+                {
+                    ::capnp::traits::FromPointerBuilder::init_pointer(
+                        self.builder.get_pointer_field(0),
+                        size,
+                    )
+                }
+                pub fn has_assets(&self) -> bool {
+                    !self.builder.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
+            }
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
+                    }
+                }
+            }
+            impl Pipeline {}
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 0,
+                    pointers: 1,
+                };
+                pub const TYPE_ID: u64 = 0xce83_1fc1_7c2b_5dff;
+            }
+        }
+
+        pub mod get_latest_asset_change_params {
+            #[derive(Copy, Clone)]
+            pub struct Owned;
+            impl<'a> ::capnp::traits::Owned<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl ::capnp::traits::Pipelined for Owned {
+                type Pipeline = Pipeline;
+            }
+
+            #[derive(Clone, Copy)]
+            pub struct Reader<'a> {
+                reader: ::capnp::private::layout::StructReader<'a>,
+            }
+
+            impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+                fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+                    Reader { reader }
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+                fn get_from_pointer(
+                    reader: &::capnp::private::layout::PointerReader<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Reader<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                        reader.get_struct(default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+                fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+                    self.reader
+                }
+            }
+
+            impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+                fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+                    self.reader
+                        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+                }
+            }
+
+            impl<'a> Reader<'a> {
+                pub fn reborrow(&self) -> Reader {
+                    Reader { ..*self }
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.reader.total_size()
+                }
+            }
+
+            pub struct Builder<'a> {
+                builder: ::capnp::private::layout::StructBuilder<'a>,
+            }
+            impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+                #[inline]
+                fn struct_size() -> ::capnp::private::layout::StructSize {
+                    _private::STRUCT_SIZE
+                }
+            }
+            impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+                fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+                    Builder { builder }
+                }
+            }
+
+            impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+                fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+                    self.builder
+                        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+                fn init_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> Builder<'a> {
+                    ::capnp::traits::FromStructBuilder::new(
+                        builder.init_struct(_private::STRUCT_SIZE),
+                    )
+                }
+                fn get_from_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Builder<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                        builder.get_struct(_private::STRUCT_SIZE, default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+                fn set_pointer_builder<'b>(
+                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                    value: Reader<'a>,
+                    canonicalize: bool,
+                ) -> ::capnp::Result<()> {
+                    pointer.set_struct(&value.reader, canonicalize)
+                }
+            }
+
+            impl<'a> Builder<'a> {
+                pub fn into_reader(self) -> Reader<'a> {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+                pub fn reborrow(&mut self) -> Builder {
+                    Builder { ..*self }
+                }
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+            }
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
+            }
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
+                    }
+                }
+            }
+            impl Pipeline {}
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 0,
+                    pointers: 0,
+                };
+                pub const TYPE_ID: u64 = 0x8f91_97be_163d_398e;
+            }
+        }
+
+        pub mod get_latest_asset_change_results {
+            #[derive(Copy, Clone)]
+            pub struct Owned;
+            impl<'a> ::capnp::traits::Owned<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl ::capnp::traits::Pipelined for Owned {
+                type Pipeline = Pipeline;
+            }
+
+            #[derive(Clone, Copy)]
+            pub struct Reader<'a> {
+                reader: ::capnp::private::layout::StructReader<'a>,
+            }
+
+            impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+                fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+                    Reader { reader }
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+                fn get_from_pointer(
+                    reader: &::capnp::private::layout::PointerReader<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Reader<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                        reader.get_struct(default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+                fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+                    self.reader
+                }
+            }
+
+            impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+                fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+                    self.reader
+                        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+                }
+            }
+
+            impl<'a> Reader<'a> {
+                pub fn reborrow(&self) -> Reader {
+                    Reader { ..*self }
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.reader.total_size()
+                }
+                #[inline]
+                pub fn get_num(self) -> u64 {
+                    self.reader.get_data_field::<u64>(0)
+                }
+            }
+
+            pub struct Builder<'a> {
+                builder: ::capnp::private::layout::StructBuilder<'a>,
+            }
+            impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+                #[inline]
+                fn struct_size() -> ::capnp::private::layout::StructSize {
+                    _private::STRUCT_SIZE
+                }
+            }
+            impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+                fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+                    Builder { builder }
+                }
+            }
+
+            impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+                fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+                    self.builder
+                        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+                fn init_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> Builder<'a> {
+                    ::capnp::traits::FromStructBuilder::new(
+                        builder.init_struct(_private::STRUCT_SIZE),
+                    )
+                }
+                fn get_from_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Builder<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                        builder.get_struct(_private::STRUCT_SIZE, default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+                fn set_pointer_builder<'b>(
+                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                    value: Reader<'a>,
+                    canonicalize: bool,
+                ) -> ::capnp::Result<()> {
+                    pointer.set_struct(&value.reader, canonicalize)
+                }
+            }
+
+            impl<'a> Builder<'a> {
+                pub fn into_reader(self) -> Reader<'a> {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+                pub fn reborrow(&mut self) -> Builder {
+                    Builder { ..*self }
+                }
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+                #[inline]
+                pub fn get_num(self) -> u64 {
+                    self.builder.get_data_field::<u64>(0)
+                }
+                #[inline]
+                pub fn set_num(&mut self, value: u64) {
+                    self.builder.set_data_field::<u64>(0, value);
+                }
+            }
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
+            }
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
+                    }
+                }
+            }
+            impl Pipeline {}
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 1,
+                    pointers: 0,
+                };
+                pub const TYPE_ID: u64 = 0xd5aa_a354_7610_5f6f;
+            }
+        }
+
+        pub mod get_asset_changes_params {
+            #[derive(Copy, Clone)]
+            pub struct Owned;
+            impl<'a> ::capnp::traits::Owned<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl ::capnp::traits::Pipelined for Owned {
+                type Pipeline = Pipeline;
+            }
+
+            #[derive(Clone, Copy)]
+            pub struct Reader<'a> {
+                reader: ::capnp::private::layout::StructReader<'a>,
+            }
+
+            impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+                fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+                    Reader { reader }
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+                fn get_from_pointer(
+                    reader: &::capnp::private::layout::PointerReader<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Reader<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                        reader.get_struct(default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+                fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+                    self.reader
+                }
+            }
+
+            impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+                fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+                    self.reader
+                        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+                }
+            }
+
+            impl<'a> Reader<'a> {
+                pub fn reborrow(&self) -> Reader {
+                    Reader { ..*self }
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.reader.total_size()
+                }
+                #[inline]
+                pub fn get_start(self) -> u64 {
+                    self.reader.get_data_field::<u64>(0)
+                }
+                #[inline]
+                pub fn get_count(self) -> u64 {
+                    self.reader.get_data_field::<u64>(1)
+                }
+            }
+
+            pub struct Builder<'a> {
+                builder: ::capnp::private::layout::StructBuilder<'a>,
+            }
+            impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+                #[inline]
+                fn struct_size() -> ::capnp::private::layout::StructSize {
+                    _private::STRUCT_SIZE
+                }
+            }
+            impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+                fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+                    Builder { builder }
+                }
+            }
+
+            impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+                fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+                    self.builder
+                        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+                fn init_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> Builder<'a> {
+                    ::capnp::traits::FromStructBuilder::new(
+                        builder.init_struct(_private::STRUCT_SIZE),
+                    )
+                }
+                fn get_from_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Builder<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                        builder.get_struct(_private::STRUCT_SIZE, default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+                fn set_pointer_builder<'b>(
+                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                    value: Reader<'a>,
+                    canonicalize: bool,
+                ) -> ::capnp::Result<()> {
+                    pointer.set_struct(&value.reader, canonicalize)
+                }
+            }
+
+            impl<'a> Builder<'a> {
+                pub fn into_reader(self) -> Reader<'a> {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+                pub fn reborrow(&mut self) -> Builder {
+                    Builder { ..*self }
+                }
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+                #[inline]
+                pub fn get_start(self) -> u64 {
+                    self.builder.get_data_field::<u64>(0)
+                }
+                #[inline]
+                pub fn set_start(&mut self, value: u64) {
+                    self.builder.set_data_field::<u64>(0, value);
+                }
+                #[inline]
+                pub fn get_count(self) -> u64 {
+                    self.builder.get_data_field::<u64>(1)
+                }
+                #[inline]
+                pub fn set_count(&mut self, value: u64) {
+                    self.builder.set_data_field::<u64>(1, value);
+                }
+            }
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
+            }
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
+                    }
+                }
+            }
+            impl Pipeline {}
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 2,
+                    pointers: 0,
+                };
+                pub const TYPE_ID: u64 = 0xba40_b57b_85a0_3cc0;
+            }
+        }
+
+        pub mod get_asset_changes_results {
+            #[derive(Copy, Clone)]
+            pub struct Owned;
+            impl<'a> ::capnp::traits::Owned<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl ::capnp::traits::Pipelined for Owned {
+                type Pipeline = Pipeline;
+            }
+
+            #[derive(Clone, Copy)]
+            pub struct Reader<'a> {
+                reader: ::capnp::private::layout::StructReader<'a>,
+            }
+
+            impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+                fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+                    Reader { reader }
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+                fn get_from_pointer(
+                    reader: &::capnp::private::layout::PointerReader<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Reader<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                        reader.get_struct(default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+                fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+                    self.reader
+                }
+            }
+
+            impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+                fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+                    self.reader
+                        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+                }
+            }
+
+            impl<'a> Reader<'a> {
+                pub fn reborrow(&self) -> Reader {
+                    Reader { ..*self }
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.reader.total_size()
+                }
+                #[inline]
+                pub fn get_changes(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Reader<
+                        'a,
+                        crate::data_capnp::asset_change_log_entry::Owned,
+                    >,
+                > {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_changes(&self) -> bool {
+                    !self.reader.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Builder<'a> {
+                builder: ::capnp::private::layout::StructBuilder<'a>,
+            }
+            impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+                #[inline]
+                fn struct_size() -> ::capnp::private::layout::StructSize {
+                    _private::STRUCT_SIZE
+                }
+            }
+            impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+                fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+                    Builder { builder }
+                }
+            }
+
+            impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+                fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+                    self.builder
+                        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+                fn init_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> Builder<'a> {
+                    ::capnp::traits::FromStructBuilder::new(
+                        builder.init_struct(_private::STRUCT_SIZE),
+                    )
+                }
+                fn get_from_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Builder<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                        builder.get_struct(_private::STRUCT_SIZE, default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+                fn set_pointer_builder<'b>(
+                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                    value: Reader<'a>,
+                    canonicalize: bool,
+                ) -> ::capnp::Result<()> {
+                    pointer.set_struct(&value.reader, canonicalize)
+                }
+            }
+
+            impl<'a> Builder<'a> {
+                pub fn into_reader(self) -> Reader<'a> {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+                pub fn reborrow(&mut self) -> Builder {
+                    Builder { ..*self }
+                }
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+                #[inline]
+                pub fn get_changes(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Builder<
+                        'a,
+                        crate::data_capnp::asset_change_log_entry::Owned,
+                    >,
+                > {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_changes(
+                    &mut self,
+                    value: ::capnp::struct_list::Reader<
+                        'a,
+                        crate::data_capnp::asset_change_log_entry::Owned,
+                    >,
+                ) -> ::capnp::Result<()> {
+                    ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                        self.builder.get_pointer_field(0),
+                        value,
+                        false,
+                    )
+                }
+                #[inline]
+                pub fn init_changes(
+                    self,
+                    size: u32,
+                ) -> ::capnp::struct_list::Builder<
+                    'a,
+                    crate::data_capnp::asset_change_log_entry::Owned,
+                > {
+                    ::capnp::traits::FromPointerBuilder::init_pointer(
+                        self.builder.get_pointer_field(0),
+                        size,
+                    )
+                }
+                pub fn has_changes(&self) -> bool {
+                    !self.builder.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
+            }
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
+                    }
+                }
+            }
+            impl Pipeline {}
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 0,
+                    pointers: 1,
+                };
+                pub const TYPE_ID: u64 = 0xd2d6_ad4d_8b8d_0fd6;
+            }
+        }
+
+        pub mod get_import_artifacts_params {
+            #[derive(Copy, Clone)]
+            pub struct Owned;
+            impl<'a> ::capnp::traits::Owned<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl ::capnp::traits::Pipelined for Owned {
+                type Pipeline = Pipeline;
+            }
+
+            #[derive(Clone, Copy)]
+            pub struct Reader<'a> {
+                reader: ::capnp::private::layout::StructReader<'a>,
+            }
+
+            impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+                fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+                    Reader { reader }
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+                fn get_from_pointer(
+                    reader: &::capnp::private::layout::PointerReader<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Reader<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                        reader.get_struct(default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+                fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+                    self.reader
+                }
+            }
+
+            impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+                fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+                    self.reader
+                        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+                }
+            }
+
+            impl<'a> Reader<'a> {
+                pub fn reborrow(&self) -> Reader {
+                    Reader { ..*self }
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.reader.total_size()
+                }
+                #[inline]
+                pub fn get_assets(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
+                > {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_assets(&self) -> bool {
+                    !self.reader.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Builder<'a> {
+                builder: ::capnp::private::layout::StructBuilder<'a>,
+            }
+            impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+                #[inline]
+                fn struct_size() -> ::capnp::private::layout::StructSize {
+                    _private::STRUCT_SIZE
+                }
+            }
+            impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+                fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+                    Builder { builder }
+                }
+            }
+
+            impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+                fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+                    self.builder
+                        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+                fn init_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> Builder<'a> {
+                    ::capnp::traits::FromStructBuilder::new(
+                        builder.init_struct(_private::STRUCT_SIZE),
+                    )
+                }
+                fn get_from_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Builder<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                        builder.get_struct(_private::STRUCT_SIZE, default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+                fn set_pointer_builder<'b>(
+                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                    value: Reader<'a>,
+                    canonicalize: bool,
+                ) -> ::capnp::Result<()> {
+                    pointer.set_struct(&value.reader, canonicalize)
+                }
+            }
+
+            impl<'a> Builder<'a> {
+                pub fn into_reader(self) -> Reader<'a> {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+                pub fn reborrow(&mut self) -> Builder {
+                    Builder { ..*self }
+                }
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+                #[inline]
+                pub fn get_assets(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>,
+                > {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_assets(
+                    &mut self,
+                    value: ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
+                ) -> ::capnp::Result<()> {
+                    ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                        self.builder.get_pointer_field(0),
+                        value,
+                        false,
+                    )
+                }
+                #[inline]
+                pub fn init_assets(
+                    self,
+                    size: u32,
+                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>
+                {
+                    ::capnp::traits::FromPointerBuilder::init_pointer(
+                        self.builder.get_pointer_field(0),
+                        size,
+                    )
+                }
+                pub fn has_assets(&self) -> bool {
+                    !self.builder.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
+            }
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
+                    }
+                }
+            }
+            impl Pipeline {}
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 0,
+                    pointers: 1,
+                };
+                pub const TYPE_ID: u64 = 0xdc83_3c1c_8873_40ae;
+            }
+        }
+
+        pub mod get_import_artifacts_results {
+            #[derive(Copy, Clone)]
+            pub struct Owned;
+            impl<'a> ::capnp::traits::Owned<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl ::capnp::traits::Pipelined for Owned {
+                type Pipeline = Pipeline;
+            }
+
+            #[derive(Clone, Copy)]
+            pub struct Reader<'a> {
+                reader: ::capnp::private::layout::StructReader<'a>,
+            }
+
+            impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+                fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+                    Reader { reader }
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+                fn get_from_pointer(
+                    reader: &::capnp::private::layout::PointerReader<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Reader<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                        reader.get_struct(default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+                fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+                    self.reader
+                }
+            }
+
+            impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+                fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+                    self.reader
+                        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+                }
+            }
+
+            impl<'a> Reader<'a> {
+                pub fn reborrow(&self) -> Reader {
+                    Reader { ..*self }
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.reader.total_size()
+                }
+                #[inline]
+                pub fn get_artifacts(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Reader<'a, crate::data_capnp::artifact::Owned>,
+                > {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_artifacts(&self) -> bool {
+                    !self.reader.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Builder<'a> {
+                builder: ::capnp::private::layout::StructBuilder<'a>,
+            }
+            impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+                #[inline]
+                fn struct_size() -> ::capnp::private::layout::StructSize {
+                    _private::STRUCT_SIZE
+                }
+            }
+            impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+                fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+                    Builder { builder }
+                }
+            }
+
+            impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+                fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+                    self.builder
+                        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+                fn init_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> Builder<'a> {
+                    ::capnp::traits::FromStructBuilder::new(
+                        builder.init_struct(_private::STRUCT_SIZE),
+                    )
+                }
+                fn get_from_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Builder<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                        builder.get_struct(_private::STRUCT_SIZE, default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+                fn set_pointer_builder<'b>(
+                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                    value: Reader<'a>,
+                    canonicalize: bool,
+                ) -> ::capnp::Result<()> {
+                    pointer.set_struct(&value.reader, canonicalize)
+                }
+            }
+
+            impl<'a> Builder<'a> {
+                pub fn into_reader(self) -> Reader<'a> {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+                pub fn reborrow(&mut self) -> Builder {
+                    Builder { ..*self }
+                }
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+                #[inline]
+                pub fn get_artifacts(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Builder<'a, crate::data_capnp::artifact::Owned>,
+                > {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_artifacts(
+                    &mut self,
+                    value: ::capnp::struct_list::Reader<'a, crate::data_capnp::artifact::Owned>,
+                ) -> ::capnp::Result<()> {
+                    ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                        self.builder.get_pointer_field(0),
+                        value,
+                        false,
+                    )
+                }
+                #[inline]
+                pub fn init_artifacts(
+                    self,
+                    size: u32,
+                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::artifact::Owned>
+                {
+                    ::capnp::traits::FromPointerBuilder::init_pointer(
+                        self.builder.get_pointer_field(0),
+                        size,
+                    )
+                }
+                pub fn has_artifacts(&self) -> bool {
+                    !self.builder.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
+            }
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
+                    }
+                }
+            }
+            impl Pipeline {}
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 0,
+                    pointers: 1,
+                };
+                pub const TYPE_ID: u64 = 0xfd22_801d_f1cf_94d4;
+            }
+        }
+
+        pub mod update_asset_params {
+            #[derive(Copy, Clone)]
+            pub struct Owned;
+            impl<'a> ::capnp::traits::Owned<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl ::capnp::traits::Pipelined for Owned {
+                type Pipeline = Pipeline;
+            }
+
+            #[derive(Clone, Copy)]
+            pub struct Reader<'a> {
+                reader: ::capnp::private::layout::StructReader<'a>,
+            }
+
+            impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+                fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+                    Reader { reader }
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+                fn get_from_pointer(
+                    reader: &::capnp::private::layout::PointerReader<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Reader<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                        reader.get_struct(default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+                fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+                    self.reader
+                }
+            }
+
+            impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+                fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+                    self.reader
+                        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+                }
+            }
+
+            impl<'a> Reader<'a> {
+                pub fn reborrow(&self) -> Reader {
+                    Reader { ..*self }
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.reader.total_size()
+                }
+                #[inline]
+                pub fn get_asset(self) -> ::capnp::Result<crate::data_capnp::artifact::Reader<'a>> {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_asset(&self) -> bool {
+                    !self.reader.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Builder<'a> {
+                builder: ::capnp::private::layout::StructBuilder<'a>,
+            }
+            impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+                #[inline]
+                fn struct_size() -> ::capnp::private::layout::StructSize {
+                    _private::STRUCT_SIZE
+                }
+            }
+            impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+                fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+                    Builder { builder }
+                }
+            }
+
+            impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+                fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+                    self.builder
+                        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+                fn init_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> Builder<'a> {
+                    ::capnp::traits::FromStructBuilder::new(
+                        builder.init_struct(_private::STRUCT_SIZE),
+                    )
+                }
+                fn get_from_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Builder<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                        builder.get_struct(_private::STRUCT_SIZE, default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+                fn set_pointer_builder<'b>(
+                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                    value: Reader<'a>,
+                    canonicalize: bool,
+                ) -> ::capnp::Result<()> {
+                    pointer.set_struct(&value.reader, canonicalize)
+                }
+            }
+
+            impl<'a> Builder<'a> {
+                pub fn into_reader(self) -> Reader<'a> {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+                pub fn reborrow(&mut self) -> Builder {
+                    Builder { ..*self }
+                }
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+                #[inline]
+                pub fn get_asset(
+                    self,
+                ) -> ::capnp::Result<crate::data_capnp::artifact::Builder<'a>> {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_asset<'b>(
+                    &mut self,
+                    value: crate::data_capnp::artifact::Reader<'b>,
+                ) -> ::capnp::Result<()> {
+                    ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                        self.builder.get_pointer_field(0),
+                        value,
+                        false,
+                    )
+                }
+                #[inline]
+                pub fn init_asset(self) -> crate::data_capnp::artifact::Builder<'a> {
+                    ::capnp::traits::FromPointerBuilder::init_pointer(
+                        self.builder.get_pointer_field(0),
+                        0,
+                    )
+                }
+                pub fn has_asset(&self) -> bool {
+                    !self.builder.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
+            }
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
+                    }
+                }
+            }
+            impl Pipeline {
+                pub fn get_asset(&self) -> crate::data_capnp::artifact::Pipeline {
+                    ::capnp::capability::FromTypelessPipeline::new(
+                        self._typeless.get_pointer_field(0),
+                    )
+                }
+            }
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 0,
+                    pointers: 1,
+                };
+                pub const TYPE_ID: u64 = 0x8e16_cf07_f0b8_1163;
+            }
+        }
+
+        pub mod update_asset_results {
+            #[derive(Copy, Clone)]
+            pub struct Owned;
+            impl<'a> ::capnp::traits::Owned<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl ::capnp::traits::Pipelined for Owned {
+                type Pipeline = Pipeline;
+            }
+
+            #[derive(Clone, Copy)]
+            pub struct Reader<'a> {
+                reader: ::capnp::private::layout::StructReader<'a>,
+            }
+
+            impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+                fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+                    Reader { reader }
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+                fn get_from_pointer(
+                    reader: &::capnp::private::layout::PointerReader<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Reader<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                        reader.get_struct(default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+                fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+                    self.reader
+                }
+            }
+
+            impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+                fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+                    self.reader
+                        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+                }
+            }
+
+            impl<'a> Reader<'a> {
+                pub fn reborrow(&self) -> Reader {
+                    Reader { ..*self }
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.reader.total_size()
+                }
+                #[inline]
+                pub fn get_new_import_hash(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_new_import_hash(&self) -> bool {
+                    !self.reader.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Builder<'a> {
+                builder: ::capnp::private::layout::StructBuilder<'a>,
+            }
+            impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+                #[inline]
+                fn struct_size() -> ::capnp::private::layout::StructSize {
+                    _private::STRUCT_SIZE
+                }
+            }
+            impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+                fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+                    Builder { builder }
+                }
+            }
+
+            impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+                fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+                    self.builder
+                        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+                fn init_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> Builder<'a> {
+                    ::capnp::traits::FromStructBuilder::new(
+                        builder.init_struct(_private::STRUCT_SIZE),
+                    )
+                }
+                fn get_from_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Builder<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                        builder.get_struct(_private::STRUCT_SIZE, default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+                fn set_pointer_builder<'b>(
+                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                    value: Reader<'a>,
+                    canonicalize: bool,
+                ) -> ::capnp::Result<()> {
+                    pointer.set_struct(&value.reader, canonicalize)
+                }
+            }
+
+            impl<'a> Builder<'a> {
+                pub fn into_reader(self) -> Reader<'a> {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+                pub fn reborrow(&mut self) -> Builder {
+                    Builder { ..*self }
+                }
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+                #[inline]
+                pub fn get_new_import_hash(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_new_import_hash(&mut self, value: ::capnp::data::Reader) {
+                    self.builder.get_pointer_field(0).set_data(value);
+                }
+                #[inline]
+                pub fn init_new_import_hash(self, size: u32) -> ::capnp::data::Builder<'a> {
+                    self.builder.get_pointer_field(0).init_data(size)
+                }
+                pub fn has_new_import_hash(&self) -> bool {
+                    !self.builder.get_pointer_field(0).is_null()
+                }
+            }
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
+            }
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
+                    }
+                }
+            }
+            impl Pipeline {}
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 0,
+                    pointers: 1,
+                };
+                pub const TYPE_ID: u64 = 0xee65_268f_6884_96c5;
+            }
+        }
+
+        pub mod patch_asset_params {
+            #[derive(Copy, Clone)]
+            pub struct Owned;
+            impl<'a> ::capnp::traits::Owned<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl ::capnp::traits::Pipelined for Owned {
+                type Pipeline = Pipeline;
+            }
+
+            #[derive(Clone, Copy)]
+            pub struct Reader<'a> {
+                reader: ::capnp::private::layout::StructReader<'a>,
+            }
+
+            impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+                fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+                    Reader { reader }
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+                fn get_from_pointer(
+                    reader: &::capnp::private::layout::PointerReader<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Reader<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                        reader.get_struct(default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+                fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+                    self.reader
+                }
+            }
+
+            impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+                fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+                    self.reader
+                        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+                }
+            }
+
+            impl<'a> Reader<'a> {
+                pub fn reborrow(&self) -> Reader {
+                    Reader { ..*self }
+                }
 
-        pub struct Client {
-            pub client: ::capnp::capability::Client,
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.reader.total_size()
+                }
+                #[inline]
+                pub fn get_asset_id(
+                    self,
+                ) -> ::capnp::Result<crate::data_capnp::asset_uuid::Reader<'a>> {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_asset_id(&self) -> bool {
+                    !self.reader.get_pointer_field(0).is_null()
+                }
+                #[inline]
+                pub fn get_asset_hash(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(1),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_asset_hash(&self) -> bool {
+                    !self.reader.get_pointer_field(1).is_null()
+                }
+                #[inline]
+                pub fn get_patch(
+                    self,
+                ) -> ::capnp::Result<crate::service_capnp::asset_data::Reader<'a>> {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(2),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_patch(&self) -> bool {
+                    !self.reader.get_pointer_field(2).is_null()
+                }
+            }
+
+            pub struct Builder<'a> {
+                builder: ::capnp::private::layout::StructBuilder<'a>,
+            }
+            impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+                #[inline]
+                fn struct_size() -> ::capnp::private::layout::StructSize {
+                    _private::STRUCT_SIZE
+                }
+            }
+            impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+                fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+                    Builder { builder }
+                }
+            }
+
+            impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+                fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+                    self.builder
+                        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+                }
+            }
+
+            impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+                fn init_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> Builder<'a> {
+                    ::capnp::traits::FromStructBuilder::new(
+                        builder.init_struct(_private::STRUCT_SIZE),
+                    )
+                }
+                fn get_from_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Builder<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                        builder.get_struct(_private::STRUCT_SIZE, default)?,
+                    ))
+                }
+            }
+
+            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+                fn set_pointer_builder<'b>(
+                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                    value: Reader<'a>,
+                    canonicalize: bool,
+                ) -> ::capnp::Result<()> {
+                    pointer.set_struct(&value.reader, canonicalize)
+                }
+            }
+
+            impl<'a> Builder<'a> {
+                pub fn into_reader(self) -> Reader<'a> {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+                pub fn reborrow(&mut self) -> Builder {
+                    Builder { ..*self }
+                }
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+                #[inline]
+                pub fn get_asset_id(
+                    self,
+                ) -> ::capnp::Result<crate::data_capnp::asset_uuid::Builder<'a>> {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_asset_id<'b>(
+                    &mut self,
+                    value: crate::data_capnp::asset_uuid::Reader<'b>,
+                ) -> ::capnp::Result<()> {
+                    ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                        self.builder.get_pointer_field(0),
+                        value,
+                        false,
+                    )
+                }
+                #[inline]
+                pub fn init_asset_id(self) -> crate::data_capnp::asset_uuid::Builder<'a> {
+                    ::capnp::traits::FromPointerBuilder::init_pointer(
+                        self.builder.get_pointer_field(0),
+                        0,
+                    )
+                }
+                pub fn has_asset_id(&self) -> bool {
+                    !self.builder.get_pointer_field(0).is_null()
+                }
+                #[inline]
+                pub fn get_asset_hash(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(1),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_asset_hash(&mut self, value: ::capnp::data::Reader) {
+                    self.builder.get_pointer_field(1).set_data(value);
+                }
+                #[inline]
+                pub fn init_asset_hash(self, size: u32) -> ::capnp::data::Builder<'a> {
+                    self.builder.get_pointer_field(1).init_data(size)
+                }
+                pub fn has_asset_hash(&self) -> bool {
+                    !self.builder.get_pointer_field(1).is_null()
+                }
+                #[inline]
+                pub fn get_patch(
+                    self,
+                ) -> ::capnp::Result<crate::service_capnp::asset_data::Builder<'a>>
+                {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(2),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_patch<'b>(
+                    &mut self,
+                    value: crate::service_capnp::asset_data::Reader<'b>,
+                ) -> ::capnp::Result<()> {
+                    ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                        self.builder.get_pointer_field(2),
+                        value,
+                        false,
+                    )
+                }
+                #[inline]
+                pub fn init_patch(self) -> crate::service_capnp::asset_data::Builder<'a> {
+                    ::capnp::traits::FromPointerBuilder::init_pointer(
+                        self.builder.get_pointer_field(2),
+                        0,
+                    )
+                }
+                pub fn has_patch(&self) -> bool {
+                    !self.builder.get_pointer_field(2).is_null()
+                }
+            }
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
+            }
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
+                    }
+                }
+            }
+            impl Pipeline {
+                pub fn get_asset_id(&self) -> crate::data_capnp::asset_uuid::Pipeline {
+                    ::capnp::capability::FromTypelessPipeline::new(
+                        self._typeless.get_pointer_field(0),
+                    )
+                }
+                pub fn get_patch(&self) -> crate::service_capnp::asset_data::Pipeline {
+                    ::capnp::capability::FromTypelessPipeline::new(
+                        self._typeless.get_pointer_field(2),
+                    )
+                }
+            }
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 0,
+                    pointers: 3,
+                };
+                pub const TYPE_ID: u64 = 0xa5a8_ad11_2bab_a100;
+            }
         }
-        impl ::capnp::capability::FromClientHook for Client {
-            fn new(hook: Box<dyn (::capnp::private::capability::ClientHook)>) -> Client {
-                Client {
-                    client: ::capnp::capability::Client::new(hook),
+
+        pub mod patch_asset_results {
+            #[derive(Copy, Clone)]
+            pub struct Owned;
+            impl<'a> ::capnp::traits::Owned<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
+            }
+            impl ::capnp::traits::Pipelined for Owned {
+                type Pipeline = Pipeline;
+            }
+
+            #[derive(Clone, Copy)]
+            pub struct Reader<'a> {
+                reader: ::capnp::private::layout::StructReader<'a>,
+            }
+
+            impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
+            }
+            impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+                fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+                    Reader { reader }
                 }
             }
-        }
-        #[derive(Copy, Clone)]
-        pub struct Owned;
-        impl<'a> ::capnp::traits::Owned<'a> for Owned {
-            type Reader = Client;
-            type Builder = Client;
-        }
-        impl ::capnp::traits::Pipelined for Owned {
-            type Pipeline = Client;
-        }
-        impl<'a> ::capnp::traits::FromPointerReader<'a> for Client {
-            fn get_from_pointer(
-                reader: &::capnp::private::layout::PointerReader<'a>,
-                _default: ::core::option::Option<&'a [capnp::Word]>,
-            ) -> ::capnp::Result<Client> {
-                ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(
-                    reader.get_capability()?,
-                ))
+
+            impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+                fn get_from_pointer(
+                    reader: &::capnp::private::layout::PointerReader<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Reader<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                        reader.get_struct(default)?,
+                    ))
+                }
             }
-        }
-        impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Client {
-            fn init_pointer(
-                _builder: ::capnp::private::layout::PointerBuilder<'a>,
-                _size: u32,
-            ) -> Client {
-                unimplemented!()
+
+            impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+                fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+                    self.reader
+                }
             }
-            fn get_from_pointer(
-                builder: ::capnp::private::layout::PointerBuilder<'a>,
-                _default: ::core::option::Option<&'a [capnp::Word]>,
-            ) -> ::capnp::Result<Client> {
-                ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(
-                    builder.get_capability()?,
-                ))
+
+            impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+                fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+                    self.reader
+                        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+                }
             }
-        }
 
-        impl ::capnp::traits::SetPointerBuilder<Client> for Client {
-            fn set_pointer_builder(
-                pointer: ::capnp::private::layout::PointerBuilder,
-                from: Client,
-                _canonicalize: bool,
-            ) -> ::capnp::Result<()> {
-                pointer.set_capability(from.client.hook);
-                ::core::result::Result::Ok(())
+            impl<'a> Reader<'a> {
+                pub fn reborrow(&self) -> Reader {
+                    Reader { ..*self }
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.reader.total_size()
+                }
+                #[inline]
+                pub fn get_new_import_hash(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_new_import_hash(&self) -> bool {
+                    !self.reader.get_pointer_field(0).is_null()
+                }
             }
-        }
-        impl ::capnp::traits::HasTypeId for Client {
-            #[inline]
-            fn type_id() -> u64 {
-                _private::TYPE_ID
+
+            pub struct Builder<'a> {
+                builder: ::capnp::private::layout::StructBuilder<'a>,
             }
-        }
-        impl Clone for Client {
-            fn clone(&self) -> Client {
-                Client {
-                    client: ::capnp::capability::Client::new(self.client.hook.add_ref()),
+            impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+                #[inline]
+                fn struct_size() -> ::capnp::private::layout::StructSize {
+                    _private::STRUCT_SIZE
                 }
             }
-        }
-        impl Client {
-            pub fn get_asset_metadata_request(
-                &self,
-            ) -> ::capnp::capability::Request<
-                crate::service_capnp::asset_hub::snapshot::get_asset_metadata_params::Owned,
-                crate::service_capnp::asset_hub::snapshot::get_asset_metadata_results::Owned,
-            > {
-                self.client.new_call(_private::TYPE_ID, 0, None)
+            impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
             }
-pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capability::Request<crate::service_capnp::asset_hub::snapshot::get_asset_metadata_with_dependencies_params::Owned,crate::service_capnp::asset_hub::snapshot::get_asset_metadata_with_dependencies_results::Owned>{
-                self.client.new_call(_private::TYPE_ID, 1, None)
+            impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+                fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+                    Builder { builder }
+                }
             }
-            pub fn get_all_asset_metadata_request(
-                &self,
-            ) -> ::capnp::capability::Request<
-                crate::service_capnp::asset_hub::snapshot::get_all_asset_metadata_params::Owned,
-                crate::service_capnp::asset_hub::snapshot::get_all_asset_metadata_results::Owned,
-            > {
-                self.client.new_call(_private::TYPE_ID, 2, None)
+
+            impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+                fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+                    self.builder
+                        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+                }
             }
-            pub fn get_latest_asset_change_request(
-                &self,
-            ) -> ::capnp::capability::Request<
-                crate::service_capnp::asset_hub::snapshot::get_latest_asset_change_params::Owned,
-                crate::service_capnp::asset_hub::snapshot::get_latest_asset_change_results::Owned,
-            > {
-                self.client.new_call(_private::TYPE_ID, 3, None)
+
+            impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+                fn init_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> Builder<'a> {
+                    ::capnp::traits::FromStructBuilder::new(
+                        builder.init_struct(_private::STRUCT_SIZE),
+                    )
+                }
+                fn get_from_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Builder<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                        builder.get_struct(_private::STRUCT_SIZE, default)?,
+                    ))
+                }
             }
-            pub fn get_asset_changes_request(
-                &self,
-            ) -> ::capnp::capability::Request<
-                crate::service_capnp::asset_hub::snapshot::get_asset_changes_params::Owned,
-                crate::service_capnp::asset_hub::snapshot::get_asset_changes_results::Owned,
-            > {
-                self.client.new_call(_private::TYPE_ID, 4, None)
+
+            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+                fn set_pointer_builder<'b>(
+                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                    value: Reader<'a>,
+                    canonicalize: bool,
+                ) -> ::capnp::Result<()> {
+                    pointer.set_struct(&value.reader, canonicalize)
+                }
             }
-            pub fn get_import_artifacts_request(
-                &self,
-            ) -> ::capnp::capability::Request<
-                crate::service_capnp::asset_hub::snapshot::get_import_artifacts_params::Owned,
-                crate::service_capnp::asset_hub::snapshot::get_import_artifacts_results::Owned,
-            > {
-                self.client.new_call(_private::TYPE_ID, 5, None)
+
+            impl<'a> Builder<'a> {
+                pub fn into_reader(self) -> Reader<'a> {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+                pub fn reborrow(&mut self) -> Builder {
+                    Builder { ..*self }
+                }
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+                #[inline]
+                pub fn get_new_import_hash(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_new_import_hash(&mut self, value: ::capnp::data::Reader) {
+                    self.builder.get_pointer_field(0).set_data(value);
+                }
+                #[inline]
+                pub fn init_new_import_hash(self, size: u32) -> ::capnp::data::Builder<'a> {
+                    self.builder.get_pointer_field(0).init_data(size)
+                }
+                pub fn has_new_import_hash(&self) -> bool {
+                    !self.builder.get_pointer_field(0).is_null()
+                }
             }
-            pub fn update_asset_request(
-                &self,
-            ) -> ::capnp::capability::Request<
-                crate::service_capnp::asset_hub::snapshot::update_asset_params::Owned,
-                crate::service_capnp::asset_hub::snapshot::update_asset_results::Owned,
-            > {
-                self.client.new_call(_private::TYPE_ID, 6, None)
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
             }
-            pub fn patch_asset_request(
-                &self,
-            ) -> ::capnp::capability::Request<
-                crate::service_capnp::asset_hub::snapshot::patch_asset_params::Owned,
-                crate::service_capnp::asset_hub::snapshot::patch_asset_results::Owned,
-            > {
-                self.client.new_call(_private::TYPE_ID, 7, None)
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
+                    }
+                }
             }
-            pub fn get_path_for_assets_request(
-                &self,
-            ) -> ::capnp::capability::Request<
-                crate::service_capnp::asset_hub::snapshot::get_path_for_assets_params::Owned,
-                crate::service_capnp::asset_hub::snapshot::get_path_for_assets_results::Owned,
-            > {
-                self.client.new_call(_private::TYPE_ID, 8, None)
+            impl Pipeline {}
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 0,
+                    pointers: 1,
+                };
+                pub const TYPE_ID: u64 = 0xa89b_dfdd_dc81_62f1;
+            }
+        }
+
+        pub mod get_path_for_assets_params {
+            #[derive(Copy, Clone)]
+            pub struct Owned;
+            impl<'a> ::capnp::traits::Owned<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
             }
-            pub fn get_assets_for_paths_request(
-                &self,
-            ) -> ::capnp::capability::Request<
-                crate::service_capnp::asset_hub::snapshot::get_assets_for_paths_params::Owned,
-                crate::service_capnp::asset_hub::snapshot::get_assets_for_paths_results::Owned,
-            > {
-                self.client.new_call(_private::TYPE_ID, 9, None)
+            impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+                type Reader = Reader<'a>;
+                type Builder = Builder<'a>;
             }
-            pub fn create_file_request(
-                &self,
-            ) -> ::capnp::capability::Request<
-                crate::service_capnp::asset_hub::snapshot::create_file_params::Owned,
-                crate::service_capnp::asset_hub::snapshot::create_file_results::Owned,
-            > {
-                self.client.new_call(_private::TYPE_ID, 10, None)
+            impl ::capnp::traits::Pipelined for Owned {
+                type Pipeline = Pipeline;
             }
-            pub fn delete_file_request(
-                &self,
-            ) -> ::capnp::capability::Request<
-                crate::service_capnp::asset_hub::snapshot::delete_file_params::Owned,
-                crate::service_capnp::asset_hub::snapshot::delete_file_results::Owned,
-            > {
-                self.client.new_call(_private::TYPE_ID, 11, None)
+
+            #[derive(Clone, Copy)]
+            pub struct Reader<'a> {
+                reader: ::capnp::private::layout::StructReader<'a>,
             }
-        }
-        pub trait Server {
-            fn get_asset_metadata(
-                &mut self,
-                _: GetAssetMetadataParams,
-                _: GetAssetMetadataResults,
-            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                    "method not implemented".to_string(),
-                ))
+
+            impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
             }
-            fn get_asset_metadata_with_dependencies(
-                &mut self,
-                _: GetAssetMetadataWithDependenciesParams,
-                _: GetAssetMetadataWithDependenciesResults,
-            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                    "method not implemented".to_string(),
-                ))
+            impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+                fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+                    Reader { reader }
+                }
             }
-            fn get_all_asset_metadata(
-                &mut self,
-                _: GetAllAssetMetadataParams,
-                _: GetAllAssetMetadataResults,
-            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                    "method not implemented".to_string(),
-                ))
+
+            impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+                fn get_from_pointer(
+                    reader: &::capnp::private::layout::PointerReader<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Reader<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                        reader.get_struct(default)?,
+                    ))
+                }
             }
-            fn get_latest_asset_change(
-                &mut self,
-                _: GetLatestAssetChangeParams,
-                _: GetLatestAssetChangeResults,
-            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                    "method not implemented".to_string(),
-                ))
+
+            impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+                fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+                    self.reader
+                }
             }
-            fn get_asset_changes(
-                &mut self,
-                _: GetAssetChangesParams,
-                _: GetAssetChangesResults,
-            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                    "method not implemented".to_string(),
-                ))
+
+            impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+                fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+                    self.reader
+                        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+                }
             }
-            fn get_import_artifacts(
-                &mut self,
-                _: GetImportArtifactsParams,
-                _: GetImportArtifactsResults,
-            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                    "method not implemented".to_string(),
-                ))
+
+            impl<'a> Reader<'a> {
+                pub fn reborrow(&self) -> Reader {
+                    Reader { ..*self }
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.reader.total_size()
+                }
+                #[inline]
+                pub fn get_assets(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
+                > {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_assets(&self) -> bool {
+                    !self.reader.get_pointer_field(0).is_null()
+                }
             }
-            fn update_asset(
-                &mut self,
-                _: UpdateAssetParams,
-                _: UpdateAssetResults,
-            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                    "method not implemented".to_string(),
-                ))
+
+            pub struct Builder<'a> {
+                builder: ::capnp::private::layout::StructBuilder<'a>,
             }
-            fn patch_asset(
-                &mut self,
-                _: PatchAssetParams,
-                _: PatchAssetResults,
-            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                    "method not implemented".to_string(),
-                ))
+            impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+                #[inline]
+                fn struct_size() -> ::capnp::private::layout::StructSize {
+                    _private::STRUCT_SIZE
+                }
             }
-            fn get_path_for_assets(
-                &mut self,
-                _: GetPathForAssetsParams,
-                _: GetPathForAssetsResults,
-            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                    "method not implemented".to_string(),
-                ))
+            impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+                #[inline]
+                fn type_id() -> u64 {
+                    _private::TYPE_ID
+                }
             }
-            fn get_assets_for_paths(
-                &mut self,
-                _: GetAssetsForPathsParams,
-                _: GetAssetsForPathsResults,
-            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                    "method not implemented".to_string(),
-                ))
+            impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+                fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+                    Builder { builder }
+                }
             }
-            fn create_file(
-                &mut self,
-                _: CreateFileParams,
-                _: CreateFileResults,
-            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                    "method not implemented".to_string(),
-                ))
+
+            impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+                fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+                    self.builder
+                        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+                }
             }
-            fn delete_file(
-                &mut self,
-                _: DeleteFileParams,
-                _: DeleteFileResults,
-            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                    "method not implemented".to_string(),
-                ))
+
+            impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+                fn init_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> Builder<'a> {
+                    ::capnp::traits::FromStructBuilder::new(
+                        builder.init_struct(_private::STRUCT_SIZE),
+                    )
+                }
+                fn get_from_pointer(
+                    builder: ::capnp::private::layout::PointerBuilder<'a>,
+                    default: ::core::option::Option<&'a [capnp::Word]>,
+                ) -> ::capnp::Result<Builder<'a>> {
+                    ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                        builder.get_struct(_private::STRUCT_SIZE, default)?,
+                    ))
+                }
             }
-        }
-        pub struct ServerDispatch<_T> {
-            pub server: _T,
-        }
-        impl<_S: Server + 'static> ::capnp::capability::FromServer<_S> for Client {
-            type Dispatch = ServerDispatch<_S>;
-            fn from_server(s: _S) -> ServerDispatch<_S> {
-                ServerDispatch { server: s }
+
+            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+                fn set_pointer_builder<'b>(
+                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                    value: Reader<'a>,
+                    canonicalize: bool,
+                ) -> ::capnp::Result<()> {
+                    pointer.set_struct(&value.reader, canonicalize)
+                }
             }
-        }
-        impl<_T: Server> ::core::ops::Deref for ServerDispatch<_T> {
-            type Target = _T;
-            fn deref(&self) -> &_T {
-                &self.server
+
+            impl<'a> Builder<'a> {
+                pub fn into_reader(self) -> Reader<'a> {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+                pub fn reborrow(&mut self) -> Builder {
+                    Builder { ..*self }
+                }
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+                #[inline]
+                pub fn get_assets(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>,
+                > {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_assets(
+                    &mut self,
+                    value: ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
+                ) -> ::capnp::Result<()> {
+                    ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                        self.builder.get_pointer_field(0),
+                        value,
+                        false,
+                    )
+                }
+                #[inline]
+                pub fn init_assets(
+                    self,
+                    size: u32,
+                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>
+                {
+                    ::capnp::traits::FromPointerBuilder::init_pointer(
+                        self.builder.get_pointer_field(0),
+                        size,
+                    )
+                }
+                pub fn has_assets(&self) -> bool {
+                    !self.builder.get_pointer_field(0).is_null()
+                }
             }
-        }
-        impl<_T: Server> ::core::ops::DerefMut for ServerDispatch<_T> {
-            fn deref_mut(&mut self) -> &mut _T {
-                &mut self.server
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
             }
-        }
-        impl<_T: Server> ::capnp::capability::Server for ServerDispatch<_T> {
-            fn dispatch_call(
-                &mut self,
-                interface_id: u64,
-                method_id: u16,
-                params: ::capnp::capability::Params<::capnp::any_pointer::Owned>,
-                results: ::capnp::capability::Results<::capnp::any_pointer::Owned>,
-            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-                match interface_id {
-                    _private::TYPE_ID => ServerDispatch::<_T>::dispatch_call_internal(
-                        &mut self.server,
-                        method_id,
-                        params,
-                        results,
-                    ),
-                    _ => ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                        "Method not implemented.".to_string(),
-                    )),
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
+                    }
                 }
             }
-        }
-        impl<_T: Server> ServerDispatch<_T> {
-            pub fn dispatch_call_internal(
-                server: &mut _T,
-                method_id: u16,
-                params: ::capnp::capability::Params<::capnp::any_pointer::Owned>,
-                results: ::capnp::capability::Results<::capnp::any_pointer::Owned>,
-            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-                match method_id {
-                    0 => server.get_asset_metadata(
-                        ::capnp::private::capability::internal_get_typed_params(params),
-                        ::capnp::private::capability::internal_get_typed_results(results),
-                    ),
-                    1 => server.get_asset_metadata_with_dependencies(
-                        ::capnp::private::capability::internal_get_typed_params(params),
-                        ::capnp::private::capability::internal_get_typed_results(results),
-                    ),
-                    2 => server.get_all_asset_metadata(
-                        ::capnp::private::capability::internal_get_typed_params(params),
-                        ::capnp::private::capability::internal_get_typed_results(results),
-                    ),
-                    3 => server.get_latest_asset_change(
-                        ::capnp::private::capability::internal_get_typed_params(params),
-                        ::capnp::private::capability::internal_get_typed_results(results),
-                    ),
-                    4 => server.get_asset_changes(
-                        ::capnp::private::capability::internal_get_typed_params(params),
-                        ::capnp::private::capability::internal_get_typed_results(results),
-                    ),
-                    5 => server.get_import_artifacts(
-                        ::capnp::private::capability::internal_get_typed_params(params),
-                        ::capnp::private::capability::internal_get_typed_results(results),
-                    ),
-                    6 => server.update_asset(
-                        ::capnp::private::capability::internal_get_typed_params(params),
-                        ::capnp::private::capability::internal_get_typed_results(results),
-                    ),
-                    7 => server.patch_asset(
-                        ::capnp::private::capability::internal_get_typed_params(params),
-                        ::capnp::private::capability::internal_get_typed_results(results),
-                    ),
-                    8 => server.get_path_for_assets(
-                        ::capnp::private::capability::internal_get_typed_params(params),
-                        ::capnp::private::capability::internal_get_typed_results(results),
-                    ),
-                    9 => server.get_assets_for_paths(
-                        ::capnp::private::capability::internal_get_typed_params(params),
-                        ::capnp::private::capability::internal_get_typed_results(results),
-                    ),
-                    10 => server.create_file(
-                        ::capnp::private::capability::internal_get_typed_params(params),
-                        ::capnp::private::capability::internal_get_typed_results(results),
-                    ),
-                    11 => server.delete_file(
-                        ::capnp::private::capability::internal_get_typed_params(params),
-                        ::capnp::private::capability::internal_get_typed_results(results),
-                    ),
-                    _ => ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                        "Method not implemented.".to_string(),
-                    )),
-                }
+            impl Pipeline {}
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 0,
+                    pointers: 1,
+                };
+                pub const TYPE_ID: u64 = 0xd7be_fb03_9009_cf6b;
             }
         }
-        pub mod _private {
-            pub const TYPE_ID: u64 = 0xda70_22fe_c2b7_2fd6;
-        }
 
-        pub mod get_asset_metadata_params {
+        pub mod get_path_for_assets_results {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -1376,17 +6444,17 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.reader.total_size()
                 }
                 #[inline]
-                pub fn get_assets(
+                pub fn get_paths(
                     self,
                 ) -> ::capnp::Result<
-                    ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
+                    ::capnp::struct_list::Reader<'a, crate::service_capnp::asset_path::Owned>,
                 > {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
                 }
-                pub fn has_assets(&self) -> bool {
+                pub fn has_paths(&self) -> bool {
                     !self.reader.get_pointer_field(0).is_null()
                 }
             }
@@ -1463,10 +6531,10 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.builder.into_reader().total_size()
                 }
                 #[inline]
-                pub fn get_assets(
+                pub fn get_paths(
                     self,
                 ) -> ::capnp::Result<
-                    ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>,
+                    ::capnp::struct_list::Builder<'a, crate::service_capnp::asset_path::Owned>,
                 > {
                     ::capnp::traits::FromPointerBuilder::get_from_pointer(
                         self.builder.get_pointer_field(0),
@@ -1474,9 +6542,12 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     )
                 }
                 #[inline]
-                pub fn set_assets(
+                pub fn set_paths(
                     &mut self,
-                    value: ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
+                    value: ::capnp::struct_list::Reader<
+                        'a,
+                        crate::service_capnp::asset_path::Owned,
+                    >,
                 ) -> ::capnp::Result<()> {
                     ::capnp::traits::SetPointerBuilder::set_pointer_builder(
                         self.builder.get_pointer_field(0),
@@ -1485,17 +6556,17 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     )
                 }
                 #[inline]
-                pub fn init_assets(
+                pub fn init_paths(
                     self,
                     size: u32,
-                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>
+                ) -> ::capnp::struct_list::Builder<'a, crate::service_capnp::asset_path::Owned>
                 {
                     ::capnp::traits::FromPointerBuilder::init_pointer(
                         self.builder.get_pointer_field(0),
                         size,
                     )
                 }
-                pub fn has_assets(&self) -> bool {
+                pub fn has_paths(&self) -> bool {
                     !self.builder.get_pointer_field(0).is_null()
                 }
             }
@@ -1517,11 +6588,11 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     data: 0,
                     pointers: 1,
                 };
-                pub const TYPE_ID: u64 = 0x9950_490a_65ff_9465;
+                pub const TYPE_ID: u64 = 0xd014_f0e5_6f91_7900;
             }
         }
 
-        pub mod get_asset_metadata_results {
+        pub mod get_assets_for_paths_params {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -1586,17 +6657,13 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.reader.total_size()
                 }
                 #[inline]
-                pub fn get_assets(
-                    self,
-                ) -> ::capnp::Result<
-                    ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_metadata::Owned>,
-                > {
+                pub fn get_paths(self) -> ::capnp::Result<::capnp::data_list::Reader<'a>> {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
                 }
-                pub fn has_assets(&self) -> bool {
+                pub fn has_paths(&self) -> bool {
                     !self.reader.get_pointer_field(0).is_null()
                 }
             }
@@ -1673,23 +6740,16 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.builder.into_reader().total_size()
                 }
                 #[inline]
-                pub fn get_assets(
-                    self,
-                ) -> ::capnp::Result<
-                    ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_metadata::Owned>,
-                > {
+                pub fn get_paths(self) -> ::capnp::Result<::capnp::data_list::Builder<'a>> {
                     ::capnp::traits::FromPointerBuilder::get_from_pointer(
                         self.builder.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
                 }
                 #[inline]
-                pub fn set_assets(
+                pub fn set_paths(
                     &mut self,
-                    value: ::capnp::struct_list::Reader<
-                        'a,
-                        crate::data_capnp::asset_metadata::Owned,
-                    >,
+                    value: ::capnp::data_list::Reader<'a>,
                 ) -> ::capnp::Result<()> {
                     ::capnp::traits::SetPointerBuilder::set_pointer_builder(
                         self.builder.get_pointer_field(0),
@@ -1698,17 +6758,13 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     )
                 }
                 #[inline]
-                pub fn init_assets(
-                    self,
-                    size: u32,
-                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_metadata::Owned>
-                {
+                pub fn init_paths(self, size: u32) -> ::capnp::data_list::Builder<'a> {
                     ::capnp::traits::FromPointerBuilder::init_pointer(
                         self.builder.get_pointer_field(0),
                         size,
                     )
                 }
-                pub fn has_assets(&self) -> bool {
+                pub fn has_paths(&self) -> bool {
                     !self.builder.get_pointer_field(0).is_null()
                 }
             }
@@ -1730,11 +6786,11 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     data: 0,
                     pointers: 1,
                 };
-                pub const TYPE_ID: u64 = 0xa407_3b0d_081c_18e1;
+                pub const TYPE_ID: u64 = 0xbd84_fd41_613d_f6de;
             }
         }
 
-        pub mod get_asset_metadata_with_dependencies_params {
+        pub mod get_assets_for_paths_results {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -1802,7 +6858,7 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn get_assets(
                     self,
                 ) -> ::capnp::Result<
-                    ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
+                    ::capnp::struct_list::Reader<'a, crate::service_capnp::path_assets::Owned>,
                 > {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
@@ -1889,7 +6945,7 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn get_assets(
                     self,
                 ) -> ::capnp::Result<
-                    ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>,
+                    ::capnp::struct_list::Builder<'a, crate::service_capnp::path_assets::Owned>,
                 > {
                     ::capnp::traits::FromPointerBuilder::get_from_pointer(
                         self.builder.get_pointer_field(0),
@@ -1899,7 +6955,10 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 #[inline]
                 pub fn set_assets(
                     &mut self,
-                    value: ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
+                    value: ::capnp::struct_list::Reader<
+                        'a,
+                        crate::service_capnp::path_assets::Owned,
+                    >,
                 ) -> ::capnp::Result<()> {
                     ::capnp::traits::SetPointerBuilder::set_pointer_builder(
                         self.builder.get_pointer_field(0),
@@ -1911,7 +6970,7 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn init_assets(
                     self,
                     size: u32,
-                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>
+                ) -> ::capnp::struct_list::Builder<'a, crate::service_capnp::path_assets::Owned>
                 {
                     ::capnp::traits::FromPointerBuilder::init_pointer(
                         self.builder.get_pointer_field(0),
@@ -1940,11 +6999,11 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     data: 0,
                     pointers: 1,
                 };
-                pub const TYPE_ID: u64 = 0xd145_75a0_93f7_4505;
+                pub const TYPE_ID: u64 = 0xb340_5cff_7b80_0692;
             }
         }
 
-        pub mod get_asset_metadata_with_dependencies_results {
+        pub mod create_file_params {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -2009,18 +7068,28 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.reader.total_size()
                 }
                 #[inline]
+                pub fn get_path(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_path(&self) -> bool {
+                    !self.reader.get_pointer_field(0).is_null()
+                }
+                #[inline]
                 pub fn get_assets(
                     self,
                 ) -> ::capnp::Result<
-                    ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_metadata::Owned>,
+                    ::capnp::struct_list::Reader<'a, crate::service_capnp::asset_data::Owned>,
                 > {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
-                        &self.reader.get_pointer_field(0),
+                        &self.reader.get_pointer_field(1),
                         ::core::option::Option::None,
                     )
                 }
                 pub fn has_assets(&self) -> bool {
-                    !self.reader.get_pointer_field(0).is_null()
+                    !self.reader.get_pointer_field(1).is_null()
                 }
             }
 
@@ -2085,24 +7154,42 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn into_reader(self) -> Reader<'a> {
                     ::capnp::traits::FromStructReader::new(self.builder.into_reader())
                 }
-                pub fn reborrow(&mut self) -> Builder {
-                    Builder { ..*self }
+                pub fn reborrow(&mut self) -> Builder {
+                    Builder { ..*self }
+                }
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+                #[inline]
+                pub fn get_path(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_path(&mut self, value: ::capnp::data::Reader) {
+                    self.builder.get_pointer_field(0).set_data(value);
                 }
-                pub fn reborrow_as_reader(&self) -> Reader {
-                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                #[inline]
+                pub fn init_path(self, size: u32) -> ::capnp::data::Builder<'a> {
+                    self.builder.get_pointer_field(0).init_data(size)
                 }
-
-                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-                    self.builder.into_reader().total_size()
+                pub fn has_path(&self) -> bool {
+                    !self.builder.get_pointer_field(0).is_null()
                 }
                 #[inline]
                 pub fn get_assets(
                     self,
                 ) -> ::capnp::Result<
-                    ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_metadata::Owned>,
+                    ::capnp::struct_list::Builder<'a, crate::service_capnp::asset_data::Owned>,
                 > {
                     ::capnp::traits::FromPointerBuilder::get_from_pointer(
-                        self.builder.get_pointer_field(0),
+                        self.builder.get_pointer_field(1),
                         ::core::option::Option::None,
                     )
                 }
@@ -2111,11 +7198,11 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     &mut self,
                     value: ::capnp::struct_list::Reader<
                         'a,
-                        crate::data_capnp::asset_metadata::Owned,
+                        crate::service_capnp::asset_data::Owned,
                     >,
                 ) -> ::capnp::Result<()> {
                     ::capnp::traits::SetPointerBuilder::set_pointer_builder(
-                        self.builder.get_pointer_field(0),
+                        self.builder.get_pointer_field(1),
                         value,
                         false,
                     )
@@ -2124,15 +7211,15 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn init_assets(
                     self,
                     size: u32,
-                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_metadata::Owned>
+                ) -> ::capnp::struct_list::Builder<'a, crate::service_capnp::asset_data::Owned>
                 {
                     ::capnp::traits::FromPointerBuilder::init_pointer(
-                        self.builder.get_pointer_field(0),
+                        self.builder.get_pointer_field(1),
                         size,
                     )
                 }
                 pub fn has_assets(&self) -> bool {
-                    !self.builder.get_pointer_field(0).is_null()
+                    !self.builder.get_pointer_field(1).is_null()
                 }
             }
 
@@ -2151,13 +7238,13 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 use capnp::private::layout;
                 pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
                     data: 0,
-                    pointers: 1,
+                    pointers: 2,
                 };
-                pub const TYPE_ID: u64 = 0xf778_60ac_c787_329d;
+                pub const TYPE_ID: u64 = 0xe3fc_52d9_281d_0f60;
             }
         }
 
-        pub mod get_all_asset_metadata_params {
+        pub mod create_file_results {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -2221,6 +7308,16 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
                     self.reader.total_size()
                 }
+                #[inline]
+                pub fn get_new_import_hash(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_new_import_hash(&self) -> bool {
+                    !self.reader.get_pointer_field(0).is_null()
+                }
             }
 
             pub struct Builder<'a> {
@@ -2294,6 +7391,24 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
                     self.builder.into_reader().total_size()
                 }
+                #[inline]
+                pub fn get_new_import_hash(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_new_import_hash(&mut self, value: ::capnp::data::Reader) {
+                    self.builder.get_pointer_field(0).set_data(value);
+                }
+                #[inline]
+                pub fn init_new_import_hash(self, size: u32) -> ::capnp::data::Builder<'a> {
+                    self.builder.get_pointer_field(0).init_data(size)
+                }
+                pub fn has_new_import_hash(&self) -> bool {
+                    !self.builder.get_pointer_field(0).is_null()
+                }
             }
 
             pub struct Pipeline {
@@ -2311,13 +7426,13 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 use capnp::private::layout;
                 pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
                     data: 0,
-                    pointers: 0,
+                    pointers: 1,
                 };
-                pub const TYPE_ID: u64 = 0xe039_b049_3c2d_4287;
+                pub const TYPE_ID: u64 = 0xf6d1_9435_d082_079c;
             }
         }
 
-        pub mod get_all_asset_metadata_results {
+        pub mod delete_file_params {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -2382,17 +7497,13 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.reader.total_size()
                 }
                 #[inline]
-                pub fn get_assets(
-                    self,
-                ) -> ::capnp::Result<
-                    ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_metadata::Owned>,
-                > {
+                pub fn get_path(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
                 }
-                pub fn has_assets(&self) -> bool {
+                pub fn has_path(&self) -> bool {
                     !self.reader.get_pointer_field(0).is_null()
                 }
             }
@@ -2469,42 +7580,21 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.builder.into_reader().total_size()
                 }
                 #[inline]
-                pub fn get_assets(
-                    self,
-                ) -> ::capnp::Result<
-                    ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_metadata::Owned>,
-                > {
+                pub fn get_path(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
                     ::capnp::traits::FromPointerBuilder::get_from_pointer(
                         self.builder.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
                 }
                 #[inline]
-                pub fn set_assets(
-                    &mut self,
-                    value: ::capnp::struct_list::Reader<
-                        'a,
-                        crate::data_capnp::asset_metadata::Owned,
-                    >,
-                ) -> ::capnp::Result<()> {
-                    ::capnp::traits::SetPointerBuilder::set_pointer_builder(
-                        self.builder.get_pointer_field(0),
-                        value,
-                        false,
-                    )
+                pub fn set_path(&mut self, value: ::capnp::data::Reader) {
+                    self.builder.get_pointer_field(0).set_data(value);
                 }
                 #[inline]
-                pub fn init_assets(
-                    self,
-                    size: u32,
-                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_metadata::Owned>
-                {
-                    ::capnp::traits::FromPointerBuilder::init_pointer(
-                        self.builder.get_pointer_field(0),
-                        size,
-                    )
+                pub fn init_path(self, size: u32) -> ::capnp::data::Builder<'a> {
+                    self.builder.get_pointer_field(0).init_data(size)
                 }
-                pub fn has_assets(&self) -> bool {
+                pub fn has_path(&self) -> bool {
                     !self.builder.get_pointer_field(0).is_null()
                 }
             }
@@ -2526,11 +7616,11 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     data: 0,
                     pointers: 1,
                 };
-                pub const TYPE_ID: u64 = 0xce83_1fc1_7c2b_5dff;
+                pub const TYPE_ID: u64 = 0xcd7c_2a1b_fc81_8409;
             }
         }
 
-        pub mod get_latest_asset_change_params {
+        pub mod delete_file_results {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -2686,11 +7776,11 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     data: 0,
                     pointers: 0,
                 };
-                pub const TYPE_ID: u64 = 0x8f91_97be_163d_398e;
+                pub const TYPE_ID: u64 = 0xce96_d824_50b6_20c1;
             }
         }
 
-        pub mod get_latest_asset_change_results {
+        pub mod force_reimport_params {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -2755,8 +7845,14 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.reader.total_size()
                 }
                 #[inline]
-                pub fn get_num(self) -> u64 {
-                    self.reader.get_data_field::<u64>(0)
+                pub fn get_path(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_path(&self) -> bool {
+                    !self.reader.get_pointer_field(0).is_null()
                 }
             }
 
@@ -2832,12 +7928,22 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.builder.into_reader().total_size()
                 }
                 #[inline]
-                pub fn get_num(self) -> u64 {
-                    self.builder.get_data_field::<u64>(0)
+                pub fn get_path(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
                 }
                 #[inline]
-                pub fn set_num(&mut self, value: u64) {
-                    self.builder.set_data_field::<u64>(0, value);
+                pub fn set_path(&mut self, value: ::capnp::data::Reader) {
+                    self.builder.get_pointer_field(0).set_data(value);
+                }
+                #[inline]
+                pub fn init_path(self, size: u32) -> ::capnp::data::Builder<'a> {
+                    self.builder.get_pointer_field(0).init_data(size)
+                }
+                pub fn has_path(&self) -> bool {
+                    !self.builder.get_pointer_field(0).is_null()
                 }
             }
 
@@ -2855,14 +7961,14 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
             mod _private {
                 use capnp::private::layout;
                 pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
-                    data: 1,
-                    pointers: 0,
+                    data: 0,
+                    pointers: 1,
                 };
-                pub const TYPE_ID: u64 = 0xd5aa_a354_7610_5f6f;
+                pub const TYPE_ID: u64 = 0x9f3a_6c1e_52d8_b704;
             }
         }
 
-        pub mod get_asset_changes_params {
+        pub mod force_reimport_results {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -2926,14 +8032,6 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
                     self.reader.total_size()
                 }
-                #[inline]
-                pub fn get_start(self) -> u64 {
-                    self.reader.get_data_field::<u64>(0)
-                }
-                #[inline]
-                pub fn get_count(self) -> u64 {
-                    self.reader.get_data_field::<u64>(1)
-                }
             }
 
             pub struct Builder<'a> {
@@ -3007,22 +8105,6 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
                     self.builder.into_reader().total_size()
                 }
-                #[inline]
-                pub fn get_start(self) -> u64 {
-                    self.builder.get_data_field::<u64>(0)
-                }
-                #[inline]
-                pub fn set_start(&mut self, value: u64) {
-                    self.builder.set_data_field::<u64>(0, value);
-                }
-                #[inline]
-                pub fn get_count(self) -> u64 {
-                    self.builder.get_data_field::<u64>(1)
-                }
-                #[inline]
-                pub fn set_count(&mut self, value: u64) {
-                    self.builder.set_data_field::<u64>(1, value);
-                }
             }
 
             pub struct Pipeline {
@@ -3039,14 +8121,14 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
             mod _private {
                 use capnp::private::layout;
                 pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
-                    data: 2,
+                    data: 0,
                     pointers: 0,
                 };
-                pub const TYPE_ID: u64 = 0xba40_b57b_85a0_3cc0;
+                pub const TYPE_ID: u64 = 0xa4e1_07b9_3d6c_58f2;
             }
         }
 
-        pub mod get_asset_changes_results {
+        pub mod get_asset_metadata_with_build_dependencies_params {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -3111,20 +8193,17 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.reader.total_size()
                 }
                 #[inline]
-                pub fn get_changes(
+                pub fn get_assets(
                     self,
                 ) -> ::capnp::Result<
-                    ::capnp::struct_list::Reader<
-                        'a,
-                        crate::data_capnp::asset_change_log_entry::Owned,
-                    >,
+                    ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
                 > {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
                 }
-                pub fn has_changes(&self) -> bool {
+                pub fn has_assets(&self) -> bool {
                     !self.reader.get_pointer_field(0).is_null()
                 }
             }
@@ -3201,13 +8280,10 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.builder.into_reader().total_size()
                 }
                 #[inline]
-                pub fn get_changes(
+                pub fn get_assets(
                     self,
                 ) -> ::capnp::Result<
-                    ::capnp::struct_list::Builder<
-                        'a,
-                        crate::data_capnp::asset_change_log_entry::Owned,
-                    >,
+                    ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>,
                 > {
                     ::capnp::traits::FromPointerBuilder::get_from_pointer(
                         self.builder.get_pointer_field(0),
@@ -3215,12 +8291,9 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     )
                 }
                 #[inline]
-                pub fn set_changes(
+                pub fn set_assets(
                     &mut self,
-                    value: ::capnp::struct_list::Reader<
-                        'a,
-                        crate::data_capnp::asset_change_log_entry::Owned,
-                    >,
+                    value: ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
                 ) -> ::capnp::Result<()> {
                     ::capnp::traits::SetPointerBuilder::set_pointer_builder(
                         self.builder.get_pointer_field(0),
@@ -3229,19 +8302,17 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     )
                 }
                 #[inline]
-                pub fn init_changes(
+                pub fn init_assets(
                     self,
                     size: u32,
-                ) -> ::capnp::struct_list::Builder<
-                    'a,
-                    crate::data_capnp::asset_change_log_entry::Owned,
-                > {
+                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>
+                {
                     ::capnp::traits::FromPointerBuilder::init_pointer(
                         self.builder.get_pointer_field(0),
                         size,
                     )
                 }
-                pub fn has_changes(&self) -> bool {
+                pub fn has_assets(&self) -> bool {
                     !self.builder.get_pointer_field(0).is_null()
                 }
             }
@@ -3263,11 +8334,11 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     data: 0,
                     pointers: 1,
                 };
-                pub const TYPE_ID: u64 = 0xd2d6_ad4d_8b8d_0fd6;
+                pub const TYPE_ID: u64 = 0xc0a3_5e8d_24f1_7b96;
             }
         }
 
-        pub mod get_import_artifacts_params {
+        pub mod get_asset_metadata_with_build_dependencies_results {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -3335,7 +8406,7 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn get_assets(
                     self,
                 ) -> ::capnp::Result<
-                    ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
+                    ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_metadata::Owned>,
                 > {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
@@ -3422,7 +8493,7 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn get_assets(
                     self,
                 ) -> ::capnp::Result<
-                    ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>,
+                    ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_metadata::Owned>,
                 > {
                     ::capnp::traits::FromPointerBuilder::get_from_pointer(
                         self.builder.get_pointer_field(0),
@@ -3432,7 +8503,10 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 #[inline]
                 pub fn set_assets(
                     &mut self,
-                    value: ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
+                    value: ::capnp::struct_list::Reader<
+                        'a,
+                        crate::data_capnp::asset_metadata::Owned,
+                    >,
                 ) -> ::capnp::Result<()> {
                     ::capnp::traits::SetPointerBuilder::set_pointer_builder(
                         self.builder.get_pointer_field(0),
@@ -3444,7 +8518,7 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn init_assets(
                     self,
                     size: u32,
-                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>
+                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_metadata::Owned>
                 {
                     ::capnp::traits::FromPointerBuilder::init_pointer(
                         self.builder.get_pointer_field(0),
@@ -3473,11 +8547,11 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     data: 0,
                     pointers: 1,
                 };
-                pub const TYPE_ID: u64 = 0xdc83_3c1c_8873_40ae;
+                pub const TYPE_ID: u64 = 0x8d62_f4b1_3a0e_c957;
             }
         }
 
-        pub mod get_import_artifacts_results {
+        pub mod search_assets_params {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -3542,19 +8616,25 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.reader.total_size()
                 }
                 #[inline]
-                pub fn get_artifacts(
-                    self,
-                ) -> ::capnp::Result<
-                    ::capnp::struct_list::Reader<'a, crate::data_capnp::artifact::Owned>,
-                > {
+                pub fn get_key(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
                 }
-                pub fn has_artifacts(&self) -> bool {
+                pub fn has_key(&self) -> bool {
                     !self.reader.get_pointer_field(0).is_null()
                 }
+                #[inline]
+                pub fn get_value(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(1),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_value(&self) -> bool {
+                    !self.reader.get_pointer_field(1).is_null()
+                }
             }
 
             pub struct Builder<'a> {
@@ -3621,48 +8701,48 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn reborrow(&mut self) -> Builder {
                     Builder { ..*self }
                 }
-                pub fn reborrow_as_reader(&self) -> Reader {
-                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+                #[inline]
+                pub fn get_key(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_key(&mut self, value: ::capnp::data::Reader) {
+                    self.builder.get_pointer_field(0).set_data(value);
+                }
+                #[inline]
+                pub fn init_key(self, size: u32) -> ::capnp::data::Builder<'a> {
+                    self.builder.get_pointer_field(0).init_data(size)
                 }
-
-                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-                    self.builder.into_reader().total_size()
+                pub fn has_key(&self) -> bool {
+                    !self.builder.get_pointer_field(0).is_null()
                 }
                 #[inline]
-                pub fn get_artifacts(
-                    self,
-                ) -> ::capnp::Result<
-                    ::capnp::struct_list::Builder<'a, crate::data_capnp::artifact::Owned>,
-                > {
+                pub fn get_value(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
                     ::capnp::traits::FromPointerBuilder::get_from_pointer(
-                        self.builder.get_pointer_field(0),
+                        self.builder.get_pointer_field(1),
                         ::core::option::Option::None,
                     )
                 }
                 #[inline]
-                pub fn set_artifacts(
-                    &mut self,
-                    value: ::capnp::struct_list::Reader<'a, crate::data_capnp::artifact::Owned>,
-                ) -> ::capnp::Result<()> {
-                    ::capnp::traits::SetPointerBuilder::set_pointer_builder(
-                        self.builder.get_pointer_field(0),
-                        value,
-                        false,
-                    )
+                pub fn set_value(&mut self, value: ::capnp::data::Reader) {
+                    self.builder.get_pointer_field(1).set_data(value);
                 }
                 #[inline]
-                pub fn init_artifacts(
-                    self,
-                    size: u32,
-                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::artifact::Owned>
-                {
-                    ::capnp::traits::FromPointerBuilder::init_pointer(
-                        self.builder.get_pointer_field(0),
-                        size,
-                    )
+                pub fn init_value(self, size: u32) -> ::capnp::data::Builder<'a> {
+                    self.builder.get_pointer_field(1).init_data(size)
                 }
-                pub fn has_artifacts(&self) -> bool {
-                    !self.builder.get_pointer_field(0).is_null()
+                pub fn has_value(&self) -> bool {
+                    !self.builder.get_pointer_field(1).is_null()
                 }
             }
 
@@ -3681,13 +8761,13 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 use capnp::private::layout;
                 pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
                     data: 0,
-                    pointers: 1,
+                    pointers: 2,
                 };
-                pub const TYPE_ID: u64 = 0xfd22_801d_f1cf_94d4;
+                pub const TYPE_ID: u64 = 0xb52e_8c61_d3f4_07a9;
             }
         }
 
-        pub mod update_asset_params {
+        pub mod search_assets_results {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -3752,13 +8832,17 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.reader.total_size()
                 }
                 #[inline]
-                pub fn get_asset(self) -> ::capnp::Result<crate::data_capnp::artifact::Reader<'a>> {
+                pub fn get_assets(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
+                > {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
                 }
-                pub fn has_asset(&self) -> bool {
+                pub fn has_assets(&self) -> bool {
                     !self.reader.get_pointer_field(0).is_null()
                 }
             }
@@ -3835,18 +8919,20 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.builder.into_reader().total_size()
                 }
                 #[inline]
-                pub fn get_asset(
+                pub fn get_assets(
                     self,
-                ) -> ::capnp::Result<crate::data_capnp::artifact::Builder<'a>> {
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>,
+                > {
                     ::capnp::traits::FromPointerBuilder::get_from_pointer(
                         self.builder.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
                 }
                 #[inline]
-                pub fn set_asset<'b>(
+                pub fn set_assets(
                     &mut self,
-                    value: crate::data_capnp::artifact::Reader<'b>,
+                    value: ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
                 ) -> ::capnp::Result<()> {
                     ::capnp::traits::SetPointerBuilder::set_pointer_builder(
                         self.builder.get_pointer_field(0),
@@ -3855,13 +8941,17 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     )
                 }
                 #[inline]
-                pub fn init_asset(self) -> crate::data_capnp::artifact::Builder<'a> {
+                pub fn init_assets(
+                    self,
+                    size: u32,
+                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>
+                {
                     ::capnp::traits::FromPointerBuilder::init_pointer(
                         self.builder.get_pointer_field(0),
-                        0,
+                        size,
                     )
                 }
-                pub fn has_asset(&self) -> bool {
+                pub fn has_assets(&self) -> bool {
                     !self.builder.get_pointer_field(0).is_null()
                 }
             }
@@ -3876,24 +8966,18 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     }
                 }
             }
-            impl Pipeline {
-                pub fn get_asset(&self) -> crate::data_capnp::artifact::Pipeline {
-                    ::capnp::capability::FromTypelessPipeline::new(
-                        self._typeless.get_pointer_field(0),
-                    )
-                }
-            }
+            impl Pipeline {}
             mod _private {
                 use capnp::private::layout;
                 pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
                     data: 0,
                     pointers: 1,
                 };
-                pub const TYPE_ID: u64 = 0x8e16_cf07_f0b8_1163;
+                pub const TYPE_ID: u64 = 0xd1a7_3e95_4b06_c82f;
             }
         }
 
-        pub mod update_asset_results {
+        pub mod get_asset_for_label_params {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -3958,13 +9042,13 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.reader.total_size()
                 }
                 #[inline]
-                pub fn get_new_import_hash(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+                pub fn get_label(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
                 }
-                pub fn has_new_import_hash(&self) -> bool {
+                pub fn has_label(&self) -> bool {
                     !self.reader.get_pointer_field(0).is_null()
                 }
             }
@@ -4041,21 +9125,21 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.builder.into_reader().total_size()
                 }
                 #[inline]
-                pub fn get_new_import_hash(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+                pub fn get_label(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
                     ::capnp::traits::FromPointerBuilder::get_from_pointer(
                         self.builder.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
                 }
                 #[inline]
-                pub fn set_new_import_hash(&mut self, value: ::capnp::data::Reader) {
+                pub fn set_label(&mut self, value: ::capnp::data::Reader) {
                     self.builder.get_pointer_field(0).set_data(value);
                 }
                 #[inline]
-                pub fn init_new_import_hash(self, size: u32) -> ::capnp::data::Builder<'a> {
+                pub fn init_label(self, size: u32) -> ::capnp::data::Builder<'a> {
                     self.builder.get_pointer_field(0).init_data(size)
                 }
-                pub fn has_new_import_hash(&self) -> bool {
+                pub fn has_label(&self) -> bool {
                     !self.builder.get_pointer_field(0).is_null()
                 }
             }
@@ -4077,11 +9161,11 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     data: 0,
                     pointers: 1,
                 };
-                pub const TYPE_ID: u64 = 0xee65_268f_6884_96c5;
+                pub const TYPE_ID: u64 = 0xc4f1_6a2d_8e37_b590;
             }
         }
 
-        pub mod patch_asset_params {
+        pub mod get_asset_for_label_results {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -4146,39 +9230,19 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.reader.total_size()
                 }
                 #[inline]
-                pub fn get_asset_id(
+                pub fn get_assets(
                     self,
-                ) -> ::capnp::Result<crate::data_capnp::asset_uuid::Reader<'a>> {
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
+                > {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
                 }
-                pub fn has_asset_id(&self) -> bool {
+                pub fn has_assets(&self) -> bool {
                     !self.reader.get_pointer_field(0).is_null()
                 }
-                #[inline]
-                pub fn get_asset_hash(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
-                    ::capnp::traits::FromPointerReader::get_from_pointer(
-                        &self.reader.get_pointer_field(1),
-                        ::core::option::Option::None,
-                    )
-                }
-                pub fn has_asset_hash(&self) -> bool {
-                    !self.reader.get_pointer_field(1).is_null()
-                }
-                #[inline]
-                pub fn get_patch(
-                    self,
-                ) -> ::capnp::Result<crate::service_capnp::asset_data::Reader<'a>> {
-                    ::capnp::traits::FromPointerReader::get_from_pointer(
-                        &self.reader.get_pointer_field(2),
-                        ::core::option::Option::None,
-                    )
-                }
-                pub fn has_patch(&self) -> bool {
-                    !self.reader.get_pointer_field(2).is_null()
-                }
             }
 
             pub struct Builder<'a> {
@@ -4253,18 +9317,20 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.builder.into_reader().total_size()
                 }
                 #[inline]
-                pub fn get_asset_id(
+                pub fn get_assets(
                     self,
-                ) -> ::capnp::Result<crate::data_capnp::asset_uuid::Builder<'a>> {
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>,
+                > {
                     ::capnp::traits::FromPointerBuilder::get_from_pointer(
                         self.builder.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
                 }
                 #[inline]
-                pub fn set_asset_id<'b>(
+                pub fn set_assets(
                     &mut self,
-                    value: crate::data_capnp::asset_uuid::Reader<'b>,
+                    value: ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
                 ) -> ::capnp::Result<()> {
                     ::capnp::traits::SetPointerBuilder::set_pointer_builder(
                         self.builder.get_pointer_field(0),
@@ -4273,63 +9339,18 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     )
                 }
                 #[inline]
-                pub fn init_asset_id(self) -> crate::data_capnp::asset_uuid::Builder<'a> {
-                    ::capnp::traits::FromPointerBuilder::init_pointer(
-                        self.builder.get_pointer_field(0),
-                        0,
-                    )
-                }
-                pub fn has_asset_id(&self) -> bool {
-                    !self.builder.get_pointer_field(0).is_null()
-                }
-                #[inline]
-                pub fn get_asset_hash(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
-                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
-                        self.builder.get_pointer_field(1),
-                        ::core::option::Option::None,
-                    )
-                }
-                #[inline]
-                pub fn set_asset_hash(&mut self, value: ::capnp::data::Reader) {
-                    self.builder.get_pointer_field(1).set_data(value);
-                }
-                #[inline]
-                pub fn init_asset_hash(self, size: u32) -> ::capnp::data::Builder<'a> {
-                    self.builder.get_pointer_field(1).init_data(size)
-                }
-                pub fn has_asset_hash(&self) -> bool {
-                    !self.builder.get_pointer_field(1).is_null()
-                }
-                #[inline]
-                pub fn get_patch(
+                pub fn init_assets(
                     self,
-                ) -> ::capnp::Result<crate::service_capnp::asset_data::Builder<'a>>
+                    size: u32,
+                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>
                 {
-                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
-                        self.builder.get_pointer_field(2),
-                        ::core::option::Option::None,
-                    )
-                }
-                #[inline]
-                pub fn set_patch<'b>(
-                    &mut self,
-                    value: crate::service_capnp::asset_data::Reader<'b>,
-                ) -> ::capnp::Result<()> {
-                    ::capnp::traits::SetPointerBuilder::set_pointer_builder(
-                        self.builder.get_pointer_field(2),
-                        value,
-                        false,
-                    )
-                }
-                #[inline]
-                pub fn init_patch(self) -> crate::service_capnp::asset_data::Builder<'a> {
                     ::capnp::traits::FromPointerBuilder::init_pointer(
-                        self.builder.get_pointer_field(2),
-                        0,
+                        self.builder.get_pointer_field(0),
+                        size,
                     )
                 }
-                pub fn has_patch(&self) -> bool {
-                    !self.builder.get_pointer_field(2).is_null()
+                pub fn has_assets(&self) -> bool {
+                    !self.builder.get_pointer_field(0).is_null()
                 }
             }
 
@@ -4343,29 +9364,18 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     }
                 }
             }
-            impl Pipeline {
-                pub fn get_asset_id(&self) -> crate::data_capnp::asset_uuid::Pipeline {
-                    ::capnp::capability::FromTypelessPipeline::new(
-                        self._typeless.get_pointer_field(0),
-                    )
-                }
-                pub fn get_patch(&self) -> crate::service_capnp::asset_data::Pipeline {
-                    ::capnp::capability::FromTypelessPipeline::new(
-                        self._typeless.get_pointer_field(2),
-                    )
-                }
-            }
+            impl Pipeline {}
             mod _private {
                 use capnp::private::layout;
                 pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
                     data: 0,
-                    pointers: 3,
+                    pointers: 1,
                 };
-                pub const TYPE_ID: u64 = 0xa5a8_ad11_2bab_a100;
+                pub const TYPE_ID: u64 = 0xe7a3_05bd_49c2_6f18;
             }
         }
 
-        pub mod patch_asset_results {
+        pub mod rebuild_all_artifacts_params {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -4429,16 +9439,6 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
                     self.reader.total_size()
                 }
-                #[inline]
-                pub fn get_new_import_hash(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
-                    ::capnp::traits::FromPointerReader::get_from_pointer(
-                        &self.reader.get_pointer_field(0),
-                        ::core::option::Option::None,
-                    )
-                }
-                pub fn has_new_import_hash(&self) -> bool {
-                    !self.reader.get_pointer_field(0).is_null()
-                }
             }
 
             pub struct Builder<'a> {
@@ -4512,24 +9512,6 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
                     self.builder.into_reader().total_size()
                 }
-                #[inline]
-                pub fn get_new_import_hash(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
-                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
-                        self.builder.get_pointer_field(0),
-                        ::core::option::Option::None,
-                    )
-                }
-                #[inline]
-                pub fn set_new_import_hash(&mut self, value: ::capnp::data::Reader) {
-                    self.builder.get_pointer_field(0).set_data(value);
-                }
-                #[inline]
-                pub fn init_new_import_hash(self, size: u32) -> ::capnp::data::Builder<'a> {
-                    self.builder.get_pointer_field(0).init_data(size)
-                }
-                pub fn has_new_import_hash(&self) -> bool {
-                    !self.builder.get_pointer_field(0).is_null()
-                }
             }
 
             pub struct Pipeline {
@@ -4547,13 +9529,13 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 use capnp::private::layout;
                 pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
                     data: 0,
-                    pointers: 1,
+                    pointers: 0,
                 };
-                pub const TYPE_ID: u64 = 0xa89b_dfdd_dc81_62f1;
+                pub const TYPE_ID: u64 = 0xb82d_4f16_e9a0_73c5;
             }
         }
 
-        pub mod get_path_for_assets_params {
+        pub mod rebuild_all_artifacts_results {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -4608,28 +9590,18 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                         .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
                 }
             }
-
-            impl<'a> Reader<'a> {
-                pub fn reborrow(&self) -> Reader {
-                    Reader { ..*self }
-                }
-
-                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-                    self.reader.total_size()
-                }
-                #[inline]
-                pub fn get_assets(
-                    self,
-                ) -> ::capnp::Result<
-                    ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
-                > {
-                    ::capnp::traits::FromPointerReader::get_from_pointer(
-                        &self.reader.get_pointer_field(0),
-                        ::core::option::Option::None,
-                    )
+
+            impl<'a> Reader<'a> {
+                pub fn reborrow(&self) -> Reader {
+                    Reader { ..*self }
                 }
-                pub fn has_assets(&self) -> bool {
-                    !self.reader.get_pointer_field(0).is_null()
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.reader.total_size()
+                }
+                #[inline]
+                pub fn get_source_files(self) -> u64 {
+                    self.reader.get_data_field::<u64>(0)
                 }
             }
 
@@ -4705,40 +9677,12 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.builder.into_reader().total_size()
                 }
                 #[inline]
-                pub fn get_assets(
-                    self,
-                ) -> ::capnp::Result<
-                    ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>,
-                > {
-                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
-                        self.builder.get_pointer_field(0),
-                        ::core::option::Option::None,
-                    )
-                }
-                #[inline]
-                pub fn set_assets(
-                    &mut self,
-                    value: ::capnp::struct_list::Reader<'a, crate::data_capnp::asset_uuid::Owned>,
-                ) -> ::capnp::Result<()> {
-                    ::capnp::traits::SetPointerBuilder::set_pointer_builder(
-                        self.builder.get_pointer_field(0),
-                        value,
-                        false,
-                    )
+                pub fn get_source_files(self) -> u64 {
+                    self.builder.get_data_field::<u64>(0)
                 }
                 #[inline]
-                pub fn init_assets(
-                    self,
-                    size: u32,
-                ) -> ::capnp::struct_list::Builder<'a, crate::data_capnp::asset_uuid::Owned>
-                {
-                    ::capnp::traits::FromPointerBuilder::init_pointer(
-                        self.builder.get_pointer_field(0),
-                        size,
-                    )
-                }
-                pub fn has_assets(&self) -> bool {
-                    !self.builder.get_pointer_field(0).is_null()
+                pub fn set_source_files(&mut self, value: u64) {
+                    self.builder.set_data_field::<u64>(0, value);
                 }
             }
 
@@ -4756,14 +9700,14 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
             mod _private {
                 use capnp::private::layout;
                 pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
-                    data: 0,
-                    pointers: 1,
+                    data: 1,
+                    pointers: 0,
                 };
-                pub const TYPE_ID: u64 = 0xd7be_fb03_9009_cf6b;
+                pub const TYPE_ID: u64 = 0xd3f7_92a4_1c6e_08b9;
             }
         }
 
-        pub mod get_path_for_assets_results {
+        pub mod get_asset_metadata_changes_params {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -4828,17 +9772,20 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.reader.total_size()
                 }
                 #[inline]
-                pub fn get_paths(
+                pub fn get_assets(
                     self,
                 ) -> ::capnp::Result<
-                    ::capnp::struct_list::Reader<'a, crate::service_capnp::asset_path::Owned>,
+                    ::capnp::struct_list::Reader<
+                        'a,
+                        crate::service_capnp::known_asset_metadata::Owned,
+                    >,
                 > {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
                 }
-                pub fn has_paths(&self) -> bool {
+                pub fn has_assets(&self) -> bool {
                     !self.reader.get_pointer_field(0).is_null()
                 }
             }
@@ -4915,10 +9862,13 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.builder.into_reader().total_size()
                 }
                 #[inline]
-                pub fn get_paths(
+                pub fn get_assets(
                     self,
                 ) -> ::capnp::Result<
-                    ::capnp::struct_list::Builder<'a, crate::service_capnp::asset_path::Owned>,
+                    ::capnp::struct_list::Builder<
+                        'a,
+                        crate::service_capnp::known_asset_metadata::Owned,
+                    >,
                 > {
                     ::capnp::traits::FromPointerBuilder::get_from_pointer(
                         self.builder.get_pointer_field(0),
@@ -4926,11 +9876,11 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     )
                 }
                 #[inline]
-                pub fn set_paths(
+                pub fn set_assets(
                     &mut self,
                     value: ::capnp::struct_list::Reader<
                         'a,
-                        crate::service_capnp::asset_path::Owned,
+                        crate::service_capnp::known_asset_metadata::Owned,
                     >,
                 ) -> ::capnp::Result<()> {
                     ::capnp::traits::SetPointerBuilder::set_pointer_builder(
@@ -4940,17 +9890,19 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     )
                 }
                 #[inline]
-                pub fn init_paths(
+                pub fn init_assets(
                     self,
                     size: u32,
-                ) -> ::capnp::struct_list::Builder<'a, crate::service_capnp::asset_path::Owned>
-                {
+                ) -> ::capnp::struct_list::Builder<
+                    'a,
+                    crate::service_capnp::known_asset_metadata::Owned,
+                > {
                     ::capnp::traits::FromPointerBuilder::init_pointer(
                         self.builder.get_pointer_field(0),
                         size,
                     )
                 }
-                pub fn has_paths(&self) -> bool {
+                pub fn has_assets(&self) -> bool {
                     !self.builder.get_pointer_field(0).is_null()
                 }
             }
@@ -4972,11 +9924,11 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     data: 0,
                     pointers: 1,
                 };
-                pub const TYPE_ID: u64 = 0xd014_f0e5_6f91_7900;
+                pub const TYPE_ID: u64 = 0xdded_b815_2da0_5b9c;
             }
         }
 
-        pub mod get_assets_for_paths_params {
+        pub mod get_asset_metadata_changes_results {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -5041,13 +9993,20 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.reader.total_size()
                 }
                 #[inline]
-                pub fn get_paths(self) -> ::capnp::Result<::capnp::data_list::Reader<'a>> {
+                pub fn get_changes(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Reader<
+                        'a,
+                        crate::service_capnp::asset_metadata_change::Owned,
+                    >,
+                > {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
                 }
-                pub fn has_paths(&self) -> bool {
+                pub fn has_changes(&self) -> bool {
                     !self.reader.get_pointer_field(0).is_null()
                 }
             }
@@ -5124,16 +10083,26 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.builder.into_reader().total_size()
                 }
                 #[inline]
-                pub fn get_paths(self) -> ::capnp::Result<::capnp::data_list::Builder<'a>> {
+                pub fn get_changes(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Builder<
+                        'a,
+                        crate::service_capnp::asset_metadata_change::Owned,
+                    >,
+                > {
                     ::capnp::traits::FromPointerBuilder::get_from_pointer(
                         self.builder.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
                 }
                 #[inline]
-                pub fn set_paths(
+                pub fn set_changes(
                     &mut self,
-                    value: ::capnp::data_list::Reader<'a>,
+                    value: ::capnp::struct_list::Reader<
+                        'a,
+                        crate::service_capnp::asset_metadata_change::Owned,
+                    >,
                 ) -> ::capnp::Result<()> {
                     ::capnp::traits::SetPointerBuilder::set_pointer_builder(
                         self.builder.get_pointer_field(0),
@@ -5142,39 +10111,262 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     )
                 }
                 #[inline]
-                pub fn init_paths(self, size: u32) -> ::capnp::data_list::Builder<'a> {
+                pub fn init_changes(
+                    self,
+                    size: u32,
+                ) -> ::capnp::struct_list::Builder<
+                    'a,
+                    crate::service_capnp::asset_metadata_change::Owned,
+                > {
                     ::capnp::traits::FromPointerBuilder::init_pointer(
                         self.builder.get_pointer_field(0),
                         size,
                     )
                 }
-                pub fn has_paths(&self) -> bool {
+                pub fn has_changes(&self) -> bool {
                     !self.builder.get_pointer_field(0).is_null()
                 }
             }
-
-            pub struct Pipeline {
-                _typeless: ::capnp::any_pointer::Pipeline,
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
+            }
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
+                    }
+                }
+            }
+            impl Pipeline {}
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 0,
+                    pointers: 1,
+                };
+                pub const TYPE_ID: u64 = 0x2a7e_a07b_8974_9cf8;
+            }
+        }
+    }
+
+    pub mod listener {
+        #![allow(unused_variables)]
+        pub type UpdateParams = ::capnp::capability::Params<
+            crate::service_capnp::asset_hub::listener::update_params::Owned,
+        >;
+        pub type UpdateResults = ::capnp::capability::Results<
+            crate::service_capnp::asset_hub::listener::update_results::Owned,
+        >;
+        pub type ImportErrorParams = ::capnp::capability::Params<
+            crate::service_capnp::asset_hub::listener::import_error_params::Owned,
+        >;
+        pub type ImportErrorResults = ::capnp::capability::Results<
+            crate::service_capnp::asset_hub::listener::import_error_results::Owned,
+        >;
+        pub type AssetsChangedParams = ::capnp::capability::Params<
+            crate::service_capnp::asset_hub::listener::assets_changed_params::Owned,
+        >;
+        pub type AssetsChangedResults = ::capnp::capability::Results<
+            crate::service_capnp::asset_hub::listener::assets_changed_results::Owned,
+        >;
+
+        pub struct Client {
+            pub client: ::capnp::capability::Client,
+        }
+        impl ::capnp::capability::FromClientHook for Client {
+            fn new(hook: Box<dyn (::capnp::private::capability::ClientHook)>) -> Client {
+                Client {
+                    client: ::capnp::capability::Client::new(hook),
+                }
+            }
+        }
+        #[derive(Copy, Clone)]
+        pub struct Owned;
+        impl<'a> ::capnp::traits::Owned<'a> for Owned {
+            type Reader = Client;
+            type Builder = Client;
+        }
+        impl ::capnp::traits::Pipelined for Owned {
+            type Pipeline = Client;
+        }
+        impl<'a> ::capnp::traits::FromPointerReader<'a> for Client {
+            fn get_from_pointer(
+                reader: &::capnp::private::layout::PointerReader<'a>,
+                _default: ::core::option::Option<&'a [capnp::Word]>,
+            ) -> ::capnp::Result<Client> {
+                ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(
+                    reader.get_capability()?,
+                ))
+            }
+        }
+        impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Client {
+            fn init_pointer(
+                _builder: ::capnp::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> Client {
+                unimplemented!()
+            }
+            fn get_from_pointer(
+                builder: ::capnp::private::layout::PointerBuilder<'a>,
+                _default: ::core::option::Option<&'a [capnp::Word]>,
+            ) -> ::capnp::Result<Client> {
+                ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(
+                    builder.get_capability()?,
+                ))
+            }
+        }
+
+        impl ::capnp::traits::SetPointerBuilder<Client> for Client {
+            fn set_pointer_builder(
+                pointer: ::capnp::private::layout::PointerBuilder,
+                from: Client,
+                _canonicalize: bool,
+            ) -> ::capnp::Result<()> {
+                pointer.set_capability(from.client.hook);
+                ::core::result::Result::Ok(())
+            }
+        }
+        impl ::capnp::traits::HasTypeId for Client {
+            #[inline]
+            fn type_id() -> u64 {
+                _private::TYPE_ID
+            }
+        }
+        impl Clone for Client {
+            fn clone(&self) -> Client {
+                Client {
+                    client: ::capnp::capability::Client::new(self.client.hook.add_ref()),
+                }
+            }
+        }
+        impl Client {
+            pub fn update_request(
+                &self,
+            ) -> ::capnp::capability::Request<
+                crate::service_capnp::asset_hub::listener::update_params::Owned,
+                crate::service_capnp::asset_hub::listener::update_results::Owned,
+            > {
+                self.client.new_call(_private::TYPE_ID, 0, None)
+            }
+            pub fn import_error_request(
+                &self,
+            ) -> ::capnp::capability::Request<
+                crate::service_capnp::asset_hub::listener::import_error_params::Owned,
+                crate::service_capnp::asset_hub::listener::import_error_results::Owned,
+            > {
+                self.client.new_call(_private::TYPE_ID, 1, None)
+            }
+            pub fn assets_changed_request(
+                &self,
+            ) -> ::capnp::capability::Request<
+                crate::service_capnp::asset_hub::listener::assets_changed_params::Owned,
+                crate::service_capnp::asset_hub::listener::assets_changed_results::Owned,
+            > {
+                self.client.new_call(_private::TYPE_ID, 2, None)
+            }
+        }
+        pub trait Server {
+            fn update(
+                &mut self,
+                _: UpdateParams,
+                _: UpdateResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+            fn import_error(
+                &mut self,
+                _: ImportErrorParams,
+                _: ImportErrorResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+            fn assets_changed(
+                &mut self,
+                _: AssetsChangedParams,
+                _: AssetsChangedResults,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                    "method not implemented".to_string(),
+                ))
+            }
+        }
+        pub struct ServerDispatch<_T> {
+            pub server: _T,
+        }
+        impl<_S: Server + 'static> ::capnp::capability::FromServer<_S> for Client {
+            type Dispatch = ServerDispatch<_S>;
+            fn from_server(s: _S) -> ServerDispatch<_S> {
+                ServerDispatch { server: s }
             }
-            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
-                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
-                    Pipeline {
-                        _typeless: typeless,
-                    }
+        }
+        impl<_T: Server> ::core::ops::Deref for ServerDispatch<_T> {
+            type Target = _T;
+            fn deref(&self) -> &_T {
+                &self.server
+            }
+        }
+        impl<_T: Server> ::core::ops::DerefMut for ServerDispatch<_T> {
+            fn deref_mut(&mut self) -> &mut _T {
+                &mut self.server
+            }
+        }
+        impl<_T: Server> ::capnp::capability::Server for ServerDispatch<_T> {
+            fn dispatch_call(
+                &mut self,
+                interface_id: u64,
+                method_id: u16,
+                params: ::capnp::capability::Params<::capnp::any_pointer::Owned>,
+                results: ::capnp::capability::Results<::capnp::any_pointer::Owned>,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                match interface_id {
+                    _private::TYPE_ID => ServerDispatch::<_T>::dispatch_call_internal(
+                        &mut self.server,
+                        method_id,
+                        params,
+                        results,
+                    ),
+                    _ => ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                        "Method not implemented.".to_string(),
+                    )),
                 }
             }
-            impl Pipeline {}
-            mod _private {
-                use capnp::private::layout;
-                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
-                    data: 0,
-                    pointers: 1,
-                };
-                pub const TYPE_ID: u64 = 0xbd84_fd41_613d_f6de;
+        }
+        impl<_T: Server> ServerDispatch<_T> {
+            pub fn dispatch_call_internal(
+                server: &mut _T,
+                method_id: u16,
+                params: ::capnp::capability::Params<::capnp::any_pointer::Owned>,
+                results: ::capnp::capability::Results<::capnp::any_pointer::Owned>,
+            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+                match method_id {
+                    0 => server.update(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    1 => server.import_error(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    2 => server.assets_changed(
+                        ::capnp::private::capability::internal_get_typed_params(params),
+                        ::capnp::private::capability::internal_get_typed_results(results),
+                    ),
+                    _ => ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
+                        "Method not implemented.".to_string(),
+                    )),
+                }
             }
         }
+        pub mod _private {
+            pub const TYPE_ID: u64 = 0xdf44_c664_bbec_8d36;
+        }
 
-        pub mod get_assets_for_paths_results {
+        pub mod update_params {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -5239,18 +10431,20 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.reader.total_size()
                 }
                 #[inline]
-                pub fn get_assets(
-                    self,
-                ) -> ::capnp::Result<
-                    ::capnp::struct_list::Reader<'a, crate::service_capnp::path_assets::Owned>,
-                > {
-                    ::capnp::traits::FromPointerReader::get_from_pointer(
-                        &self.reader.get_pointer_field(0),
-                        ::core::option::Option::None,
-                    )
+                pub fn get_latest_change(self) -> u64 {
+                    self.reader.get_data_field::<u64>(0)
                 }
-                pub fn has_assets(&self) -> bool {
-                    !self.reader.get_pointer_field(0).is_null()
+                #[inline]
+                pub fn get_snapshot(
+                    self,
+                ) -> ::capnp::Result<crate::service_capnp::asset_hub::snapshot::Client>
+                {
+                    match self.reader.get_pointer_field(0).get_capability() {
+                        ::core::result::Result::Ok(c) => {
+                            ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(c))
+                        }
+                        ::core::result::Result::Err(e) => ::core::result::Result::Err(e),
+                    }
                 }
             }
 
@@ -5326,43 +10520,33 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.builder.into_reader().total_size()
                 }
                 #[inline]
-                pub fn get_assets(
-                    self,
-                ) -> ::capnp::Result<
-                    ::capnp::struct_list::Builder<'a, crate::service_capnp::path_assets::Owned>,
-                > {
-                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
-                        self.builder.get_pointer_field(0),
-                        ::core::option::Option::None,
-                    )
+                pub fn get_latest_change(self) -> u64 {
+                    self.builder.get_data_field::<u64>(0)
                 }
                 #[inline]
-                pub fn set_assets(
-                    &mut self,
-                    value: ::capnp::struct_list::Reader<
-                        'a,
-                        crate::service_capnp::path_assets::Owned,
-                    >,
-                ) -> ::capnp::Result<()> {
-                    ::capnp::traits::SetPointerBuilder::set_pointer_builder(
-                        self.builder.get_pointer_field(0),
-                        value,
-                        false,
-                    )
+                pub fn set_latest_change(&mut self, value: u64) {
+                    self.builder.set_data_field::<u64>(0, value);
                 }
                 #[inline]
-                pub fn init_assets(
+                pub fn get_snapshot(
                     self,
-                    size: u32,
-                ) -> ::capnp::struct_list::Builder<'a, crate::service_capnp::path_assets::Owned>
+                ) -> ::capnp::Result<crate::service_capnp::asset_hub::snapshot::Client>
                 {
-                    ::capnp::traits::FromPointerBuilder::init_pointer(
-                        self.builder.get_pointer_field(0),
-                        size,
-                    )
+                    match self.builder.get_pointer_field(0).get_capability() {
+                        ::core::result::Result::Ok(c) => {
+                            ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(c))
+                        }
+                        ::core::result::Result::Err(e) => ::core::result::Result::Err(e),
+                    }
                 }
-                pub fn has_assets(&self) -> bool {
-                    !self.builder.get_pointer_field(0).is_null()
+                #[inline]
+                pub fn set_snapshot(
+                    &mut self,
+                    value: crate::service_capnp::asset_hub::snapshot::Client,
+                ) {
+                    self.builder
+                        .get_pointer_field(0)
+                        .set_capability(value.client.hook);
                 }
             }
 
@@ -5376,18 +10560,24 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     }
                 }
             }
-            impl Pipeline {}
+            impl Pipeline {
+                pub fn get_snapshot(&self) -> crate::service_capnp::asset_hub::snapshot::Client {
+                    ::capnp::capability::FromClientHook::new(
+                        self._typeless.get_pointer_field(0).as_cap(),
+                    )
+                }
+            }
             mod _private {
                 use capnp::private::layout;
                 pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
-                    data: 0,
+                    data: 1,
                     pointers: 1,
                 };
-                pub const TYPE_ID: u64 = 0xb340_5cff_7b80_0692;
+                pub const TYPE_ID: u64 = 0xb906_8ad0_71c0_62a8;
             }
         }
 
-        pub mod create_file_params {
+        pub mod update_results {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -5451,30 +10641,6 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
                     self.reader.total_size()
                 }
-                #[inline]
-                pub fn get_path(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
-                    ::capnp::traits::FromPointerReader::get_from_pointer(
-                        &self.reader.get_pointer_field(0),
-                        ::core::option::Option::None,
-                    )
-                }
-                pub fn has_path(&self) -> bool {
-                    !self.reader.get_pointer_field(0).is_null()
-                }
-                #[inline]
-                pub fn get_assets(
-                    self,
-                ) -> ::capnp::Result<
-                    ::capnp::struct_list::Reader<'a, crate::service_capnp::asset_data::Owned>,
-                > {
-                    ::capnp::traits::FromPointerReader::get_from_pointer(
-                        &self.reader.get_pointer_field(1),
-                        ::core::option::Option::None,
-                    )
-                }
-                pub fn has_assets(&self) -> bool {
-                    !self.reader.get_pointer_field(1).is_null()
-                }
             }
 
             pub struct Builder<'a> {
@@ -5542,68 +10708,11 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     Builder { ..*self }
                 }
                 pub fn reborrow_as_reader(&self) -> Reader {
-                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
-                }
-
-                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-                    self.builder.into_reader().total_size()
-                }
-                #[inline]
-                pub fn get_path(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
-                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
-                        self.builder.get_pointer_field(0),
-                        ::core::option::Option::None,
-                    )
-                }
-                #[inline]
-                pub fn set_path(&mut self, value: ::capnp::data::Reader) {
-                    self.builder.get_pointer_field(0).set_data(value);
-                }
-                #[inline]
-                pub fn init_path(self, size: u32) -> ::capnp::data::Builder<'a> {
-                    self.builder.get_pointer_field(0).init_data(size)
-                }
-                pub fn has_path(&self) -> bool {
-                    !self.builder.get_pointer_field(0).is_null()
-                }
-                #[inline]
-                pub fn get_assets(
-                    self,
-                ) -> ::capnp::Result<
-                    ::capnp::struct_list::Builder<'a, crate::service_capnp::asset_data::Owned>,
-                > {
-                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
-                        self.builder.get_pointer_field(1),
-                        ::core::option::Option::None,
-                    )
-                }
-                #[inline]
-                pub fn set_assets(
-                    &mut self,
-                    value: ::capnp::struct_list::Reader<
-                        'a,
-                        crate::service_capnp::asset_data::Owned,
-                    >,
-                ) -> ::capnp::Result<()> {
-                    ::capnp::traits::SetPointerBuilder::set_pointer_builder(
-                        self.builder.get_pointer_field(1),
-                        value,
-                        false,
-                    )
-                }
-                #[inline]
-                pub fn init_assets(
-                    self,
-                    size: u32,
-                ) -> ::capnp::struct_list::Builder<'a, crate::service_capnp::asset_data::Owned>
-                {
-                    ::capnp::traits::FromPointerBuilder::init_pointer(
-                        self.builder.get_pointer_field(1),
-                        size,
-                    )
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
                 }
-                pub fn has_assets(&self) -> bool {
-                    !self.builder.get_pointer_field(1).is_null()
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
                 }
             }
 
@@ -5622,13 +10731,13 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 use capnp::private::layout;
                 pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
                     data: 0,
-                    pointers: 2,
+                    pointers: 0,
                 };
-                pub const TYPE_ID: u64 = 0xe3fc_52d9_281d_0f60;
+                pub const TYPE_ID: u64 = 0xf88a_b972_b7a6_725c;
             }
         }
 
-        pub mod create_file_results {
+        pub mod import_error_params {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -5693,15 +10802,25 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.reader.total_size()
                 }
                 #[inline]
-                pub fn get_new_import_hash(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+                pub fn get_path(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
                 }
-                pub fn has_new_import_hash(&self) -> bool {
+                pub fn has_path(&self) -> bool {
                     !self.reader.get_pointer_field(0).is_null()
                 }
+                #[inline]
+                pub fn get_message(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(1),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_message(&self) -> bool {
+                    !self.reader.get_pointer_field(1).is_null()
+                }
             }
 
             pub struct Builder<'a> {
@@ -5776,23 +10895,41 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                     self.builder.into_reader().total_size()
                 }
                 #[inline]
-                pub fn get_new_import_hash(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+                pub fn get_path(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
                     ::capnp::traits::FromPointerBuilder::get_from_pointer(
                         self.builder.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
                 }
                 #[inline]
-                pub fn set_new_import_hash(&mut self, value: ::capnp::data::Reader) {
+                pub fn set_path(&mut self, value: ::capnp::data::Reader) {
                     self.builder.get_pointer_field(0).set_data(value);
                 }
                 #[inline]
-                pub fn init_new_import_hash(self, size: u32) -> ::capnp::data::Builder<'a> {
+                pub fn init_path(self, size: u32) -> ::capnp::data::Builder<'a> {
                     self.builder.get_pointer_field(0).init_data(size)
                 }
-                pub fn has_new_import_hash(&self) -> bool {
+                pub fn has_path(&self) -> bool {
                     !self.builder.get_pointer_field(0).is_null()
                 }
+                #[inline]
+                pub fn get_message(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(1),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_message(&mut self, value: ::capnp::text::Reader) {
+                    self.builder.get_pointer_field(1).set_text(value);
+                }
+                #[inline]
+                pub fn init_message(self, size: u32) -> ::capnp::text::Builder<'a> {
+                    self.builder.get_pointer_field(1).init_text(size)
+                }
+                pub fn has_message(&self) -> bool {
+                    !self.builder.get_pointer_field(1).is_null()
+                }
             }
 
             pub struct Pipeline {
@@ -5810,13 +10947,13 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 use capnp::private::layout;
                 pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
                     data: 0,
-                    pointers: 1,
+                    pointers: 2,
                 };
-                pub const TYPE_ID: u64 = 0xf6d1_9435_d082_079c;
+                pub const TYPE_ID: u64 = 0xc25e_93d1_6a07_f4b8;
             }
         }
 
-        pub mod delete_file_params {
+        pub mod import_error_results {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -5880,16 +11017,6 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
                     self.reader.total_size()
                 }
-                #[inline]
-                pub fn get_path(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
-                    ::capnp::traits::FromPointerReader::get_from_pointer(
-                        &self.reader.get_pointer_field(0),
-                        ::core::option::Option::None,
-                    )
-                }
-                pub fn has_path(&self) -> bool {
-                    !self.reader.get_pointer_field(0).is_null()
-                }
             }
 
             pub struct Builder<'a> {
@@ -5963,24 +11090,6 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
                     self.builder.into_reader().total_size()
                 }
-                #[inline]
-                pub fn get_path(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
-                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
-                        self.builder.get_pointer_field(0),
-                        ::core::option::Option::None,
-                    )
-                }
-                #[inline]
-                pub fn set_path(&mut self, value: ::capnp::data::Reader) {
-                    self.builder.get_pointer_field(0).set_data(value);
-                }
-                #[inline]
-                pub fn init_path(self, size: u32) -> ::capnp::data::Builder<'a> {
-                    self.builder.get_pointer_field(0).init_data(size)
-                }
-                pub fn has_path(&self) -> bool {
-                    !self.builder.get_pointer_field(0).is_null()
-                }
             }
 
             pub struct Pipeline {
@@ -5998,13 +11107,13 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 use capnp::private::layout;
                 pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
                     data: 0,
-                    pointers: 1,
+                    pointers: 0,
                 };
-                pub const TYPE_ID: u64 = 0xcd7c_2a1b_fc81_8409;
+                pub const TYPE_ID: u64 = 0xd7b0_4f2e_8c61_a935;
             }
         }
 
-        pub mod delete_file_results {
+        pub mod assets_changed_params {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -6068,6 +11177,20 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
                     self.reader.total_size()
                 }
+                #[inline]
+                pub fn get_changes(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Reader<'a, crate::service_capnp::asset_change::Owned>,
+                > {
+                    ::capnp::traits::FromPointerReader::get_from_pointer(
+                        &self.reader.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                pub fn has_changes(&self) -> bool {
+                    !self.reader.get_pointer_field(0).is_null()
+                }
             }
 
             pub struct Builder<'a> {
@@ -6113,221 +11236,97 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 ) -> ::capnp::Result<Builder<'a>> {
                     ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
                         builder.get_struct(_private::STRUCT_SIZE, default)?,
-                    ))
-                }
-            }
-
-            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
-                fn set_pointer_builder<'b>(
-                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
-                    value: Reader<'a>,
-                    canonicalize: bool,
-                ) -> ::capnp::Result<()> {
-                    pointer.set_struct(&value.reader, canonicalize)
-                }
-            }
-
-            impl<'a> Builder<'a> {
-                pub fn into_reader(self) -> Reader<'a> {
-                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
-                }
-                pub fn reborrow(&mut self) -> Builder {
-                    Builder { ..*self }
-                }
-                pub fn reborrow_as_reader(&self) -> Reader {
-                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
-                }
-
-                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-                    self.builder.into_reader().total_size()
-                }
-            }
-
-            pub struct Pipeline {
-                _typeless: ::capnp::any_pointer::Pipeline,
-            }
-            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
-                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
-                    Pipeline {
-                        _typeless: typeless,
-                    }
-                }
-            }
-            impl Pipeline {}
-            mod _private {
-                use capnp::private::layout;
-                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
-                    data: 0,
-                    pointers: 0,
-                };
-                pub const TYPE_ID: u64 = 0xce96_d824_50b6_20c1;
-            }
-        }
-    }
-
-    pub mod listener {
-        #![allow(unused_variables)]
-        pub type UpdateParams = ::capnp::capability::Params<
-            crate::service_capnp::asset_hub::listener::update_params::Owned,
-        >;
-        pub type UpdateResults = ::capnp::capability::Results<
-            crate::service_capnp::asset_hub::listener::update_results::Owned,
-        >;
-
-        pub struct Client {
-            pub client: ::capnp::capability::Client,
-        }
-        impl ::capnp::capability::FromClientHook for Client {
-            fn new(hook: Box<dyn (::capnp::private::capability::ClientHook)>) -> Client {
-                Client {
-                    client: ::capnp::capability::Client::new(hook),
-                }
-            }
-        }
-        #[derive(Copy, Clone)]
-        pub struct Owned;
-        impl<'a> ::capnp::traits::Owned<'a> for Owned {
-            type Reader = Client;
-            type Builder = Client;
-        }
-        impl ::capnp::traits::Pipelined for Owned {
-            type Pipeline = Client;
-        }
-        impl<'a> ::capnp::traits::FromPointerReader<'a> for Client {
-            fn get_from_pointer(
-                reader: &::capnp::private::layout::PointerReader<'a>,
-                _default: ::core::option::Option<&'a [capnp::Word]>,
-            ) -> ::capnp::Result<Client> {
-                ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(
-                    reader.get_capability()?,
-                ))
-            }
-        }
-        impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Client {
-            fn init_pointer(
-                _builder: ::capnp::private::layout::PointerBuilder<'a>,
-                _size: u32,
-            ) -> Client {
-                unimplemented!()
-            }
-            fn get_from_pointer(
-                builder: ::capnp::private::layout::PointerBuilder<'a>,
-                _default: ::core::option::Option<&'a [capnp::Word]>,
-            ) -> ::capnp::Result<Client> {
-                ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(
-                    builder.get_capability()?,
-                ))
-            }
-        }
-
-        impl ::capnp::traits::SetPointerBuilder<Client> for Client {
-            fn set_pointer_builder(
-                pointer: ::capnp::private::layout::PointerBuilder,
-                from: Client,
-                _canonicalize: bool,
-            ) -> ::capnp::Result<()> {
-                pointer.set_capability(from.client.hook);
-                ::core::result::Result::Ok(())
-            }
-        }
-        impl ::capnp::traits::HasTypeId for Client {
-            #[inline]
-            fn type_id() -> u64 {
-                _private::TYPE_ID
-            }
-        }
-        impl Clone for Client {
-            fn clone(&self) -> Client {
-                Client {
-                    client: ::capnp::capability::Client::new(self.client.hook.add_ref()),
-                }
-            }
-        }
-        impl Client {
-            pub fn update_request(
-                &self,
-            ) -> ::capnp::capability::Request<
-                crate::service_capnp::asset_hub::listener::update_params::Owned,
-                crate::service_capnp::asset_hub::listener::update_results::Owned,
-            > {
-                self.client.new_call(_private::TYPE_ID, 0, None)
-            }
-        }
-        pub trait Server {
-            fn update(
-                &mut self,
-                _: UpdateParams,
-                _: UpdateResults,
-            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-                ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                    "method not implemented".to_string(),
-                ))
-            }
-        }
-        pub struct ServerDispatch<_T> {
-            pub server: _T,
-        }
-        impl<_S: Server + 'static> ::capnp::capability::FromServer<_S> for Client {
-            type Dispatch = ServerDispatch<_S>;
-            fn from_server(s: _S) -> ServerDispatch<_S> {
-                ServerDispatch { server: s }
-            }
-        }
-        impl<_T: Server> ::core::ops::Deref for ServerDispatch<_T> {
-            type Target = _T;
-            fn deref(&self) -> &_T {
-                &self.server
+                    ))
+                }
             }
-        }
-        impl<_T: Server> ::core::ops::DerefMut for ServerDispatch<_T> {
-            fn deref_mut(&mut self) -> &mut _T {
-                &mut self.server
+
+            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+                fn set_pointer_builder<'b>(
+                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                    value: Reader<'a>,
+                    canonicalize: bool,
+                ) -> ::capnp::Result<()> {
+                    pointer.set_struct(&value.reader, canonicalize)
+                }
             }
-        }
-        impl<_T: Server> ::capnp::capability::Server for ServerDispatch<_T> {
-            fn dispatch_call(
-                &mut self,
-                interface_id: u64,
-                method_id: u16,
-                params: ::capnp::capability::Params<::capnp::any_pointer::Owned>,
-                results: ::capnp::capability::Results<::capnp::any_pointer::Owned>,
-            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-                match interface_id {
-                    _private::TYPE_ID => ServerDispatch::<_T>::dispatch_call_internal(
-                        &mut self.server,
-                        method_id,
-                        params,
-                        results,
-                    ),
-                    _ => ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                        "Method not implemented.".to_string(),
-                    )),
+
+            impl<'a> Builder<'a> {
+                pub fn into_reader(self) -> Reader<'a> {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+                pub fn reborrow(&mut self) -> Builder {
+                    Builder { ..*self }
+                }
+                pub fn reborrow_as_reader(&self) -> Reader {
+                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+                }
+
+                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                    self.builder.into_reader().total_size()
+                }
+                #[inline]
+                pub fn get_changes(
+                    self,
+                ) -> ::capnp::Result<
+                    ::capnp::struct_list::Builder<'a, crate::service_capnp::asset_change::Owned>,
+                > {
+                    ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                        self.builder.get_pointer_field(0),
+                        ::core::option::Option::None,
+                    )
+                }
+                #[inline]
+                pub fn set_changes(
+                    &mut self,
+                    value: ::capnp::struct_list::Reader<
+                        'a,
+                        crate::service_capnp::asset_change::Owned,
+                    >,
+                ) -> ::capnp::Result<()> {
+                    ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                        self.builder.get_pointer_field(0),
+                        value,
+                        false,
+                    )
+                }
+                #[inline]
+                pub fn init_changes(
+                    self,
+                    size: u32,
+                ) -> ::capnp::struct_list::Builder<'a, crate::service_capnp::asset_change::Owned>
+                {
+                    ::capnp::traits::FromPointerBuilder::init_pointer(
+                        self.builder.get_pointer_field(0),
+                        size,
+                    )
+                }
+                pub fn has_changes(&self) -> bool {
+                    !self.builder.get_pointer_field(0).is_null()
                 }
             }
-        }
-        impl<_T: Server> ServerDispatch<_T> {
-            pub fn dispatch_call_internal(
-                server: &mut _T,
-                method_id: u16,
-                params: ::capnp::capability::Params<::capnp::any_pointer::Owned>,
-                results: ::capnp::capability::Results<::capnp::any_pointer::Owned>,
-            ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-                match method_id {
-                    0 => server.update(
-                        ::capnp::private::capability::internal_get_typed_params(params),
-                        ::capnp::private::capability::internal_get_typed_results(results),
-                    ),
-                    _ => ::capnp::capability::Promise::err(::capnp::Error::unimplemented(
-                        "Method not implemented.".to_string(),
-                    )),
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
+            }
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
+                    }
                 }
             }
-        }
-        pub mod _private {
-            pub const TYPE_ID: u64 = 0xdf44_c664_bbec_8d36;
+            impl Pipeline {}
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 0,
+                    pointers: 1,
+                };
+                pub const TYPE_ID: u64 = 0x9f4d_2a87_e1c3_5b60;
+            }
         }
 
-        pub mod update_params {
+        pub mod assets_changed_results {
             #[derive(Copy, Clone)]
             pub struct Owned;
             impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -6391,22 +11390,6 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
                     self.reader.total_size()
                 }
-                #[inline]
-                pub fn get_latest_change(self) -> u64 {
-                    self.reader.get_data_field::<u64>(0)
-                }
-                #[inline]
-                pub fn get_snapshot(
-                    self,
-                ) -> ::capnp::Result<crate::service_capnp::asset_hub::snapshot::Client>
-                {
-                    match self.reader.get_pointer_field(0).get_capability() {
-                        ::core::result::Result::Ok(c) => {
-                            ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(c))
-                        }
-                        ::core::result::Result::Err(e) => ::core::result::Result::Err(e),
-                    }
-                }
             }
 
             pub struct Builder<'a> {
@@ -6480,226 +11463,384 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
                     self.builder.into_reader().total_size()
                 }
-                #[inline]
-                pub fn get_latest_change(self) -> u64 {
-                    self.builder.get_data_field::<u64>(0)
-                }
-                #[inline]
-                pub fn set_latest_change(&mut self, value: u64) {
-                    self.builder.set_data_field::<u64>(0, value);
-                }
-                #[inline]
-                pub fn get_snapshot(
-                    self,
-                ) -> ::capnp::Result<crate::service_capnp::asset_hub::snapshot::Client>
-                {
-                    match self.builder.get_pointer_field(0).get_capability() {
-                        ::core::result::Result::Ok(c) => {
-                            ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(c))
-                        }
-                        ::core::result::Result::Err(e) => ::core::result::Result::Err(e),
+            }
+
+            pub struct Pipeline {
+                _typeless: ::capnp::any_pointer::Pipeline,
+            }
+            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                    Pipeline {
+                        _typeless: typeless,
                     }
                 }
-                #[inline]
-                pub fn set_snapshot(
-                    &mut self,
-                    value: crate::service_capnp::asset_hub::snapshot::Client,
-                ) {
-                    self.builder
-                        .get_pointer_field(0)
-                        .set_capability(value.client.hook);
+            }
+            impl Pipeline {}
+            mod _private {
+                use capnp::private::layout;
+                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                    data: 0,
+                    pointers: 0,
+                };
+                pub const TYPE_ID: u64 = 0xe86a_0b3f_7d25_c491;
+            }
+        }
+    }
+
+    pub mod register_listener_params {
+        #[derive(Copy, Clone)]
+        pub struct Owned;
+        impl<'a> ::capnp::traits::Owned<'a> for Owned {
+            type Reader = Reader<'a>;
+            type Builder = Builder<'a>;
+        }
+        impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+            type Reader = Reader<'a>;
+            type Builder = Builder<'a>;
+        }
+        impl ::capnp::traits::Pipelined for Owned {
+            type Pipeline = Pipeline;
+        }
+
+        #[derive(Clone, Copy)]
+        pub struct Reader<'a> {
+            reader: ::capnp::private::layout::StructReader<'a>,
+        }
+
+        impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+            #[inline]
+            fn type_id() -> u64 {
+                _private::TYPE_ID
+            }
+        }
+        impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+            fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+                Reader { reader }
+            }
+        }
+
+        impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+            fn get_from_pointer(
+                reader: &::capnp::private::layout::PointerReader<'a>,
+                default: ::core::option::Option<&'a [capnp::Word]>,
+            ) -> ::capnp::Result<Reader<'a>> {
+                ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                    reader.get_struct(default)?,
+                ))
+            }
+        }
+
+        impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+            fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+                self.reader
+            }
+        }
+
+        impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+            fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+                self.reader
+                    .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+            }
+        }
+
+        impl<'a> Reader<'a> {
+            pub fn reborrow(&self) -> Reader {
+                Reader { ..*self }
+            }
+
+            pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                self.reader.total_size()
+            }
+            #[inline]
+            pub fn get_listener(
+                self,
+            ) -> ::capnp::Result<crate::service_capnp::asset_hub::listener::Client> {
+                match self.reader.get_pointer_field(0).get_capability() {
+                    ::core::result::Result::Ok(c) => {
+                        ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(c))
+                    }
+                    ::core::result::Result::Err(e) => ::core::result::Result::Err(e),
                 }
             }
+        }
 
-            pub struct Pipeline {
-                _typeless: ::capnp::any_pointer::Pipeline,
+        pub struct Builder<'a> {
+            builder: ::capnp::private::layout::StructBuilder<'a>,
+        }
+        impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+            #[inline]
+            fn struct_size() -> ::capnp::private::layout::StructSize {
+                _private::STRUCT_SIZE
             }
-            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
-                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
-                    Pipeline {
-                        _typeless: typeless,
-                    }
-                }
+        }
+        impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+            #[inline]
+            fn type_id() -> u64 {
+                _private::TYPE_ID
             }
-            impl Pipeline {
-                pub fn get_snapshot(&self) -> crate::service_capnp::asset_hub::snapshot::Client {
-                    ::capnp::capability::FromClientHook::new(
-                        self._typeless.get_pointer_field(0).as_cap(),
-                    )
-                }
+        }
+        impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+            fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+                Builder { builder }
             }
-            mod _private {
-                use capnp::private::layout;
-                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
-                    data: 1,
-                    pointers: 1,
-                };
-                pub const TYPE_ID: u64 = 0xb906_8ad0_71c0_62a8;
+        }
+
+        impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+            fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+                self.builder
+                    .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
             }
         }
 
-        pub mod update_results {
-            #[derive(Copy, Clone)]
-            pub struct Owned;
-            impl<'a> ::capnp::traits::Owned<'a> for Owned {
-                type Reader = Reader<'a>;
-                type Builder = Builder<'a>;
+        impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+            fn init_pointer(
+                builder: ::capnp::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> Builder<'a> {
+                ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
             }
-            impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
-                type Reader = Reader<'a>;
-                type Builder = Builder<'a>;
+            fn get_from_pointer(
+                builder: ::capnp::private::layout::PointerBuilder<'a>,
+                default: ::core::option::Option<&'a [capnp::Word]>,
+            ) -> ::capnp::Result<Builder<'a>> {
+                ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                    builder.get_struct(_private::STRUCT_SIZE, default)?,
+                ))
             }
-            impl ::capnp::traits::Pipelined for Owned {
-                type Pipeline = Pipeline;
+        }
+
+        impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+            fn set_pointer_builder<'b>(
+                pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                value: Reader<'a>,
+                canonicalize: bool,
+            ) -> ::capnp::Result<()> {
+                pointer.set_struct(&value.reader, canonicalize)
             }
+        }
 
-            #[derive(Clone, Copy)]
-            pub struct Reader<'a> {
-                reader: ::capnp::private::layout::StructReader<'a>,
+        impl<'a> Builder<'a> {
+            pub fn into_reader(self) -> Reader<'a> {
+                ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+            }
+            pub fn reborrow(&mut self) -> Builder {
+                Builder { ..*self }
+            }
+            pub fn reborrow_as_reader(&self) -> Reader {
+                ::capnp::traits::FromStructReader::new(self.builder.into_reader())
             }
 
-            impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
-                #[inline]
-                fn type_id() -> u64 {
-                    _private::TYPE_ID
-                }
+            pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                self.builder.into_reader().total_size()
             }
-            impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
-                fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
-                    Reader { reader }
+            #[inline]
+            pub fn get_listener(
+                self,
+            ) -> ::capnp::Result<crate::service_capnp::asset_hub::listener::Client> {
+                match self.builder.get_pointer_field(0).get_capability() {
+                    ::core::result::Result::Ok(c) => {
+                        ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(c))
+                    }
+                    ::core::result::Result::Err(e) => ::core::result::Result::Err(e),
                 }
             }
+            #[inline]
+            pub fn set_listener(
+                &mut self,
+                value: crate::service_capnp::asset_hub::listener::Client,
+            ) {
+                self.builder
+                    .get_pointer_field(0)
+                    .set_capability(value.client.hook);
+            }
+        }
 
-            impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
-                fn get_from_pointer(
-                    reader: &::capnp::private::layout::PointerReader<'a>,
-                    default: ::core::option::Option<&'a [capnp::Word]>,
-                ) -> ::capnp::Result<Reader<'a>> {
-                    ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
-                        reader.get_struct(default)?,
-                    ))
+        pub struct Pipeline {
+            _typeless: ::capnp::any_pointer::Pipeline,
+        }
+        impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+            fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                Pipeline {
+                    _typeless: typeless,
                 }
             }
+        }
+        impl Pipeline {
+            pub fn get_listener(&self) -> crate::service_capnp::asset_hub::listener::Client {
+                ::capnp::capability::FromClientHook::new(
+                    self._typeless.get_pointer_field(0).as_cap(),
+                )
+            }
+        }
+        mod _private {
+            use capnp::private::layout;
+            pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                data: 0,
+                pointers: 1,
+            };
+            pub const TYPE_ID: u64 = 0xc474_621f_7679_e4ca;
+        }
+    }
 
-            impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
-                fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
-                    self.reader
-                }
+    pub mod register_listener_results {
+        #[derive(Copy, Clone)]
+        pub struct Owned;
+        impl<'a> ::capnp::traits::Owned<'a> for Owned {
+            type Reader = Reader<'a>;
+            type Builder = Builder<'a>;
+        }
+        impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+            type Reader = Reader<'a>;
+            type Builder = Builder<'a>;
+        }
+        impl ::capnp::traits::Pipelined for Owned {
+            type Pipeline = Pipeline;
+        }
+
+        #[derive(Clone, Copy)]
+        pub struct Reader<'a> {
+            reader: ::capnp::private::layout::StructReader<'a>,
+        }
+
+        impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+            #[inline]
+            fn type_id() -> u64 {
+                _private::TYPE_ID
+            }
+        }
+        impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+            fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+                Reader { reader }
             }
+        }
 
-            impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
-                fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
-                    self.reader
-                        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
-                }
+        impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+            fn get_from_pointer(
+                reader: &::capnp::private::layout::PointerReader<'a>,
+                default: ::core::option::Option<&'a [capnp::Word]>,
+            ) -> ::capnp::Result<Reader<'a>> {
+                ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(
+                    reader.get_struct(default)?,
+                ))
             }
+        }
 
-            impl<'a> Reader<'a> {
-                pub fn reborrow(&self) -> Reader {
-                    Reader { ..*self }
-                }
+        impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+            fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+                self.reader
+            }
+        }
 
-                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-                    self.reader.total_size()
-                }
+        impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+            fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+                self.reader
+                    .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
             }
+        }
 
-            pub struct Builder<'a> {
-                builder: ::capnp::private::layout::StructBuilder<'a>,
+        impl<'a> Reader<'a> {
+            pub fn reborrow(&self) -> Reader {
+                Reader { ..*self }
             }
-            impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
-                #[inline]
-                fn struct_size() -> ::capnp::private::layout::StructSize {
-                    _private::STRUCT_SIZE
-                }
+
+            pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                self.reader.total_size()
             }
-            impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
-                #[inline]
-                fn type_id() -> u64 {
-                    _private::TYPE_ID
-                }
+        }
+
+        pub struct Builder<'a> {
+            builder: ::capnp::private::layout::StructBuilder<'a>,
+        }
+        impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+            #[inline]
+            fn struct_size() -> ::capnp::private::layout::StructSize {
+                _private::STRUCT_SIZE
             }
-            impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
-                fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
-                    Builder { builder }
-                }
+        }
+        impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+            #[inline]
+            fn type_id() -> u64 {
+                _private::TYPE_ID
+            }
+        }
+        impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+            fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+                Builder { builder }
             }
+        }
 
-            impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
-                fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
-                    self.builder
-                        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
-                }
+        impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+            fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+                self.builder
+                    .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
             }
+        }
 
-            impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
-                fn init_pointer(
-                    builder: ::capnp::private::layout::PointerBuilder<'a>,
-                    _size: u32,
-                ) -> Builder<'a> {
-                    ::capnp::traits::FromStructBuilder::new(
-                        builder.init_struct(_private::STRUCT_SIZE),
-                    )
-                }
-                fn get_from_pointer(
-                    builder: ::capnp::private::layout::PointerBuilder<'a>,
-                    default: ::core::option::Option<&'a [capnp::Word]>,
-                ) -> ::capnp::Result<Builder<'a>> {
-                    ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
-                        builder.get_struct(_private::STRUCT_SIZE, default)?,
-                    ))
-                }
+        impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+            fn init_pointer(
+                builder: ::capnp::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> Builder<'a> {
+                ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
             }
-
-            impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
-                fn set_pointer_builder<'b>(
-                    pointer: ::capnp::private::layout::PointerBuilder<'b>,
-                    value: Reader<'a>,
-                    canonicalize: bool,
-                ) -> ::capnp::Result<()> {
-                    pointer.set_struct(&value.reader, canonicalize)
-                }
+            fn get_from_pointer(
+                builder: ::capnp::private::layout::PointerBuilder<'a>,
+                default: ::core::option::Option<&'a [capnp::Word]>,
+            ) -> ::capnp::Result<Builder<'a>> {
+                ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+                    builder.get_struct(_private::STRUCT_SIZE, default)?,
+                ))
             }
+        }
 
-            impl<'a> Builder<'a> {
-                pub fn into_reader(self) -> Reader<'a> {
-                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
-                }
-                pub fn reborrow(&mut self) -> Builder {
-                    Builder { ..*self }
-                }
-                pub fn reborrow_as_reader(&self) -> Reader {
-                    ::capnp::traits::FromStructReader::new(self.builder.into_reader())
-                }
+        impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+            fn set_pointer_builder<'b>(
+                pointer: ::capnp::private::layout::PointerBuilder<'b>,
+                value: Reader<'a>,
+                canonicalize: bool,
+            ) -> ::capnp::Result<()> {
+                pointer.set_struct(&value.reader, canonicalize)
+            }
+        }
 
-                pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-                    self.builder.into_reader().total_size()
-                }
+        impl<'a> Builder<'a> {
+            pub fn into_reader(self) -> Reader<'a> {
+                ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+            }
+            pub fn reborrow(&mut self) -> Builder {
+                Builder { ..*self }
+            }
+            pub fn reborrow_as_reader(&self) -> Reader {
+                ::capnp::traits::FromStructReader::new(self.builder.into_reader())
             }
 
-            pub struct Pipeline {
-                _typeless: ::capnp::any_pointer::Pipeline,
+            pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+                self.builder.into_reader().total_size()
             }
-            impl ::capnp::capability::FromTypelessPipeline for Pipeline {
-                fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
-                    Pipeline {
-                        _typeless: typeless,
-                    }
+        }
+
+        pub struct Pipeline {
+            _typeless: ::capnp::any_pointer::Pipeline,
+        }
+        impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+            fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+                Pipeline {
+                    _typeless: typeless,
                 }
             }
-            impl Pipeline {}
-            mod _private {
-                use capnp::private::layout;
-                pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
-                    data: 0,
-                    pointers: 0,
-                };
-                pub const TYPE_ID: u64 = 0xf88a_b972_b7a6_725c;
-            }
+        }
+        impl Pipeline {}
+        mod _private {
+            use capnp::private::layout;
+            pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
+                data: 0,
+                pointers: 0,
+            };
+            pub const TYPE_ID: u64 = 0x9d68_9421_bd64_ee98;
         }
     }
 
-    pub mod register_listener_params {
+    pub mod get_snapshot_params {
         #[derive(Copy, Clone)]
         pub struct Owned;
         impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -6763,17 +11904,6 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
             pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
                 self.reader.total_size()
             }
-            #[inline]
-            pub fn get_listener(
-                self,
-            ) -> ::capnp::Result<crate::service_capnp::asset_hub::listener::Client> {
-                match self.reader.get_pointer_field(0).get_capability() {
-                    ::core::result::Result::Ok(c) => {
-                        ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(c))
-                    }
-                    ::core::result::Result::Err(e) => ::core::result::Result::Err(e),
-                }
-            }
         }
 
         pub struct Builder<'a> {
@@ -6845,26 +11975,6 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
             pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
                 self.builder.into_reader().total_size()
             }
-            #[inline]
-            pub fn get_listener(
-                self,
-            ) -> ::capnp::Result<crate::service_capnp::asset_hub::listener::Client> {
-                match self.builder.get_pointer_field(0).get_capability() {
-                    ::core::result::Result::Ok(c) => {
-                        ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(c))
-                    }
-                    ::core::result::Result::Err(e) => ::core::result::Result::Err(e),
-                }
-            }
-            #[inline]
-            pub fn set_listener(
-                &mut self,
-                value: crate::service_capnp::asset_hub::listener::Client,
-            ) {
-                self.builder
-                    .get_pointer_field(0)
-                    .set_capability(value.client.hook);
-            }
         }
 
         pub struct Pipeline {
@@ -6877,24 +11987,18 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 }
             }
         }
-        impl Pipeline {
-            pub fn get_listener(&self) -> crate::service_capnp::asset_hub::listener::Client {
-                ::capnp::capability::FromClientHook::new(
-                    self._typeless.get_pointer_field(0).as_cap(),
-                )
-            }
-        }
+        impl Pipeline {}
         mod _private {
             use capnp::private::layout;
             pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
                 data: 0,
-                pointers: 1,
+                pointers: 0,
             };
-            pub const TYPE_ID: u64 = 0xc474_621f_7679_e4ca;
+            pub const TYPE_ID: u64 = 0x8b18_92c4_9920_f998;
         }
     }
 
-    pub mod register_listener_results {
+    pub mod get_snapshot_results {
         #[derive(Copy, Clone)]
         pub struct Owned;
         impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -6958,6 +12062,17 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
             pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
                 self.reader.total_size()
             }
+            #[inline]
+            pub fn get_snapshot(
+                self,
+            ) -> ::capnp::Result<crate::service_capnp::asset_hub::snapshot::Client> {
+                match self.reader.get_pointer_field(0).get_capability() {
+                    ::core::result::Result::Ok(c) => {
+                        ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(c))
+                    }
+                    ::core::result::Result::Err(e) => ::core::result::Result::Err(e),
+                }
+            }
         }
 
         pub struct Builder<'a> {
@@ -7029,6 +12144,26 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
             pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
                 self.builder.into_reader().total_size()
             }
+            #[inline]
+            pub fn get_snapshot(
+                self,
+            ) -> ::capnp::Result<crate::service_capnp::asset_hub::snapshot::Client> {
+                match self.builder.get_pointer_field(0).get_capability() {
+                    ::core::result::Result::Ok(c) => {
+                        ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(c))
+                    }
+                    ::core::result::Result::Err(e) => ::core::result::Result::Err(e),
+                }
+            }
+            #[inline]
+            pub fn set_snapshot(
+                &mut self,
+                value: crate::service_capnp::asset_hub::snapshot::Client,
+            ) {
+                self.builder
+                    .get_pointer_field(0)
+                    .set_capability(value.client.hook);
+            }
         }
 
         pub struct Pipeline {
@@ -7041,18 +12176,24 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 }
             }
         }
-        impl Pipeline {}
+        impl Pipeline {
+            pub fn get_snapshot(&self) -> crate::service_capnp::asset_hub::snapshot::Client {
+                ::capnp::capability::FromClientHook::new(
+                    self._typeless.get_pointer_field(0).as_cap(),
+                )
+            }
+        }
         mod _private {
             use capnp::private::layout;
             pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
                 data: 0,
-                pointers: 0,
+                pointers: 1,
             };
-            pub const TYPE_ID: u64 = 0x9d68_9421_bd64_ee98;
+            pub const TYPE_ID: u64 = 0x9dd9_afd2_86e7_766c;
         }
     }
 
-    pub mod get_snapshot_params {
+    pub mod get_importers_params {
         #[derive(Copy, Clone)]
         pub struct Owned;
         impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -7206,11 +12347,11 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 data: 0,
                 pointers: 0,
             };
-            pub const TYPE_ID: u64 = 0x8b18_92c4_9920_f998;
+            pub const TYPE_ID: u64 = 0x91c2_35c6_aa8b_d536;
         }
     }
 
-    pub mod get_snapshot_results {
+    pub mod get_importers_results {
         #[derive(Copy, Clone)]
         pub struct Owned;
         impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -7275,15 +12416,18 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 self.reader.total_size()
             }
             #[inline]
-            pub fn get_snapshot(
+            pub fn get_importers(
                 self,
-            ) -> ::capnp::Result<crate::service_capnp::asset_hub::snapshot::Client> {
-                match self.reader.get_pointer_field(0).get_capability() {
-                    ::core::result::Result::Ok(c) => {
-                        ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(c))
-                    }
-                    ::core::result::Result::Err(e) => ::core::result::Result::Err(e),
-                }
+            ) -> ::capnp::Result<
+                ::capnp::struct_list::Reader<'a, crate::service_capnp::importer_info::Owned>,
+            > {
+                ::capnp::traits::FromPointerReader::get_from_pointer(
+                    &self.reader.get_pointer_field(0),
+                    ::core::option::Option::None,
+                )
+            }
+            pub fn has_importers(&self) -> bool {
+                !self.reader.get_pointer_field(0).is_null()
             }
         }
 
@@ -7357,24 +12501,40 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 self.builder.into_reader().total_size()
             }
             #[inline]
-            pub fn get_snapshot(
+            pub fn get_importers(
                 self,
-            ) -> ::capnp::Result<crate::service_capnp::asset_hub::snapshot::Client> {
-                match self.builder.get_pointer_field(0).get_capability() {
-                    ::core::result::Result::Ok(c) => {
-                        ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(c))
-                    }
-                    ::core::result::Result::Err(e) => ::core::result::Result::Err(e),
-                }
+            ) -> ::capnp::Result<
+                ::capnp::struct_list::Builder<'a, crate::service_capnp::importer_info::Owned>,
+            > {
+                ::capnp::traits::FromPointerBuilder::get_from_pointer(
+                    self.builder.get_pointer_field(0),
+                    ::core::option::Option::None,
+                )
             }
             #[inline]
-            pub fn set_snapshot(
+            pub fn set_importers(
                 &mut self,
-                value: crate::service_capnp::asset_hub::snapshot::Client,
-            ) {
-                self.builder
-                    .get_pointer_field(0)
-                    .set_capability(value.client.hook);
+                value: ::capnp::struct_list::Reader<'a, crate::service_capnp::importer_info::Owned>,
+            ) -> ::capnp::Result<()> {
+                ::capnp::traits::SetPointerBuilder::set_pointer_builder(
+                    self.builder.get_pointer_field(0),
+                    value,
+                    false,
+                )
+            }
+            #[inline]
+            pub fn init_importers(
+                self,
+                size: u32,
+            ) -> ::capnp::struct_list::Builder<'a, crate::service_capnp::importer_info::Owned>
+            {
+                ::capnp::traits::FromPointerBuilder::init_pointer(
+                    self.builder.get_pointer_field(0),
+                    size,
+                )
+            }
+            pub fn has_importers(&self) -> bool {
+                !self.builder.get_pointer_field(0).is_null()
             }
         }
 
@@ -7388,20 +12548,14 @@ pub fn get_asset_metadata_with_dependencies_request(&self) -> ::capnp::capabilit
                 }
             }
         }
-        impl Pipeline {
-            pub fn get_snapshot(&self) -> crate::service_capnp::asset_hub::snapshot::Client {
-                ::capnp::capability::FromClientHook::new(
-                    self._typeless.get_pointer_field(0).as_cap(),
-                )
-            }
-        }
+        impl Pipeline {}
         mod _private {
             use capnp::private::layout;
             pub const STRUCT_SIZE: layout::StructSize = layout::StructSize {
                 data: 0,
                 pointers: 1,
             };
-            pub const TYPE_ID: u64 = 0x9dd9_afd2_86e7_766c;
+            pub const TYPE_ID: u64 = 0xb801_3232_a154_cf3b;
         }
     }
 }